@@ -6,7 +6,8 @@ use super::{
     runs::{HomePlateRuns, Runs},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Base {
     First,
     Second,
@@ -35,9 +36,28 @@ impl Base {
         }
         current
     }
+
+    /// Bases from the plate: first is one, home is four.
+    fn number(self) -> u8 {
+        match self {
+            Base::First => 1,
+            Base::Second => 2,
+            Base::Third => 3,
+            Base::Home => 4,
+        }
+    }
+}
+
+/// Where a player ends a play: safe on a base, having scored, or retired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Destination {
+    Safe(Base),
+    Out,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BaseOutcome {
     ForceOut,
     TagOut,
@@ -78,6 +98,7 @@ impl BaseOutcome {
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HomeOutcome {
     pub runs: HomePlateRuns,
     pub outs: Outs,
@@ -131,6 +152,7 @@ impl HomeOutcome {
 /// runner is still on first". That is what `batter_out` is for; without it the
 /// runner would be overwritten by the out marker and silently vanish.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayOutcome {
     first: BaseOutcome,
     second: BaseOutcome,
@@ -306,6 +328,172 @@ impl PlayOutcome {
         }
     }
 
+    /// Every runner moves up exactly one base and the runner on third scores:
+    /// a wild pitch, a passed ball, or a balk.
+    pub fn runners_advance(baserunners: BaserunnerState) -> Self {
+        PlayOutcome {
+            first: BaseOutcome::None,
+            second: Self::occupant(baserunners.first()),
+            third: Self::occupant(baserunners.second()),
+            home: Self::scored(None, None, baserunners.third(), None),
+            batter_out: false,
+        }
+    }
+
+    /// The runner who started on the base before `base` takes it. Stealing home
+    /// scores the run. A steal that nobody could attempt — the base behind is
+    /// empty or the one ahead is still occupied — leaves everyone where they are.
+    pub fn stolen_base(baserunners: BaserunnerState, base: Base) -> Self {
+        let hold = Self::runners_hold(baserunners);
+        match base {
+            Base::First => hold,
+            Base::Second => match (baserunners.first(), baserunners.second()) {
+                (Some(runner), None) => hold
+                    .with_first(BaseOutcome::None)
+                    .with_second(BaseOutcome::Runner(runner)),
+                _ => hold,
+            },
+            Base::Third => match (baserunners.second(), baserunners.third()) {
+                (Some(runner), None) => hold
+                    .with_second(BaseOutcome::None)
+                    .with_third(BaseOutcome::Runner(runner)),
+                _ => hold,
+            },
+            Base::Home => match baserunners.third() {
+                Some(runner) => {
+                    hold.with_third(BaseOutcome::None)
+                        .with_home(Self::scored(None, None, Some(runner), None))
+                }
+                None => hold,
+            },
+        }
+    }
+
+    /// The runner who started on the base before `base` is tagged out trying to
+    /// take it. As with [`PlayOutcome::stolen_base`], an attempt nobody could
+    /// have made changes nothing.
+    pub fn caught_stealing(baserunners: BaserunnerState, base: Base) -> Self {
+        let hold = Self::runners_hold(baserunners);
+        match base {
+            Base::First => hold,
+            Base::Second => match (baserunners.first(), baserunners.second()) {
+                (Some(_), None) => hold.with_first(BaseOutcome::None).with_second(BaseOutcome::TagOut),
+                _ => hold,
+            },
+            Base::Third => match (baserunners.second(), baserunners.third()) {
+                (Some(_), None) => hold.with_second(BaseOutcome::None).with_third(BaseOutcome::TagOut),
+                _ => hold,
+            },
+            Base::Home => match baserunners.third() {
+                Some(_) => hold
+                    .with_third(BaseOutcome::None)
+                    .with_home(HomeOutcome::none().with_outs(Outs::One)),
+                None => hold,
+            },
+        }
+    }
+
+    /// The runner standing on `base` is caught off it and tagged.
+    pub fn pickoff(baserunners: BaserunnerState, base: Base) -> Self {
+        let hold = Self::runners_hold(baserunners);
+        if !baserunners.has_runner_on(base) {
+            return hold;
+        }
+        match base {
+            Base::First => hold.with_first(BaseOutcome::TagOut),
+            Base::Second => hold.with_second(BaseOutcome::TagOut),
+            Base::Third => hold.with_third(BaseOutcome::TagOut),
+            Base::Home => hold,
+        }
+    }
+
+    /// The play spelled out player by player: where the batter and the runners
+    /// on first, second and third end up, in that order. An empty base's entry
+    /// is ignored.
+    ///
+    /// Whatever the entries say, runners never pass one another and never go
+    /// backwards: anyone told to stop short of the runner behind them is pushed
+    /// along ahead of that runner. A retired runner is forced out if the batter
+    /// reaching base forced them, and tagged out otherwise; the out is marked on
+    /// the base they were running to, or at the plate if that base is taken.
+    pub fn from_destinations(
+        baserunners: BaserunnerState,
+        batter: BattingPosition,
+        destinations: [Destination; 4],
+    ) -> Self {
+        let batter_out = destinations[0] == Destination::Out;
+        let mut outcome = PlayOutcome {
+            first: BaseOutcome::None,
+            second: BaseOutcome::None,
+            third: BaseOutcome::None,
+            home: HomeOutcome::none(),
+            batter_out,
+        };
+        // A runner is forced when the batter reaching first leaves them no
+        // base to stand on.
+        let forced = [
+            !batter_out,
+            !batter_out && baserunners.first().is_some(),
+            !batter_out && baserunners.first().is_some() && baserunners.second().is_some(),
+        ];
+        let mut scorers = [None; 4];
+        let mut outs_at_home = Outs::Zero;
+        // The trailing player goes first, so the base they take pushes
+        // everyone ahead of them along.
+        let mut floor = 0;
+        let players = [
+            Some(batter),
+            baserunners.first(),
+            baserunners.second(),
+            baserunners.third(),
+        ];
+        for (start, (player, destination)) in (0_u8..).zip(players.into_iter().zip(destinations)) {
+            let Some(player) = player else {
+                continue;
+            };
+            match destination {
+                Destination::Out if start == 0 => {}
+                Destination::Out => {
+                    let marker = if forced[usize::from(start - 1)] {
+                        BaseOutcome::ForceOut
+                    } else {
+                        BaseOutcome::TagOut
+                    };
+                    match outcome.slot(start + 1) {
+                        Some(slot) if *slot == BaseOutcome::None => *slot = marker,
+                        _ => outs_at_home = outs_at_home.inc(),
+                    }
+                }
+                Destination::Safe(base) => {
+                    let reached = base.number().max(start.max(1)).max(floor + 1);
+                    floor = reached;
+                    match outcome.slot(reached) {
+                        Some(slot) => {
+                            if slot.is_out() {
+                                outs_at_home = outs_at_home.inc();
+                            }
+                            *slot = BaseOutcome::Runner(player);
+                        }
+                        None => scorers[usize::from(start)] = Some(player),
+                    }
+                }
+            }
+        }
+        let [batter, first, second, third] = scorers;
+        outcome.home = Self::scored(first, second, third, batter).with_outs(outs_at_home);
+        outcome
+    }
+
+    /// The slot for base `number`, or `None` for the plate.
+    fn slot(&mut self, number: u8) -> Option<&mut BaseOutcome> {
+        match number {
+            1 => Some(&mut self.first),
+            2 => Some(&mut self.second),
+            3 => Some(&mut self.third),
+            _ => None,
+        }
+    }
+
     pub fn outs(self) -> Outs {
         let batter = if self.batter_out { Outs::One } else { Outs::Zero };
         self.first().outs() + self.second().outs() + self.third().outs() + self.home.outs() + batter
@@ -397,6 +585,7 @@ impl PlayOutcome {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaserunnerState {
     first: Option<BattingPosition>,
     second: Option<BattingPosition>,
@@ -470,18 +659,23 @@ impl BaserunnerState {
         }
     }
 
+    /// The batter takes first, and only the runners forced by that move up:
+    /// a runner on third with first base open stays where they are.
     pub fn walk(&self, batter: BattingPosition) -> (BaserunnerState, Runs) {
-        let mut new_state = BaserunnerState::new().set_first(Some(batter));
+        let mut new_state = BaserunnerState::new()
+            .set_first(Some(batter))
+            .set_second(self.second)
+            .set_third(self.third);
         let mut runs_scored = Runs::default();
 
         if let Some(runner) = self.first {
             new_state = new_state.set_second(Some(runner));
-        }
-        if let Some(runner) = self.second {
-            new_state = new_state.set_third(Some(runner));
-        }
-        if self.third.is_some() {
-            runs_scored += 1;
+            if let Some(runner) = self.second {
+                new_state = new_state.set_third(Some(runner));
+                if self.third.is_some() {
+                    runs_scored += 1;
+                }
+            }
         }
 
         (new_state, runs_scored)
@@ -549,6 +743,27 @@ mod tests {
         assert!(state.has_runner_on(Base::Third));
     }
 
+    #[test]
+    fn a_walk_moves_only_the_runners_it_forces() {
+        let batter = BattingPosition::Fifth;
+        let corners = BaserunnerState::new()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Third));
+        let (after, runs) = corners.walk(batter);
+        assert_eq!(runs, 0);
+        assert_eq!(after.first(), Some(batter));
+        assert_eq!(after.second(), Some(BattingPosition::First));
+        assert_eq!(after.third(), Some(BattingPosition::Third));
+
+        let third_only = BaserunnerState::new().set_third(Some(BattingPosition::Third));
+        assert_eq!(third_only.walk(batter), (third_only.set_first(Some(batter)), 0));
+
+        let loaded = corners.set_second(Some(BattingPosition::Second));
+        let (after, runs) = loaded.walk(batter);
+        assert_eq!(runs, 1);
+        assert_eq!(after.third(), Some(BattingPosition::Second));
+    }
+
     #[test]
     fn test_home_outcome_creation() {
         let outcome = HomeOutcome::new().with_runs(HomePlateRuns::One).with_outs(Outs::Two);
@@ -0,0 +1,523 @@
+//! The box score, written up pitch by pitch as the game is played.
+//!
+//! [`Game`](super::game::Game) hands over every pitch together with the half
+//! inning as it stood before and after, and this module turns the difference
+//! into the same columns a baseball-reference box score prints. Batting lines
+//! are kept per slot and per player: a pinch hitter opens a new line under the
+//! slot they took over, so the order lines were opened is the order the box
+//! lists them in.
+
+use super::{
+    baserunners::BaserunnerState,
+    inning::{HalfInning, InningHalf},
+    lineup::{BattingPosition, PlayerPosition},
+    plate_appearance::{PitchOutcome, PlateAppearanceResult},
+    play::{PlayResult, Scoring},
+    roster::{Lineup, PlayerId, Substitution},
+    runs::Runs,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Decision {
+    Win,
+    Loss,
+    /// Finished a win that went to someone else, with the lead in danger, as
+    /// rule 9.19 has it.
+    Save,
+}
+
+/// One hitter's line. `player` is `None` in a game started without lineup
+/// cards, where the slot is the only identity there is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BattingLine {
+    pub slot: BattingPosition,
+    pub player: Option<PlayerId>,
+    pub plate_appearances: u16,
+    pub at_bats: u16,
+    pub runs: u16,
+    pub hits: u16,
+    pub doubles: u16,
+    pub triples: u16,
+    pub home_runs: u16,
+    pub runs_batted_in: u16,
+    pub walks: u16,
+    pub strikeouts: u16,
+}
+
+impl BattingLine {
+    fn new(slot: BattingPosition, player: Option<PlayerId>) -> Self {
+        BattingLine {
+            slot,
+            player,
+            plate_appearances: 0,
+            at_bats: 0,
+            runs: 0,
+            hits: 0,
+            doubles: 0,
+            triples: 0,
+            home_runs: 0,
+            runs_batted_in: 0,
+            walks: 0,
+            strikeouts: 0,
+        }
+    }
+
+    /// A plate appearance that ended with the ball in play, and the runs that
+    /// scored on it.
+    fn credit_play(&mut self, play: PlayResult, runs: u16) {
+        self.plate_appearances += 1;
+        if play.is_at_bat() {
+            self.at_bats += 1;
+        }
+        if play.is_hit() {
+            self.hits += 1;
+        }
+        match play.scoring() {
+            Scoring::Double => self.doubles += 1,
+            Scoring::Triple => self.triples += 1,
+            Scoring::Strikeout => self.strikeouts += 1,
+            _ => {}
+        }
+        // Rule 9.04(b): no RBI on a ground ball double play, nor on a run that
+        // scores because of an error.
+        if !matches!(play, PlayResult::DoublePlay | PlayResult::TriplePlay) && play.error().is_none() {
+            self.runs_batted_in += runs;
+        }
+    }
+}
+
+/// One pitcher's line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PitchingLine {
+    pub player: Option<PlayerId>,
+    pub outs_recorded: u16,
+    pub pitches: u16,
+    pub strikes: u16,
+    pub hits: u16,
+    pub walks: u16,
+    pub strikeouts: u16,
+    pub runs: u16,
+    pub earned_runs: u16,
+    pub decision: Option<Decision>,
+    /// The team's lead when this pitcher came in, for deciding a save.
+    entered_with_lead: i16,
+    /// Runners the other side had on base when this pitcher came in, which
+    /// says whether the tying run was on base, at bat or on deck.
+    #[cfg_attr(feature = "serde", serde(default))]
+    entered_with_on_base: u8,
+}
+
+impl PitchingLine {
+    fn new(player: Option<PlayerId>, entered_with_lead: i16, entered_with_on_base: u8) -> Self {
+        PitchingLine {
+            player,
+            outs_recorded: 0,
+            pitches: 0,
+            strikes: 0,
+            hits: 0,
+            walks: 0,
+            strikeouts: 0,
+            runs: 0,
+            earned_runs: 0,
+            decision: None,
+            entered_with_lead,
+            entered_with_on_base,
+        }
+    }
+
+    /// Rule 9.19(c) and (d), for the last pitcher of a winning team who did not
+    /// get the win: at least one out, and came in with a lead and either
+    ///
+    /// 1. a lead of three runs or fewer, and pitched at least an inning,
+    /// 2. the tying run on base, at bat or on deck, or
+    /// 3. pitched at least three innings.
+    ///
+    /// The rule asks for three innings pitched "effectively", which is the
+    /// scorer's judgement; any three innings that finish a win are taken to be.
+    fn earns_save(&self) -> bool {
+        let lead = self.entered_with_lead;
+        let tying_run_near = lead <= i16::from(self.entered_with_on_base) + 2;
+        self.outs_recorded >= 1
+            && lead >= 1
+            && ((lead <= 3 && self.outs_recorded >= 3) || tying_run_near || self.outs_recorded >= 9)
+    }
+
+    /// Innings pitched in the usual notation, where `6.2` means six and two
+    /// thirds.
+    pub fn innings_pitched(&self) -> String {
+        format!("{}.{}", self.outs_recorded / 3, self.outs_recorded % 3)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TeamBox {
+    batting: Vec<BattingLine>,
+    pitching: Vec<PitchingLine>,
+    /// The fielder charged with each error, in the order they were made.
+    errors: Vec<PlayerPosition>,
+}
+
+impl TeamBox {
+    fn new(lineup: Option<&Lineup>) -> Self {
+        let batting = BattingPosition::ALL
+            .into_iter()
+            .map(|slot| BattingLine::new(slot, lineup.map(|lineup| lineup.player_at(slot))))
+            .collect();
+        TeamBox {
+            batting,
+            pitching: vec![PitchingLine::new(lineup.map(Lineup::pitcher), 0, 0)],
+            errors: Vec::new(),
+        }
+    }
+
+    /// Every batting line, in the order the players entered.
+    pub fn batting(&self) -> &[BattingLine] {
+        &self.batting
+    }
+
+    /// Every pitcher used, in the order they pitched.
+    pub fn pitching(&self) -> &[PitchingLine] {
+        &self.pitching
+    }
+
+    pub fn batting_line(&self, player: PlayerId) -> Option<&BattingLine> {
+        self.batting.iter().find(|line| line.player == Some(player))
+    }
+
+    pub fn pitching_line(&self, player: PlayerId) -> Option<&PitchingLine> {
+        self.pitching.iter().find(|line| line.player == Some(player))
+    }
+
+    pub fn hits(&self) -> u16 {
+        self.batting.iter().map(|line| line.hits).sum()
+    }
+
+    pub fn runs(&self) -> u16 {
+        self.batting.iter().map(|line| line.runs).sum()
+    }
+
+    /// Errors this team made in the field.
+    pub fn errors(&self) -> &[PlayerPosition] {
+        &self.errors
+    }
+
+    pub fn errors_by(&self, fielder: PlayerPosition) -> u16 {
+        let errors = self.errors.iter().filter(|&&error| error == fielder).count();
+        u16::try_from(errors).unwrap_or(u16::MAX)
+    }
+
+    /// Whoever fills `slot` now: the most recently opened line for it.
+    fn current_batter(&mut self, slot: BattingPosition) -> &mut BattingLine {
+        let index = self
+            .batting
+            .iter()
+            .rposition(|line| line.slot == slot)
+            .expect("every slot has a line from the start");
+        &mut self.batting[index]
+    }
+
+    fn current_pitcher_index(&self) -> usize {
+        self.pitching.len() - 1
+    }
+}
+
+/// The pitcher a runner's run will be charged to, and whether it counts
+/// against their earned run average.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Charge {
+    pitcher: usize,
+    earned: bool,
+}
+
+/// Who put a runner on base, so their run is charged to the right pitcher
+/// even after a reliever has come in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Responsibility([Option<Charge>; 9]);
+
+/// The lead last changed hands here. At the end of the game this names the
+/// winning and losing pitchers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct GoAhead {
+    team: InningHalf,
+    winner: usize,
+    loser: usize,
+}
+
+/// What happened on one pitch, as [`BoxScore::record`] needs it.
+pub(crate) struct PitchRecord {
+    pub half: InningHalf,
+    pub outcome: PitchOutcome,
+    /// The half inning before the pitch was thrown.
+    pub before: HalfInning,
+    /// The runners after the pitch, or `None` if it ended the half inning.
+    pub runners_after: Option<BaserunnerState>,
+    pub outs_recorded: u8,
+    pub runs_scored: Runs,
+    /// Runs for the batting and fielding team before the pitch, counting runs
+    /// already in this half inning.
+    pub batting_runs: Runs,
+    pub fielding_runs: Runs,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxScore {
+    away: TeamBox,
+    home: TeamBox,
+    responsible: Responsibility,
+    go_ahead: Option<GoAhead>,
+}
+
+impl BoxScore {
+    pub(crate) fn new(away: Option<&Lineup>, home: Option<&Lineup>) -> Self {
+        BoxScore {
+            away: TeamBox::new(away),
+            home: TeamBox::new(home),
+            responsible: Responsibility::default(),
+            go_ahead: None,
+        }
+    }
+
+    pub fn away(&self) -> &TeamBox {
+        &self.away
+    }
+
+    pub fn home(&self) -> &TeamBox {
+        &self.home
+    }
+
+    /// The box of the team that bats in `half`.
+    pub fn team(&self, half: InningHalf) -> &TeamBox {
+        match half {
+            InningHalf::Top => &self.away,
+            InningHalf::Bottom => &self.home,
+        }
+    }
+
+    fn teams_mut(&mut self, batting: InningHalf) -> (&mut TeamBox, &mut TeamBox) {
+        match batting {
+            InningHalf::Top => (&mut self.away, &mut self.home),
+            InningHalf::Bottom => (&mut self.home, &mut self.away),
+        }
+    }
+
+    /// Opens new lines for whoever a substitution brought in.
+    pub(crate) fn record_substitution(
+        &mut self,
+        team: InningHalf,
+        lineup: &Lineup,
+        substitution: Substitution,
+        lead: i16,
+        on_base: u8,
+    ) {
+        let (own, _) = self.teams_mut(team);
+        match substitution {
+            Substitution::PinchHitter { slot, player }
+            | Substitution::PinchRunner { slot, player }
+            | Substitution::DefensiveReplacement { slot, player } => {
+                own.batting.push(BattingLine::new(slot, Some(player)));
+            }
+            Substitution::DefensiveSwitch { .. } => {}
+            Substitution::PitchingChange { player } => {
+                own.pitching.push(PitchingLine::new(Some(player), lead, on_base));
+                if let Some(slot) = BattingPosition::ALL
+                    .into_iter()
+                    .find(|&slot| lineup.player_at(slot) == player)
+                {
+                    own.batting.push(BattingLine::new(slot, Some(player)));
+                }
+            }
+        }
+    }
+
+    /// Puts the automatic extra-inning runner on base. Rule 9.16(a)(2)(B)
+    /// treats them as having reached on an error, so their run is unearned.
+    pub(crate) fn place_runner(&mut self, half: InningHalf, runner: BattingPosition) {
+        let (_, fielding) = self.teams_mut(half);
+        let pitcher = fielding.current_pitcher_index();
+        self.responsible.0[runner.index()] = Some(Charge { pitcher, earned: false });
+    }
+
+    pub(crate) fn record(&mut self, pitch: &PitchRecord) {
+        let batter = pitch.before.current_batter();
+        let result = pitch.before.current_plate_appearance().advance(pitch.outcome);
+        let (batting, fielding) = self.teams_mut(pitch.half);
+        let pitcher_index = fielding.current_pitcher_index();
+
+        // The pitch itself.
+        let pitcher = &mut fielding.pitching[pitcher_index];
+        match pitch.outcome {
+            PitchOutcome::Baserunning(_) => {}
+            PitchOutcome::Ball | PitchOutcome::HitByPitch => pitcher.pitches += 1,
+            PitchOutcome::Strike | PitchOutcome::Foul | PitchOutcome::InPlay(_) | PitchOutcome::HomeRun => {
+                pitcher.pitches += 1;
+                pitcher.strikes += 1;
+            }
+        }
+        pitcher.outs_recorded += u16::from(pitch.outs_recorded);
+
+        // The plate appearance, if this pitch finished it.
+        let line = batting.current_batter(batter);
+        let runs = u16::from(pitch.runs_scored);
+        match result {
+            PlateAppearanceResult::InProgress(_) => {}
+            PlateAppearanceResult::Strikeout => {
+                line.plate_appearances += 1;
+                line.at_bats += 1;
+                line.strikeouts += 1;
+                pitcher.strikeouts += 1;
+            }
+            PlateAppearanceResult::Walk => {
+                line.plate_appearances += 1;
+                line.walks += 1;
+                line.runs_batted_in += runs;
+                pitcher.walks += 1;
+            }
+            PlateAppearanceResult::HitByPitch => {
+                line.plate_appearances += 1;
+                line.runs_batted_in += runs;
+            }
+            PlateAppearanceResult::HomeRun => {
+                line.plate_appearances += 1;
+                line.at_bats += 1;
+                line.hits += 1;
+                line.home_runs += 1;
+                line.runs_batted_in += runs;
+                pitcher.hits += 1;
+            }
+            PlateAppearanceResult::InPlay(play) => {
+                line.credit_play(play, runs);
+                if play.is_hit() {
+                    pitcher.hits += 1;
+                }
+                if play.scoring() == Scoring::Strikeout {
+                    pitcher.strikeouts += 1;
+                }
+                if let Some(fielder) = play.error() {
+                    fielding.errors.push(fielder);
+                }
+            }
+        }
+        // Rule 9.16(a): a run that only scores because of an error is unearned,
+        // and so is the run of a batter who reached on one.
+        let earned = !matches!(result, PlateAppearanceResult::InPlay(play) if play.error().is_some());
+
+        // A finished plate appearance starts the batter's responsibility afresh,
+        // so a home run is never charged to whoever put them on base last time.
+        if result.is_complete() {
+            self.responsible.0[batter.index()] = None;
+        }
+
+        // Runners can never pass each other, so whoever scored were the lead
+        // runners, with the batter last in line behind them.
+        let runners = pitch.before.baserunners();
+        let scorers = [runners.third(), runners.second(), runners.first(), Some(batter)]
+            .into_iter()
+            .flatten()
+            .take(usize::from(pitch.runs_scored));
+        let mut batting_runs = pitch.batting_runs;
+        for scorer in scorers {
+            let mut charge = self.responsible.0[scorer.index()].take().unwrap_or(Charge {
+                pitcher: pitcher_index,
+                earned: true,
+            });
+            charge.earned &= earned;
+            let (batting, fielding) = self.teams_mut(pitch.half);
+            batting.current_batter(scorer).runs += 1;
+            let charged_line = &mut fielding.pitching[charge.pitcher];
+            charged_line.runs += 1;
+            charged_line.earned_runs += u16::from(charge.earned);
+
+            batting_runs += 1;
+            if batting_runs == pitch.fielding_runs + 1 {
+                self.go_ahead = Some(GoAhead {
+                    team: pitch.half,
+                    winner: batting.current_pitcher_index(),
+                    loser: charge.pitcher,
+                });
+            }
+        }
+
+        // Whoever the pitch put on base is the current pitcher's responsibility.
+        match pitch.runners_after {
+            Some(after) => {
+                if result.is_complete() && [after.first(), after.second(), after.third()].contains(&Some(batter)) {
+                    self.responsible.0[batter.index()] = Some(Charge {
+                        pitcher: pitcher_index,
+                        earned,
+                    });
+                }
+            }
+            None => self.responsible = Responsibility::default(),
+        }
+    }
+
+    /// Hands out the win, the loss and the save once the result is final.
+    ///
+    /// The winning pitcher is whoever was pitching for the winners when they
+    /// took the lead for good, and the loser is the pitcher charged with the run
+    /// that gave it to them. The save goes to the winners' last pitcher if they
+    /// are not also the winner and the save rule is met; see
+    /// [`PitchingLine::earns_save`].
+    pub(crate) fn decide(&mut self) {
+        let Some(go_ahead) = self.go_ahead else {
+            return;
+        };
+        let (winners, losers) = self.teams_mut(go_ahead.team);
+        winners.pitching[go_ahead.winner].decision = Some(Decision::Win);
+        losers.pitching[go_ahead.loser].decision = Some(Decision::Loss);
+
+        let closer = winners.current_pitcher_index();
+        let line = &mut winners.pitching[closer];
+        if closer != go_ahead.winner && line.earns_save() {
+            line.decision = Some(Decision::Save);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::roster, *};
+
+    #[test]
+    fn innings_pitched_reads_in_thirds() {
+        let mut line = PitchingLine::new(None, 0, 0);
+        line.outs_recorded = 20;
+        assert_eq!(line.innings_pitched(), "6.2");
+    }
+
+    #[test]
+    fn a_new_box_has_a_line_for_every_starter() {
+        let lineup = roster::tests::lineup();
+        let box_score = BoxScore::new(Some(&lineup), None);
+
+        assert_eq!(box_score.away().batting().len(), 9);
+        assert_eq!(box_score.away().batting()[3].player, Some(PlayerId(4)));
+        assert_eq!(box_score.away().pitching()[0].player, Some(PlayerId(10)));
+        assert_eq!(box_score.home().batting()[0].player, None, "anonymous team");
+    }
+
+    #[test]
+    fn a_pinch_hitter_opens_a_second_line_under_the_same_slot() {
+        let lineup = roster::tests::lineup();
+        let mut box_score = BoxScore::new(Some(&lineup), Some(&lineup));
+        let substitution = Substitution::PinchHitter {
+            slot: BattingPosition::Ninth,
+            player: PlayerId(11),
+        };
+        let lineup = lineup.substitute(substitution).expect("bench player");
+        box_score.record_substitution(InningHalf::Top, &lineup, substitution, 0, 0);
+
+        let batting = box_score.away().batting();
+        assert_eq!(batting.len(), 10);
+        assert_eq!(batting[9].slot, BattingPosition::Ninth);
+        assert_eq!(batting[9].player, Some(PlayerId(11)));
+    }
+}
@@ -0,0 +1,300 @@
+//! Everything that has happened in a game, in order.
+//!
+//! [`Game`] is a value that every pitch replaces, so on its own it forgets how
+//! it got where it is. The log remembers: the rules and lineup cards the game
+//! started from, then every pitch and substitution together with the outs,
+//! runners and score it left behind. Replaying the events from the start
+//! rebuilds any intermediate `Game` exactly, which is what undo, save/load and
+//! recorded regression fixtures are built on. The recorded state after each
+//! event is a check, not an input: a replay that does not land on it again is
+//! reported rather than trusted.
+
+use std::fmt::Display;
+
+use super::{
+    baserunners::BaserunnerState,
+    game::{Game, GameOutcome, GameScore, InningNumber},
+    inning::{InningHalf, Outs},
+    plate_appearance::PitchOutcome,
+    roster::{Lineup, LineupError, Substitution},
+    rule_set::RuleSet,
+};
+
+/// Something that changes the state of a game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameEvent {
+    Pitch(PitchOutcome),
+    /// A change to the lineup of the team that bats in `team`.
+    Substitution {
+        team: InningHalf,
+        substitution: Substitution,
+    },
+}
+
+/// Where the game stood right after an event.
+///
+/// For the pitch that ends a half inning this is the half as it ended, with
+/// three outs, rather than the fresh half that follows. The score counts runs
+/// already in across the plate in the half under way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventState {
+    pub inning: InningNumber,
+    pub half: InningHalf,
+    pub outs: Outs,
+    pub baserunners: BaserunnerState,
+    pub score: GameScore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoggedEvent {
+    pub event: GameEvent,
+    pub state: EventState,
+}
+
+/// The first event at which two logs disagree. `None` on either side means
+/// that log had already ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Divergence {
+    pub index: usize,
+    pub ours: Option<LoggedEvent>,
+    pub theirs: Option<LoggedEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayError {
+    /// The game was already over when this event came.
+    GameOver { index: usize },
+    /// The substitution was refused on replay.
+    Substitution { index: usize, error: LineupError },
+    /// The event replayed, but not to the state recorded for it.
+    Diverged { index: usize },
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::GameOver { index } => write!(f, "event {index} comes after the final out"),
+            ReplayError::Substitution { index, error } => {
+                write!(f, "event {index} is an illegal substitution: {error}")
+            }
+            ReplayError::Diverged { index } => write!(f, "event {index} does not replay to the state recorded for it"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// The append-only record of a game. Only [`Game`] appends to it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventLog {
+    rules: RuleSet,
+    away_lineup: Option<Box<Lineup>>,
+    home_lineup: Option<Box<Lineup>>,
+    events: Vec<LoggedEvent>,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new(RuleSet::default(), None, None)
+    }
+}
+
+impl EventLog {
+    pub(crate) fn new(rules: RuleSet, away_lineup: Option<&Lineup>, home_lineup: Option<&Lineup>) -> Self {
+        EventLog {
+            rules,
+            away_lineup: away_lineup.cloned().map(Box::new),
+            home_lineup: home_lineup.cloned().map(Box::new),
+            events: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, event: GameEvent, state: EventState) {
+        self.events.push(LoggedEvent { event, state });
+    }
+
+    pub fn rules(&self) -> RuleSet {
+        self.rules
+    }
+
+    pub fn events(&self) -> &[LoggedEvent] {
+        &self.events
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// The game as it was before the first event.
+    ///
+    /// # Panics
+    ///
+    /// Never for a log a [`Game`] kept: its cards were accepted under the same
+    /// rules when that game started.
+    pub fn start(&self) -> Game {
+        match (&self.away_lineup, &self.home_lineup) {
+            (Some(away), Some(home)) => Game::with_batting_orders(self.rules, (**away).clone(), (**home).clone())
+                .expect("the cards were accepted when the game started"),
+            _ => Game::new(self.rules),
+        }
+    }
+
+    /// Rebuilds the game as it stands at the end of the log.
+    pub fn replay(&self) -> Result<GameOutcome, ReplayError> {
+        self.replay_to(self.events.len())
+    }
+
+    /// Rebuilds the game as it stood after the first `count` events. Replaying
+    /// one fewer than [`EventLog::len`] is undo.
+    pub fn replay_to(&self, count: usize) -> Result<GameOutcome, ReplayError> {
+        let mut outcome = GameOutcome::InProgress(self.start());
+        for (index, logged) in self.events.iter().take(count).enumerate() {
+            let game = outcome.game().ok_or(ReplayError::GameOver { index })?;
+            outcome = match logged.event {
+                GameEvent::Pitch(pitch) => game.advance(pitch),
+                GameEvent::Substitution { team, substitution } => GameOutcome::InProgress(
+                    game.substitute(team, substitution)
+                        .map_err(|error| ReplayError::Substitution { index, error })?,
+                ),
+            };
+            if outcome.log().events.last() != Some(logged) {
+                return Err(ReplayError::Diverged { index });
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// The first event at which this log and `other` part ways, or `None` if
+    /// they record the same events to the same states.
+    pub fn diff(&self, other: &EventLog) -> Option<Divergence> {
+        let len = self.events.len().max(other.events.len());
+        (0..len)
+            .map(|index| Divergence {
+                index,
+                ours: self.events.get(index).copied(),
+                theirs: other.events.get(index).copied(),
+            })
+            .find(|divergence| divergence.ours != divergence.theirs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{lineup::BattingPosition, play::PlayResult, roster, roster::PlayerId},
+        *,
+    };
+
+    fn play(game: Game, pitches: &[PitchOutcome]) -> GameOutcome {
+        pitches
+            .iter()
+            .fold(GameOutcome::InProgress(game), |outcome, &pitch| outcome.advance(pitch))
+    }
+
+    const RALLY: [PitchOutcome; 6] = [
+        PitchOutcome::Ball,
+        PitchOutcome::InPlay(PlayResult::Single),
+        PitchOutcome::Strike,
+        PitchOutcome::InPlay(PlayResult::Double),
+        PitchOutcome::InPlay(PlayResult::SacrificeFly),
+        PitchOutcome::HomeRun,
+    ];
+
+    #[test]
+    fn every_pitch_is_logged_with_the_state_it_left() {
+        let outcome = play(Game::new(RuleSet::default()), &RALLY);
+        let log = outcome.log();
+
+        assert_eq!(log.len(), RALLY.len());
+        let after_double = log.events()[3].state;
+        assert_eq!(after_double.outs, Outs::Zero);
+        assert_eq!(after_double.baserunners.second(), Some(BattingPosition::Second));
+        assert_eq!(after_double.baserunners.third(), Some(BattingPosition::First));
+
+        let last = log.events()[5];
+        assert_eq!(last.event, GameEvent::Pitch(PitchOutcome::HomeRun));
+        assert_eq!(last.state.outs, Outs::One);
+        assert_eq!(last.state.score.away(), 3, "live score, before the half is banked");
+    }
+
+    #[test]
+    fn replaying_the_log_rebuilds_the_same_game() {
+        let outcome = play(Game::new(RuleSet::default()), &RALLY);
+        assert_eq!(outcome.log().replay(), Ok(outcome.clone()));
+    }
+
+    #[test]
+    fn replaying_part_of_the_log_rebuilds_an_intermediate_game() {
+        let midway = play(Game::new(RuleSet::default()), &RALLY[..3]);
+        let finished = play(Game::new(RuleSet::default()), &RALLY);
+
+        assert_eq!(finished.log().replay_to(3), Ok(midway));
+        let undone = finished.log().replay_to(finished.log().len() - 1).expect("undo");
+        assert_eq!(undone.log().len(), RALLY.len() - 1);
+    }
+
+    #[test]
+    fn substitutions_and_lineups_survive_a_replay() {
+        let game = Game::with_batting_orders(RuleSet::default(), roster::tests::lineup(), roster::tests::lineup())
+            .expect("two valid cards")
+            .advance(PitchOutcome::InPlay(PlayResult::Single))
+            .game()
+            .expect("one hit")
+            .substitute(
+                InningHalf::Bottom,
+                Substitution::PitchingChange { player: PlayerId(15) },
+            )
+            .expect("bench arm");
+        let outcome = game.advance(PitchOutcome::Strike);
+
+        let replayed = outcome.log().replay().expect("a clean log");
+        assert_eq!(replayed, outcome);
+        assert_eq!(replayed.game_ref().and_then(Game::pitcher), Some(PlayerId(15)));
+    }
+
+    #[test]
+    fn a_finished_game_keeps_its_log() {
+        let game = Game::new(RuleSet::default().with_regulation_innings(1));
+        let mut outcome = play(game, &[PitchOutcome::HomeRun]);
+        while !outcome.is_complete() {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+
+        let log = outcome.log();
+        assert_eq!(log.len(), 7);
+        assert_eq!(log.replay(), Ok(outcome.clone()));
+    }
+
+    #[test]
+    fn diff_finds_the_first_pitch_that_differs() {
+        let ours = play(Game::new(RuleSet::default()), &RALLY);
+        let mut other = RALLY;
+        other[4] = PitchOutcome::InPlay(PlayResult::Flyout);
+        let theirs = play(Game::new(RuleSet::default()), &other);
+
+        let divergence = ours.log().diff(theirs.log()).expect("they differ");
+        assert_eq!(divergence.index, 4);
+        assert_eq!(ours.log().diff(ours.log()), None);
+
+        let shorter = play(Game::new(RuleSet::default()), &RALLY[..2]);
+        let divergence = ours.log().diff(shorter.log()).expect("one is longer");
+        assert_eq!((divergence.index, divergence.theirs), (2, None));
+    }
+
+    #[test]
+    fn a_log_that_does_not_replay_to_its_recorded_state_is_refused() {
+        let outcome = play(Game::new(RuleSet::default()), &RALLY);
+        let mut log = outcome.log().clone();
+        log.events[1].state.outs = Outs::Two;
+
+        assert_eq!(log.replay(), Err(ReplayError::Diverged { index: 1 }));
+    }
+}
@@ -1,13 +1,19 @@
 use std::fmt::Display;
 
 use super::{
-    inning::{HalfInning, HalfInningResult, InningHalf},
+    baserunners::BaserunnerState,
+    box_score::{BoxScore, PitchRecord},
+    event_log::{EventLog, EventState, GameEvent},
+    inning::{HalfInning, HalfInningResult, InningHalf, Outs},
     lineup::BattingPosition,
     plate_appearance::PitchOutcome,
+    roster::{Lineup, LineupError, PlayerId, Substitution},
+    rule_set::RuleSet,
     runs::Runs,
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InningNumber {
     #[default]
     First,
@@ -61,6 +67,7 @@ impl InningNumber {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameScore {
     away: Runs,
     home: Runs,
@@ -111,17 +118,23 @@ impl Default for GameScore {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameWinner {
     Away,
     Home,
+    /// Still level when [`RuleSet::innings_limit`] ran out.
+    Tie,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameSummary {
     final_score: GameScore,
     innings_played: InningNumber,
     winner: GameWinner,
     line_score: LineScore,
+    box_score: BoxScore,
+    log: EventLog,
 }
 
 impl Display for GameSummary {
@@ -146,9 +159,19 @@ impl GameSummary {
             innings_played,
             winner,
             line_score,
+            box_score: BoxScore::default(),
+            log: EventLog::default(),
         }
     }
 
+    pub fn with_box_score(self, box_score: BoxScore) -> Self {
+        Self { box_score, ..self }
+    }
+
+    pub fn with_log(self, log: EventLog) -> Self {
+        Self { log, ..self }
+    }
+
     pub fn line_score(&self) -> &LineScore {
         &self.line_score
     }
@@ -164,19 +187,32 @@ impl GameSummary {
     pub fn winner(&self) -> GameWinner {
         self.winner
     }
+
+    pub fn box_score(&self) -> &BoxScore {
+        &self.box_score
+    }
+
+    /// Every event of the game, down to the final out.
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
 }
 
-/// Runs per half inning plus hit totals — everything a box score line needs.
+/// Runs per half inning plus hit and error totals — everything a box score
+/// line needs.
 ///
 /// The two run vectors can legitimately differ in length: a game that ends after
 /// the top of the ninth never gives the home team a ninth at-bat, which is the
 /// `X` you see on a real scoreboard.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineScore {
     away_innings: Vec<Runs>,
     home_innings: Vec<Runs>,
     away_hits: u16,
     home_hits: u16,
+    away_errors: u16,
+    home_errors: u16,
 }
 
 impl LineScore {
@@ -196,6 +232,15 @@ impl LineScore {
         self.home_hits
     }
 
+    /// Errors made by the away team in the field.
+    pub fn away_errors(&self) -> u16 {
+        self.away_errors
+    }
+
+    pub fn home_errors(&self) -> u16 {
+        self.home_errors
+    }
+
     /// Number of columns the scoreboard needs, i.e. the longer of the two halves.
     pub fn columns(&self) -> usize {
         self.away_innings.len().max(self.home_innings.len())
@@ -214,9 +259,18 @@ impl LineScore {
             InningHalf::Bottom => self.home_hits += 1,
         }
     }
+
+    /// An error while `half` bats, charged to the team in the field.
+    fn record_error(&mut self, half: InningHalf) {
+        match half {
+            InningHalf::Top => self.home_errors += 1,
+            InningHalf::Bottom => self.away_errors += 1,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameStatus {
     Inning(InningHalf),
     InningEnd(InningHalf),
@@ -234,7 +288,9 @@ impl GameStatus {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game {
+    rules: RuleSet,
     current_inning: InningNumber,
     state: GameStatus,
     score: GameScore,
@@ -242,6 +298,12 @@ pub struct Game {
     away_batting_order: BattingPosition,
     home_batting_order: BattingPosition,
     line_score: LineScore,
+    /// Who fills each slot. `None` for an anonymous game that only ever talks
+    /// about batting positions. Boxed so an anonymous game stays small.
+    away_lineup: Option<Box<Lineup>>,
+    home_lineup: Option<Box<Lineup>>,
+    box_score: BoxScore,
+    log: EventLog,
 }
 
 impl Display for Game {
@@ -251,8 +313,9 @@ impl Display for Game {
 }
 
 impl Game {
-    pub fn new() -> Self {
+    pub fn new(rules: RuleSet) -> Self {
         Game {
+            rules,
             current_inning: InningNumber::First,
             state: GameStatus::Inning(InningHalf::Top),
             score: GameScore::new(),
@@ -260,21 +323,30 @@ impl Game {
             away_batting_order: BattingPosition::First,
             home_batting_order: BattingPosition::First,
             line_score: LineScore::default(),
+            away_lineup: None,
+            home_lineup: None,
+            box_score: BoxScore::new(None, None),
+            log: EventLog::new(rules, None, None),
         }
     }
 
-    pub fn with_batting_orders(away_order: BattingPosition, home_order: BattingPosition) -> Self {
-        let first_half = HalfInning::new(InningHalf::Top, away_order);
-
-        Game {
-            current_inning: InningNumber::First,
-            state: GameStatus::Inning(InningHalf::Top),
-            score: GameScore::new(),
-            current_half_inning: first_half,
-            away_batting_order: away_order,
-            home_batting_order: home_order,
-            line_score: LineScore::default(),
+    /// A game between two named lineups. Both orders start from the leadoff
+    /// slot, as they do on a real lineup card.
+    pub fn with_batting_orders(rules: RuleSet, away: Lineup, home: Lineup) -> Result<Self, LineupError> {
+        if !rules.designated_hitter() && (away.uses_designated_hitter() || home.uses_designated_hitter()) {
+            return Err(LineupError::DesignatedHitterNotAllowed);
         }
+        Ok(Game {
+            box_score: BoxScore::new(Some(&away), Some(&home)),
+            log: EventLog::new(rules, Some(&away), Some(&home)),
+            away_lineup: Some(Box::new(away)),
+            home_lineup: Some(Box::new(home)),
+            ..Self::new(rules)
+        })
+    }
+
+    pub fn rules(&self) -> RuleSet {
+        self.rules
     }
 
     pub fn current_inning(&self) -> InningNumber {
@@ -297,6 +369,89 @@ impl Game {
         &self.line_score
     }
 
+    /// The box score so far.
+    pub fn box_score(&self) -> &BoxScore {
+        &self.box_score
+    }
+
+    /// Every event so far. Replaying it rebuilds this game.
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+
+    /// The lineup card of the team that bats in `half`.
+    pub fn lineup(&self, half: InningHalf) -> Option<&Lineup> {
+        match half {
+            InningHalf::Top => self.away_lineup.as_deref(),
+            InningHalf::Bottom => self.home_lineup.as_deref(),
+        }
+    }
+
+    /// The player at the plate, if the game has lineup cards.
+    pub fn batter(&self) -> Option<PlayerId> {
+        let half = self.current_half_inning.half();
+        self.lineup(half)
+            .map(|lineup| lineup.player_at(self.current_half_inning.current_batter()))
+    }
+
+    /// The player on the mound for the team in the field.
+    pub fn pitcher(&self) -> Option<PlayerId> {
+        let fielding = match self.current_half_inning.half() {
+            InningHalf::Top => InningHalf::Bottom,
+            InningHalf::Bottom => InningHalf::Top,
+        };
+        self.lineup(fielding).map(Lineup::pitcher)
+    }
+
+    /// Makes a change to the lineup of the team that bats in `half`. Runners are
+    /// tracked by slot, so a pinch runner takes over the base their slot is on.
+    pub fn substitute(mut self, half: InningHalf, substitution: Substitution) -> Result<Self, LineupError> {
+        let lead = self.live_lead(half);
+        // Runners only count against a pitcher brought in while the other side
+        // is batting; between innings the bases are empty.
+        let runners = self.current_half_inning.baserunners();
+        let on_base = match self.batting_half() {
+            Some(batting) if batting != half => [runners.first(), runners.second(), runners.third()]
+                .into_iter()
+                .map(|runner| u8::from(runner.is_some()))
+                .sum(),
+            _ => 0,
+        };
+        let lineup = match half {
+            InningHalf::Top => &mut self.away_lineup,
+            InningHalf::Bottom => &mut self.home_lineup,
+        };
+        let current = lineup.take().ok_or(LineupError::NoLineup)?;
+        let updated = current.substitute(substitution)?;
+        self.box_score
+            .record_substitution(half, &updated, substitution, lead, on_base);
+        *lineup = Some(Box::new(updated));
+        let state = self.event_state();
+        self.log.push(
+            GameEvent::Substitution {
+                team: half,
+                substitution,
+            },
+            state,
+        );
+        Ok(self)
+    }
+
+    /// How far the team batting in `half` is ahead, counting runs already
+    /// scored in the half inning under way.
+    fn live_lead(&self, half: InningHalf) -> i16 {
+        let (mut away, mut home) = (i16::from(self.score.away()), i16::from(self.score.home()));
+        match self.batting_half() {
+            Some(InningHalf::Top) => away += i16::from(self.current_half_inning.runs_scored()),
+            Some(InningHalf::Bottom) => home += i16::from(self.current_half_inning.runs_scored()),
+            None => {}
+        }
+        match half {
+            InningHalf::Top => away - home,
+            InningHalf::Bottom => home - away,
+        }
+    }
+
     pub fn advance(mut self, outcome: PitchOutcome) -> GameOutcome {
         // Tallied before the half inning advances, while `state` still names the
         // team that is batting.
@@ -309,16 +464,28 @@ impl Game {
             if is_hit {
                 self.line_score.record_hit(half);
             }
+            if let PitchOutcome::InPlay(play) = outcome
+                && play.error().is_some()
+            {
+                self.line_score.record_error(half);
+            }
         }
 
-        match self.current_half_inning.advance(outcome) {
+        let before = self.current_half_inning;
+        let result = self.current_half_inning.advance(outcome);
+        if let Some(half) = self.batting_half() {
+            self.record_pitch(half, before, outcome, &result);
+            self.log_pitch(half, outcome, &result);
+        }
+
+        match result {
             HalfInningResult::InProgress(half_inning) => {
                 self.current_half_inning = half_inning;
                 let pending_runs = self.current_half_inning.runs_scored();
                 // A walk-off ends the game the instant the run scores, without
                 // waiting for the third out. This applies in extra innings too,
                 // not just the ninth.
-                if self.is_walkoff_window() && self.should_end_game(pending_runs) {
+                if self.state.is_bottom() && self.should_end_game(pending_runs) {
                     self.complete_half_inning(pending_runs);
                     return self.finish();
                 }
@@ -328,6 +495,7 @@ impl Game {
 
             HalfInningResult::Complete(summary) => {
                 // Half inning completed, update score and advance
+                self.record_next_batter(summary.next_batter());
                 self.complete_half_inning(summary.runs_scored());
 
                 // Check if game should end
@@ -342,6 +510,61 @@ impl Game {
         }
     }
 
+    /// Writes one pitch into the box score, from the half inning before it and
+    /// whatever it turned into.
+    fn record_pitch(&mut self, half: InningHalf, before: HalfInning, outcome: PitchOutcome, result: &HalfInningResult) {
+        let (runners_after, outs_after, runs_after) = after_pitch(result);
+        let outs_after = outs_after.as_number();
+        let (batting_runs, fielding_runs) = match half {
+            InningHalf::Top => (self.score.away(), self.score.home()),
+            InningHalf::Bottom => (self.score.home(), self.score.away()),
+        };
+        self.box_score.record(&PitchRecord {
+            half,
+            outcome,
+            before,
+            runners_after,
+            outs_recorded: outs_after - before.outs().as_number(),
+            runs_scored: runs_after - before.runs_scored(),
+            batting_runs: batting_runs + before.runs_scored(),
+            fielding_runs,
+        });
+    }
+
+    /// Appends the pitch to the log, with the half inning as the pitch left it.
+    fn log_pitch(&mut self, half: InningHalf, outcome: PitchOutcome, result: &HalfInningResult) {
+        let (runners_after, outs, runs) = after_pitch(result);
+        let score = match half {
+            InningHalf::Top => self.score.add_away_runs(runs),
+            InningHalf::Bottom => self.score.add_home_runs(runs),
+        };
+        let state = EventState {
+            inning: self.current_inning,
+            half,
+            outs,
+            baserunners: runners_after.unwrap_or_default(),
+            score,
+        };
+        self.log.push(GameEvent::Pitch(outcome), state);
+    }
+
+    /// Where the game stands now, as the log records it.
+    pub(crate) fn event_state(&self) -> EventState {
+        let half = self.current_half_inning.half();
+        let runs = self.current_half_inning.runs_scored();
+        EventState {
+            inning: self.current_inning,
+            half,
+            outs: self.current_half_inning.outs(),
+            baserunners: self.current_half_inning.baserunners(),
+            score: match self.batting_half() {
+                Some(InningHalf::Top) => self.score.add_away_runs(runs),
+                Some(InningHalf::Bottom) => self.score.add_home_runs(runs),
+                None => self.score,
+            },
+        }
+    }
+
     /// Which team is at bat right now, or `None` between halves and after the
     /// final out.
     fn batting_half(&self) -> Option<InningHalf> {
@@ -351,6 +574,16 @@ impl Game {
         }
     }
 
+    /// Remembers where the side that just batted picks up next time, so the
+    /// order carries over from inning to inning instead of restarting.
+    fn record_next_batter(&mut self, next_batter: BattingPosition) {
+        match self.state {
+            GameStatus::Inning(InningHalf::Top) => self.away_batting_order = next_batter,
+            GameStatus::Inning(InningHalf::Bottom) => self.home_batting_order = next_batter,
+            GameStatus::InningEnd(_) | GameStatus::Complete => {}
+        }
+    }
+
     fn complete_half_inning(&mut self, pending_runs: Runs) {
         match self.state {
             GameStatus::Inning(InningHalf::Top) => {
@@ -371,32 +604,35 @@ impl Game {
 
     fn should_end_game(&self, pending_runs: Runs) -> bool {
         // The state represents the NEXT half inning to be played after completing a half
-        match self.current_inning {
-            // Regular innings 1-8: never end
-            InningNumber::First
-            | InningNumber::Second
-            | InningNumber::Third
-            | InningNumber::Fourth
-            | InningNumber::Fifth
-            | InningNumber::Sixth
-            | InningNumber::Seventh
-            | InningNumber::Eighth => false,
-
-            // The ninth and every extra inning share one set of ending rules;
-            // they used to be spelled out separately, and the extra-innings copy
-            // was missing the mid-inning walk-off case.
-            InningNumber::Ninth | InningNumber::Extra(_) => match self.state {
-                // Visitors have finished batting. If the home team is ahead there
-                // is no reason to play the home half.
-                GameStatus::InningEnd(InningHalf::Top) => self.score.home() > self.score.away(),
-                // Home team has been retired. Anyone ahead has won.
-                GameStatus::InningEnd(InningHalf::Bottom) => self.score.home() != self.score.away(),
-                // Mid-inning with the home team batting: a run that takes the
-                // lead ends it on the spot. This is the walk-off.
-                GameStatus::Inning(InningHalf::Bottom) => self.score.home() + pending_runs > self.score.away(),
-                GameStatus::Inning(InningHalf::Top) => false,
-                GameStatus::Complete => true,
-            },
+        let inning = self.current_inning.as_number();
+        let regulation = inning >= self.rules.regulation_innings() && self.is_decided_by(1, pending_runs);
+        let mercy = self
+            .rules
+            .mercy_rule()
+            .is_some_and(|mercy| inning >= mercy.from_inning && self.is_decided_by(mercy.margin, pending_runs));
+        let called = self
+            .rules
+            .innings_limit()
+            .is_some_and(|limit| inning >= limit && self.state == GameStatus::InningEnd(InningHalf::Bottom));
+        regulation || mercy || called
+    }
+
+    /// Whether a lead of `margin` runs is final at this point of an inning that
+    /// is allowed to end the game. Regulation is a margin of one; the mercy rule
+    /// is the same reasoning with a bigger one.
+    fn is_decided_by(&self, margin: Runs, pending_runs: Runs) -> bool {
+        let (away, home) = (self.score.away(), self.score.home());
+        match self.state {
+            // Visitors have finished batting. If the home team is ahead there
+            // is no reason to play the home half.
+            GameStatus::InningEnd(InningHalf::Top) => home.saturating_sub(away) >= margin,
+            // Home team has been retired. Anyone ahead has won.
+            GameStatus::InningEnd(InningHalf::Bottom) => home.abs_diff(away) >= margin,
+            // Mid-inning with the home team batting: a run that takes the
+            // lead ends it on the spot. This is the walk-off.
+            GameStatus::Inning(InningHalf::Bottom) => (home + pending_runs).saturating_sub(away) >= margin,
+            GameStatus::Inning(InningHalf::Top) => false,
+            GameStatus::Complete => true,
         }
     }
 
@@ -415,6 +651,11 @@ impl Game {
         }
 
         self.current_half_inning = HalfInning::new(half, batting_order);
+        if self.rules.extra_inning_runner() && self.is_extra_innings() {
+            let runner = batting_order.previous();
+            self.current_half_inning = self.current_half_inning.with_runner_on_second(runner);
+            self.box_score.place_runner(half, runner);
+        }
         self.state = GameStatus::Inning(half);
         self
     }
@@ -423,6 +664,11 @@ impl Game {
         matches!(self.state, GameStatus::Complete)
     }
 
+    /// Whether the game has gone past regulation under its rules.
+    pub fn is_extra_innings(&self) -> bool {
+        self.rules.is_extra(self.current_inning.as_number())
+    }
+
     pub fn inning_description(&self) -> String {
         let inning_text = if self.current_inning.is_extra() {
             format!("{}th", self.current_inning.as_number())
@@ -446,35 +692,40 @@ impl Game {
         format!("{half_text} of the {inning_text}")
     }
 
-    /// The home team is batting in an inning that could end the game, so a run
-    /// scoring right now is a walk-off. True for the ninth and every extra
-    /// inning; before the ninth there is always more baseball to play.
-    fn is_walkoff_window(&self) -> bool {
-        let late_enough = self.current_inning == InningNumber::Ninth || self.current_inning.is_extra();
-        late_enough && self.state.is_bottom()
-    }
-
     /// Seals the game: marks the state complete and hands back the summary.
     /// `state` is set here so [`Game::is_complete`] agrees with the outcome.
     fn finish(mut self) -> GameOutcome {
-        let winner = self.score.winner().expect("a completed game must have a winner");
+        // Only the innings limit ends a game that is level.
+        let winner = self.score.winner().unwrap_or(GameWinner::Tie);
         self.state = GameStatus::Complete;
-        GameOutcome::Complete(GameSummary::new(
-            self.score,
-            self.current_inning,
-            winner,
-            self.line_score,
-        ))
+        if winner != GameWinner::Tie {
+            self.box_score.decide();
+        }
+        GameOutcome::Complete(
+            GameSummary::new(self.score, self.current_inning, winner, self.line_score)
+                .with_box_score(self.box_score)
+                .with_log(self.log),
+        )
+    }
+}
+
+/// The runners, outs and runs in the half inning as a pitch left it. Runners
+/// are `None` when the pitch retired the side.
+fn after_pitch(result: &HalfInningResult) -> (Option<BaserunnerState>, Outs, Runs) {
+    match result {
+        HalfInningResult::InProgress(after) => (Some(after.baserunners()), after.outs(), after.runs_scored()),
+        HalfInningResult::Complete(summary) => (None, Outs::Three, summary.runs_scored()),
     }
 }
 
 impl Default for Game {
     fn default() -> Self {
-        Self::new()
+        Self::new(RuleSet::default())
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameOutcome {
     InProgress(Game),
     Complete(GameSummary),
@@ -528,6 +779,13 @@ impl GameOutcome {
             GameOutcome::Complete(summary) => Some(summary),
         }
     }
+
+    pub fn log(&self) -> &EventLog {
+        match self {
+            GameOutcome::InProgress(game) => game.log(),
+            GameOutcome::Complete(summary) => summary.log(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -535,7 +793,10 @@ mod tests {
     use tracingx::info;
 
     use super::{
-        super::{plate_appearance::PitchOutcome, play::PlayResult},
+        super::{
+            box_score::Decision, lineup::PlayerPosition, plate_appearance::PitchOutcome, play::PlayResult, roster,
+            rule_set::MercyRule, running::RunnerEvent,
+        },
         *,
     };
 
@@ -552,7 +813,7 @@ mod tests {
 
     #[test]
     fn test_game_creation() {
-        let game = Game::new();
+        let game = Game::new(RuleSet::default());
         assert_eq!(game.current_inning(), InningNumber::First);
         assert_eq!(game.state(), GameStatus::Inning(InningHalf::Top));
         assert_eq!(game.score().away(), 0);
@@ -572,7 +833,7 @@ mod tests {
 
     #[test]
     fn test_simple_half_inning_completion() {
-        let game = Game::new();
+        let game = Game::new(RuleSet::default());
 
         // Three quick outs to complete top 1st
         let game = game
@@ -593,7 +854,7 @@ mod tests {
 
     #[test]
     fn test_home_run_scoring() {
-        let game = Game::new();
+        let game = Game::new(RuleSet::default());
 
         // Home run in top 1st
         let game = game
@@ -617,10 +878,9 @@ mod tests {
 
     #[test]
     fn test_inning_description() {
-        let game = Game::new();
+        let game = Game::new(RuleSet::default());
         assert_eq!(game.inning_description(), "Top of the 1st");
 
-        let game = Game::with_batting_orders(BattingPosition::First, BattingPosition::First);
         // Simulate completing top half
         let mut game_state = game;
         game_state.state = GameStatus::Inning(InningHalf::Bottom);
@@ -634,7 +894,7 @@ mod tests {
 
     #[test]
     fn test_game_ending_conditions() {
-        let mut game = Game::new();
+        let mut game = Game::new(RuleSet::default());
 
         // Simulate game state at end of 9th inning
         game.current_inning = InningNumber::Ninth;
@@ -664,7 +924,7 @@ mod tests {
     #[test]
     fn demo_baseball_game() {
         info!("Starting a new baseball game...");
-        let game = Game::new();
+        let game = Game::new(RuleSet::default());
 
         info!("Initial state: {}", game.inning_description());
         info!("Score: Away {} - Home {}", game.score().away(), game.score().home());
@@ -844,7 +1104,7 @@ mod tests {
     fn a_walk_off_ends_an_extra_inning_without_waiting_for_the_third_out() {
         // Regression: the walk-off check only looked for the ninth, so an extra
         // innings winner sat on the field until the side was retired.
-        let mut game = Game::new();
+        let mut game = Game::new(RuleSet::default());
         game.current_inning = InningNumber::Extra(10);
         game.state = GameStatus::Inning(InningHalf::Bottom);
         game.score = GameScore::new().add_away_runs(4).add_home_runs(4);
@@ -861,7 +1121,7 @@ mod tests {
 
     #[test]
     fn a_tie_after_nine_goes_to_extras_rather_than_ending() {
-        let mut game = Game::new();
+        let mut game = Game::new(RuleSet::default());
         game.current_inning = InningNumber::Ninth;
         game.state = GameStatus::Inning(InningHalf::Bottom);
         game.score = GameScore::new().add_away_runs(2).add_home_runs(2);
@@ -877,7 +1137,7 @@ mod tests {
 
     #[test]
     fn the_line_score_records_runs_by_half_inning_and_counts_hits() {
-        let mut outcome = GameOutcome::InProgress(Game::new());
+        let mut outcome = GameOutcome::InProgress(Game::new(RuleSet::default()));
 
         // Top 1st: solo homer, then three outs.
         outcome = outcome.advance(PitchOutcome::HomeRun);
@@ -904,7 +1164,7 @@ mod tests {
     fn the_line_score_leaves_the_home_ninth_blank_when_it_is_never_played() {
         // Home team leading after the top of the ninth: they do not bat, so the
         // scoreboard has one fewer column for them.
-        let mut game = Game::new();
+        let mut game = Game::new(RuleSet::default());
         game.current_inning = InningNumber::Ninth;
         game.state = GameStatus::Inning(InningHalf::Top);
         game.score = GameScore::new().add_home_runs(3).add_away_runs(1);
@@ -920,9 +1180,99 @@ mod tests {
         assert!(line.home_innings().is_empty(), "the home ninth was never played");
     }
 
+    #[test]
+    fn the_batting_order_carries_over_between_innings() {
+        // Regression: every half inning used to start again from the leadoff man.
+        let mut outcome = GameOutcome::InProgress(Game::new(RuleSet::default()));
+        outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Single));
+        for _ in 0..3 {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+        for _ in 0..3 {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+
+        let game = outcome.game_ref().expect("only the second inning");
+        assert_eq!(game.state(), GameStatus::Inning(InningHalf::Top));
+        assert_eq!(game.current_inning(), InningNumber::Second);
+        assert_eq!(
+            game.current_half_inning().current_batter(),
+            BattingPosition::Fifth,
+            "four batters came up in the first, so the fifth leads off the second"
+        );
+    }
+
+    #[test]
+    fn a_walk_off_wild_pitch_ends_the_game() {
+        let mut game = Game::new(RuleSet::default());
+        game.current_inning = InningNumber::Ninth;
+        game.state = GameStatus::Inning(InningHalf::Bottom);
+        game.score = GameScore::new().add_away_runs(2).add_home_runs(2);
+
+        let outcome = GameOutcome::InProgress(game)
+            .advance(PitchOutcome::InPlay(PlayResult::Triple))
+            .advance(PitchOutcome::Baserunning(RunnerEvent::WildPitch));
+
+        let summary = outcome.summary_ref().expect("the runner from third won it");
+        assert_eq!(summary.winner(), GameWinner::Home);
+        assert_eq!(summary.line_score().home_innings(), &[1]);
+        assert_eq!(summary.line_score().home_hits(), 1, "the wild pitch is not a hit");
+    }
+
+    #[test]
+    fn a_game_with_lineups_knows_who_is_batting_and_who_is_pitching() {
+        let away = roster::tests::lineup();
+        let home = roster::tests::lineup()
+            .substitute(Substitution::PitchingChange { player: PlayerId(15) })
+            .expect("bench arm");
+        let game = Game::with_batting_orders(RuleSet::default(), away, home).expect("DH is allowed");
+
+        assert_eq!(game.batter(), Some(PlayerId(1)));
+        assert_eq!(game.pitcher(), Some(PlayerId(15)), "the home team is in the field");
+
+        let game = GameOutcome::InProgress(game)
+            .advance(PitchOutcome::InPlay(PlayResult::Single))
+            .game()
+            .expect("one hit");
+        assert_eq!(game.batter(), Some(PlayerId(2)));
+    }
+
+    #[test]
+    fn a_pinch_runner_takes_over_the_base_their_slot_is_on() {
+        let game = named_game()
+            .advance(PitchOutcome::InPlay(PlayResult::Single))
+            .game()
+            .expect("one hit")
+            .substitute(
+                InningHalf::Top,
+                Substitution::PinchRunner {
+                    slot: BattingPosition::First,
+                    player: PlayerId(13),
+                },
+            )
+            .expect("bench player is available");
+
+        let runner = game
+            .current_half_inning()
+            .baserunners()
+            .first()
+            .expect("runner on first");
+        let lineup = game.lineup(InningHalf::Top).expect("has a lineup");
+        assert_eq!(lineup.player_at(runner), PlayerId(13));
+        assert_eq!(lineup.removed(), &[PlayerId(1)]);
+    }
+
+    #[test]
+    fn an_anonymous_game_has_nobody_to_substitute() {
+        let err = Game::new(RuleSet::default())
+            .substitute(InningHalf::Top, Substitution::PitchingChange { player: PlayerId(1) })
+            .unwrap_err();
+        assert_eq!(err, LineupError::NoLineup);
+    }
+
     #[test]
     fn a_decided_game_stops_accepting_pitches() {
-        let mut game = Game::new();
+        let mut game = Game::new(RuleSet::default());
         game.current_inning = InningNumber::Ninth;
         game.state = GameStatus::Inning(InningHalf::Bottom);
         game.score = GameScore::new().add_away_runs(1).add_home_runs(1);
@@ -939,4 +1289,364 @@ mod tests {
             "a completed game must not keep scoring"
         );
     }
+
+    fn named_game() -> Game {
+        Game::with_batting_orders(RuleSet::default(), roster::tests::lineup(), roster::tests::lineup())
+            .expect("two valid cards")
+    }
+
+    fn three_outs(mut outcome: GameOutcome) -> GameOutcome {
+        for _ in 0..3 {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+        outcome
+    }
+
+    #[test]
+    fn the_box_score_credits_hits_runs_and_runs_batted_in() {
+        let game = named_game();
+        let outcome = GameOutcome::InProgress(game)
+            .advance(PitchOutcome::InPlay(PlayResult::Single))
+            .advance(PitchOutcome::Strike)
+            .advance(PitchOutcome::HomeRun);
+
+        let game = outcome.game_ref().expect("two runs in");
+        let away = game.box_score().away();
+        let leadoff = away.batting_line(PlayerId(1)).expect("starter");
+        assert_eq!(
+            (leadoff.at_bats, leadoff.hits, leadoff.runs, leadoff.runs_batted_in),
+            (1, 1, 1, 0)
+        );
+        let slugger = away.batting_line(PlayerId(2)).expect("starter");
+        assert_eq!((slugger.home_runs, slugger.runs, slugger.runs_batted_in), (1, 1, 2));
+
+        let pitcher = game.box_score().home().pitching_line(PlayerId(10)).expect("starter");
+        assert_eq!((pitcher.pitches, pitcher.strikes, pitcher.hits), (3, 3, 2));
+        assert_eq!((pitcher.runs, pitcher.earned_runs), (2, 2));
+    }
+
+    #[test]
+    fn an_error_is_charged_to_the_fielder_and_its_run_is_unearned() {
+        let outcome = GameOutcome::InProgress(named_game())
+            .advance(PitchOutcome::InPlay(PlayResult::Error(PlayerPosition::Shortstop)))
+            .advance(PitchOutcome::InPlay(PlayResult::Triple));
+
+        let game = outcome.game_ref().expect("nobody out");
+        assert_eq!(game.current_half_inning().runs_scored(), 1);
+        assert_eq!(
+            (game.line_score().home_errors(), game.line_score().away_errors()),
+            (1, 0)
+        );
+        assert_eq!(game.line_score().away_hits(), 1);
+
+        let (away, home) = (game.box_score().away(), game.box_score().home());
+        assert_eq!(home.errors_by(PlayerPosition::Shortstop), 1);
+        assert!(away.errors().is_empty());
+        let leadoff = away.batting_line(PlayerId(1)).expect("starter");
+        assert_eq!((leadoff.at_bats, leadoff.hits, leadoff.runs), (1, 0, 1));
+        let pitcher = home.pitching_line(PlayerId(10)).expect("starter");
+        assert_eq!((pitcher.hits, pitcher.runs, pitcher.earned_runs), (1, 1, 0));
+    }
+
+    #[test]
+    fn a_squeeze_is_a_sacrifice_that_drives_in_the_run() {
+        let outcome = GameOutcome::InProgress(named_game())
+            .advance(PitchOutcome::InPlay(PlayResult::Triple))
+            .advance(PitchOutcome::InPlay(PlayResult::SqueezePlay));
+
+        let game = outcome.game_ref().expect("one out");
+        assert_eq!(game.current_half_inning().runs_scored(), 1);
+        let batter = game.box_score().away().batting_line(PlayerId(2)).expect("starter");
+        assert_eq!(
+            (batter.plate_appearances, batter.at_bats, batter.runs_batted_in),
+            (1, 0, 1)
+        );
+    }
+
+    #[test]
+    fn the_box_score_agrees_with_the_line_score() {
+        let mut outcome = GameOutcome::InProgress(Game::new(RuleSet::default()));
+        outcome = outcome
+            .advance(PitchOutcome::HomeRun)
+            .advance(PitchOutcome::InPlay(PlayResult::Double))
+            .advance(PitchOutcome::Ball);
+        outcome = three_outs(outcome.advance(PitchOutcome::InPlay(PlayResult::Single)));
+        outcome = three_outs(outcome.advance(PitchOutcome::InPlay(PlayResult::Triple)));
+
+        let game = outcome.game_ref().expect("only the second inning");
+        let (box_score, line) = (game.box_score(), game.line_score());
+        assert_eq!(box_score.away().hits(), line.away_hits());
+        assert_eq!(box_score.home().hits(), line.home_hits());
+        assert_eq!(box_score.away().runs(), u16::from(game.score().away()));
+        assert_eq!(box_score.away().pitching()[0].outs_recorded, 3);
+        assert_eq!(box_score.home().pitching()[0].innings_pitched(), "1.0");
+    }
+
+    #[test]
+    fn the_pitcher_of_record_when_the_lead_is_taken_gets_the_win() {
+        let game = named_game();
+        let mut outcome = GameOutcome::InProgress(game).advance(PitchOutcome::HomeRun);
+        while !outcome.is_complete() {
+            outcome = three_outs(outcome);
+        }
+
+        let summary = outcome.summary_ref().expect("nine innings of groundouts");
+        assert_eq!(summary.winner(), GameWinner::Away);
+        let box_score = summary.box_score();
+        assert_eq!(box_score.away().pitching()[0].decision, Some(Decision::Win));
+        assert_eq!(box_score.home().pitching()[0].decision, Some(Decision::Loss));
+        assert_eq!(box_score.away().pitching()[0].innings_pitched(), "9.0");
+    }
+
+    #[test]
+    fn a_reliever_who_holds_a_narrow_lead_gets_the_save() {
+        let game = named_game();
+        let mut outcome = GameOutcome::InProgress(game).advance(PitchOutcome::HomeRun);
+        while outcome
+            .game_ref()
+            .is_some_and(|game| game.current_inning() != InningNumber::Ninth)
+        {
+            outcome = three_outs(outcome);
+        }
+        outcome = three_outs(outcome);
+        let game = outcome
+            .game()
+            .expect("bottom of the ninth")
+            .substitute(InningHalf::Top, Substitution::PitchingChange { player: PlayerId(15) })
+            .expect("bench arm");
+        let outcome = three_outs(GameOutcome::InProgress(game));
+
+        let box_score = outcome.summary_ref().expect("away held on").box_score();
+        let pitching = box_score.away().pitching();
+        assert_eq!(pitching.len(), 2);
+        assert_eq!(pitching[0].decision, Some(Decision::Win));
+        assert_eq!(pitching[1].player, Some(PlayerId(15)));
+        assert_eq!(pitching[1].decision, Some(Decision::Save));
+        assert_eq!(pitching[1].innings_pitched(), "1.0");
+    }
+
+    /// Away goes up by `lead` in the first and nobody scores again; PlayerId(15)
+    /// takes the ball in the bottom of `inning` after `outs` outs and `singles`
+    /// singles, and finishes the game. Returns the reliever's decision.
+    fn relief_decision(lead: u8, inning: InningNumber, outs: u8, singles: u8) -> Option<Decision> {
+        let mut outcome = GameOutcome::InProgress(named_game());
+        for _ in 0..lead {
+            outcome = outcome.advance(PitchOutcome::HomeRun);
+        }
+        while outcome.game_ref().is_some_and(|game| game.current_inning() != inning) {
+            outcome = three_outs(outcome);
+        }
+        outcome = three_outs(outcome);
+        for _ in 0..outs {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+        for _ in 0..singles {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Single));
+        }
+        let game = outcome
+            .game()
+            .expect("the home side is batting")
+            .substitute(InningHalf::Top, Substitution::PitchingChange { player: PlayerId(15) })
+            .expect("bench arm");
+        let mut outcome = GameOutcome::InProgress(game);
+        while !outcome.is_complete() {
+            outcome = outcome.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        }
+
+        let summary = outcome.summary_ref().expect("away held on");
+        assert_eq!(summary.winner(), GameWinner::Away);
+        let reliever = summary.box_score().away().pitching().last().expect("two pitchers");
+        assert_eq!(reliever.player, Some(PlayerId(15)));
+        reliever.decision
+    }
+
+    #[test]
+    fn a_three_run_lead_is_only_saved_over_a_full_inning() {
+        assert_eq!(relief_decision(3, InningNumber::Ninth, 0, 0), Some(Decision::Save));
+        assert_eq!(
+            relief_decision(3, InningNumber::Ninth, 2, 0),
+            None,
+            "one out with the tying run still two batters away"
+        );
+    }
+
+    #[test]
+    fn the_tying_run_on_deck_makes_a_bigger_lead_a_save() {
+        assert_eq!(
+            relief_decision(4, InningNumber::Ninth, 2, 2),
+            Some(Decision::Save),
+            "two on, so the tying run is on deck"
+        );
+        assert_eq!(relief_decision(4, InningNumber::Ninth, 2, 0), None, "bases empty");
+        assert_eq!(
+            relief_decision(5, InningNumber::Ninth, 2, 2),
+            None,
+            "the tying run is still in the hole"
+        );
+    }
+
+    #[test]
+    fn three_innings_to_finish_a_win_is_a_save_whatever_the_lead() {
+        assert_eq!(relief_decision(6, InningNumber::Seventh, 0, 0), Some(Decision::Save));
+        assert_eq!(relief_decision(6, InningNumber::Eighth, 0, 0), None, "only two innings");
+    }
+
+    #[test]
+    fn an_inherited_runner_is_charged_to_the_pitcher_who_let_them_on() {
+        let game = named_game();
+        let game = three_outs(GameOutcome::InProgress(game))
+            .advance(PitchOutcome::InPlay(PlayResult::Single))
+            .game()
+            .expect("bottom of the first")
+            .substitute(InningHalf::Top, Substitution::PitchingChange { player: PlayerId(15) })
+            .expect("bench arm");
+        let outcome = game.advance(PitchOutcome::HomeRun);
+
+        let game = outcome.game_ref().expect("only two runs");
+        let pitching = game.box_score().away().pitching();
+        assert_eq!(
+            (pitching[0].runs, pitching[0].hits),
+            (1, 1),
+            "the starter allowed the runner"
+        );
+        assert_eq!(
+            (pitching[1].runs, pitching[1].hits),
+            (1, 1),
+            "the reliever allowed the homer"
+        );
+    }
+
+    #[test]
+    fn a_seven_inning_game_is_over_after_seven() {
+        let mut outcome =
+            GameOutcome::InProgress(Game::new(RuleSet::seven_inning_doubleheader())).advance(PitchOutcome::HomeRun);
+        while !outcome.is_complete() {
+            outcome = three_outs(outcome);
+        }
+
+        let summary = outcome.summary_ref().expect("played to the end");
+        assert_eq!(summary.innings_played(), InningNumber::Seventh);
+        assert_eq!(summary.winner(), GameWinner::Away);
+        assert_eq!(summary.line_score().home_innings().len(), 7);
+    }
+
+    #[test]
+    fn an_extra_inning_starts_with_the_previous_batter_on_second() {
+        let game = Game {
+            rules: RuleSet::major_league(),
+            current_inning: InningNumber::Ninth,
+            state: GameStatus::Inning(InningHalf::Bottom),
+            away_batting_order: BattingPosition::Fourth,
+            ..named_game()
+        };
+
+        let game = three_outs(GameOutcome::InProgress(game))
+            .game()
+            .expect("tied after nine");
+        assert!(game.is_extra_innings());
+        let half = game.current_half_inning();
+        assert_eq!(half.current_batter(), BattingPosition::Fourth);
+        assert_eq!(half.baserunners().second(), Some(BattingPosition::Third));
+
+        let game = game
+            .advance(PitchOutcome::InPlay(PlayResult::Double))
+            .game()
+            .expect("one run in");
+        let pitching = game.box_score().home().pitching();
+        assert_eq!(
+            (pitching[0].runs, pitching[0].earned_runs),
+            (1, 0),
+            "the placed runner is unearned"
+        );
+    }
+
+    #[test]
+    fn extras_start_with_empty_bases_by_default() {
+        let game = Game {
+            current_inning: InningNumber::Ninth,
+            state: GameStatus::Inning(InningHalf::Bottom),
+            ..Game::new(RuleSet::default())
+        };
+        let game = three_outs(GameOutcome::InProgress(game))
+            .game()
+            .expect("tied after nine");
+        assert!(game.current_half_inning().baserunners().is_empty());
+    }
+
+    #[test]
+    fn the_mercy_rule_ends_a_lopsided_game_once_it_applies() {
+        let rules = RuleSet::default().with_mercy_rule(Some(MercyRule {
+            margin: 10,
+            from_inning: 5,
+        }));
+
+        // Too early: the fourth is played out.
+        let game = Game {
+            current_inning: InningNumber::Fourth,
+            score: GameScore::new().add_home_runs(12),
+            ..Game::new(rules)
+        };
+        assert!(!three_outs(GameOutcome::InProgress(game)).is_complete());
+
+        // From the fifth, a home lead that big means the bottom half is skipped.
+        let game = Game {
+            current_inning: InningNumber::Fifth,
+            score: GameScore::new().add_home_runs(12),
+            ..Game::new(rules)
+        };
+        let summary = three_outs(GameOutcome::InProgress(game)).summary().expect("called");
+        assert_eq!(summary.winner(), GameWinner::Home);
+        assert_eq!(summary.innings_played(), InningNumber::Fifth);
+    }
+
+    #[test]
+    fn the_home_team_can_end_it_by_mercy_mid_inning() {
+        let rules = RuleSet::default().with_mercy_rule(Some(MercyRule {
+            margin: 10,
+            from_inning: 5,
+        }));
+        let game = Game {
+            current_inning: InningNumber::Sixth,
+            state: GameStatus::Inning(InningHalf::Bottom),
+            score: GameScore::new().add_home_runs(9),
+            ..Game::new(rules)
+        };
+
+        let outcome = GameOutcome::InProgress(game).advance(PitchOutcome::InPlay(PlayResult::Single));
+        assert!(!outcome.is_complete(), "a single does not score");
+        let outcome = outcome.advance(PitchOutcome::HomeRun);
+        let summary = outcome.summary_ref().expect("eleven-run lead");
+        assert_eq!(summary.final_score().home(), 11);
+    }
+
+    #[test]
+    fn a_game_level_at_the_innings_limit_is_a_tie_with_no_decisions() {
+        let game = Game {
+            rules: RuleSet::default().with_innings_limit(Some(12)),
+            current_inning: InningNumber::Extra(12),
+            ..named_game()
+        };
+        let mut outcome = GameOutcome::InProgress(game);
+        for _ in 0..2 {
+            outcome = three_outs(outcome);
+        }
+
+        let summary = outcome.summary_ref().expect("called at the limit");
+        assert_eq!(summary.winner(), GameWinner::Tie);
+        assert!(
+            summary
+                .box_score()
+                .home()
+                .pitching()
+                .iter()
+                .all(|line| line.decision.is_none())
+        );
+    }
+
+    #[test]
+    fn a_designated_hitter_card_is_refused_when_the_rules_forbid_it() {
+        let rules = RuleSet::default().with_designated_hitter(false);
+        let err = Game::with_batting_orders(rules, roster::tests::lineup(), roster::tests::lineup()).unwrap_err();
+        assert_eq!(err, LineupError::DesignatedHitterNotAllowed);
+    }
 }
@@ -10,6 +10,7 @@ use super::{
 };
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InningHalf {
     #[default]
     Top,
@@ -17,6 +18,7 @@ pub enum InningHalf {
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Outs {
     #[default]
     Zero,
@@ -78,6 +80,7 @@ impl Outs {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HalfInning {
     half: InningHalf,
     outs: Outs,
@@ -105,6 +108,13 @@ impl HalfInning {
         }
     }
 
+    /// The half inning with `runner` already standing on second, as the
+    /// automatic runner starts an extra inning.
+    pub(crate) fn with_runner_on_second(self, runner: BattingPosition) -> Self {
+        let baserunners = self.baserunners.set_second(Some(runner));
+        self.with_baserunners(baserunners)
+    }
+
     pub fn half(&self) -> InningHalf {
         self.half
     }
@@ -129,18 +139,46 @@ impl HalfInning {
         self.baserunners
     }
 
+    /// Outs that finished the plate appearance, so the next batter is due up
+    /// whether or not the side is retired.
     fn increment_outs(self, inc: Outs) -> HalfInningResult {
         let outs = self.outs + inc;
 
         if matches!(outs, Outs::Three) {
-            debug!("Inning over, runs scored: {}", self.runs_scored);
-            return HalfInningResult::Complete(HalfInningSummary::new(self.runs_scored));
+            return self.retire_side(self.current_batter.next());
         }
 
         self.set_outs(outs).advance_batter()
     }
 
+    /// Outs made on the bases mid plate appearance. If one retires the side the
+    /// batter never finished, so they lead off the next inning instead.
+    fn increment_runner_outs(self, inc: Outs) -> HalfInningResult {
+        let outs = self.outs + inc;
+
+        if matches!(outs, Outs::Three) {
+            return self.retire_side(self.current_batter);
+        }
+
+        HalfInningResult::in_progress(self.set_outs(outs))
+    }
+
+    fn retire_side(self, next_batter: BattingPosition) -> HalfInningResult {
+        debug!("Inning over, runs scored: {}", self.runs_scored);
+        HalfInningResult::Complete(HalfInningSummary::new(self.runs_scored, next_batter))
+    }
+
     pub fn advance(mut self, outcome: PitchOutcome) -> HalfInningResult {
+        if let PitchOutcome::Baserunning(event) = outcome {
+            // Resolved against the runners we own, like a ball in play, but the
+            // batter and the count are left exactly as they were.
+            let outcome = event.resolve(self.baserunners);
+            return self
+                .add_runs(outcome.runs_scored())
+                .with_baserunners(outcome.baserunners())
+                .increment_runner_outs(outcome.outs());
+        }
+
         let pa = self.current_pa.advance(outcome);
 
         match pa {
@@ -186,7 +224,7 @@ impl HalfInning {
         }
     }
 
-    fn set_outs(mut self, outs: Outs) -> Self {
+    pub(crate) fn set_outs(mut self, outs: Outs) -> Self {
         self.outs = outs;
         self
     }
@@ -202,7 +240,7 @@ impl HalfInning {
         self
     }
 
-    fn with_baserunners(mut self, baserunners: BaserunnerState) -> Self {
+    pub(crate) fn with_baserunners(mut self, baserunners: BaserunnerState) -> Self {
         self.baserunners = baserunners;
         self
     }
@@ -237,21 +275,32 @@ impl HalfInning {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HalfInningSummary {
     runs_scored: Runs,
+    next_batter: BattingPosition,
 }
 
 impl HalfInningSummary {
-    pub fn new(runs_scored: Runs) -> Self {
-        HalfInningSummary { runs_scored }
+    pub fn new(runs_scored: Runs, next_batter: BattingPosition) -> Self {
+        HalfInningSummary {
+            runs_scored,
+            next_batter,
+        }
     }
 
     pub fn runs_scored(&self) -> Runs {
         self.runs_scored
     }
+
+    /// Who leads off this team's next half inning.
+    pub fn next_batter(&self) -> BattingPosition {
+        self.next_batter
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HalfInningResult {
     InProgress(HalfInning),
     Complete(HalfInningSummary),
@@ -293,7 +342,7 @@ mod tests {
     use tracingx::info;
 
     use super::*;
-    use crate::baseball::{plate_appearance::PitchOutcome, play::PlayResult};
+    use crate::baseball::{baserunners::Base, plate_appearance::PitchOutcome, play::PlayResult, running::RunnerEvent};
 
     #[test]
     fn test_batting_position_as_number() {
@@ -513,4 +562,64 @@ mod tests {
             "the batter was retired for the third out, so the run cannot count"
         );
     }
+
+    #[test]
+    fn a_steal_moves_the_runner_without_touching_the_batter_or_the_count() {
+        let mut advance = HalfInningResult::InProgress(HalfInning::new(InningHalf::Top, BattingPosition::First));
+        advance = advance.advance(PitchOutcome::InPlay(PlayResult::Single));
+        advance = advance.advance(PitchOutcome::Ball);
+        advance = advance.advance(PitchOutcome::Baserunning(RunnerEvent::StolenBase(Base::Second)));
+
+        let hi = advance.half_inning().expect("nobody is out");
+        assert_eq!(hi.baserunners().first(), None);
+        assert_eq!(hi.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(hi.current_batter(), BattingPosition::Second, "still the same batter");
+        assert_eq!(
+            hi.current_plate_appearance().count().balls().as_number(),
+            1,
+            "the count stands"
+        );
+    }
+
+    #[test]
+    fn a_wild_pitch_with_a_runner_on_third_scores_a_run() {
+        let mut advance = HalfInningResult::InProgress(HalfInning::new(InningHalf::Top, BattingPosition::First));
+        advance = advance.advance(PitchOutcome::InPlay(PlayResult::Triple));
+        advance = advance.advance(PitchOutcome::Baserunning(RunnerEvent::WildPitch));
+
+        let hi = advance.half_inning().expect("nobody is out");
+        assert_eq!(hi.runs_scored(), 1);
+        assert!(hi.baserunners().is_empty());
+    }
+
+    #[test]
+    fn caught_stealing_for_the_third_out_brings_the_same_batter_back_next_inning() {
+        let mut advance = HalfInningResult::InProgress(HalfInning::new(InningHalf::Top, BattingPosition::First));
+        advance = advance.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        advance = advance.advance(PitchOutcome::InPlay(PlayResult::Groundout));
+        advance = advance.advance(PitchOutcome::InPlay(PlayResult::Single));
+        advance = advance.advance(PitchOutcome::Baserunning(RunnerEvent::CaughtStealing(Base::Second)));
+
+        let HalfInningResult::Complete(summary) = advance else {
+            panic!("the runner was the third out");
+        };
+        assert_eq!(
+            summary.next_batter(),
+            BattingPosition::Fourth,
+            "the batter at the plate never finished and leads off next time"
+        );
+    }
+
+    #[test]
+    fn a_batter_who_makes_the_third_out_is_followed_by_the_next_man_up() {
+        let mut advance = HalfInningResult::InProgress(HalfInning::new(InningHalf::Top, BattingPosition::Eighth));
+        for _ in 0..3 {
+            advance = advance.advance(PitchOutcome::InPlay(PlayResult::Flyout));
+        }
+
+        let HalfInningResult::Complete(summary) = advance else {
+            panic!("three outs end the inning");
+        };
+        assert_eq!(summary.next_batter(), BattingPosition::Second);
+    }
 }
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BattingPosition {
     #[default]
     First,
@@ -32,6 +33,19 @@ impl Display for BattingPosition {
 }
 
 impl BattingPosition {
+    /// The order, leadoff first.
+    pub const ALL: [BattingPosition; 9] = [
+        BattingPosition::First,
+        BattingPosition::Second,
+        BattingPosition::Third,
+        BattingPosition::Fourth,
+        BattingPosition::Fifth,
+        BattingPosition::Sixth,
+        BattingPosition::Seventh,
+        BattingPosition::Eighth,
+        BattingPosition::Ninth,
+    ];
+
     pub fn next(self) -> BattingPosition {
         use BattingPosition::*;
         match self {
@@ -47,6 +61,21 @@ impl BattingPosition {
         }
     }
 
+    pub fn previous(self) -> BattingPosition {
+        use BattingPosition::*;
+        match self {
+            First => Ninth,
+            Second => First,
+            Third => Second,
+            Fourth => Third,
+            Fifth => Fourth,
+            Sixth => Fifth,
+            Seventh => Sixth,
+            Eighth => Seventh,
+            Ninth => Eighth,
+        }
+    }
+
     pub fn num(self) -> u8 {
         use BattingPosition::*;
         match self {
@@ -61,6 +90,11 @@ impl BattingPosition {
             Ninth => 9,
         }
     }
+
+    /// Zero-based, for indexing a nine-slot array.
+    pub(crate) fn index(self) -> usize {
+        usize::from(self.num() - 1)
+    }
 }
 
 impl From<BattingPosition> for u8 {
@@ -70,6 +104,7 @@ impl From<BattingPosition> for u8 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlayerPosition {
     Pitcher,
     Catcher,
@@ -154,6 +189,14 @@ mod test {
         assert_eq!(BattingPosition::Ninth.next(), BattingPosition::First);
     }
 
+    #[test]
+    fn previous_undoes_next() {
+        for slot in BattingPosition::ALL {
+            assert_eq!(slot.next().previous(), slot);
+        }
+        assert_eq!(BattingPosition::First.previous(), BattingPosition::Ninth);
+    }
+
     #[test]
     fn demo_batting_position_api() {
         info!("Creating batting positions - no Result unwrapping needed!");
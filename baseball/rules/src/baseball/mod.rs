@@ -1,16 +1,34 @@
 mod baserunners;
+mod box_score;
+mod event_log;
+mod expectancy;
 mod field;
 mod game;
 mod inning;
 mod lineup;
+mod outcome_weights;
 mod plate_appearance;
 mod play;
+mod roster;
+mod rule_set;
+mod running;
 mod runs;
+#[cfg(feature = "serde")]
+mod save;
 
-pub use baserunners::{Base, BaseOutcome, BaserunnerState, HomeOutcome, PlayOutcome};
+pub use baserunners::{Base, BaseOutcome, BaserunnerState, Destination, HomeOutcome, PlayOutcome};
+pub use box_score::{BattingLine, BoxScore, Decision, PitchingLine, TeamBox};
+pub use event_log::{Divergence, EventLog, EventState, GameEvent, LoggedEvent, ReplayError};
+pub use expectancy::{MAX_RUNS, RunExpectancy, WinExpectancy};
 pub use game::{Game, GameOutcome, GameScore, GameStatus, GameSummary, GameWinner, InningNumber, LineScore};
 pub use inning::{HalfInning, InningHalf, Outs};
 pub use lineup::{BattingPosition, PlayerPosition};
+pub use outcome_weights::{OutcomeWeights, PlateAppearanceOutcome};
 pub use plate_appearance::{Balls, Count, PitchOutcome, Strikes};
-pub use play::PlayResult;
+pub use play::{PlayResult, RunnerAdvance, Scoring};
+pub use roster::{Lineup, LineupError, LineupSlot, Player, PlayerId, Roster, Substitution};
+pub use rule_set::{MercyRule, RuleSet};
+pub use running::RunnerEvent;
 pub use runs::{HomePlateRuns, Runs};
+#[cfg(feature = "serde")]
+pub use save::SavedGame;
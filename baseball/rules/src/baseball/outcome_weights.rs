@@ -0,0 +1,179 @@
+use super::{
+    inning::{HalfInning, Outs},
+    plate_appearance::PitchOutcome,
+    play::PlayResult,
+};
+
+/// How a plate appearance ends, with the pitches in between left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlateAppearanceOutcome {
+    Strikeout,
+    Walk,
+    HitByPitch,
+    HomeRun,
+    InPlay(PlayResult),
+}
+
+impl PlateAppearanceOutcome {
+    /// The pitches that produce this outcome from an 0-0 count.
+    ///
+    /// A fly ball with a runner on third and fewer than two outs becomes a
+    /// sacrifice fly, since there is no fielder here to decide whether the
+    /// runner tags.
+    pub fn pitches(self, situation: &HalfInning) -> Vec<PitchOutcome> {
+        match self {
+            PlateAppearanceOutcome::Strikeout => vec![PitchOutcome::Strike; 3],
+            PlateAppearanceOutcome::Walk => vec![PitchOutcome::Ball; 4],
+            PlateAppearanceOutcome::HitByPitch => vec![PitchOutcome::HitByPitch],
+            PlateAppearanceOutcome::HomeRun => vec![PitchOutcome::HomeRun],
+            PlateAppearanceOutcome::InPlay(PlayResult::Flyout)
+                if situation.baserunners().third().is_some()
+                    && situation.outs().as_number() < Outs::Two.as_number() =>
+            {
+                vec![PitchOutcome::InPlay(PlayResult::SacrificeFly)]
+            }
+            PlateAppearanceOutcome::InPlay(play) => vec![PitchOutcome::InPlay(play)],
+        }
+    }
+}
+
+/// Relative weight of every way a plate appearance can end. The weights are
+/// relative, so rates per plate appearance and raw season counts both work.
+/// Missing fields read as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OutcomeWeights {
+    pub strikeout: f64,
+    pub walk: f64,
+    pub hit_by_pitch: f64,
+    pub single: f64,
+    pub double: f64,
+    pub triple: f64,
+    pub home_run: f64,
+    pub groundout: f64,
+    pub flyout: f64,
+    pub lineout: f64,
+    pub popout: f64,
+}
+
+impl OutcomeWeights {
+    /// Roughly a major league hitter of the mid-2020s, per 1000 plate
+    /// appearances.
+    pub fn league_average() -> Self {
+        OutcomeWeights {
+            strikeout: 225.0,
+            walk: 85.0,
+            hit_by_pitch: 11.0,
+            single: 140.0,
+            double: 44.0,
+            triple: 4.0,
+            home_run: 30.0,
+            groundout: 230.0,
+            flyout: 140.0,
+            lineout: 41.0,
+            popout: 50.0,
+        }
+    }
+
+    /// Every outcome beside its weight.
+    pub fn entries(&self) -> [(f64, PlateAppearanceOutcome); 11] {
+        [
+            (self.strikeout, PlateAppearanceOutcome::Strikeout),
+            (self.walk, PlateAppearanceOutcome::Walk),
+            (self.hit_by_pitch, PlateAppearanceOutcome::HitByPitch),
+            (self.single, PlateAppearanceOutcome::InPlay(PlayResult::Single)),
+            (self.double, PlateAppearanceOutcome::InPlay(PlayResult::Double)),
+            (self.triple, PlateAppearanceOutcome::InPlay(PlayResult::Triple)),
+            (self.home_run, PlateAppearanceOutcome::HomeRun),
+            (self.groundout, PlateAppearanceOutcome::InPlay(PlayResult::Groundout)),
+            (self.flyout, PlateAppearanceOutcome::InPlay(PlayResult::Flyout)),
+            (self.lineout, PlateAppearanceOutcome::InPlay(PlayResult::Lineout)),
+            (self.popout, PlateAppearanceOutcome::InPlay(PlayResult::Popout)),
+        ]
+    }
+
+    pub fn total(&self) -> f64 {
+        self.entries().iter().map(|(weight, _)| weight).sum()
+    }
+
+    /// Usable weights are finite, none negative, and not all zero.
+    pub fn is_valid(&self) -> bool {
+        self.entries()
+            .iter()
+            .all(|(weight, _)| weight.is_finite() && *weight >= 0.0)
+            && self.total() > 0.0
+    }
+
+    pub fn can_make_an_out(&self) -> bool {
+        self.strikeout + self.groundout + self.flyout + self.lineout + self.popout > 0.0
+    }
+
+    /// The outcome a uniform draw in `[0, 1)` lands on, so a caller with a
+    /// random number generator can sample plate appearances.
+    pub fn pick(&self, draw: f64) -> PlateAppearanceOutcome {
+        let entries = self.entries();
+        let mut draw = draw * self.total();
+        for (weight, outcome) in entries {
+            if draw < weight {
+                return outcome;
+            }
+            draw -= weight;
+        }
+        // Only reachable through floating-point rounding at the very top.
+        entries
+            .iter()
+            .rev()
+            .find(|(weight, _)| *weight > 0.0)
+            .map_or(PlateAppearanceOutcome::Strikeout, |(_, outcome)| *outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BattingPosition, Game, InningHalf};
+
+    #[test]
+    fn a_draw_lands_in_proportion_to_the_weights() {
+        let weights = OutcomeWeights {
+            strikeout: 3.0,
+            walk: 1.0,
+            ..OutcomeWeights::default()
+        };
+        assert_eq!(weights.pick(0.0), PlateAppearanceOutcome::Strikeout);
+        assert_eq!(weights.pick(0.74), PlateAppearanceOutcome::Strikeout);
+        assert_eq!(weights.pick(0.76), PlateAppearanceOutcome::Walk);
+        assert_eq!(weights.pick(0.999_999), PlateAppearanceOutcome::Walk);
+    }
+
+    #[test]
+    fn a_zero_weight_is_never_picked() {
+        let weights = OutcomeWeights {
+            home_run: 1.0,
+            ..OutcomeWeights::default()
+        };
+        assert!(
+            [0.0, 0.5, 1.0]
+                .into_iter()
+                .all(|draw| weights.pick(draw) == PlateAppearanceOutcome::HomeRun)
+        );
+    }
+
+    #[test]
+    fn a_fly_ball_with_a_runner_on_third_is_a_sacrifice_fly() {
+        let empty = HalfInning::new(InningHalf::Top, BattingPosition::First);
+        let fly = PlateAppearanceOutcome::InPlay(PlayResult::Flyout);
+        assert_eq!(fly.pitches(&empty), vec![PitchOutcome::InPlay(PlayResult::Flyout)]);
+
+        let game = Game::default()
+            .advance(PitchOutcome::InPlay(PlayResult::Triple))
+            .game()
+            .expect("a triple is not an out");
+        assert_eq!(
+            fly.pitches(game.current_half_inning()),
+            vec![PitchOutcome::InPlay(PlayResult::SacrificeFly)]
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
-use super::play::PlayResult;
+use super::{play::PlayResult, running::RunnerEvent};
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Balls {
     #[default]
     Zero,
@@ -29,6 +30,7 @@ impl std::fmt::Display for Balls {
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Strikes {
     #[default]
     Zero,
@@ -53,6 +55,7 @@ impl std::fmt::Display for Strikes {
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Count {
     balls: Balls,
     strikes: Strikes,
@@ -129,6 +132,7 @@ impl Count {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CountResult {
     InProgress(Count),
     Strikeout,
@@ -142,6 +146,7 @@ impl From<Count> for CountResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PitchOutcome {
     Ball,
     Strike,
@@ -149,6 +154,9 @@ pub enum PitchOutcome {
     InPlay(PlayResult),
     HomeRun,
     HitByPitch,
+    /// Runners move, or are retired, while the batter stays at the plate with
+    /// the count unchanged.
+    Baserunning(RunnerEvent),
 }
 
 impl Display for PitchOutcome {
@@ -160,6 +168,7 @@ impl Display for PitchOutcome {
             PitchOutcome::InPlay(play) => write!(f, "InPlay({play})"),
             PitchOutcome::HomeRun => write!(f, "HomeRun"),
             PitchOutcome::HitByPitch => write!(f, "HitByPitch"),
+            PitchOutcome::Baserunning(event) => write!(f, "Baserunning({event})"),
         }
     }
 }
@@ -174,11 +183,13 @@ impl PitchOutcome {
             PitchOutcome::Foul => "FOUL BALL",
             PitchOutcome::HitByPitch => "HIT BY PITCH",
             PitchOutcome::InPlay(play) => play.label(),
+            PitchOutcome::Baserunning(event) => event.label(),
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlateAppearance {
     count: Count,
 }
@@ -208,6 +219,7 @@ impl PlateAppearance {
             PitchOutcome::InPlay(outcome) => PlateAppearanceResult::InPlay(outcome),
             PitchOutcome::HomeRun => PlateAppearanceResult::HomeRun,
             PitchOutcome::HitByPitch => PlateAppearanceResult::HitByPitch,
+            PitchOutcome::Baserunning(_) => PlateAppearanceResult::InProgress(self),
         }
     }
 
@@ -217,6 +229,7 @@ impl PlateAppearance {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlateAppearanceResult {
     InProgress(PlateAppearance),
     InPlay(PlayResult),
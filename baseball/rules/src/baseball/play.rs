@@ -7,12 +7,21 @@
 //! the play instead and lets [`HalfInning`](super::inning::HalfInning) resolve it
 //! against the baserunners it already owns, so there is no second copy of the
 //! truth to get wrong.
+//!
+//! When a named play moves the runners the wrong way — a single that scores the
+//! runner from second, a runner thrown out stretching — [`PlayResult::Advanced`]
+//! keeps the scoring of a named play and says where each runner went instead.
+//! Runners it says nothing about move the default way.
 
 use std::fmt::Display;
 
-use super::{baserunners::PlayOutcome, lineup::BattingPosition};
+use super::{
+    baserunners::{Base, BaserunnerState, Destination, PlayOutcome},
+    lineup::{BattingPosition, PlayerPosition},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlayResult {
     Single,
     Double,
@@ -31,43 +40,230 @@ pub enum PlayResult {
     FieldersChoice,
     /// Two outs on one ball: the forced runner and the batter.
     DoublePlay,
+    /// The batter reaches first on a misplay charged to this fielder, and every
+    /// runner moves up a base as on a single.
+    Error(PlayerPosition),
+    /// The batter bunts and is thrown out at first while the runners move up
+    /// a base where the base ahead comes free. A runner on third holds; when
+    /// they score it is a [`SqueezePlay`](PlayResult::SqueezePlay).
+    SacrificeBunt,
+    /// A bunt with the runner from third already running: the batter is thrown
+    /// out at first and every runner moves up a base.
+    SqueezePlay,
+    /// Rule 5.09(a)(5): a fair fly an infielder can catch with ordinary effort,
+    /// with first and second occupied and fewer than two out. The batter is out
+    /// whether or not the ball is caught, so the runners are not forced and
+    /// hold.
+    InfieldFly,
+    /// Three outs on one ball: the batter and the runners forced from first and
+    /// second. Without both to force it degrades to a double play.
+    TriplePlay,
+    /// A play spelled out runner by runner, scored as `Scoring` says. Anyone
+    /// the [`RunnerAdvance`] leaves out moves as that scoring normally would.
+    Advanced(Scoring, RunnerAdvance),
+}
+
+/// How the official scorer credits a play whose runners are spelled out by
+/// [`PlayResult::Advanced`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scoring {
+    /// Batter to first, runners up a base.
+    Single,
+    /// Batter to second, runners up two.
+    Double,
+    /// Batter to third, everyone scores.
+    Triple,
+    /// Batter retired, runners hold.
+    Out,
+    /// Batter struck out, runners hold. He is still out unless the catcher
+    /// drops strike three and the batter beats the play to first.
+    Strikeout,
+    /// Batter retired without being charged an at-bat, runners hold.
+    Sacrifice,
+    /// Batter to first, runner from first retired, the rest hold.
+    FieldersChoice,
+    /// Batter to first on this fielder's misplay, runners up a base.
+    Error(PlayerPosition),
+}
+
+/// Where the batter and each runner ended a play, for those who did not go
+/// where the play's scoring would send them. `None` means the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunnerAdvance {
+    pub batter: Option<Destination>,
+    pub first: Option<Destination>,
+    pub second: Option<Destination>,
+    pub third: Option<Destination>,
+}
+
+impl RunnerAdvance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_batter(self, destination: Destination) -> Self {
+        RunnerAdvance {
+            batter: Some(destination),
+            ..self
+        }
+    }
+
+    /// Sends the runner who started the play on `from` to `destination`.
+    /// Nobody starts on home, so `Base::Home` changes nothing.
+    pub fn with_runner(self, from: Base, destination: Destination) -> Self {
+        match from {
+            Base::First => RunnerAdvance {
+                first: Some(destination),
+                ..self
+            },
+            Base::Second => RunnerAdvance {
+                second: Some(destination),
+                ..self
+            },
+            Base::Third => RunnerAdvance {
+                third: Some(destination),
+                ..self
+            },
+            Base::Home => self,
+        }
+    }
+
+    fn over(self, defaults: [Destination; 4]) -> [Destination; 4] {
+        [
+            self.batter.unwrap_or(defaults[0]),
+            self.first.unwrap_or(defaults[1]),
+            self.second.unwrap_or(defaults[2]),
+            self.third.unwrap_or(defaults[3]),
+        ]
+    }
+}
+
+impl Scoring {
+    /// Where everyone goes when nothing says otherwise: batter, then the
+    /// runners on first, second and third.
+    fn defaults(self) -> [Destination; 4] {
+        use Destination::{Out, Safe};
+        match self {
+            Scoring::Single | Scoring::Error(_) => [
+                Safe(Base::First),
+                Safe(Base::Second),
+                Safe(Base::Third),
+                Safe(Base::Home),
+            ],
+            Scoring::Double => [
+                Safe(Base::Second),
+                Safe(Base::Third),
+                Safe(Base::Home),
+                Safe(Base::Home),
+            ],
+            Scoring::Triple => [Safe(Base::Third), Safe(Base::Home), Safe(Base::Home), Safe(Base::Home)],
+            Scoring::Out | Scoring::Strikeout | Scoring::Sacrifice => {
+                [Out, Safe(Base::First), Safe(Base::Second), Safe(Base::Third)]
+            }
+            Scoring::FieldersChoice => [Safe(Base::First), Out, Safe(Base::Second), Safe(Base::Third)],
+        }
+    }
+
+    /// Whether the batter is charged an at-bat.
+    fn is_at_bat(self) -> bool {
+        self != Scoring::Sacrifice
+    }
+}
+
+/// With the batter out at first, each runner moves up a base if the one ahead
+/// comes free, the lead runner first. A runner on third scores only if
+/// `third_scores`.
+fn move_up(baserunners: BaserunnerState, third_scores: bool) -> [Destination; 4] {
+    use Destination::{Out, Safe};
+    let third_moves = baserunners.third().is_none() || third_scores;
+    let second_moves = baserunners.second().is_none() || third_moves;
+    let first_moves = baserunners.first().is_none() || second_moves;
+    let to = |moves: bool, from: Base| Safe(if moves { from.next() } else { from });
+    [
+        Out,
+        to(first_moves, Base::First),
+        to(second_moves, Base::Second),
+        to(third_moves, Base::Third),
+    ]
 }
 
 impl PlayResult {
     /// Applies this play to a specific game state.
-    pub(crate) fn resolve(
-        self,
-        baserunners: super::baserunners::BaserunnerState,
-        batter: BattingPosition,
-    ) -> PlayOutcome {
+    pub(crate) fn resolve(self, baserunners: BaserunnerState, batter: BattingPosition) -> PlayOutcome {
         match self {
-            PlayResult::Single => PlayOutcome::single(baserunners, batter),
+            PlayResult::Single | PlayResult::Error(_) => PlayOutcome::single(baserunners, batter),
             PlayResult::Double => PlayOutcome::double(baserunners, batter),
             PlayResult::Triple => PlayOutcome::triple(baserunners, batter),
             PlayResult::Groundout => PlayOutcome::groundout(baserunners),
-            PlayResult::Flyout | PlayResult::Lineout | PlayResult::Popout => PlayOutcome::flyout(baserunners),
+            PlayResult::Flyout | PlayResult::Lineout | PlayResult::Popout | PlayResult::InfieldFly => {
+                PlayOutcome::flyout(baserunners)
+            }
             PlayResult::SacrificeFly => PlayOutcome::sacrifice_fly(baserunners),
             PlayResult::FieldersChoice => PlayOutcome::fielders_choice(baserunners, batter),
             PlayResult::DoublePlay => PlayOutcome::double_play(baserunners),
+            PlayResult::SacrificeBunt => {
+                PlayOutcome::from_destinations(baserunners, batter, move_up(baserunners, false))
+            }
+            PlayResult::SqueezePlay => PlayOutcome::from_destinations(baserunners, batter, move_up(baserunners, true)),
+            PlayResult::TriplePlay => {
+                if baserunners.first().is_none() || baserunners.second().is_none() {
+                    return PlayOutcome::double_play(baserunners);
+                }
+                let out = Destination::Out;
+                PlayOutcome::from_destinations(baserunners, batter, [out, out, out, Destination::Safe(Base::Third)])
+            }
+            PlayResult::Advanced(scoring, advance) => {
+                PlayOutcome::from_destinations(baserunners, batter, advance.over(scoring.defaults()))
+            }
+        }
+    }
+
+    /// How the play goes in the scorebook, whatever happened to the runners.
+    pub fn scoring(self) -> Scoring {
+        match self {
+            PlayResult::Single => Scoring::Single,
+            PlayResult::Double => Scoring::Double,
+            PlayResult::Triple => Scoring::Triple,
+            PlayResult::Groundout
+            | PlayResult::Flyout
+            | PlayResult::Lineout
+            | PlayResult::Popout
+            | PlayResult::DoublePlay
+            | PlayResult::InfieldFly
+            | PlayResult::TriplePlay => Scoring::Out,
+            PlayResult::SacrificeFly | PlayResult::SacrificeBunt | PlayResult::SqueezePlay => Scoring::Sacrifice,
+            PlayResult::FieldersChoice => Scoring::FieldersChoice,
+            PlayResult::Error(fielder) => Scoring::Error(fielder),
+            PlayResult::Advanced(scoring, _) => scoring,
         }
     }
 
     /// Whether the batter was retired, before any base-running is resolved.
     pub fn is_out(self) -> bool {
-        matches!(
-            self,
-            PlayResult::Groundout
-                | PlayResult::Flyout
-                | PlayResult::Lineout
-                | PlayResult::Popout
-                | PlayResult::SacrificeFly
-                | PlayResult::DoublePlay
-        )
+        match self {
+            PlayResult::Advanced(scoring, advance) => advance.over(scoring.defaults())[0] == Destination::Out,
+            _ => matches!(self.scoring(), Scoring::Out | Scoring::Sacrifice),
+        }
     }
 
     /// Whether the batter is credited with a hit.
     pub fn is_hit(self) -> bool {
-        matches!(self, PlayResult::Single | PlayResult::Double | PlayResult::Triple)
+        matches!(self.scoring(), Scoring::Single | Scoring::Double | Scoring::Triple)
+    }
+
+    /// Whether the batter is charged an at-bat.
+    pub fn is_at_bat(self) -> bool {
+        self.scoring().is_at_bat()
+    }
+
+    /// The fielder charged with an error on the play, if any.
+    pub fn error(self) -> Option<PlayerPosition> {
+        match self.scoring() {
+            Scoring::Error(fielder) => Some(fielder),
+            _ => None,
+        }
     }
 
     /// Short all-caps label for the result banner.
@@ -83,6 +279,21 @@ impl PlayResult {
             PlayResult::SacrificeFly => "SAC FLY",
             PlayResult::FieldersChoice => "FIELDER'S CHOICE",
             PlayResult::DoublePlay => "DOUBLE PLAY!",
+            PlayResult::Error(_) => "ERROR",
+            PlayResult::SacrificeBunt => "SAC BUNT",
+            PlayResult::SqueezePlay => "SQUEEZE!",
+            PlayResult::InfieldFly => "INFIELD FLY",
+            PlayResult::TriplePlay => "TRIPLE PLAY!",
+            PlayResult::Advanced(scoring, _) => match scoring {
+                Scoring::Single => "SINGLE!",
+                Scoring::Double => "DOUBLE!",
+                Scoring::Triple => "TRIPLE!",
+                Scoring::Out => "OUT",
+                Scoring::Strikeout => "STRIKEOUT",
+                Scoring::Sacrifice => "SACRIFICE",
+                Scoring::FieldersChoice => "FIELDER'S CHOICE",
+                Scoring::Error(_) => "ERROR",
+            },
         }
     }
 }
@@ -95,7 +306,7 @@ impl Display for PlayResult {
 
 #[cfg(test)]
 mod tests {
-    use super::{super::baserunners::BaserunnerState, *};
+    use super::{super::inning::Outs, *};
 
     fn loaded() -> BaserunnerState {
         BaserunnerState::empty()
@@ -112,7 +323,7 @@ mod tests {
         assert_eq!(after.first(), Some(BattingPosition::First));
         assert_eq!(after.second(), Some(BattingPosition::Second));
         assert_eq!(after.third(), Some(BattingPosition::Third));
-        assert_eq!(outcome.outs(), super::super::inning::Outs::One);
+        assert_eq!(outcome.outs(), Outs::One);
         assert_eq!(outcome.runs_scored(), 0);
     }
 
@@ -120,7 +331,7 @@ mod tests {
     fn a_flyout_with_nobody_on_records_one_out_and_nothing_else() {
         let outcome = PlayResult::Flyout.resolve(BaserunnerState::empty(), BattingPosition::First);
         assert!(outcome.baserunners().is_empty());
-        assert_eq!(outcome.outs(), super::super::inning::Outs::One);
+        assert_eq!(outcome.outs(), Outs::One);
     }
 
     #[test]
@@ -133,7 +344,7 @@ mod tests {
         assert_eq!(outcome.runs_scored(), 1);
         assert_eq!(outcome.baserunners().first(), Some(BattingPosition::First));
         assert_eq!(outcome.baserunners().third(), None);
-        assert_eq!(outcome.outs(), super::super::inning::Outs::One);
+        assert_eq!(outcome.outs(), Outs::One);
     }
 
     #[test]
@@ -153,7 +364,7 @@ mod tests {
             .set_second(Some(BattingPosition::Second));
         let outcome = PlayResult::DoublePlay.resolve(runners, BattingPosition::Third);
 
-        assert_eq!(outcome.outs(), super::super::inning::Outs::Two);
+        assert_eq!(outcome.outs(), Outs::Two);
         assert_eq!(outcome.baserunners().first(), None);
         assert_eq!(outcome.baserunners().second(), None);
         assert_eq!(
@@ -168,7 +379,7 @@ mod tests {
         let runners = BaserunnerState::empty().set_second(Some(BattingPosition::Second));
         let outcome = PlayResult::DoublePlay.resolve(runners, BattingPosition::Third);
 
-        assert_eq!(outcome.outs(), super::super::inning::Outs::One, "no force available");
+        assert_eq!(outcome.outs(), Outs::One, "no force available");
         assert_eq!(outcome.baserunners().second(), Some(BattingPosition::Second));
     }
 
@@ -177,7 +388,7 @@ mod tests {
         let runners = BaserunnerState::empty().set_first(Some(BattingPosition::First));
         let outcome = PlayResult::FieldersChoice.resolve(runners, BattingPosition::Second);
 
-        assert_eq!(outcome.outs(), super::super::inning::Outs::One);
+        assert_eq!(outcome.outs(), Outs::One);
         assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Second));
         assert_eq!(outcome.baserunners().second(), None);
     }
@@ -186,7 +397,7 @@ mod tests {
     fn a_fielders_choice_with_nobody_forced_is_a_single() {
         let outcome = PlayResult::FieldersChoice.resolve(BaserunnerState::empty(), BattingPosition::First);
 
-        assert_eq!(outcome.outs(), super::super::inning::Outs::Zero);
+        assert_eq!(outcome.outs(), Outs::Zero);
         assert_eq!(outcome.baserunners().first(), Some(BattingPosition::First));
     }
 
@@ -219,4 +430,140 @@ mod tests {
                 .suppresses_runs_on_third_out()
         );
     }
+
+    #[test]
+    fn an_error_puts_the_batter_on_and_moves_everyone_up_without_a_hit() {
+        let play = PlayResult::Error(PlayerPosition::Shortstop);
+        let outcome = play.resolve(loaded(), BattingPosition::Fourth);
+
+        assert_eq!(outcome.runs_scored(), 1);
+        assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Fourth));
+        assert_eq!(outcome.outs(), Outs::Zero);
+        assert!(!play.is_hit() && !play.is_out() && play.is_at_bat());
+        assert_eq!(play.error(), Some(PlayerPosition::Shortstop));
+    }
+
+    #[test]
+    fn a_sacrifice_bunt_moves_the_runners_up_for_the_batter() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let outcome = PlayResult::SacrificeBunt.resolve(runners, BattingPosition::Third);
+
+        assert_eq!(outcome.outs(), Outs::One);
+        assert_eq!(outcome.baserunners().first(), None);
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::Second));
+        assert!(!PlayResult::SacrificeBunt.is_at_bat());
+    }
+
+    #[test]
+    fn a_sacrifice_bunt_cannot_move_a_runner_into_an_occupied_base() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Third));
+        let outcome = PlayResult::SacrificeBunt.resolve(runners, BattingPosition::Fourth);
+
+        assert_eq!(outcome.runs_scored(), 0);
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::Third));
+
+        let outcome = PlayResult::SacrificeBunt.resolve(loaded(), BattingPosition::Fourth);
+        assert_eq!(outcome.baserunners(), loaded(), "nobody can move");
+    }
+
+    #[test]
+    fn a_squeeze_scores_the_runner_from_third() {
+        let outcome = PlayResult::SqueezePlay.resolve(loaded(), BattingPosition::Fourth);
+
+        assert_eq!(outcome.runs_scored(), 1);
+        assert_eq!(outcome.outs(), Outs::One);
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::Second));
+    }
+
+    #[test]
+    fn an_infield_fly_retires_the_batter_and_nobody_else() {
+        let outcome = PlayResult::InfieldFly.resolve(loaded(), BattingPosition::Fourth);
+
+        assert_eq!(outcome.outs(), Outs::One);
+        assert_eq!(outcome.baserunners(), loaded());
+        assert!(PlayResult::InfieldFly.is_out());
+    }
+
+    #[test]
+    fn a_triple_play_needs_two_runners_to_force() {
+        let outcome = PlayResult::TriplePlay.resolve(loaded(), BattingPosition::Fourth);
+        assert_eq!(outcome.outs(), Outs::Three);
+        assert!(outcome.suppresses_runs_on_third_out());
+
+        let runner_on_first = BaserunnerState::empty().set_first(Some(BattingPosition::First));
+        let outcome = PlayResult::TriplePlay.resolve(runner_on_first, BattingPosition::Second);
+        assert_eq!(outcome.outs(), Outs::Two, "only a double play to be had");
+    }
+
+    #[test]
+    fn a_single_can_score_the_runner_from_second() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let advance = RunnerAdvance::new().with_runner(Base::Second, Destination::Safe(Base::Home));
+        let play = PlayResult::Advanced(Scoring::Single, advance);
+        let outcome = play.resolve(runners, BattingPosition::Third);
+
+        assert_eq!(outcome.runs_scored(), 1);
+        assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Third));
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.baserunners().third(), None);
+        assert!(play.is_hit());
+        assert_eq!(play.label(), "SINGLE!");
+    }
+
+    #[test]
+    fn a_batter_thrown_out_stretching_still_has_a_hit() {
+        let runners = BaserunnerState::empty().set_third(Some(BattingPosition::Third));
+        let play = PlayResult::Advanced(Scoring::Double, RunnerAdvance::new().with_batter(Destination::Out));
+        let outcome = play.resolve(runners, BattingPosition::Fourth);
+
+        assert_eq!(outcome.outs(), Outs::One);
+        assert_eq!(outcome.runs_scored(), 1);
+        assert!(outcome.baserunners().is_empty());
+        assert!(play.is_hit() && play.is_out());
+    }
+
+    #[test]
+    fn a_runner_thrown_out_at_home_is_a_tag_out_that_keeps_the_earlier_run() {
+        let advance = RunnerAdvance::new()
+            .with_runner(Base::Second, Destination::Out)
+            .with_runner(Base::First, Destination::Safe(Base::Third));
+        let outcome = PlayResult::Advanced(Scoring::Single, advance).resolve(loaded(), BattingPosition::Fourth);
+
+        assert_eq!(outcome.outs(), Outs::One);
+        assert_eq!(outcome.runs_scored(), 1, "the runner from third");
+        assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Fourth));
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::First));
+        assert!(!outcome.suppresses_runs_on_third_out());
+    }
+
+    #[test]
+    fn runners_told_to_hold_are_pushed_along_by_the_runner_behind() {
+        let runners = BaserunnerState::empty().set_first(Some(BattingPosition::First));
+        let advance = RunnerAdvance::new().with_runner(Base::First, Destination::Safe(Base::First));
+        let outcome = PlayResult::Advanced(Scoring::Single, advance).resolve(runners, BattingPosition::Second);
+
+        assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Second));
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+    }
+
+    #[test]
+    fn a_dropped_third_strike_is_a_strikeout_the_batter_can_survive() {
+        let advance = RunnerAdvance::new().with_batter(Destination::Safe(Base::First));
+        let play = PlayResult::Advanced(Scoring::Strikeout, advance);
+        let outcome = play.resolve(BaserunnerState::empty(), BattingPosition::Fourth);
+
+        assert_eq!(outcome.outs(), Outs::Zero);
+        assert_eq!(outcome.baserunners().first(), Some(BattingPosition::Fourth));
+        assert!(play.is_at_bat() && !play.is_hit() && !play.is_out());
+        assert_eq!(play.label(), "STRIKEOUT");
+    }
 }
@@ -0,0 +1,470 @@
+//! Who is actually playing.
+//!
+//! The rest of the engine only ever talks about [`BattingPosition`] slots: a
+//! runner on first is "the fifth hitter", not a person. That keeps the state
+//! machine small, and this module is the one place a slot is bound to a player.
+//! Swapping who fills a slot is all a substitution is, so none of the inning or
+//! baserunner logic has to know that a pinch runner now stands where the slow
+//! catcher used to.
+
+use std::fmt::Display;
+
+use super::lineup::{BattingPosition, PlayerPosition};
+
+/// Stable identifier for a player. The engine never interprets it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerId(pub u32);
+
+impl Display for PlayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    id: PlayerId,
+    name: String,
+}
+
+impl Player {
+    pub fn new(id: PlayerId, name: impl Into<String>) -> Self {
+        Player { id, name: name.into() }
+    }
+
+    pub fn id(&self) -> PlayerId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Everyone a team may use in a game, starters and bench alike.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roster {
+    players: Vec<Player>,
+}
+
+impl Roster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_player(mut self, player: Player) -> Self {
+        self.players.push(player);
+        self
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn get(&self, id: PlayerId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn contains(&self, id: PlayerId) -> bool {
+        self.get(id).is_some()
+    }
+}
+
+/// One line of the lineup card: who bats in a slot and where they field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineupSlot {
+    pub player: PlayerId,
+    pub position: PlayerPosition,
+}
+
+/// A change to the lineup, named the way a manager would hand it to the umpire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Substitution {
+    /// `player` bats in `slot`; whoever was there leaves the game.
+    PinchHitter { slot: BattingPosition, player: PlayerId },
+    /// `player` takes over as the runner for `slot`; whoever was there leaves
+    /// the game. The runner keeps their base because bases hold slots.
+    PinchRunner { slot: BattingPosition, player: PlayerId },
+    /// `player` takes over `slot` and the position that goes with it.
+    DefensiveReplacement { slot: BattingPosition, player: PlayerId },
+    /// The player batting in `slot` trades fielding positions with whoever is
+    /// playing `position`. Neither may be the pitcher or the designated hitter.
+    DefensiveSwitch {
+        slot: BattingPosition,
+        position: PlayerPosition,
+    },
+    /// `player` takes the mound. Without a designated hitter they also take the
+    /// outgoing pitcher's turn in the order.
+    PitchingChange { player: PlayerId },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineupError {
+    /// The player is not on this team's roster.
+    NotOnRoster(PlayerId),
+    /// The player appears more than once on the card.
+    DuplicatePlayer(PlayerId),
+    /// The player is already in the game.
+    AlreadyInGame(PlayerId),
+    /// Rule 5.10(d): a player once removed may not re-enter.
+    CannotReenter(PlayerId),
+    /// Nobody on the card plays this position.
+    MissingPosition(PlayerPosition),
+    /// More than one player on the card plays this position.
+    DuplicatePosition(PlayerPosition),
+    /// The pitcher named on the card is not the one batting in the pitcher's
+    /// slot, or bats despite a designated hitter.
+    PitcherMismatch(PlayerId),
+    /// Pitchers and designated hitters change through their own substitutions,
+    /// not a defensive switch.
+    CannotSwitchTo(PlayerPosition),
+    /// The game was started without lineup cards, so there is nobody to swap.
+    NoLineup,
+    /// The card uses a designated hitter under rules that do not allow one.
+    DesignatedHitterNotAllowed,
+}
+
+impl Display for LineupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineupError::NotOnRoster(player) => write!(f, "player {player} is not on the roster"),
+            LineupError::DuplicatePlayer(player) => write!(f, "player {player} is on the card twice"),
+            LineupError::AlreadyInGame(player) => write!(f, "player {player} is already in the game"),
+            LineupError::CannotReenter(player) => write!(f, "player {player} has been removed and cannot re-enter"),
+            LineupError::MissingPosition(position) => write!(f, "nobody is playing {position}"),
+            LineupError::DuplicatePosition(position) => write!(f, "more than one player is playing {position}"),
+            LineupError::PitcherMismatch(player) => {
+                write!(f, "player {player} does not match the pitcher's place in the order")
+            }
+            LineupError::CannotSwitchTo(position) => write!(f, "cannot switch a fielder to {position}"),
+            LineupError::NoLineup => write!(f, "this game has no lineup cards"),
+            LineupError::DesignatedHitterNotAllowed => write!(f, "these rules do not allow a designated hitter"),
+        }
+    }
+}
+
+impl std::error::Error for LineupError {}
+
+/// The defensive positions every card must fill, apart from the pitcher.
+const FIELDERS: [PlayerPosition; 8] = [
+    PlayerPosition::Catcher,
+    PlayerPosition::FirstBase,
+    PlayerPosition::SecondBase,
+    PlayerPosition::ThirdBase,
+    PlayerPosition::Shortstop,
+    PlayerPosition::LeftField,
+    PlayerPosition::CenterField,
+    PlayerPosition::RightField,
+];
+
+/// A team's lineup card as it stands right now, plus everyone who has already
+/// been used up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lineup {
+    roster: Roster,
+    slots: [LineupSlot; 9],
+    pitcher: PlayerId,
+    removed: Vec<PlayerId>,
+}
+
+impl Lineup {
+    /// Checks a starting card. The nine slots must cover every fielding position
+    /// once, plus either the pitcher or a designated hitter. With a designated
+    /// hitter the pitcher does not bat; without one, `pitcher` must be the player
+    /// in the pitcher's slot.
+    pub fn new(roster: Roster, slots: [LineupSlot; 9], pitcher: PlayerId) -> Result<Self, LineupError> {
+        for (i, slot) in slots.iter().enumerate() {
+            if !roster.contains(slot.player) {
+                return Err(LineupError::NotOnRoster(slot.player));
+            }
+            if slots[..i].iter().any(|other| other.player == slot.player) {
+                return Err(LineupError::DuplicatePlayer(slot.player));
+            }
+            if slots[..i].iter().any(|other| other.position == slot.position) {
+                return Err(LineupError::DuplicatePosition(slot.position));
+            }
+        }
+        if !roster.contains(pitcher) {
+            return Err(LineupError::NotOnRoster(pitcher));
+        }
+        for position in FIELDERS {
+            if !slots.iter().any(|slot| slot.position == position) {
+                return Err(LineupError::MissingPosition(position));
+            }
+        }
+
+        let pitcher_slot = slots.iter().find(|slot| slot.position == PlayerPosition::Pitcher);
+        let bats = slots.iter().any(|slot| slot.player == pitcher);
+        match pitcher_slot {
+            Some(slot) if slot.player != pitcher => return Err(LineupError::PitcherMismatch(pitcher)),
+            None if bats => return Err(LineupError::PitcherMismatch(pitcher)),
+            _ => {}
+        }
+
+        Ok(Lineup {
+            roster,
+            slots,
+            pitcher,
+            removed: Vec::new(),
+        })
+    }
+
+    pub fn roster(&self) -> &Roster {
+        &self.roster
+    }
+
+    pub fn slot(&self, position: BattingPosition) -> LineupSlot {
+        self.slots[position.index()]
+    }
+
+    pub fn player_at(&self, position: BattingPosition) -> PlayerId {
+        self.slot(position).player
+    }
+
+    pub fn slots(&self) -> &[LineupSlot; 9] {
+        &self.slots
+    }
+
+    /// Who is on the mound.
+    pub fn pitcher(&self) -> PlayerId {
+        self.pitcher
+    }
+
+    /// Whether a designated hitter bats for the pitcher.
+    pub fn uses_designated_hitter(&self) -> bool {
+        self.slots
+            .iter()
+            .any(|slot| slot.position == PlayerPosition::DesignatedHitter)
+    }
+
+    /// The player fielding `position` right now, if anybody is.
+    pub fn fielder(&self, position: PlayerPosition) -> Option<PlayerId> {
+        if position == PlayerPosition::Pitcher {
+            return Some(self.pitcher);
+        }
+        self.slots
+            .iter()
+            .find(|slot| slot.position == position)
+            .map(|slot| slot.player)
+    }
+
+    /// Everyone who has been taken out of the game, in the order they left.
+    pub fn removed(&self) -> &[PlayerId] {
+        &self.removed
+    }
+
+    pub fn is_in_game(&self, player: PlayerId) -> bool {
+        self.pitcher == player || self.slots.iter().any(|slot| slot.player == player)
+    }
+
+    pub fn substitute(mut self, substitution: Substitution) -> Result<Self, LineupError> {
+        match substitution {
+            Substitution::PinchHitter { slot, player }
+            | Substitution::PinchRunner { slot, player }
+            | Substitution::DefensiveReplacement { slot, player } => {
+                self.check_available(player)?;
+                let outgoing = self.slots[slot.index()];
+                self.slots[slot.index()].player = player;
+                // A pinch hitter for the pitcher is, on paper, pitching until
+                // the manager says otherwise.
+                if outgoing.player == self.pitcher {
+                    self.pitcher = player;
+                }
+                self.removed.push(outgoing.player);
+            }
+            Substitution::DefensiveSwitch { slot, position } => {
+                if matches!(position, PlayerPosition::Pitcher | PlayerPosition::DesignatedHitter) {
+                    return Err(LineupError::CannotSwitchTo(position));
+                }
+                let from = self.slots[slot.index()].position;
+                if matches!(from, PlayerPosition::Pitcher | PlayerPosition::DesignatedHitter) {
+                    return Err(LineupError::CannotSwitchTo(from));
+                }
+                if let Some(other) = self.slots.iter_mut().find(|other| other.position == position) {
+                    other.position = from;
+                }
+                self.slots[slot.index()].position = position;
+            }
+            Substitution::PitchingChange { player } => {
+                self.check_available(player)?;
+                let outgoing = self.pitcher;
+                if let Some(slot) = self.slots.iter_mut().find(|slot| slot.player == outgoing) {
+                    slot.player = player;
+                }
+                self.pitcher = player;
+                self.removed.push(outgoing);
+            }
+        }
+        Ok(self)
+    }
+
+    fn check_available(&self, player: PlayerId) -> Result<(), LineupError> {
+        if !self.roster.contains(player) {
+            return Err(LineupError::NotOnRoster(player));
+        }
+        if self.removed.contains(&player) {
+            return Err(LineupError::CannotReenter(player));
+        }
+        if self.is_in_game(player) {
+            return Err(LineupError::AlreadyInGame(player));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Players 1-9 start, 10 pitches, 11-15 sit on the bench.
+    pub(crate) fn roster() -> Roster {
+        (1..=15).fold(Roster::new(), |roster, id| {
+            roster.with_player(Player::new(PlayerId(id), format!("Player {id}")))
+        })
+    }
+
+    const POSITIONS: [PlayerPosition; 9] = [
+        PlayerPosition::CenterField,
+        PlayerPosition::Shortstop,
+        PlayerPosition::FirstBase,
+        PlayerPosition::RightField,
+        PlayerPosition::ThirdBase,
+        PlayerPosition::LeftField,
+        PlayerPosition::Catcher,
+        PlayerPosition::SecondBase,
+        PlayerPosition::DesignatedHitter,
+    ];
+
+    fn card(positions: [PlayerPosition; 9]) -> [LineupSlot; 9] {
+        let mut id = 0;
+        positions.map(|position| {
+            id += 1;
+            LineupSlot {
+                player: PlayerId(id),
+                position,
+            }
+        })
+    }
+
+    /// A designated-hitter card: players 1-9 bat, player 10 pitches.
+    pub(crate) fn lineup() -> Lineup {
+        Lineup::new(roster(), card(POSITIONS), PlayerId(10)).expect("a valid card")
+    }
+
+    #[test]
+    fn a_full_card_binds_every_slot_to_a_player() {
+        let lineup = lineup();
+        assert_eq!(lineup.player_at(BattingPosition::First), PlayerId(1));
+        assert_eq!(lineup.player_at(BattingPosition::Ninth), PlayerId(9));
+        assert_eq!(lineup.pitcher(), PlayerId(10));
+        assert!(lineup.uses_designated_hitter());
+        assert_eq!(lineup.fielder(PlayerPosition::Catcher), Some(PlayerId(7)));
+    }
+
+    #[test]
+    fn a_card_missing_a_fielder_is_rejected() {
+        let mut positions = POSITIONS;
+        positions[6] = PlayerPosition::Pitcher;
+        let err = Lineup::new(roster(), card(positions), PlayerId(7)).unwrap_err();
+        assert_eq!(err, LineupError::MissingPosition(PlayerPosition::Catcher));
+    }
+
+    #[test]
+    fn a_card_with_two_shortstops_is_rejected() {
+        let mut positions = POSITIONS;
+        positions[0] = PlayerPosition::Shortstop;
+        let err = Lineup::new(roster(), card(positions), PlayerId(10)).unwrap_err();
+        assert_eq!(err, LineupError::DuplicatePosition(PlayerPosition::Shortstop));
+    }
+
+    #[test]
+    fn without_a_designated_hitter_the_pitcher_must_bat() {
+        let mut positions = POSITIONS;
+        positions[8] = PlayerPosition::Pitcher;
+
+        let err = Lineup::new(roster(), card(positions), PlayerId(10)).unwrap_err();
+        assert_eq!(err, LineupError::PitcherMismatch(PlayerId(10)));
+
+        let lineup = Lineup::new(roster(), card(positions), PlayerId(9)).expect("the ninth hitter pitches");
+        assert!(!lineup.uses_designated_hitter());
+    }
+
+    #[test]
+    fn a_pinch_hitter_takes_the_slot_and_the_starter_is_gone_for_good() {
+        let lineup = lineup()
+            .substitute(Substitution::PinchHitter {
+                slot: BattingPosition::Seventh,
+                player: PlayerId(11),
+            })
+            .expect("bench player is available");
+
+        assert_eq!(lineup.player_at(BattingPosition::Seventh), PlayerId(11));
+        assert_eq!(lineup.removed(), &[PlayerId(7)]);
+
+        let err = lineup
+            .substitute(Substitution::DefensiveReplacement {
+                slot: BattingPosition::Seventh,
+                player: PlayerId(7),
+            })
+            .unwrap_err();
+        assert_eq!(err, LineupError::CannotReenter(PlayerId(7)));
+    }
+
+    #[test]
+    fn a_player_already_in_the_game_cannot_be_sent_in_again() {
+        let err = lineup()
+            .substitute(Substitution::PinchRunner {
+                slot: BattingPosition::First,
+                player: PlayerId(2),
+            })
+            .unwrap_err();
+        assert_eq!(err, LineupError::AlreadyInGame(PlayerId(2)));
+    }
+
+    #[test]
+    fn a_defensive_switch_trades_positions() {
+        let lineup = lineup()
+            .substitute(Substitution::DefensiveSwitch {
+                slot: BattingPosition::First,
+                position: PlayerPosition::LeftField,
+            })
+            .expect("outfielders can swap");
+
+        assert_eq!(lineup.slot(BattingPosition::First).position, PlayerPosition::LeftField);
+        assert_eq!(
+            lineup.slot(BattingPosition::Sixth).position,
+            PlayerPosition::CenterField
+        );
+    }
+
+    #[test]
+    fn a_pitching_change_without_the_designated_hitter_takes_over_the_pitchers_turn() {
+        let mut positions = POSITIONS;
+        positions[8] = PlayerPosition::Pitcher;
+        let lineup = Lineup::new(roster(), card(positions), PlayerId(9))
+            .expect("valid")
+            .substitute(Substitution::PitchingChange { player: PlayerId(12) })
+            .expect("reliever is available");
+
+        assert_eq!(lineup.pitcher(), PlayerId(12));
+        assert_eq!(lineup.player_at(BattingPosition::Ninth), PlayerId(12));
+        assert_eq!(lineup.removed(), &[PlayerId(9)]);
+    }
+
+    #[test]
+    fn a_pitching_change_with_the_designated_hitter_leaves_the_order_alone() {
+        let lineup = lineup()
+            .substitute(Substitution::PitchingChange { player: PlayerId(12) })
+            .expect("reliever is available");
+
+        assert_eq!(lineup.pitcher(), PlayerId(12));
+        assert_eq!(lineup.player_at(BattingPosition::Ninth), PlayerId(9));
+        assert!(!lineup.is_in_game(PlayerId(10)));
+    }
+}
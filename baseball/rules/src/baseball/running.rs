@@ -0,0 +1,153 @@
+//! Base-running that happens between or during pitches, without the batter.
+//!
+//! Like [`PlayResult`](super::play::PlayResult), a `RunnerEvent` only names what
+//! happened and is resolved by [`HalfInning`](super::inning::HalfInning) against
+//! the baserunners it owns. Unlike a ball in play it never finishes the plate
+//! appearance: the count stands and the same batter stays at the plate. A wild
+//! pitch on ball two is therefore two calls — the `Ball`, then the runners.
+
+use std::fmt::Display;
+
+use super::baserunners::{Base, BaserunnerState, PlayOutcome};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunnerEvent {
+    /// The runner on the base before this one takes it.
+    StolenBase(Base),
+    /// The runner on the base before this one is thrown out trying to take it.
+    CaughtStealing(Base),
+    /// Every runner moves up a base on a pitch the catcher could not reach.
+    WildPitch,
+    /// Every runner moves up a base on a pitch the catcher should have held.
+    PassedBall,
+    /// Every runner is awarded a base for an illegal motion by the pitcher.
+    Balk,
+    /// The runner on this base is caught off it by a throw.
+    Pickoff(Base),
+}
+
+impl RunnerEvent {
+    /// Applies this event to a specific set of baserunners.
+    pub(crate) fn resolve(self, baserunners: BaserunnerState) -> PlayOutcome {
+        match self {
+            RunnerEvent::StolenBase(base) => PlayOutcome::stolen_base(baserunners, base),
+            RunnerEvent::CaughtStealing(base) => PlayOutcome::caught_stealing(baserunners, base),
+            RunnerEvent::WildPitch | RunnerEvent::PassedBall | RunnerEvent::Balk => {
+                PlayOutcome::runners_advance(baserunners)
+            }
+            RunnerEvent::Pickoff(base) => PlayOutcome::pickoff(baserunners, base),
+        }
+    }
+
+    /// Whether the defence retires a runner on this event.
+    pub fn is_out(self) -> bool {
+        matches!(self, RunnerEvent::CaughtStealing(_) | RunnerEvent::Pickoff(_))
+    }
+
+    /// Short all-caps label for the result banner.
+    pub fn label(self) -> &'static str {
+        match self {
+            RunnerEvent::StolenBase(_) => "STOLEN BASE!",
+            RunnerEvent::CaughtStealing(_) => "CAUGHT STEALING",
+            RunnerEvent::WildPitch => "WILD PITCH",
+            RunnerEvent::PassedBall => "PASSED BALL",
+            RunnerEvent::Balk => "BALK",
+            RunnerEvent::Pickoff(_) => "PICKED OFF",
+        }
+    }
+}
+
+impl Display for RunnerEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{baserunners::BaseOutcome, inning::Outs, lineup::BattingPosition},
+        *,
+    };
+
+    #[test]
+    fn a_stolen_base_moves_the_runner_up_one() {
+        let runners = BaserunnerState::empty().set_first(Some(BattingPosition::First));
+        let outcome = RunnerEvent::StolenBase(Base::Second).resolve(runners);
+
+        assert_eq!(outcome.baserunners().first(), None);
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.outs(), Outs::Zero);
+    }
+
+    #[test]
+    fn stealing_an_occupied_base_changes_nothing() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second));
+        let outcome = RunnerEvent::StolenBase(Base::Second).resolve(runners);
+
+        assert_eq!(outcome.baserunners(), runners);
+    }
+
+    #[test]
+    fn stealing_home_scores_the_run() {
+        let runners = BaserunnerState::empty().set_third(Some(BattingPosition::Third));
+        let outcome = RunnerEvent::StolenBase(Base::Home).resolve(runners);
+
+        assert!(outcome.baserunners().is_empty());
+        assert_eq!(outcome.runs_scored(), 1);
+    }
+
+    #[test]
+    fn caught_stealing_removes_the_runner_and_records_the_out() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_third(Some(BattingPosition::Third));
+        let outcome = RunnerEvent::CaughtStealing(Base::Second).resolve(runners);
+
+        assert_eq!(outcome.second(), BaseOutcome::TagOut);
+        assert_eq!(outcome.baserunners().first(), None);
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::Third));
+        assert_eq!(outcome.outs(), Outs::One);
+        assert!(!outcome.suppresses_runs_on_third_out(), "a tag is not a force");
+    }
+
+    #[test]
+    fn caught_stealing_home_is_an_out_at_the_plate() {
+        let runners = BaserunnerState::empty().set_third(Some(BattingPosition::Third));
+        let outcome = RunnerEvent::CaughtStealing(Base::Home).resolve(runners);
+
+        assert!(outcome.baserunners().is_empty());
+        assert_eq!(outcome.runs_scored(), 0);
+        assert_eq!(outcome.outs(), Outs::One);
+    }
+
+    #[test]
+    fn a_wild_pitch_moves_everybody_up_and_scores_from_third() {
+        let runners = BaserunnerState::empty()
+            .set_first(Some(BattingPosition::First))
+            .set_second(Some(BattingPosition::Second))
+            .set_third(Some(BattingPosition::Third));
+        let outcome = RunnerEvent::WildPitch.resolve(runners);
+
+        assert_eq!(outcome.baserunners().first(), None);
+        assert_eq!(outcome.baserunners().second(), Some(BattingPosition::First));
+        assert_eq!(outcome.baserunners().third(), Some(BattingPosition::Second));
+        assert_eq!(outcome.runs_scored(), 1);
+    }
+
+    #[test]
+    fn a_pickoff_only_retires_someone_who_is_there() {
+        let runners = BaserunnerState::empty().set_first(Some(BattingPosition::First));
+
+        let picked = RunnerEvent::Pickoff(Base::First).resolve(runners);
+        assert!(picked.baserunners().is_empty());
+        assert_eq!(picked.outs(), Outs::One);
+
+        let nobody = RunnerEvent::Pickoff(Base::Second).resolve(runners);
+        assert_eq!(nobody.baserunners(), runners);
+        assert_eq!(nobody.outs(), Outs::Zero);
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::Display;
 pub type Runs = u8;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HomePlateRuns {
     #[default]
     Zero,
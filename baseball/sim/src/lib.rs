@@ -0,0 +1,15 @@
+//! A headless Monte Carlo simulator on top of `baseball_game_rules`.
+//!
+//! Every game goes through `Game::advance` like one played by hand, so whatever
+//! the rules engine knows — force plays, walk-offs, the extra-inning runner —
+//! the simulated season knows too. Hitters are described by how often each
+//! kind of plate appearance happens to them; fielding and pitching are folded
+//! into those numbers. A seed makes every run repeatable.
+
+pub mod profile;
+pub mod report;
+pub mod season;
+
+pub use profile::{BatterProfile, League, OutcomeWeights, PlateAppearanceOutcome, ProfileError, TeamProfile, sample};
+pub use report::{Report, Simulation, TeamSummary};
+pub use season::{GameResult, Schedule, Season, TeamRecord, play_game};
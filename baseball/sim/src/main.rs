@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use baseball_game_rules::RuleSet;
+use baseball_sim::{League, Schedule, Simulation};
+use clap::{Parser, ValueEnum};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Rules {
+    /// Nine innings, a runner on second in extras.
+    MajorLeague,
+    /// Nine innings, extras played the old way.
+    Traditional,
+    /// Seven innings, a runner on second in extras.
+    Doubleheader,
+}
+
+impl From<Rules> for RuleSet {
+    fn from(rules: Rules) -> Self {
+        match rules {
+            Rules::MajorLeague => RuleSet::major_league(),
+            Rules::Traditional => RuleSet::default(),
+            Rules::Doubleheader => RuleSet::seven_inning_doubleheader(),
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(name = "simulate")]
+#[command(about = "Simulate seasons of baseball from per-player outcome probabilities")]
+struct Cli {
+    /// League file: JSON with a `teams` array, each a `name` and nine `batters`
+    /// carrying outcome weights. A built-in four-team league is used if omitted.
+    #[arg(long)]
+    league: Option<PathBuf>,
+
+    /// Number of seasons to simulate.
+    #[arg(long, default_value_t = 1000)]
+    seasons: u32,
+
+    /// Times each team hosts each other team in a season.
+    #[arg(long, default_value_t = 3)]
+    series: u32,
+
+    /// Seed for the random number generator.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[arg(long, value_enum, default_value_t = Rules::MajorLeague)]
+    rules: Rules,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    let league = match &cli.league {
+        Some(path) => {
+            let json = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+            serde_json::from_str::<League>(&json).with_context(|| format!("parsing {}", path.display()))?
+        }
+        None => League::demo(),
+    };
+    league.validate()?;
+
+    let report = Simulation {
+        schedule: Schedule::round_robin(league.teams.len(), cli.series),
+        league,
+        rules: cli.rules.into(),
+        seasons: cli.seasons,
+        seed: cli.seed,
+    }
+    .run();
+
+    print!("{report}");
+    Ok(())
+}
@@ -0,0 +1,181 @@
+//! What each hitter does with a plate appearance, as relative weights.
+//!
+//! The simulator never looks at pitches: it draws a whole plate appearance from
+//! the batter's weights and spells it out as the pitches the rules engine
+//! understands — three strikes, four balls, or the one pitch put in play. The
+//! weights are relative, so rates per plate appearance and raw season counts
+//! both work as input.
+
+pub use baseball_game_rules::{OutcomeWeights, PlateAppearanceOutcome};
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+
+/// Draws one plate appearance from `weights`.
+pub fn sample(weights: &OutcomeWeights, rng: &mut impl Rng) -> PlateAppearanceOutcome {
+    weights.pick(rng.random::<f64>())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatterProfile {
+    pub name: String,
+    #[serde(flatten)]
+    pub weights: OutcomeWeights,
+}
+
+/// A team is its batting order, leadoff first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamProfile {
+    pub name: String,
+    pub batters: Vec<BatterProfile>,
+}
+
+impl TeamProfile {
+    /// Nine copies of the same hitter.
+    pub fn uniform(name: &str, weights: OutcomeWeights) -> Self {
+        TeamProfile {
+            name: name.to_string(),
+            batters: (1..=9)
+                .map(|slot| BatterProfile {
+                    name: format!("{name} #{slot}"),
+                    weights,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileError {
+    #[error("a league needs at least two teams, found {0}")]
+    TooFewTeams(usize),
+    #[error("{team} bats {found} players; a batting order is nine")]
+    WrongBattingOrder { team: String, found: usize },
+    #[error("{batter} of {team} has weights that are negative, not finite, or all zero")]
+    InvalidWeights { team: String, batter: String },
+    /// A side that can never be retired never finishes a game.
+    #[error("nobody on {0} ever makes an out")]
+    NeverOut(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct League {
+    pub teams: Vec<TeamProfile>,
+}
+
+impl League {
+    /// Four teams built around the league-average hitter, for when no league
+    /// file is given.
+    pub fn demo() -> Self {
+        let average = OutcomeWeights::league_average();
+        let sluggers = OutcomeWeights {
+            strikeout: 270.0,
+            home_run: 45.0,
+            walk: 100.0,
+            ..average
+        };
+        let contact = OutcomeWeights {
+            strikeout: 150.0,
+            single: 175.0,
+            home_run: 18.0,
+            ..average
+        };
+        let rebuilding = OutcomeWeights {
+            single: 120.0,
+            double: 35.0,
+            home_run: 20.0,
+            walk: 70.0,
+            ..average
+        };
+        League {
+            teams: vec![
+                TeamProfile::uniform("Average", average),
+                TeamProfile::uniform("Sluggers", sluggers),
+                TeamProfile::uniform("Contact", contact),
+                TeamProfile::uniform("Rebuilding", rebuilding),
+            ],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ProfileError> {
+        if self.teams.len() < 2 {
+            return Err(ProfileError::TooFewTeams(self.teams.len()));
+        }
+        for team in &self.teams {
+            if team.batters.len() != 9 {
+                return Err(ProfileError::WrongBattingOrder {
+                    team: team.name.clone(),
+                    found: team.batters.len(),
+                });
+            }
+            if let Some(batter) = team.batters.iter().find(|batter| !batter.weights.is_valid()) {
+                return Err(ProfileError::InvalidWeights {
+                    team: team.name.clone(),
+                    batter: batter.name.clone(),
+                });
+            }
+            if !team.batters.iter().any(|batter| batter.weights.can_make_an_out()) {
+                return Err(ProfileError::NeverOut(team.name.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    #[test]
+    fn sampling_follows_the_weights() {
+        let weights = OutcomeWeights {
+            strikeout: 3.0,
+            walk: 1.0,
+            ..OutcomeWeights::default()
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let strikeouts = (0..4000)
+            .filter(|_| sample(&weights, &mut rng) == PlateAppearanceOutcome::Strikeout)
+            .count();
+
+        assert!((2850..3150).contains(&strikeouts), "about three in four: {strikeouts}");
+    }
+
+    #[test]
+    fn a_zero_weight_is_never_drawn() {
+        let weights = OutcomeWeights {
+            home_run: 1.0,
+            ..OutcomeWeights::default()
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert!((0..100).all(|_| sample(&weights, &mut rng) == PlateAppearanceOutcome::HomeRun));
+    }
+
+    #[test]
+    fn a_league_must_field_nine_batters_who_can_make_outs() {
+        assert!(League::demo().validate().is_ok());
+
+        let mut short = League::demo();
+        short.teams[1].batters.pop();
+        assert!(matches!(
+            short.validate(),
+            Err(ProfileError::WrongBattingOrder { found: 8, .. })
+        ));
+
+        let mut unstoppable = League::demo();
+        unstoppable.teams[0] = TeamProfile::uniform(
+            "Unstoppable",
+            OutcomeWeights {
+                single: 1.0,
+                ..OutcomeWeights::default()
+            },
+        );
+        assert!(matches!(unstoppable.validate(), Err(ProfileError::NeverOut(_))));
+
+        let mut negative = League::demo();
+        negative.teams[2].batters[0].weights.walk = -1.0;
+        assert!(matches!(negative.validate(), Err(ProfileError::InvalidWeights { .. })));
+    }
+}
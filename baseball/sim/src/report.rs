@@ -0,0 +1,293 @@
+//! Many seasons, boiled down to what they say about each team.
+
+use std::fmt::{self, Display};
+
+use baseball_game_rules::{GameWinner, RuleSet, Runs};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    profile::League,
+    season::{Schedule, Season},
+};
+
+/// Runs per game at or above this share the last bucket of the distribution.
+pub const RUN_BUCKETS: usize = 11;
+
+/// Everything needed to reproduce a run of the simulator exactly.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    pub league: League,
+    pub rules: RuleSet,
+    pub schedule: Schedule,
+    pub seasons: u32,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamSummary {
+    pub name: String,
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+    /// Seasons finished in first place. A share of first counts as a fraction.
+    pub pennants: f64,
+    /// How many games this team scored `n` runs in, at index `n`.
+    pub run_distribution: [u32; RUN_BUCKETS],
+}
+
+impl TeamSummary {
+    fn new(name: &str) -> Self {
+        TeamSummary {
+            name: name.to_string(),
+            games: 0,
+            wins: 0,
+            losses: 0,
+            ties: 0,
+            runs_scored: 0,
+            runs_allowed: 0,
+            pennants: 0.0,
+            run_distribution: [0; RUN_BUCKETS],
+        }
+    }
+
+    pub fn win_probability(&self) -> f64 {
+        ratio(f64::from(self.wins) + f64::from(self.ties) / 2.0, self.games)
+    }
+
+    pub fn runs_per_game(&self) -> f64 {
+        ratio(f64::from(self.runs_scored), self.games)
+    }
+
+    fn record_runs(&mut self, runs: Runs) {
+        self.run_distribution[usize::from(runs).min(RUN_BUCKETS - 1)] += 1;
+    }
+}
+
+fn ratio(numerator: f64, denominator: u32) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator / f64::from(denominator)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub seasons: u32,
+    pub teams: Vec<TeamSummary>,
+    /// `head_to_head[a][b]` is how many of their meetings `a` won, and how
+    /// many they played.
+    pub head_to_head: Vec<Vec<(u32, u32)>>,
+}
+
+impl Simulation {
+    pub fn run(&self) -> Report {
+        let teams = &self.league.teams;
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let mut summaries: Vec<TeamSummary> = teams.iter().map(|team| TeamSummary::new(&team.name)).collect();
+        let mut head_to_head = vec![vec![(0, 0); teams.len()]; teams.len()];
+
+        for _ in 0..self.seasons {
+            let season = Season::play(teams, &self.schedule, self.rules, &mut rng);
+            for result in &season.results {
+                summaries[result.away].record_runs(result.away_runs);
+                summaries[result.home].record_runs(result.home_runs);
+                let (away, home) = (result.away, result.home);
+                head_to_head[away][home].1 += 1;
+                head_to_head[home][away].1 += 1;
+                match result.winner {
+                    GameWinner::Away => head_to_head[away][home].0 += 1,
+                    GameWinner::Home => head_to_head[home][away].0 += 1,
+                    GameWinner::Tie => {}
+                }
+            }
+            for (summary, record) in summaries.iter_mut().zip(&season.records) {
+                summary.games += record.games();
+                summary.wins += record.wins;
+                summary.losses += record.losses;
+                summary.ties += record.ties;
+                summary.runs_scored += record.runs_scored;
+                summary.runs_allowed += record.runs_allowed;
+            }
+
+            let standings = season.standings();
+            let best = season.records[standings[0]].percentage();
+            let leaders: Vec<usize> = standings
+                .into_iter()
+                .take_while(|&team| season.records[team].percentage() >= best)
+                .collect();
+            let share = 1.0 / f64::from(u32::try_from(leaders.len()).unwrap_or(u32::MAX));
+            for team in leaders {
+                summaries[team].pennants += share;
+            }
+        }
+
+        Report {
+            seasons: self.seasons,
+            teams: summaries,
+            head_to_head,
+        }
+    }
+}
+
+impl Report {
+    /// Team indices ordered by win probability, best first.
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.teams.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.teams[b]
+                .win_probability()
+                .total_cmp(&self.teams[a].win_probability())
+        });
+        order
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seasons = f64::from(self.seasons.max(1));
+        let ranking = self.ranking();
+        let width = self.teams.iter().map(|team| team.name.len()).max().unwrap_or(4).max(4);
+
+        writeln!(f, "Standings, averaged over {} seasons", self.seasons)?;
+        writeln!(
+            f,
+            "{:<width$}  {:>6}  {:>6}  {:>5}  {:>5}  {:>5}  {:>7}",
+            "Team", "W", "L", "Pct", "RS/G", "RA/G", "Pennant"
+        )?;
+        for &index in &ranking {
+            let team = &self.teams[index];
+            writeln!(
+                f,
+                "{:<width$}  {:>6.1}  {:>6.1}  {:>5.3}  {:>5.2}  {:>5.2}  {:>6.1}%",
+                team.name,
+                f64::from(team.wins) / seasons,
+                f64::from(team.losses) / seasons,
+                team.win_probability(),
+                team.runs_per_game(),
+                ratio(f64::from(team.runs_allowed), team.games),
+                100.0 * team.pennants / seasons,
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Runs per game (share of games)")?;
+        write!(f, "{:<width$}", "Team")?;
+        for runs in 0..RUN_BUCKETS {
+            let label = if runs == RUN_BUCKETS - 1 {
+                format!("{runs}+")
+            } else {
+                runs.to_string()
+            };
+            write!(f, "  {label:>4}")?;
+        }
+        writeln!(f)?;
+        for &index in &ranking {
+            let team = &self.teams[index];
+            write!(f, "{:<width$}", team.name)?;
+            for games in team.run_distribution {
+                write!(f, "  {:>3.0}%", 100.0 * ratio(f64::from(games), team.games))?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Win probability, row against column")?;
+        write!(f, "{:<width$}", "")?;
+        for &index in &ranking {
+            write!(f, "  {:>width$}", self.teams[index].name)?;
+        }
+        writeln!(f)?;
+        for &row in &ranking {
+            write!(f, "{:<width$}", self.teams[row].name)?;
+            for &column in &ranking {
+                let (won, played) = self.head_to_head[row][column];
+                if row == column {
+                    write!(f, "  {:>width$}", "-")?;
+                } else {
+                    write!(f, "  {:>width$.3}", ratio(f64::from(won), played))?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::{OutcomeWeights, TeamProfile};
+
+    fn simulation(seed: u64) -> Simulation {
+        let league = League::demo();
+        Simulation {
+            schedule: Schedule::round_robin(league.teams.len(), 2),
+            league,
+            rules: RuleSet::major_league(),
+            seasons: 20,
+            seed,
+        }
+    }
+
+    #[test]
+    fn the_report_accounts_for_every_game() {
+        let report = simulation(5).run();
+        let games_per_team = 20 * 3 * 2 * 2;
+        for team in &report.teams {
+            assert_eq!(team.games, games_per_team);
+            assert_eq!(team.run_distribution.iter().sum::<u32>(), games_per_team);
+            assert_eq!(team.wins + team.losses + team.ties, games_per_team);
+        }
+        let pennants: f64 = report.teams.iter().map(|team| team.pennants).sum();
+        assert!((pennants - 20.0).abs() < 1e-9, "one pennant a season: {pennants}");
+        assert_eq!(report.head_to_head[0][1].1, report.head_to_head[1][0].1);
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_report() {
+        assert_eq!(simulation(11).run(), simulation(11).run());
+        assert_ne!(simulation(11).run(), simulation(12).run());
+    }
+
+    #[test]
+    fn the_better_lineup_has_the_better_win_probability() {
+        let average = OutcomeWeights::league_average();
+        let league = League {
+            teams: vec![
+                TeamProfile::uniform(
+                    "Weak",
+                    OutcomeWeights {
+                        home_run: 5.0,
+                        single: 90.0,
+                        ..average
+                    },
+                ),
+                TeamProfile::uniform(
+                    "Strong",
+                    OutcomeWeights {
+                        home_run: 60.0,
+                        single: 190.0,
+                        ..average
+                    },
+                ),
+            ],
+        };
+        let report = Simulation {
+            schedule: Schedule::round_robin(2, 10),
+            league,
+            rules: RuleSet::major_league(),
+            seasons: 20,
+            seed: 2,
+        }
+        .run();
+
+        assert_eq!(report.ranking(), vec![1, 0]);
+        assert!(report.teams[1].win_probability() > 0.7);
+        assert!(report.to_string().contains("Strong"));
+    }
+}
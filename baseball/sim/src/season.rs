@@ -0,0 +1,239 @@
+//! Playing games, and seasons of them.
+
+use baseball_game_rules::{Game, GameOutcome, GameSummary, GameWinner, InningHalf, RuleSet, Runs};
+use rand::Rng;
+
+use crate::profile::{TeamProfile, sample};
+
+/// Plays one game to the end, one plate appearance at a time. Both teams must
+/// bat nine, as [`League::validate`](crate::profile::League::validate) checks.
+pub fn play_game(away: &TeamProfile, home: &TeamProfile, rules: RuleSet, rng: &mut impl Rng) -> GameSummary {
+    let mut game = Game::new(rules);
+    loop {
+        let half_inning = game.current_half_inning();
+        let team = match half_inning.half() {
+            InningHalf::Top => away,
+            InningHalf::Bottom => home,
+        };
+        let batter = &team.batters[usize::from(half_inning.current_batter().num() - 1)];
+        let pitches = sample(&batter.weights, rng).pitches(half_inning);
+
+        // A walk-off can end the game partway through; `advance` ignores
+        // whatever is left.
+        let outcome = pitches
+            .into_iter()
+            .fold(GameOutcome::InProgress(game), GameOutcome::advance);
+        match outcome {
+            GameOutcome::InProgress(next) => game = next,
+            GameOutcome::Complete(summary) => return summary,
+        }
+    }
+}
+
+/// Who plays whom, as `(away, home)` indices into the league's teams.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    games: Vec<(usize, usize)>,
+}
+
+impl Schedule {
+    /// Every team hosts every other team `series` times.
+    pub fn round_robin(teams: usize, series: u32) -> Self {
+        let games = (0..series)
+            .flat_map(|_| (0..teams).flat_map(move |home| (0..teams).map(move |away| (away, home))))
+            .filter(|(away, home)| away != home)
+            .collect();
+        Schedule { games }
+    }
+
+    pub fn games(&self) -> &[(usize, usize)] {
+        &self.games
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TeamRecord {
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+}
+
+impl TeamRecord {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+
+    /// Winning percentage, counting a tie as half a win.
+    pub fn percentage(&self) -> f64 {
+        match self.games() {
+            0 => 0.0,
+            games => (f64::from(self.wins) + f64::from(self.ties) / 2.0) / f64::from(games),
+        }
+    }
+
+    fn record(&mut self, scored: Runs, allowed: Runs) {
+        self.runs_scored += u32::from(scored);
+        self.runs_allowed += u32::from(allowed);
+        match scored.cmp(&allowed) {
+            std::cmp::Ordering::Greater => self.wins += 1,
+            std::cmp::Ordering::Less => self.losses += 1,
+            std::cmp::Ordering::Equal => self.ties += 1,
+        }
+    }
+}
+
+/// One finished game, by team index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameResult {
+    pub away: usize,
+    pub home: usize,
+    pub away_runs: Runs,
+    pub home_runs: Runs,
+    pub winner: GameWinner,
+}
+
+/// A played-out season: every game, and each team's record in the same order
+/// as the league.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Season {
+    pub results: Vec<GameResult>,
+    pub records: Vec<TeamRecord>,
+}
+
+impl Season {
+    pub fn play(teams: &[TeamProfile], schedule: &Schedule, rules: RuleSet, rng: &mut impl Rng) -> Self {
+        let mut records = vec![TeamRecord::default(); teams.len()];
+        let results = schedule
+            .games()
+            .iter()
+            .map(|&(away, home)| {
+                let summary = play_game(&teams[away], &teams[home], rules, rng);
+                let score = summary.final_score();
+                records[away].record(score.away(), score.home());
+                records[home].record(score.home(), score.away());
+                GameResult {
+                    away,
+                    home,
+                    away_runs: score.away(),
+                    home_runs: score.home(),
+                    winner: summary.winner(),
+                }
+            })
+            .collect();
+        Season { results, records }
+    }
+
+    /// Team indices from first place to last. Ties in the standings go to the
+    /// better run differential.
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.records.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&self.records[a], &self.records[b]);
+            b.percentage().total_cmp(&a.percentage()).then_with(|| {
+                (i64::from(b.runs_scored) - i64::from(b.runs_allowed))
+                    .cmp(&(i64::from(a.runs_scored) - i64::from(a.runs_allowed)))
+            })
+        });
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::{BaserunnerState, Outs, RunExpectancy};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::profile::{League, OutcomeWeights};
+
+    #[test]
+    fn a_round_robin_has_everyone_host_everyone() {
+        let schedule = Schedule::round_robin(4, 2);
+        assert_eq!(schedule.games().len(), 4 * 3 * 2);
+        assert_eq!(schedule.games().iter().filter(|&&(_, home)| home == 0).count(), 6);
+        assert!(schedule.games().iter().all(|(away, home)| away != home));
+    }
+
+    #[test]
+    fn a_game_is_played_to_a_decision() {
+        let league = League::demo();
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..20 {
+            let summary = play_game(&league.teams[0], &league.teams[1], RuleSet::major_league(), &mut rng);
+            assert_ne!(summary.winner(), GameWinner::Tie);
+            assert!(summary.innings_played().as_number() >= 9);
+        }
+    }
+
+    #[test]
+    fn the_same_seed_plays_the_same_season() {
+        let league = League::demo();
+        let schedule = Schedule::round_robin(league.teams.len(), 1);
+        let first = Season::play(
+            &league.teams,
+            &schedule,
+            RuleSet::default(),
+            &mut ChaCha8Rng::seed_from_u64(9),
+        );
+        let second = Season::play(
+            &league.teams,
+            &schedule,
+            RuleSet::default(),
+            &mut ChaCha8Rng::seed_from_u64(9),
+        );
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn simulated_first_innings_score_what_the_markov_chain_expects() {
+        let average = TeamProfile::uniform("Average", OutcomeWeights::league_average());
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        let games = 4000;
+        let runs: u32 = (0..games)
+            .map(|_| {
+                let summary = play_game(&average, &average, RuleSet::default(), &mut rng);
+                u32::from(summary.line_score().away_innings()[0])
+            })
+            .sum();
+        let simulated = f64::from(runs) / f64::from(games);
+
+        let expected = RunExpectancy::solve(&OutcomeWeights::league_average())
+            .expect("league average weights are usable")
+            .expected_runs(Outs::Zero, BaserunnerState::empty());
+        assert!(
+            (simulated - expected).abs() < 0.05,
+            "simulated {simulated}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn a_lineup_of_sluggers_wins_the_pennant_over_one_that_only_strikes_out() {
+        let hopeless = OutcomeWeights {
+            strikeout: 1.0,
+            ..OutcomeWeights::default()
+        };
+        let mighty = OutcomeWeights {
+            home_run: 1.0,
+            strikeout: 2.0,
+            ..OutcomeWeights::default()
+        };
+        let teams = [
+            TeamProfile::uniform("Hopeless", hopeless),
+            TeamProfile::uniform("Mighty", mighty),
+        ];
+        let season = Season::play(
+            &teams,
+            &Schedule::round_robin(2, 5),
+            RuleSet::default(),
+            &mut ChaCha8Rng::seed_from_u64(1),
+        );
+
+        assert_eq!(season.standings(), vec![1, 0]);
+        assert_eq!(season.records[1].wins, 10);
+        assert_eq!(season.records[0].runs_scored, 0);
+        assert_eq!(season.records[0].runs_allowed, season.records[1].runs_scored);
+    }
+}
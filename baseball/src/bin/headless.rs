@@ -0,0 +1,61 @@
+//! Plays the Bevy game with the AI on both sides and no window, and prints the
+//! batting line the pitch physics and the fielding produced.
+
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "headless")]
+#[command(about = "Play whole games with no window and report what the ball physics made of them")]
+struct Cli {
+    /// Number of games to play.
+    #[arg(long, default_value_t = 100)]
+    games: usize,
+
+    /// Seed for the random number generator.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let games = baseball::play_headless(cli.games, cli.seed);
+
+    let (mut sides, mut runs, mut extra_innings) = (0u32, 0u32, 0u32);
+    let (mut plate_appearances, mut at_bats, mut hits, mut bases) = (0u32, 0u32, 0u32, 0u32);
+    let (mut home_runs, mut walks, mut strikeouts) = (0u32, 0u32, 0u32);
+    for game in &games {
+        sides += 2;
+        runs += u32::from(game.final_score().away()) + u32::from(game.final_score().home());
+        if game.innings_played().is_extra() {
+            extra_innings += 1;
+        }
+        for line in [game.box_score().away(), game.box_score().home()]
+            .into_iter()
+            .flat_map(|team| team.batting())
+        {
+            plate_appearances += u32::from(line.plate_appearances);
+            at_bats += u32::from(line.at_bats);
+            hits += u32::from(line.hits);
+            bases += u32::from(line.hits + line.doubles + 2 * line.triples + 3 * line.home_runs);
+            home_runs += u32::from(line.home_runs);
+            walks += u32::from(line.walks);
+            strikeouts += u32::from(line.strikeouts);
+        }
+    }
+
+    let per = |count: u32, of: u32| f64::from(count) / f64::from(of.max(1));
+    println!("{} games, seed {}", games.len(), cli.seed);
+    println!("  runs per side     {:.2}", per(runs, sides));
+    println!("  home runs a side  {:.2}", per(home_runs, sides));
+    println!("  extra innings     {extra_innings}");
+    println!(
+        "  AVG / SLG         {:.3} / {:.3}",
+        per(hits, at_bats),
+        per(bases, at_bats)
+    );
+    println!(
+        "  K% / BB%          {:.1} / {:.1}",
+        100.0 * per(strikeouts, plate_appearances),
+        100.0 * per(walks, plate_appearances)
+    );
+}
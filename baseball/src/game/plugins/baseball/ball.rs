@@ -8,17 +8,24 @@
 
 use bevy::prelude::*;
 
-use super::field;
+use super::{field, park::Park, weather::Weather};
 
 /// Feet per second squared.
 pub const GRAVITY: f32 = 32.174;
 
-/// Linear drag, per second. A baseball loses a great deal to the air: with no
-/// drag at all, 105 mph off the bat at 28° would carry 579 feet. Solved
-/// numerically so that same contact lands at 405 — a comfortable home run —
-/// which puts the rest of the launch-angle range in realistic territory too.
+/// Linear drag, per second, at sea level in [`Weather::STILL`]. A baseball loses
+/// a great deal to the air: with no drag at all, 105 mph off the bat at 28° would
+/// carry 579 feet. Solved numerically so that same contact lands at 405 — a
+/// comfortable home run — which puts the rest of the launch-angle range in
+/// realistic territory too. Drag scales with how thick the [`Air`] is.
 pub const DRAG: f32 = 0.166;
 
+/// How much harder the wind pushes than the linear drag alone would say. Real
+/// drag grows with the square of the ball's speed through the air, and the wind
+/// changes that speed; linearised around a fly ball's speed, the change comes out
+/// at twice what [`DRAG`] applied to the wind would give.
+const WIND_COUPLING: f32 = 2.0;
+
 /// How much speed a ball keeps when it bounces.
 const RESTITUTION: f32 = 0.36;
 
@@ -70,11 +77,35 @@ impl LiveBall {
     }
 }
 
+/// The air a ball flies through: how hard it drags, and which way it is moving.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Air {
+    /// Per second, as [`DRAG`].
+    pub drag: f32,
+    /// Feet per second.
+    pub wind: Vec3,
+}
+
+impl Air {
+    /// The air at `park` in `weather`: thinned by the altitude, the heat and the
+    /// humidity.
+    pub fn at(park: &Park, weather: Weather) -> Air {
+        Air {
+            drag: DRAG * park.air_density() * weather.air_density(),
+            wind: weather.wind(),
+        }
+    }
+}
+
 /// Advances a ball one step, bouncing it off the ground. Shared by the live
 /// simulation and the lookahead used to position fielders, so the prediction can
 /// never disagree with what the player watches.
-pub fn step(pos: Vec3, vel: Vec3, dt: f32) -> (Vec3, Vec3) {
-    let accel = Vec3::new(-DRAG * vel.x, -DRAG * vel.y, -GRAVITY - DRAG * vel.z);
+///
+/// Drag acts on the ball's speed through the air rather than over the ground, so
+/// a following wind pushes it along. A ball on the turf is out of the wind.
+pub fn step(air: Air, pos: Vec3, vel: Vec3, dt: f32) -> (Vec3, Vec3) {
+    let wind = if pos.z > 0.0 { air.wind } else { Vec3::ZERO };
+    let accel = Vec3::new(0.0, 0.0, -GRAVITY) - air.drag * (vel - wind * WIND_COUPLING);
     let mut vel = vel + accel * dt;
     let mut pos = pos + vel * dt;
 
@@ -113,6 +144,9 @@ pub struct Flight {
     /// Where the ball finally stops rolling, and when.
     pub resting: Vec2,
     pub time_to_rest: f32,
+    /// Seconds until the ball left the field of play — over the wall or into the
+    /// stands — if it did. Once it has, no fielder can get to it.
+    pub out_of_play: Option<f32>,
     /// `(time, position)` samples from contact until the ball is dead.
     pub path: Vec<(f32, Vec3)>,
 }
@@ -123,6 +157,19 @@ const SIM_MAX_TIME: f32 = 15.0;
 const PATH_STRIDE: usize = 4;
 
 impl Flight {
+    /// Where the ball is `time` seconds after contact, read back off the
+    /// recorded path. Before contact it is at the plate; after it stops, it stays
+    /// where it stopped.
+    pub fn position_at(&self, time: f32) -> Vec3 {
+        let after = self.path.partition_point(|&(at, _)| at <= time);
+        match (self.path.get(after.wrapping_sub(1)), self.path.get(after)) {
+            (Some(&(t0, p0)), Some(&(t1, p1))) => p0.lerp(p1, (time - t0) / (t1 - t0)),
+            (Some(&(_, last)), None) => last,
+            (None, Some(&(_, first))) => first,
+            (None, None) => Vec3::ZERO,
+        }
+    }
+
     /// The earliest moment a fielder starting at `from` could be on the ball with
     /// it low enough to reach, and where that happens.
     ///
@@ -132,15 +179,15 @@ impl Flight {
     /// so the fielder ends up collecting it off the turf instead.
     pub fn intercept(&self, from: Vec2, speed: f32) -> Intercept {
         for &(time, pos) in &self.path {
+            // A fielder cannot be standing beyond the wall or in the seats, so a
+            // ball that has already left the field is not his to catch.
+            if self.out_of_play.is_some_and(|at| time >= at) {
+                break;
+            }
             if pos.z > field::CATCH_REACH {
                 continue;
             }
-            // A fielder cannot be standing beyond the wall, so a ball that has
-            // already left the park is not his to catch.
             let ground = pos.truncate();
-            if field::is_fair(ground) && ground.length() > field::fence_distance(field::spray_angle(ground)) {
-                continue;
-            }
             if field::FIELDER_REACTION + from.distance(ground) / speed <= time {
                 return Intercept {
                     time,
@@ -179,8 +226,9 @@ impl Intercept {
     }
 }
 
-/// Runs a batted ball to its conclusion.
-pub fn simulate(exit_velocity: f32, launch: f32, spray: f32) -> Flight {
+/// Runs a batted ball to its conclusion in `park`, in `weather`.
+pub fn simulate(park: &Park, weather: Weather, exit_velocity: f32, launch: f32, spray: f32) -> Flight {
+    let air = Air::at(park, weather);
     let mut probe = LiveBall::default();
     probe.hit(exit_velocity, launch, spray);
 
@@ -191,21 +239,32 @@ pub fn simulate(exit_velocity: f32, launch: f32, spray: f32) -> Flight {
     let mut landing = None;
     let mut time_to_land = SIM_MAX_TIME;
     let mut home_run = false;
+    let mut out_of_play = None;
     let mut t = 0.0;
     let mut path = vec![(0.0, pos)];
     let mut steps = 0usize;
 
     while t < SIM_MAX_TIME {
-        let (next_pos, next_vel) = step(pos, vel, SIM_DT);
+        let (next_pos, next_vel) = step(air, pos, vel, SIM_DT);
         t += SIM_DT;
         steps += 1;
 
         apex = apex.max(next_pos.z);
 
-        if !home_run && field::clears_fence(next_pos.truncate(), next_pos.z) {
+        if !home_run && field::clears_fence(park, next_pos.truncate(), next_pos.z) {
             home_run = true;
         }
 
+        // Once the ball is past the wall or among the seats it is gone, even if
+        // a wind blowing in would carry it back toward the field.
+        let ground = next_pos.truncate();
+        if out_of_play.is_none()
+            && ((field::is_fair(ground) && ground.length() > field::fence_distance(park, field::spray_angle(ground)))
+                || field::in_the_stands(park, ground))
+        {
+            out_of_play = Some(t);
+        }
+
         if landing.is_none() && next_pos.z <= 0.0 {
             landing = Some(next_pos.truncate());
             time_to_land = t;
@@ -235,6 +294,7 @@ pub fn simulate(exit_velocity: f32, launch: f32, spray: f32) -> Flight {
         home_run,
         resting: pos.truncate(),
         time_to_rest: t,
+        out_of_play,
         path,
     }
 }
@@ -252,16 +312,103 @@ mod tests {
     fn a_crushed_ball_carries_a_realistic_distance() {
         // 105 mph off the bat at 28 degrees. With no drag at all this would sail
         // 579 feet, so this is the check that keeps the air honest.
-        let distance = simulate(105.0 * MPH_TO_FPS, deg(28.0), 0.0).landing.length();
+        let distance = simulate(&Park::default(), Weather::STILL, 105.0 * MPH_TO_FPS, deg(28.0), 0.0)
+            .landing
+            .length();
         assert!(
             (390.0..=420.0).contains(&distance),
             "expected roughly 400 feet, got {distance}"
         );
     }
 
+    /// The distance 100 mph at 28° to dead center carries in `weather`.
+    fn carry(weather: Weather) -> f32 {
+        simulate(&Park::default(), weather, 100.0 * MPH_TO_FPS, deg(28.0), 0.0)
+            .landing
+            .length()
+    }
+
+    fn windy(speed: f32, toward_degrees: f32) -> Weather {
+        Weather {
+            wind_speed: speed,
+            wind_toward: deg(toward_degrees),
+            ..Weather::STILL
+        }
+    }
+
+    #[test]
+    fn a_crushed_ball_carries_further_on_a_hot_day() {
+        let cold = Weather {
+            temperature: 48.0,
+            ..Weather::STILL
+        };
+        let hot = Weather {
+            temperature: 95.0,
+            ..Weather::STILL
+        };
+        // A few feet for every ten degrees, which is what the ballpark studies find.
+        let gained = carry(hot) - carry(cold);
+        assert!(
+            (8.0..=25.0).contains(&gained),
+            "95 degrees only gained {gained} feet on 48"
+        );
+    }
+
+    #[test]
+    fn a_wind_blowing_out_carries_a_fly_ball_further() {
+        let still = carry(Weather::STILL);
+        let blowing_out = carry(windy(15.0, 0.0));
+        assert!(
+            (25.0..=70.0).contains(&(blowing_out - still)),
+            "15 mph out took {still} feet to {blowing_out}"
+        );
+    }
+
+    #[test]
+    fn a_wind_blowing_in_knocks_a_fly_ball_down() {
+        let still = carry(Weather::STILL);
+        let blowing_in = carry(windy(15.0, 180.0));
+        assert!(
+            (25.0..=70.0).contains(&(still - blowing_in)),
+            "15 mph in took {still} feet to {blowing_in}"
+        );
+    }
+
+    #[test]
+    fn a_crosswind_drifts_the_ball_toward_the_line() {
+        let flight = simulate(&Park::default(), windy(15.0, 90.0), 100.0 * MPH_TO_FPS, deg(28.0), 0.0);
+        assert!(
+            flight.landing.x > 15.0,
+            "a ball to dead center in a left-to-right wind came down at {:?}",
+            flight.landing
+        );
+    }
+
+    #[test]
+    fn a_ball_on_the_ground_is_out_of_the_wind() {
+        let air = Air::at(&Park::default(), windy(20.0, 0.0));
+        let (_, vel) = step(air, Vec3::new(0.0, 40.0, 0.0), Vec3::ZERO, SIM_DT);
+        assert_eq!(vel, Vec3::ZERO, "a dead ball should not be blown along the turf");
+    }
+
+    #[test]
+    fn a_ball_carries_further_a_mile_up() {
+        let coors = Park::preset("coors").expect("coors is a preset");
+        let sea_level = simulate(&Park::default(), Weather::STILL, 100.0 * MPH_TO_FPS, deg(28.0), 0.0)
+            .landing
+            .length();
+        let denver = simulate(&coors, Weather::STILL, 100.0 * MPH_TO_FPS, deg(28.0), 0.0)
+            .landing
+            .length();
+        assert!(
+            (15.0..=45.0).contains(&(denver - sea_level)),
+            "{sea_level} feet at sea level went {denver} in Denver"
+        );
+    }
+
     #[test]
     fn a_ball_with_height_to_spare_over_the_wall_is_a_home_run() {
-        let flight = simulate(108.0 * MPH_TO_FPS, deg(30.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 108.0 * MPH_TO_FPS, deg(30.0), 0.0);
         assert!(flight.home_run, "432 feet to center should be well gone");
     }
 
@@ -271,9 +418,9 @@ mod tests {
         // time it gets there it is barely a foot off the ground, so it hits the
         // wall instead of clearing it. Distance alone is not enough — which is
         // what makes a ball off the wall a different outcome from a home run.
-        let flight = simulate(102.0 * MPH_TO_FPS, deg(28.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 102.0 * MPH_TO_FPS, deg(28.0), 0.0);
         assert!(
-            flight.landing.length() > field::fence_distance(0.0),
+            flight.landing.length() > field::fence_distance(&Park::default(), 0.0),
             "this ball should land past the wall, got {} feet",
             flight.landing.length()
         );
@@ -282,7 +429,7 @@ mod tests {
 
     #[test]
     fn a_routine_fly_ball_stays_in_the_park() {
-        let flight = simulate(88.0 * MPH_TO_FPS, deg(35.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 88.0 * MPH_TO_FPS, deg(35.0), 0.0);
         let distance = flight.landing.length();
         assert!(
             (250.0..=360.0).contains(&distance),
@@ -293,7 +440,7 @@ mod tests {
 
     #[test]
     fn a_towering_pop_up_goes_high_and_nowhere() {
-        let flight = simulate(70.0 * MPH_TO_FPS, deg(72.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 70.0 * MPH_TO_FPS, deg(72.0), 0.0);
         assert!(flight.apex > 90.0, "a pop up should climb, got apex {}", flight.apex);
         assert!(
             flight.landing.length() < 160.0,
@@ -304,7 +451,7 @@ mod tests {
 
     #[test]
     fn a_fielder_standing_where_a_fly_ball_lands_catches_it_in_the_air() {
-        let flight = simulate(88.0 * MPH_TO_FPS, deg(35.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 88.0 * MPH_TO_FPS, deg(35.0), 0.0);
         let intercept = flight.intercept(flight.landing, field::FIELDER_SPEED);
         assert!(intercept.in_air(), "he should catch it before it lands");
         assert!(intercept.time < flight.time_to_land);
@@ -314,7 +461,13 @@ mod tests {
     fn a_fielder_too_far_away_cannot_catch_it_on_the_fly() {
         // A fly ball to right field, with the fielder standing in left. He has no
         // chance in the air and has to go and pick it up.
-        let flight = simulate(90.0 * MPH_TO_FPS, deg(32.0), deg(30.0));
+        let flight = simulate(
+            &Park::default(),
+            Weather::STILL,
+            90.0 * MPH_TO_FPS,
+            deg(32.0),
+            deg(30.0),
+        );
         let wrong_side = Vec2::new(-200.0, 240.0);
 
         let intercept = flight.intercept(wrong_side, field::FIELDER_SPEED);
@@ -332,7 +485,13 @@ mod tests {
         let mut safe = 0;
         for launch in [10.0, 14.0, 18.0, 22.0] {
             for spray in [-40.0, -16.0, 0.0, 16.0, 40.0] {
-                let flight = simulate(102.0 * MPH_TO_FPS, deg(launch), deg(spray));
+                let flight = simulate(
+                    &Park::default(),
+                    Weather::STILL,
+                    102.0 * MPH_TO_FPS,
+                    deg(launch),
+                    deg(spray),
+                );
                 if flight.home_run || !field::is_fair(flight.landing) {
                     continue;
                 }
@@ -347,12 +506,26 @@ mod tests {
         assert!(safe > 0, "no hard-hit fair ball anywhere found a hole in the defence");
     }
 
+    #[test]
+    fn a_foul_pop_into_the_seats_cannot_be_caught() {
+        let flight = simulate(
+            &Park::default(),
+            Weather::STILL,
+            80.0 * MPH_TO_FPS,
+            deg(55.0),
+            deg(75.0),
+        );
+        assert!(flight.out_of_play.is_some(), "this one comes down in the stands");
+        let first_baseman = field::FIELDER_HOMES[2].1;
+        assert!(!flight.intercept(first_baseman, field::FIELDER_SPEED).in_air());
+    }
+
     #[test]
     fn a_grounder_that_stops_rolling_still_gets_picked_up() {
         // Regression: the trajectory samples stop when the ball comes to rest, so
         // a fielder who arrives afterwards found no intercept at all and the play
         // could never be resolved.
-        let flight = simulate(95.0 * MPH_TO_FPS, deg(3.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 95.0 * MPH_TO_FPS, deg(3.0), 0.0);
         let shortstop = field::FIELDER_HOMES[4].1;
 
         let intercept = flight.intercept(shortstop, field::FIELDER_SPEED);
@@ -369,7 +542,13 @@ mod tests {
         // The intercept contract: running time must fit inside the arrival time,
         // otherwise the fielder is teleporting.
         for (ev, launch, spray) in [(92.0, 30.0, 15.0), (78.0, 8.0, -35.0), (101.0, 45.0, 5.0)] {
-            let flight = simulate(ev * MPH_TO_FPS, deg(launch), deg(spray));
+            let flight = simulate(
+                &Park::default(),
+                Weather::STILL,
+                ev * MPH_TO_FPS,
+                deg(launch),
+                deg(spray),
+            );
             for (position, spot) in field::FIELDER_HOMES {
                 let intercept = flight.intercept(spot, field::FIELDER_SPEED);
                 let run = spot.distance(intercept.point) / field::FIELDER_SPEED;
@@ -384,7 +563,7 @@ mod tests {
 
     #[test]
     fn the_recorded_path_agrees_with_the_summary() {
-        let flight = simulate(100.0 * MPH_TO_FPS, deg(25.0), 0.0);
+        let flight = simulate(&Park::default(), Weather::STILL, 100.0 * MPH_TO_FPS, deg(25.0), 0.0);
         assert!(flight.path.len() > 10, "the path should be sampled, not empty");
 
         let (last_time, last_pos) = *flight.path.last().expect("the path is never empty");
@@ -399,13 +578,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn a_flight_plays_back_the_way_it_was_simulated() {
+        let flight = simulate(
+            &Park::default(),
+            Weather::STILL,
+            95.0 * MPH_TO_FPS,
+            deg(30.0),
+            deg(-10.0),
+        );
+        assert_eq!(flight.position_at(0.0), flight.path[0].1);
+        assert!(
+            flight.position_at(flight.time_to_land).z < 0.5,
+            "down by the time it lands"
+        );
+        assert!(
+            flight.position_at(flight.time_to_land * 0.5).z > 20.0,
+            "well up halfway through"
+        );
+        let rest = flight.position_at(flight.time_to_rest + 5.0);
+        assert!(rest.truncate().distance(flight.resting) < 1e-3);
+    }
+
     #[test]
     fn pulling_the_ball_sends_it_to_left_field() {
-        let pulled = simulate(95.0 * MPH_TO_FPS, deg(25.0), deg(-30.0));
+        let pulled = simulate(
+            &Park::default(),
+            Weather::STILL,
+            95.0 * MPH_TO_FPS,
+            deg(25.0),
+            deg(-30.0),
+        );
         assert!(pulled.landing.x < 0.0, "a negative spray angle is left field");
         assert!(field::is_fair(pulled.landing), "30 degrees is inside the foul line");
 
-        let sliced = simulate(95.0 * MPH_TO_FPS, deg(25.0), deg(30.0));
+        let sliced = simulate(
+            &Park::default(),
+            Weather::STILL,
+            95.0 * MPH_TO_FPS,
+            deg(25.0),
+            deg(30.0),
+        );
         assert!(sliced.landing.x > 0.0, "a positive spray angle is right field");
         // Symmetric: same distance either way.
         assert!((pulled.landing.length() - sliced.landing.length()).abs() < 1.0);
@@ -413,7 +626,13 @@ mod tests {
 
     #[test]
     fn a_ball_hit_outside_the_foul_lines_lands_foul() {
-        let flight = simulate(95.0 * MPH_TO_FPS, deg(25.0), deg(60.0));
+        let flight = simulate(
+            &Park::default(),
+            Weather::STILL,
+            95.0 * MPH_TO_FPS,
+            deg(25.0),
+            deg(60.0),
+        );
         assert!(!field::is_fair(flight.landing));
         assert!(!flight.home_run, "a foul ball is never a home run");
     }
@@ -422,7 +641,9 @@ mod tests {
     fn harder_contact_travels_further() {
         let mut previous = 0.0;
         for mph in [70.0, 80.0, 90.0, 100.0, 110.0] {
-            let distance = simulate(mph * MPH_TO_FPS, deg(28.0), 0.0).landing.length();
+            let distance = simulate(&Park::default(), Weather::STILL, mph * MPH_TO_FPS, deg(28.0), 0.0)
+                .landing
+                .length();
             assert!(
                 distance > previous,
                 "{mph} mph went {distance} feet, not further than the previous {previous}"
@@ -436,7 +657,7 @@ mod tests {
         // Nothing should still be moving when the simulation gives up, or the
         // fielding code would wait forever for a ball that never stops.
         for launch in [1.0, 10.0, 30.0, 50.0, 70.0] {
-            let flight = simulate(100.0 * MPH_TO_FPS, deg(launch), 0.0);
+            let flight = simulate(&Park::default(), Weather::STILL, 100.0 * MPH_TO_FPS, deg(launch), 0.0);
             assert!(flight.time_to_rest < SIM_MAX_TIME, "launch {launch} never came to rest");
             assert!(flight.time_to_land <= flight.time_to_rest);
         }
@@ -447,7 +668,7 @@ mod tests {
         let mut pos = Vec3::new(0.0, 40.0, 0.0);
         let mut vel = Vec3::new(0.0, 60.0, 0.0);
         for _ in 0..2000 {
-            let (p, v) = step(pos, vel, SIM_DT);
+            let (p, v) = step(Air::at(&Park::default(), Weather::STILL), pos, vel, SIM_DT);
             pos = p;
             vel = v;
         }
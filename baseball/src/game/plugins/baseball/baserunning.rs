@@ -0,0 +1,541 @@
+//! The race on the bases, worked out at contact and played back while the ball is
+//! live.
+//!
+//! Once the defence has the ball, everything is a race between a runner's legs
+//! and a throw. Each runner decides how far to go from where the ball is and how
+//! quick he is, the fielder who got to the ball throws to the base where he can
+//! do the most damage, and whoever gets to the bag first wins. The result is a
+//! [`Race`]: a route for every runner who moved and the throws that chased them,
+//! which the field view plays back second by second and [`fielding`] turns into a
+//! play the rules engine can score.
+//!
+//! Runners on base go on contact when they are forced or when the ball is
+//! through the infield, and freeze on a grounder in front of them otherwise. On a
+//! ball caught in the air they tag up and go at the catch. They only take an
+//! extra base they can make standing up, except a run: with the third-base coach
+//! waving, a runner will chance a close play at the plate.
+//!
+//! [`fielding`]: super::fielding
+
+use baseball_game_rules::{Base, PlayerPosition};
+use bevy::prelude::*;
+
+use super::{ball::Intercept, field, fielding::Situation};
+
+/// How far apart the slowest and fastest runners are, as a share of an average
+/// runner's time. From speed `0.0` to `1.0` the run to first goes from about
+/// five seconds to just over three and a half, the range big-league batters
+/// actually run; any narrower and no batter is fast enough to beat out a slow
+/// roller.
+const PACE_SPREAD: f32 = 0.35;
+
+/// Time a runner already on base saves on his first leg when he goes on contact:
+/// his lead and the few steps he takes as the pitch is delivered.
+const LEAD: f32 = 0.6;
+
+/// Extra time a runner tagging up needs over one already under way: he is
+/// standing on the bag when the ball is caught.
+const STANDING_START: f32 = 0.3;
+
+/// How close a play at the plate a runner is sent on: he goes if the throw beats
+/// him by less than this. A runner tagging up has to stop dead and start again,
+/// so he is only sent when it is closer still.
+const SEND_HOME: f32 = 0.2;
+const SEND_ON_TAG: f32 = 0.05;
+
+/// An outfielder catching on the run has to set his feet before he can throw.
+const SET_AFTER_CATCH: f32 = 0.35;
+
+/// Time to take a throw at second and get rid of it again for the double play.
+const PIVOT: f32 = 0.7;
+
+/// How high a throw arcs, in feet per foot travelled.
+const THROW_LOFT: f32 = 0.06;
+
+/// How long a runner of this speed takes over a run an average runner makes in
+/// `seconds`.
+pub fn pace(speed: f32, seconds: f32) -> f32 {
+    seconds * (1.0 + PACE_SPREAD * (0.5 - speed))
+}
+
+/// Bases in the order they are run, counting the plate as both ends.
+const PATH: [Vec2; 5] = [field::HOME, field::FIRST, field::SECOND, field::THIRD, field::HOME];
+
+/// How far round a base is: `0` for where the batter starts, `4` to score.
+fn start_index(base: Base) -> usize {
+    match base {
+        Base::Home => 0,
+        Base::First => 1,
+        Base::Second => 2,
+        Base::Third => 3,
+    }
+}
+
+fn end_index(base: Base) -> usize {
+    match base {
+        Base::Home => 4,
+        other => start_index(other),
+    }
+}
+
+/// One runner's trip round the bases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Route {
+    /// Where he started the play: [`Base::Home`] for the batter.
+    pub from: Base,
+    /// The base he is going for.
+    pub to: Base,
+    /// Seconds after contact he sets off.
+    pub leaves: f32,
+    /// Seconds over his first base: longer from a standing start, shorter with
+    /// a lead.
+    pub first_leg: f32,
+    /// Seconds over each base after that.
+    pub per_base: f32,
+    /// When the ball beat him to `to`, if it did.
+    pub out_at: Option<f32>,
+}
+
+impl Route {
+    fn legs(&self) -> usize {
+        end_index(self.to) - start_index(self.from)
+    }
+
+    /// When he reaches the base `legs` ahead of where he started.
+    fn reaches(&self, legs: usize) -> f32 {
+        if legs == 0 {
+            return self.leaves;
+        }
+        self.leaves + self.first_leg + self.per_base * (legs - 1) as f32
+    }
+
+    /// When he gets to `to`, or would have.
+    pub fn arrives(&self) -> f32 {
+        self.reaches(self.legs())
+    }
+
+    /// When he is done with: safe on the bag or tagged short of it.
+    pub fn ends(&self) -> f32 {
+        self.out_at.unwrap_or_else(|| self.arrives())
+    }
+
+    pub fn is_out(&self) -> bool {
+        self.out_at.is_some()
+    }
+
+    /// Where he is `time` seconds after contact.
+    pub fn position_at(&self, time: f32) -> Vec2 {
+        let start = start_index(self.from);
+        let time = time.min(self.ends());
+        for leg in 0..self.legs() {
+            let (begun, done) = (self.reaches(leg), self.reaches(leg + 1));
+            if time < done {
+                let along = ((time - begun) / (done - begun)).clamp(0.0, 1.0);
+                return PATH[start + leg].lerp(PATH[start + leg + 1], along);
+            }
+        }
+        PATH[end_index(self.to)]
+    }
+}
+
+/// The ball going from a fielder to a base.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Throw {
+    pub from: Vec2,
+    pub to: Base,
+    /// Seconds after contact it leaves the hand and reaches the bag.
+    pub released: f32,
+    pub arrives: f32,
+}
+
+impl Throw {
+    fn new(point: Vec2, released: f32, to: Base) -> Self {
+        Throw {
+            from: point,
+            to,
+            released,
+            arrives: released + field::throw_time(point.distance(field::base_position(to))),
+        }
+    }
+
+    /// Where the ball is `time` seconds after contact, with `z` as height.
+    pub fn position_at(&self, time: f32) -> Vec3 {
+        let along = ((time - self.released) / (self.arrives - self.released)).clamp(0.0, 1.0);
+        let target = field::base_position(self.to);
+        let ground = self.from.lerp(target, along);
+        let arc = self.from.distance(target) * THROW_LOFT * 4.0 * along * (1.0 - along);
+        ground.extend(5.0 + arc)
+    }
+}
+
+/// Everything that happened on the bases after the ball was put in play.
+#[derive(Debug, Clone, Default, PartialEq, Resource)]
+pub struct Race {
+    /// A route for every runner who left his base, the batter included.
+    pub routes: Vec<Route>,
+    pub throws: Vec<Throw>,
+    /// When and where the defence first had the ball in hand, if it ever did.
+    pub fielded: Option<(f32, Vec2)>,
+}
+
+impl Race {
+    /// The route of whoever started the play on `from`. `None` if he held.
+    pub fn route(&self, from: Base) -> Option<&Route> {
+        self.routes.iter().find(|route| route.from == from)
+    }
+
+    /// When the last runner is safe or out and the last throw has arrived.
+    pub fn over_at(&self) -> f32 {
+        let running = self.routes.iter().map(Route::ends);
+        let throwing = self.throws.iter().map(|throw| throw.arrives);
+        running.chain(throwing).fold(0.0, f32::max)
+    }
+
+    /// Where the ball is once a fielder has it: in his glove, in the air between
+    /// bases, or in the hand of whoever took the last throw. `None` while the
+    /// ball is still loose.
+    pub fn ball_at(&self, time: f32) -> Option<Vec3> {
+        let (fielded_at, point) = self.fielded?;
+        if time < fielded_at {
+            return None;
+        }
+        let mut holder = point;
+        for throw in &self.throws {
+            if time < throw.released {
+                break;
+            }
+            if time < throw.arrives {
+                return Some(throw.position_at(time));
+            }
+            holder = field::base_position(throw.to);
+        }
+        Some(holder.extend(5.0))
+    }
+}
+
+/// Who takes a throw at `base` when `fielder` has the ball: the man whose bag it
+/// is, unless he is the one throwing.
+pub fn covering(base: Base, fielder: PlayerPosition) -> PlayerPosition {
+    use PlayerPosition::{Catcher, FirstBase, LeftField, Pitcher, SecondBase, Shortstop, ThirdBase};
+    match base {
+        Base::First if fielder == FirstBase => Pitcher,
+        Base::First => FirstBase,
+        Base::Second if matches!(fielder, Shortstop | ThirdBase | LeftField) => SecondBase,
+        Base::Second => Shortstop,
+        Base::Third if fielder == ThirdBase => Shortstop,
+        Base::Third => ThirdBase,
+        Base::Home if fielder == Catcher => Pitcher,
+        Base::Home => Catcher,
+    }
+}
+
+/// Where the ball is once someone has it, and how long until it can be thrown.
+struct Defence {
+    point: Vec2,
+    ready: f32,
+}
+
+impl Defence {
+    fn throw_to(&self, base: Base) -> Throw {
+        Throw::new(self.point, self.ready, base)
+    }
+
+    /// Whether a throw from here gets to `route.to` before he does.
+    fn beats(&self, route: &Route) -> bool {
+        self.throw_to(route.to).arrives <= route.arrives()
+    }
+}
+
+/// Runs the race for a ball the defence got to at `intercept`. `caught` is a ball
+/// taken in the air, which retires the batter and sends the runners back to tag.
+pub fn race(intercept: Intercept, caught: bool, in_infield: bool, situation: Situation) -> Race {
+    let mut race = Race {
+        fielded: Some((intercept.time, intercept.point)),
+        ..Race::default()
+    };
+    let defence = Defence {
+        point: intercept.point,
+        ready: intercept.time + field::FIELDING_DELAY + if caught { SET_AFTER_CATCH } else { 0.0 },
+    };
+
+    let batter_to_first = situation.batter_runs(field::RUN_TO_FIRST);
+    let batter_per_base = situation.batter_runs(field::RUN_PER_BASE);
+    if caught {
+        race.routes.push(Route {
+            from: Base::Home,
+            to: Base::First,
+            leaves: 0.0,
+            first_leg: batter_to_first,
+            per_base: batter_per_base,
+            out_at: Some(intercept.time),
+        });
+        if situation.outs < 2 {
+            tag_up(&mut race, &defence, intercept.time, situation);
+        }
+        return race;
+    }
+
+    // Lead runner first, so everyone behind knows which bases are spoken for.
+    let mut limit = 4;
+    for base in [Base::Third, Base::Second, Base::First] {
+        let Some(speed) = situation.runner(base) else {
+            continue;
+        };
+        let forced = situation.is_forced(base);
+        let per_base = pace(speed, field::RUN_PER_BASE);
+        let mut route = Route {
+            from: base,
+            to: base.next(),
+            leaves: 0.0,
+            first_leg: per_base - LEAD,
+            per_base,
+            out_at: None,
+        };
+        let goes = forced || situation.outs == 2 || !in_infield;
+        if !goes || (!forced && !safe_to_take(&defence, &route, limit, SEND_HOME)) {
+            limit = start_index(base);
+            continue;
+        }
+        extend(&mut route, &defence, limit, true);
+        limit = if route.to == Base::Home { 4 } else { end_index(route.to) };
+        race.routes.push(route);
+    }
+
+    let mut batter = Route {
+        from: Base::Home,
+        to: Base::First,
+        leaves: 0.0,
+        first_leg: batter_to_first,
+        per_base: batter_per_base,
+        out_at: None,
+    };
+    extend(&mut batter, &defence, limit, false);
+    race.routes.push(batter);
+
+    throw_on_the_ground(&mut race, &defence, in_infield, situation);
+    race
+}
+
+/// Whether the next base past `route.to` is one he can take: free, and reached
+/// before the throw.
+fn safe_to_take(defence: &Defence, route: &Route, limit: usize, send: f32) -> bool {
+    let free = route.to == Base::Home || end_index(route.to) < limit;
+    let margin = if route.to == Base::Home { send } else { 0.0 };
+    free && route.arrives() < defence.throw_to(route.to).arrives + margin
+}
+
+/// Keeps a runner going base by base for as long as he would beat the throw to
+/// the next one. The batter is never sent on a gamble.
+fn extend(route: &mut Route, defence: &Defence, limit: usize, gamble: bool) {
+    while route.to != Base::Home {
+        let further = Route {
+            to: route.to.next(),
+            ..*route
+        };
+        let take = if gamble {
+            safe_to_take(defence, &further, limit, SEND_HOME)
+        } else {
+            // The batter stops at third: nobody circles the bases on a ball that
+            // stays in the park.
+            further.to != Base::Home
+                && end_index(further.to) < limit
+                && further.arrives() < defence.throw_to(further.to).arrives
+        };
+        if !take {
+            break;
+        }
+        *route = further;
+    }
+}
+
+/// Runners go back to their bags at the catch and decide whether to try for the
+/// next one.
+fn tag_up(race: &mut Race, defence: &Defence, caught_at: f32, situation: Situation) {
+    let mut limit = 4;
+    for base in [Base::Third, Base::Second, Base::First] {
+        let Some(speed) = situation.runner(base) else {
+            continue;
+        };
+        let per_base = pace(speed, field::RUN_PER_BASE);
+        let route = Route {
+            from: base,
+            to: base.next(),
+            leaves: caught_at,
+            first_leg: per_base + STANDING_START,
+            per_base,
+            out_at: None,
+        };
+        if safe_to_take(defence, &route, limit, SEND_ON_TAG) {
+            limit = end_index(route.to);
+            race.routes.push(route);
+        } else {
+            limit = start_index(base);
+        }
+    }
+
+    // The throw goes after the lead runner; anyone else who went is safe.
+    if let Some(lead) = race.routes.iter().position(|route| route.from != Base::Home) {
+        let throw = defence.throw_to(race.routes[lead].to);
+        if defence.beats(&race.routes[lead]) {
+            race.routes[lead].out_at = Some(throw.arrives);
+        }
+        race.throws.push(throw);
+    }
+}
+
+/// The fielder picks the one throw that hurts the offence most: two outs if he
+/// can get them, then the runner trying to score, then the lead runner on a
+/// force, then the batter, then anyone. With two out any out ends the inning, so
+/// he takes the batter first.
+fn throw_on_the_ground(race: &mut Race, defence: &Defence, in_infield: bool, situation: Situation) {
+    let batter = race.routes.len() - 1;
+    let can_get = |route: &Route| defence.beats(route);
+
+    // Force at second and the relay to first.
+    let from_first = race.routes.iter().position(|route| route.from == Base::First);
+    if let Some(runner) = from_first
+        && situation.outs < 2
+        && in_infield
+        && race.routes[runner].to == Base::Second
+        && race.routes[batter].to == Base::First
+        && can_get(&race.routes[runner])
+    {
+        let feed = defence.throw_to(Base::Second);
+        let relay = Throw::new(field::SECOND, feed.arrives + PIVOT, Base::First);
+        if relay.arrives <= race.routes[batter].arrives() {
+            race.routes[runner].out_at = Some(feed.arrives);
+            race.routes[batter].out_at = Some(relay.arrives);
+            race.throws.extend([feed, relay]);
+            return;
+        }
+    }
+
+    let at_batter = can_get(&race.routes[batter]).then_some(batter);
+    let target = if situation.outs == 2 {
+        at_batter
+    } else {
+        let scoring = race
+            .routes
+            .iter()
+            .position(|route| route.to == Base::Home && can_get(route));
+        let force = race.routes[..batter]
+            .iter()
+            .position(|route| situation.is_forced(route.from) && route.to == route.from.next() && can_get(route));
+        scoring.or(force).or(at_batter)
+    };
+    let target = target.or_else(|| race.routes.iter().position(can_get));
+
+    match target {
+        Some(index) => {
+            let throw = defence.throw_to(race.routes[index].to);
+            race.routes[index].out_at = Some(throw.arrives);
+            race.throws.push(throw);
+        }
+        // Nobody can be got: the ball goes in ahead of the lead runner to stop
+        // anyone taking another base.
+        None => race.throws.push(defence.throw_to(race.routes[0].to)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runner_on(base: Base) -> Situation {
+        let mut situation = Situation::default();
+        situation.runners[start_index(base) - 1] = Some(0.5);
+        situation
+    }
+
+    fn grounder_to(point: Vec2, time: f32) -> Intercept {
+        Intercept {
+            time,
+            point,
+            height: 0.0,
+        }
+    }
+
+    #[test]
+    fn a_runner_moves_along_the_base_path_and_stops_on_the_bag() {
+        let route = Route {
+            from: Base::First,
+            to: Base::Third,
+            leaves: 1.0,
+            first_leg: 3.0,
+            per_base: 3.0,
+            out_at: None,
+        };
+        assert_eq!(route.position_at(0.0), field::FIRST);
+        assert!(route.position_at(2.5).distance(field::FIRST.lerp(field::SECOND, 0.5)) < 1e-3);
+        assert_eq!(route.position_at(4.0), field::SECOND);
+        assert_eq!(route.position_at(20.0), field::THIRD);
+    }
+
+    #[test]
+    fn a_runner_thrown_out_stops_where_the_ball_caught_him() {
+        let route = Route {
+            from: Base::Home,
+            to: Base::First,
+            leaves: 0.0,
+            first_leg: 4.0,
+            per_base: 3.6,
+            out_at: Some(3.0),
+        };
+        let tagged = route.position_at(3.0);
+        assert_eq!(route.position_at(10.0), tagged);
+        assert!(tagged.distance(field::FIRST) > 1.0);
+    }
+
+    #[test]
+    fn a_slow_runner_takes_longer_than_a_fast_one() {
+        assert!(pace(0.1, field::RUN_PER_BASE) > pace(0.9, field::RUN_PER_BASE));
+        assert!((pace(0.5, field::RUN_PER_BASE) - field::RUN_PER_BASE).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn a_runner_freezes_on_a_grounder_in_front_of_him_unless_forced() {
+        let shortstop = grounder_to(Vec2::new(-30.0, 120.0), 1.4);
+        let second = race(shortstop, false, true, runner_on(Base::Second));
+        assert!(second.route(Base::Second).is_none(), "he should hold at second");
+
+        let first = race(shortstop, false, true, runner_on(Base::First));
+        assert!(first.route(Base::First).is_some(), "a forced runner has to go");
+    }
+
+    #[test]
+    fn a_sharp_grounder_to_short_with_a_man_on_first_is_two() {
+        let shortstop = grounder_to(Vec2::new(-30.0, 120.0), 1.0);
+        let race = race(shortstop, false, true, runner_on(Base::First));
+        assert!(race.route(Base::First).is_some_and(Route::is_out));
+        assert!(race.route(Base::Home).is_some_and(Route::is_out));
+        assert_eq!(race.throws.len(), 2, "the feed and the relay");
+        assert_eq!(race.throws[1].from, field::SECOND);
+    }
+
+    #[test]
+    fn the_ball_is_in_a_glove_or_in_the_air_once_it_is_fielded() {
+        let shortstop = grounder_to(Vec2::new(-30.0, 120.0), 1.0);
+        let race = race(shortstop, false, true, Situation::default());
+        assert!(race.ball_at(0.5).is_none(), "still loose");
+        assert_eq!(race.ball_at(1.2).map(Vec3::truncate), Some(shortstop.point));
+        let throw = race.throws[0];
+        let midway = race
+            .ball_at(f32::midpoint(throw.released, throw.arrives))
+            .expect("in the air");
+        assert!(midway.z > 5.0, "a throw arcs");
+        assert_eq!(race.ball_at(30.0).map(Vec3::truncate), Some(field::FIRST));
+    }
+
+    #[test]
+    fn nobody_tags_up_with_two_out() {
+        let deep = Intercept {
+            time: 4.5,
+            point: Vec2::new(0.0, 330.0),
+            height: 6.0,
+        };
+        let mut situation = runner_on(Base::Third);
+        assert!(race(deep, true, false, situation).route(Base::Third).is_some());
+        situation.outs = 2;
+        assert!(race(deep, true, false, situation).route(Base::Third).is_none());
+    }
+}
@@ -12,9 +12,15 @@
 //! contact into a grounder or a fly ball. Choosing a style is a real gamble: only
 //! an uppercut can drive a ball out of the park, and an uppercut cannot reach a
 //! pitch at the top of the zone at all.
+//!
+//! Who is swinging changes the margins, not the rules. The constants below are
+//! written for an average right-handed [`Hitter`]; a contact hitter gets a wider
+//! window, a slugger a higher ceiling, and a left-hander the mirror image of the
+//! spray.
 
 use super::{
     ball::MPH_TO_FPS,
+    lineup::Hitter,
     pitch::{self, LivePitch},
 };
 
@@ -22,6 +28,10 @@ use super::{
 /// entirely. About a third of a fastball's flight, so timing is genuinely tight.
 pub const TIMING_WINDOW: f32 = 0.155;
 
+/// How much of [`TIMING_WINDOW`] the worst and best contact hitters get, either
+/// side of the average hitter's whole window.
+const CONTACT_STRETCH: f32 = 0.3;
+
 /// Miss the ball's height by this much and the bat goes clean over or under it.
 pub const HEIGHT_WINDOW: f32 = 1.05;
 
@@ -30,6 +40,9 @@ pub const HEIGHT_WINDOW: f32 = 1.05;
 const EV_WEAK: f32 = 52.0;
 const EV_BEST: f32 = 108.0;
 
+/// Miles per hour between the weakest and the strongest hitter's best contact.
+const POWER_SPREAD: f32 = 14.0;
+
 /// Degrees of launch angle gained per foot of "swinging under the ball". Most of
 /// the launch angle comes from the swing's own attack angle; this is the part the
 /// pitch location contributes.
@@ -123,11 +136,18 @@ pub enum Contact {
     },
 }
 
-/// Works out what happens when this swing meets this pitch.
-pub fn resolve(pitch: &LivePitch, swing: Swing) -> Contact {
+/// How far either side of the perfect moment this batter can still get wood on
+/// the ball.
+pub fn timing_window(batter: &Hitter) -> f32 {
+    TIMING_WINDOW * (1.0 + CONTACT_STRETCH * (batter.contact - 0.5) * 2.0)
+}
+
+/// Works out what happens when this batter's swing meets this pitch.
+pub fn resolve(batter: &Hitter, pitch: &LivePitch, swing: Swing) -> Contact {
     let crossing = pitch.crossing();
+    let window = timing_window(batter);
 
-    if swing.timing.abs() >= TIMING_WINDOW {
+    if swing.timing.abs() >= window {
         return Contact::Whiff;
     }
 
@@ -140,11 +160,12 @@ pub fn resolve(pitch: &LivePitch, swing: Swing) -> Contact {
     // a foot under the ball. Both fall off quadratically, so there is a usable
     // sweet spot rather than a knife edge — a linear penalty made it impossible to
     // pull a ball with any authority.
-    let timing_quality = 1.0 - (swing.timing / TIMING_WINDOW).powi(2);
+    let timing_quality = 1.0 - (swing.timing / window).powi(2);
     let height_quality = 1.0 - (height_error / HEIGHT_WINDOW).powi(2);
     let quality = (timing_quality * height_quality).clamp(0.0, 1.0);
 
-    let exit_velocity = (EV_WEAK + (EV_BEST - EV_WEAK) * quality) * MPH_TO_FPS;
+    let best = EV_BEST + POWER_SPREAD * (batter.power - 0.5);
+    let exit_velocity = (EV_WEAK + (best - EV_WEAK) * quality) * MPH_TO_FPS;
 
     // The swing's own attack angle does most of the work; catching the ball above
     // or below the plane tilts it further up or beats it into the ground.
@@ -153,9 +174,12 @@ pub fn resolve(pitch: &LivePitch, swing: Swing) -> Contact {
     // Early swings pull the ball, late swings push it the other way. A
     // right-handed batter pulls to left field, which is negative on the field's
     // x-axis, so an early (negative) swing gives a negative spray angle. Pitch
-    // location adds to that: inside pitches (also negative x) get pulled.
-    let spray =
-        ((swing.timing / TIMING_WINDOW) * SPRAY_LIMIT + crossing.x * PULL_PER_FOOT).clamp(-SPRAY_CLAMP, SPRAY_CLAMP);
+    // location adds to that: inside pitches (also negative x) get pulled. A
+    // left-hander is the same swing in a mirror: he pulls to right field, and
+    // inside to him is positive x.
+    let side = batter.bats.side();
+    let pull = (swing.timing / window) * SPRAY_LIMIT + side * crossing.x * PULL_PER_FOOT;
+    let spray = (side * pull).clamp(-SPRAY_CLAMP, SPRAY_CLAMP);
 
     Contact::Struck {
         exit_velocity,
@@ -170,7 +194,7 @@ mod tests {
     use bevy::math::Vec2;
 
     use super::{
-        super::{ball, field, pitch::PitchPlan},
+        super::{ball, field, lineup::Bats, park::Park, pitch::PitchPlan, weather::Weather},
         *,
     };
 
@@ -189,7 +213,7 @@ mod tests {
     }
 
     fn swing_at(pitch: &LivePitch, timing: f32, style: SwingStyle) -> Hit {
-        match resolve(pitch, Swing { timing, style }) {
+        match resolve(&Hitter::AVERAGE, pitch, Swing { timing, style }) {
             Contact::Struck {
                 exit_velocity,
                 launch,
@@ -207,6 +231,8 @@ mod tests {
 
     fn flight_of(hit: &Hit) -> ball::Flight {
         ball::simulate(
+            &Park::default(),
+            Weather::STILL,
             hit.exit_velocity,
             hit.launch_degrees.to_radians(),
             hit.spray_degrees.to_radians(),
@@ -219,6 +245,7 @@ mod tests {
         for timing in [-0.5, -TIMING_WINDOW, TIMING_WINDOW, 0.4] {
             assert_eq!(
                 resolve(
+                    &Hitter::AVERAGE,
                     &pitch,
                     Swing {
                         timing,
@@ -237,6 +264,7 @@ mod tests {
         let high = pitch_at(0.0, pitch::ZONE_TOP);
         assert_eq!(
             resolve(
+                &Hitter::AVERAGE,
                 &high,
                 Swing {
                     timing: 0.0,
@@ -249,6 +277,7 @@ mod tests {
         // A normal swing handles it.
         assert!(matches!(
             resolve(
+                &Hitter::AVERAGE,
                 &high,
                 Swing {
                     timing: 0.0,
@@ -354,12 +383,12 @@ mod tests {
                             launch,
                             spray,
                             ..
-                        } = resolve(&pitch, Swing { timing, style })
+                        } = resolve(&Hitter::AVERAGE, &pitch, Swing { timing, style })
                         else {
                             continue;
                         };
                         contacts += 1;
-                        if ball::simulate(exit_velocity, launch, spray).home_run {
+                        if ball::simulate(&Park::default(), Weather::STILL, exit_velocity, launch, spray).home_run {
                             home_runs += 1;
                             lift_only &= style == SwingStyle::Lift;
                         }
@@ -389,6 +418,63 @@ mod tests {
         assert!(flight_of(&late).landing.x > 0.0);
     }
 
+    #[test]
+    fn a_left_handed_batter_is_the_mirror_image() {
+        let lefty = Hitter {
+            bats: Bats::Left,
+            ..Hitter::AVERAGE
+        };
+        // Inside to a lefty is the first-base side of the plate.
+        for (x, timing) in [(0.0, -0.09), (0.4, -0.03), (-0.3, 0.06)] {
+            let right = swing_at(&pitch_at(x, pitch::ZONE_MID), timing, SwingStyle::Normal);
+            let Contact::Struck { spray, .. } = resolve(
+                &lefty,
+                &pitch_at(-x, pitch::ZONE_MID),
+                Swing {
+                    timing,
+                    style: SwingStyle::Normal,
+                },
+            ) else {
+                panic!("the lefty missed what the righty hit");
+            };
+            assert!(
+                (spray.to_degrees() + right.spray_degrees).abs() < 1e-3,
+                "x {x}, timing {timing}: {} against {}",
+                spray.to_degrees(),
+                right.spray_degrees
+            );
+        }
+    }
+
+    #[test]
+    fn a_slugger_hits_it_harder_and_a_contact_hitter_reaches_more_swings() {
+        let pitch = pitch_at(0.0, pitch::ZONE_MID);
+        let flush = Swing {
+            timing: 0.0,
+            style: SwingStyle::Normal,
+        };
+        let exit_velocity = |batter: &Hitter| match resolve(batter, &pitch, flush) {
+            Contact::Struck { exit_velocity, .. } => exit_velocity,
+            Contact::Whiff => panic!("{} whiffed on a perfect swing", batter.name),
+        };
+        let slugger = Hitter {
+            power: 0.9,
+            ..Hitter::AVERAGE
+        };
+        assert!(exit_velocity(&slugger) > exit_velocity(&Hitter::AVERAGE) + 4.0 * MPH_TO_FPS);
+
+        let late = Swing {
+            timing: TIMING_WINDOW * 1.05,
+            ..flush
+        };
+        let contact = Hitter {
+            contact: 0.9,
+            ..Hitter::AVERAGE
+        };
+        assert_eq!(resolve(&Hitter::AVERAGE, &pitch, late), Contact::Whiff);
+        assert!(matches!(resolve(&contact, &pitch, late), Contact::Struck { .. }));
+    }
+
     #[test]
     fn the_worst_timed_contact_hooks_into_foul_territory() {
         // Foul balls are meant to emerge from the geometry, not a special case.
@@ -436,10 +522,12 @@ mod tests {
         for style in [SwingStyle::Level, SwingStyle::Normal, SwingStyle::Lift] {
             let best = heights
                 .iter()
-                .filter_map(|&h| match resolve(&pitch_at(0.0, h), Swing { timing: 0.0, style }) {
-                    Contact::Struck { quality, .. } => Some(quality),
-                    Contact::Whiff => None,
-                })
+                .filter_map(
+                    |&h| match resolve(&Hitter::AVERAGE, &pitch_at(0.0, h), Swing { timing: 0.0, style }) {
+                        Contact::Struck { quality, .. } => Some(quality),
+                        Contact::Whiff => None,
+                    },
+                )
                 .fold(0.0_f32, f32::max);
             assert!(
                 best > 0.7,
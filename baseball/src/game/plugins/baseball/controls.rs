@@ -0,0 +1,312 @@
+//! What each seat is holding, read once a frame.
+//!
+//! The pitch loop never looks at a key or a button. [`read_controls`] boils the
+//! keyboard and any gamepads down to a [`Pad`] per [`Seat`], and the loop asks the
+//! pad belonging to whoever is batting or pitching. That is what lets one person
+//! play with the whole keyboard, two people split it down the middle, and either of
+//! them pick up a gamepad instead.
+//!
+//! | | Solo | Player 1 | Player 2 |
+//! | --- | --- | --- | --- |
+//! | Aim, lift / level | arrows | `W` `A` `S` `D` | arrows |
+//! | Pitches | `1`–`4` | `1`–`4` | `7`–`0` |
+//! | Cycle pitch | `Q` `E` | `Q` `E` | `,` `.` |
+//! | Throw, swing | `Space` | `Space` | `Enter` |
+//! | Call the bullpen | `B` | `B` | `P` |
+//!
+//! On a gamepad the left stick or the d-pad aims and picks the swing, the bumpers
+//! cycle the pitch, the south face button throws and swings, and the north one
+//! calls the bullpen. A lone player can use any pad; with two players the first
+//! pad connected is player 1's.
+
+use bevy::prelude::*;
+
+use super::{Seat, Seats, bat::SwingStyle, pitch::PitchKind};
+
+/// How far a stick has to lean before a swing counts as lifted or levelled.
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// One seat's keys.
+#[derive(Debug)]
+pub struct KeyLayout {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    /// Fastball, slider, curveball, changeup.
+    pub pitches: [KeyCode; 4],
+    pub previous: KeyCode,
+    pub next: KeyCode,
+    /// Throws the pitch or swings the bat.
+    pub act: KeyCode,
+    /// How [`KeyLayout::act`] is written in a prompt.
+    pub act_name: &'static str,
+    /// Brings in a reliever.
+    pub bullpen: KeyCode,
+}
+
+/// The whole keyboard, for a lone player.
+pub const SOLO: KeyLayout = KeyLayout {
+    up: KeyCode::ArrowUp,
+    down: KeyCode::ArrowDown,
+    left: KeyCode::ArrowLeft,
+    right: KeyCode::ArrowRight,
+    pitches: [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4],
+    previous: KeyCode::KeyQ,
+    next: KeyCode::KeyE,
+    act: KeyCode::Space,
+    act_name: "SPACE",
+    bullpen: KeyCode::KeyB,
+};
+
+/// The left-hand half, for player 1.
+pub const LEFT_HAND: KeyLayout = KeyLayout {
+    up: KeyCode::KeyW,
+    down: KeyCode::KeyS,
+    left: KeyCode::KeyA,
+    right: KeyCode::KeyD,
+    pitches: [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4],
+    previous: KeyCode::KeyQ,
+    next: KeyCode::KeyE,
+    act: KeyCode::Space,
+    act_name: "SPACE",
+    bullpen: KeyCode::KeyB,
+};
+
+/// The right-hand half, for player 2.
+pub const RIGHT_HAND: KeyLayout = KeyLayout {
+    up: KeyCode::ArrowUp,
+    down: KeyCode::ArrowDown,
+    left: KeyCode::ArrowLeft,
+    right: KeyCode::ArrowRight,
+    pitches: [KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9, KeyCode::Digit0],
+    previous: KeyCode::Comma,
+    next: KeyCode::Period,
+    act: KeyCode::Enter,
+    act_name: "ENTER",
+    bullpen: KeyCode::KeyP,
+};
+
+/// The keys a seat uses, which depends on whether it has the keyboard to itself.
+pub fn layout(seats: Seats, seat: Seat) -> &'static KeyLayout {
+    match (seats.is_two_player(), seat) {
+        (false, _) => &SOLO,
+        (true, Seat::One) => &LEFT_HAND,
+        (true, Seat::Two) => &RIGHT_HAND,
+    }
+}
+
+/// What one seat is doing this frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Pad {
+    /// Direction held, each axis in `[-1, 1]`; up is positive.
+    pub stick: Vec2,
+    /// A pitch picked outright.
+    pub pick: Option<PitchKind>,
+    /// A step through the pitches: `true` forward, `false` back.
+    pub cycle: Option<bool>,
+    /// Throw or swing, pressed this frame.
+    pub act: bool,
+    /// Going to the bullpen, pressed this frame.
+    pub bullpen: bool,
+}
+
+impl Pad {
+    /// The swing the batter is holding for: up lifts, down levels.
+    pub fn swing_style(&self) -> SwingStyle {
+        if self.stick.y > STICK_THRESHOLD {
+            SwingStyle::Lift
+        } else if self.stick.y < -STICK_THRESHOLD {
+            SwingStyle::Level
+        } else {
+            SwingStyle::Normal
+        }
+    }
+
+    /// Both sources at once, so a player can mix keys and a pad.
+    fn merge(self, other: Pad) -> Pad {
+        Pad {
+            stick: (self.stick + other.stick).clamp(Vec2::NEG_ONE, Vec2::ONE),
+            pick: self.pick.or(other.pick),
+            cycle: self.cycle.or(other.cycle),
+            act: self.act || other.act,
+            bullpen: self.bullpen || other.bullpen,
+        }
+    }
+}
+
+/// This frame's [`Pad`] for each seat.
+#[derive(Debug, Default, Resource)]
+pub struct Controls {
+    pub one: Pad,
+    pub two: Pad,
+}
+
+impl Controls {
+    pub fn pad(&self, seat: Seat) -> Pad {
+        match seat {
+            Seat::One => self.one,
+            Seat::Two => self.two,
+        }
+    }
+
+    /// Whether anyone at all pressed throw or swing.
+    pub fn anyone_acted(&self) -> bool {
+        self.one.act || self.two.act
+    }
+}
+
+fn read_keys(keys: &ButtonInput<KeyCode>, layout: &KeyLayout) -> Pad {
+    let axis = |negative: KeyCode, positive: KeyCode| {
+        f32::from(u8::from(keys.pressed(positive))) - f32::from(u8::from(keys.pressed(negative)))
+    };
+    let kinds = [
+        PitchKind::Fastball,
+        PitchKind::Slider,
+        PitchKind::Curveball,
+        PitchKind::Changeup,
+    ];
+    Pad {
+        stick: Vec2::new(axis(layout.left, layout.right), axis(layout.down, layout.up)),
+        pick: layout
+            .pitches
+            .iter()
+            .zip(kinds)
+            .find_map(|(key, kind)| keys.just_pressed(*key).then_some(kind)),
+        cycle: if keys.just_pressed(layout.next) {
+            Some(true)
+        } else if keys.just_pressed(layout.previous) {
+            Some(false)
+        } else {
+            None
+        },
+        act: keys.just_pressed(layout.act),
+        bullpen: keys.just_pressed(layout.bullpen),
+    }
+}
+
+fn read_gamepad(gamepad: &Gamepad) -> Pad {
+    // Whichever of the stick and the d-pad is leaning further.
+    let stick = gamepad.left_stick();
+    let dpad = gamepad.dpad();
+    Pad {
+        stick: if dpad.length_squared() > stick.length_squared() {
+            dpad
+        } else {
+            stick
+        },
+        pick: None,
+        cycle: if gamepad.just_pressed(GamepadButton::RightTrigger) {
+            Some(true)
+        } else if gamepad.just_pressed(GamepadButton::LeftTrigger) {
+            Some(false)
+        } else {
+            None
+        },
+        act: gamepad.just_pressed(GamepadButton::South),
+        bullpen: gamepad.just_pressed(GamepadButton::North),
+    }
+}
+
+/// Fills in [`Controls`] for the frame. A seat nobody is sitting in reads as idle.
+pub fn read_controls(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    seats: Res<Seats>,
+    mut controls: ResMut<Controls>,
+) {
+    // Entity order is connection order, which is the only stable order there is.
+    let mut pads: Vec<_> = gamepads.iter().collect();
+    pads.sort_by_key(|(entity, _)| *entity);
+
+    let mut one = read_keys(&keys, layout(*seats, Seat::One));
+    let mut two = Pad::default();
+    if seats.is_two_player() {
+        two = read_keys(&keys, layout(*seats, Seat::Two));
+        if let Some((_, pad)) = pads.first() {
+            one = one.merge(read_gamepad(pad));
+        }
+        if let Some((_, pad)) = pads.get(1) {
+            two = two.merge(read_gamepad(pad));
+        }
+    } else {
+        for (_, pad) in &pads {
+            one = one.merge(read_gamepad(pad));
+        }
+    }
+
+    *controls = Controls { one, two };
+}
+
+/// Whether any connected pad pressed the button this frame, for the menus, which
+/// do not care whose pad it is.
+pub fn any_pad_pressed(gamepads: &Query<&Gamepad>, button: GamepadButton) -> bool {
+    gamepads.iter().any(|gamepad| gamepad.just_pressed(button))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pressing(keys: &[KeyCode]) -> ButtonInput<KeyCode> {
+        let mut input = ButtonInput::default();
+        for key in keys {
+            input.press(*key);
+        }
+        input
+    }
+
+    #[test]
+    fn the_solo_keys_read_as_they_always_have() {
+        let pad = read_keys(
+            &pressing(&[KeyCode::ArrowLeft, KeyCode::ArrowUp, KeyCode::Digit3, KeyCode::Space]),
+            &SOLO,
+        );
+        assert_eq!(pad.stick, Vec2::new(-1.0, 1.0));
+        assert_eq!(pad.pick, Some(PitchKind::Curveball));
+        assert!(pad.act);
+        assert_eq!(pad.swing_style(), SwingStyle::Lift);
+    }
+
+    #[test]
+    fn the_split_keyboard_halves_share_no_keys() {
+        let keys = |layout: &KeyLayout| {
+            let mut all = vec![layout.up, layout.down, layout.left, layout.right];
+            all.extend(layout.pitches);
+            all.extend([layout.previous, layout.next, layout.act, layout.bullpen]);
+            all
+        };
+        let left = keys(&LEFT_HAND);
+        for key in keys(&RIGHT_HAND) {
+            assert!(!left.contains(&key), "{key:?} is bound on both halves");
+        }
+    }
+
+    #[test]
+    fn one_player_pressing_does_not_move_the_other() {
+        let keys = pressing(&[KeyCode::KeyW, KeyCode::Space]);
+        let one = read_keys(&keys, &LEFT_HAND);
+        let two = read_keys(&keys, &RIGHT_HAND);
+        assert_eq!(one.swing_style(), SwingStyle::Lift);
+        assert!(one.act);
+        assert_eq!(two, Pad::default());
+    }
+
+    #[test]
+    fn holding_down_levels_the_swing_and_nothing_is_a_normal_one() {
+        let down = Pad {
+            stick: Vec2::new(0.0, -0.8),
+            ..default()
+        };
+        assert_eq!(down.swing_style(), SwingStyle::Level);
+        assert_eq!(Pad::default().swing_style(), SwingStyle::Normal);
+    }
+
+    #[test]
+    fn a_lone_player_gets_the_whole_keyboard_and_two_players_get_half_each() {
+        let solo = Seats::solo(baseball_game_rules::InningHalf::Top);
+        assert_eq!(layout(solo, Seat::One).act, KeyCode::Space);
+        assert_eq!(layout(Seats::HOT_SEAT, Seat::One).up, KeyCode::KeyW);
+        assert_eq!(layout(Seats::HOT_SEAT, Seat::Two).act, KeyCode::Enter);
+    }
+}
@@ -9,10 +9,15 @@
 //! 45°-rotated square while positioning the nine fielders in unrotated world
 //! space. The two disagreed, and nothing in the code could tell you which was
 //! right. Deriving every position from real dimensions removes the question.
+//!
+//! The outfield is the exception: the wall and the foul ground belong to the
+//! [`Park`], and the functions that need them take one.
 
 use baseball_game_rules::{Base, PlayerPosition};
 use bevy::prelude::*;
 
+use super::park::Park;
+
 /// Distance between consecutive bases.
 pub const BASE_PATH: f32 = 90.0;
 
@@ -37,16 +42,6 @@ pub const INFIELD_DIRT_RADIUS: f32 = 95.0;
 /// the diagonal through first and third base.
 pub const FOUL_LINE_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
 
-/// Fence distance straight up the middle and down the lines. A touch shorter than
-/// a real park: the hardest contact the batter can produce carries about 410 feet,
-/// and a ball has to clear the wall *with height to spare* rather than merely
-/// reach it, so a deeper fence would put home runs out of reach entirely.
-const FENCE_CENTER: f32 = 385.0;
-const FENCE_CORNER: f32 = 320.0;
-
-/// Height of the outfield wall, which a fly ball has to clear.
-pub const FENCE_HEIGHT: f32 = 8.0;
-
 /// Highest a fielder can reach to pull a ball down.
 pub const CATCH_REACH: f32 = 9.0;
 
@@ -73,6 +68,16 @@ pub const THROW_SPEED: f32 = 115.0;
 /// Time a fielder spends collecting the ball and getting rid of it.
 pub const FIELDING_DELAY: f32 = 0.55;
 
+/// Throws much past this distance lose real time to the height they need and the
+/// cutoff man they usually go through.
+pub const THROW_ARC: f32 = 330.0;
+
+/// Seconds a throw takes to cover `distance` feet. An infielder's throw is on a
+/// line; one from the warning track is not.
+pub fn throw_time(distance: f32) -> f32 {
+    distance / THROW_SPEED + (distance / THROW_ARC).powi(2)
+}
+
 /// Spray angle of a batted ball: `0` is dead center, negative pulls to left field
 /// and positive slices to right. Fair territory is the ±45° wedge.
 pub fn spray_angle(point: Vec2) -> f32 {
@@ -90,17 +95,37 @@ pub fn is_fair(point: Vec2) -> bool {
     point.y > 0.0 && point.x.abs() <= point.y
 }
 
-/// Distance to the wall along a given spray angle. Deepest to center and
-/// shortest down the lines, bulging out through the power alleys the way a real
-/// outfield does rather than tapering in a straight line.
-pub fn fence_distance(spray: f32) -> f32 {
-    let t = (spray.abs() / FOUL_LINE_ANGLE).clamp(0.0, 1.0);
-    FENCE_CENTER - (FENCE_CENTER - FENCE_CORNER) * t.powf(1.5)
+/// Distance to the wall along a given spray angle. Every park's wall is a touch
+/// shorter than the real one it is drawn from: the hardest contact the batter can
+/// produce carries about 410 feet at sea level, and a ball has to clear the wall
+/// *with height to spare* rather than merely reach it.
+pub fn fence_distance(park: &Park, spray: f32) -> f32 {
+    park.fence_at(spray).distance
 }
 
 /// Whether a ball at this spot has left the park, given how high it still is.
-pub fn clears_fence(point: Vec2, height: f32) -> bool {
-    is_fair(point) && point.length() >= fence_distance(spray_angle(point)) && height >= FENCE_HEIGHT
+pub fn clears_fence(park: &Park, point: Vec2, height: f32) -> bool {
+    let fence = park.fence_at(spray_angle(point));
+    is_fair(point) && point.length() >= fence.distance && height >= fence.height
+}
+
+/// How far a point is from fair territory: from the nearer foul line, or from
+/// the plate for anything behind it.
+fn distance_from_fair(point: Vec2) -> f32 {
+    if is_fair(point) {
+        return 0.0;
+    }
+    let from_line = |angle: f32| {
+        let line = point_at(angle, 1.0);
+        point.distance(line * point.dot(line).max(0.0))
+    };
+    from_line(-FOUL_LINE_ANGLE).min(from_line(FOUL_LINE_ANGLE))
+}
+
+/// Whether a ball at this spot is in the stands, where nobody on the field can
+/// get to it.
+pub fn in_the_stands(park: &Park, point: Vec2) -> bool {
+    distance_from_fair(point) > park.foul_territory
 }
 
 pub fn base_position(base: Base) -> Vec2 {
@@ -138,7 +163,7 @@ pub const VIEW_CENTER_Y: f32 = 185.0;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::{super::park::PRESETS, *};
 
     #[test]
     fn the_bases_are_ninety_feet_apart() {
@@ -179,31 +204,71 @@ mod tests {
 
     #[test]
     fn the_fence_is_deepest_to_center_and_shortest_down_the_lines() {
-        let center = fence_distance(0.0);
-        let alley = fence_distance(FOUL_LINE_ANGLE / 2.0);
-        let corner = fence_distance(FOUL_LINE_ANGLE);
+        let park = Park::default();
+        let center = fence_distance(&park, 0.0);
+        let alley = fence_distance(&park, FOUL_LINE_ANGLE / 2.0);
+        let corner = fence_distance(&park, FOUL_LINE_ANGLE);
 
-        assert!((center - FENCE_CENTER).abs() < 0.01);
-        assert!((corner - FENCE_CORNER).abs() < 0.01);
+        assert!((center - 385.0).abs() < 0.01);
+        assert!((corner - 320.0).abs() < 0.01);
         assert!(
             alley < center && alley > corner,
             "power alley {alley} should sit between {corner} and {center}"
         );
-        // Symmetric about center field.
-        assert!((fence_distance(0.4) - fence_distance(-0.4)).abs() < 1e-5);
+        // The standard park is symmetric about center field.
+        assert!((fence_distance(&park, 0.4) - fence_distance(&park, -0.4)).abs() < 1e-3);
     }
 
     #[test]
     fn a_ball_only_leaves_the_park_if_it_is_fair_deep_and_high() {
-        let deep_center = point_at(0.0, FENCE_CENTER + 5.0);
-        assert!(clears_fence(deep_center, 20.0), "deep, fair and high should be gone");
-        assert!(!clears_fence(deep_center, 2.0), "a ball on the ground hits the wall");
+        let park = Park::default();
+        let deep_center = point_at(0.0, 390.0);
+        assert!(
+            clears_fence(&park, deep_center, 20.0),
+            "deep, fair and high should be gone"
+        );
+        assert!(
+            !clears_fence(&park, deep_center, 2.0),
+            "a ball on the ground hits the wall"
+        );
 
         let shallow = point_at(0.0, 300.0);
-        assert!(!clears_fence(shallow, 40.0), "300 feet to center is not a home run");
+        assert!(
+            !clears_fence(&park, shallow, 40.0),
+            "300 feet to center is not a home run"
+        );
 
-        let foul = point_at(FOUL_LINE_ANGLE + 0.1, FENCE_CENTER);
-        assert!(!clears_fence(foul, 40.0), "foul territory is never a home run");
+        let foul = point_at(FOUL_LINE_ANGLE + 0.1, 385.0);
+        assert!(!clears_fence(&park, foul, 40.0), "foul territory is never a home run");
+    }
+
+    #[test]
+    fn a_ball_over_the_green_monster_has_to_clear_it() {
+        let fenway = Park::preset("fenway").expect("fenway is a preset");
+        let over = point_at(-0.75, 305.0);
+        assert!(!clears_fence(&fenway, over, 20.0), "twenty feet up hits the Monster");
+        assert!(clears_fence(&fenway, over, 40.0));
+        assert!(
+            !clears_fence(&Park::default(), over, 40.0),
+            "305 feet down the line is short of the standard wall"
+        );
+    }
+
+    #[test]
+    fn the_stands_start_where_the_foul_ground_runs_out() {
+        let park = Park::default();
+        let beside_first = FIRST + Vec2::new(1.0, -1.0).normalize() * (park.foul_territory - 5.0);
+        assert!(!in_the_stands(&park, beside_first), "still foul ground");
+        let in_the_seats = FIRST + Vec2::new(1.0, -1.0).normalize() * (park.foul_territory + 5.0);
+        assert!(in_the_stands(&park, in_the_seats));
+        assert!(
+            in_the_stands(&park, Vec2::new(0.0, -park.foul_territory - 1.0)),
+            "behind the backstop"
+        );
+        assert!(
+            !in_the_stands(&park, point_at(0.0, 300.0)),
+            "fair territory is never the stands"
+        );
     }
 
     #[test]
@@ -219,34 +284,48 @@ mod tests {
 
     #[test]
     fn no_fielder_starts_beyond_the_wall() {
-        for (position, spot) in FIELDER_HOMES {
-            if matches!(position, PlayerPosition::Catcher) {
-                continue;
+        for (_, text) in PRESETS {
+            let park = Park::parse(text.as_bytes()).expect("presets load");
+            for (position, spot) in FIELDER_HOMES {
+                if matches!(position, PlayerPosition::Catcher) {
+                    continue;
+                }
+                let fence = fence_distance(&park, spray_angle(spot));
+                assert!(
+                    spot.length() < fence,
+                    "{position} starts {} feet out, past the {fence} foot wall in {}",
+                    spot.length(),
+                    park.name
+                );
             }
-            let fence = fence_distance(spray_angle(spot));
-            assert!(
-                spot.length() < fence,
-                "{position} starts {} feet out, past the {fence} foot wall",
-                spot.length()
-            );
         }
     }
 
     #[test]
     fn the_camera_view_covers_the_whole_park() {
-        // Both foul poles and the deepest point of the wall have to be inside the
-        // region the camera guarantees, or the layout is clipped.
+        // Both foul poles and the deepest point of every wall have to be inside
+        // the region the camera guarantees, or the layout is clipped.
         let half_width = VIEW_WIDTH / 2.0;
         let top = VIEW_CENTER_Y + VIEW_HEIGHT / 2.0;
         let bottom = VIEW_CENTER_Y - VIEW_HEIGHT / 2.0;
 
-        for spray in [-FOUL_LINE_ANGLE, 0.0, FOUL_LINE_ANGLE] {
-            let wall = point_at(spray, fence_distance(spray));
+        for (_, text) in PRESETS {
+            let park = Park::parse(text.as_bytes()).expect("presets load");
+            for step in -9..=9 {
+                let spray = FOUL_LINE_ANGLE * step as f32 / 9.0;
+                let wall = point_at(spray, fence_distance(&park, spray));
+                assert!(
+                    wall.x.abs() <= half_width,
+                    "{} wall at {wall:?} is off the side of the view",
+                    park.name
+                );
+                assert!(wall.y <= top, "{} wall at {wall:?} is above the view", park.name);
+            }
             assert!(
-                wall.x.abs() <= half_width,
-                "wall at {wall:?} is off the side of the view"
+                bottom < -park.foul_territory,
+                "{} backstop is below the view",
+                park.name
             );
-            assert!(wall.y <= top, "wall at {wall:?} is above the view");
         }
         assert!(bottom < CATCHER.y, "the catcher should be inside the view");
     }
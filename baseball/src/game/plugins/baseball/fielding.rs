@@ -2,28 +2,73 @@
 //!
 //! Nothing here looks at how well the ball was struck. It looks at where the ball
 //! went, who could get to it, and whether the throw beat the runner. Everything
-//! falls out of those three races, which is why the same swing can be an out or a
-//! double depending on where the defence happens to be standing.
+//! falls out of those races, which is why the same swing can be an out or a
+//! double depending on where the defence happens to be standing. The races
+//! themselves are run in [`baserunning`]; this module finds the fielder, hands
+//! him the ball, and writes up what the runners did.
+//!
+//! [`baserunning`]: super::baserunning
 
-use baseball_game_rules::{PitchOutcome, PlayResult, PlayerPosition};
-use bevy::prelude::*;
+use baseball_game_rules::{Base, Destination, PitchOutcome, PlayResult, PlayerPosition, RunnerAdvance, Scoring};
 
 use super::{
     ball::{Flight, Intercept},
+    baserunning::{self, Race},
     field,
 };
 
 /// The bits of game state that change what a batted ball is worth.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Situation {
-    pub runner_on_first: bool,
-    pub runner_on_third: bool,
+    /// The [`Hitter::speed`](super::lineup::Hitter::speed) of whoever is on
+    /// first, second and third, if anyone is.
+    pub runners: [Option<f32>; 3],
     /// Outs already recorded this half inning.
     pub outs: u8,
+    /// The batter's [`Hitter::speed`](super::lineup::Hitter::speed).
+    pub batter_speed: f32,
+}
+
+impl Default for Situation {
+    fn default() -> Self {
+        Situation {
+            runners: [None; 3],
+            outs: 0,
+            batter_speed: 0.5,
+        }
+    }
+}
+
+impl Situation {
+    /// How long the batter takes over a run that takes an average runner
+    /// `seconds`.
+    pub fn batter_runs(self, seconds: f32) -> f32 {
+        baserunning::pace(self.batter_speed, seconds)
+    }
+
+    /// The speed of the runner on `base`. Nobody is ever on home.
+    pub fn runner(self, base: Base) -> Option<f32> {
+        match base {
+            Base::First => self.runners[0],
+            Base::Second => self.runners[1],
+            Base::Third => self.runners[2],
+            Base::Home => None,
+        }
+    }
+
+    /// Whether the runner on `base` has to run because everyone behind him does.
+    pub fn is_forced(self, base: Base) -> bool {
+        match base {
+            Base::First => true,
+            Base::Second => self.runners[0].is_some(),
+            Base::Third => self.runners[0].is_some() && self.runners[1].is_some(),
+            Base::Home => false,
+        }
+    }
 }
 
 /// A batted ball, resolved.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Resolution {
     pub outcome: PitchOutcome,
     /// Who made the play, for the commentary line. `None` for a home run.
@@ -31,11 +76,11 @@ pub struct Resolution {
     /// Where and when the defence got to the ball, so the fielders can be
     /// animated running to exactly the spot the outcome was computed from.
     pub intercept: Option<Intercept>,
+    /// The runners and throws the outcome was decided by, for the field view to
+    /// play back. Empty when nobody had to run.
+    pub race: Race,
 }
 
-/// A caught fly this deep lets a runner tag up and score.
-const SACRIFICE_DEPTH: f32 = 220.0;
-
 /// A line drive never climbs above this.
 const LINE_DRIVE_APEX: f32 = 26.0;
 
@@ -45,9 +90,6 @@ const POPUP_APEX: f32 = 70.0;
 /// Balls fielded inside this radius count as infield plays.
 const INFIELD_RADIUS: f32 = 165.0;
 
-/// How much faster than the runner the throw has to be to turn two.
-const DOUBLE_PLAY_MARGIN: f32 = 0.6;
-
 fn is_outfielder(position: PlayerPosition) -> bool {
     matches!(
         position,
@@ -78,35 +120,8 @@ fn best_fielder(flight: &Flight) -> (PlayerPosition, Intercept) {
     best.expect("there are always nine fielders")
 }
 
-/// How many bases the batter takes on a ball the defence failed to catch.
-///
-/// The runner is racing the ball to each bag in turn: he keeps going for as long
-/// as the throw to the next base would arrive after he does.
-fn bases_taken(point: Vec2, ready_at: f32) -> u8 {
-    let bags = [
-        (field::FIRST, field::RUN_TO_FIRST),
-        (field::SECOND, field::RUN_TO_FIRST + field::RUN_PER_BASE),
-        (field::THIRD, field::RUN_TO_FIRST + field::RUN_PER_BASE * 2.0),
-    ];
-
-    let mut bases = 0;
-    for (bag, runner_arrives) in bags {
-        let throw_arrives = ready_at + point.distance(bag) / field::THROW_SPEED;
-        if throw_arrives > runner_arrives {
-            bases += 1;
-        } else {
-            break;
-        }
-    }
-    bases
-}
-
 /// Classifies a caught ball by the shape of its flight.
-fn catch_kind(flight: &Flight, by: PlayerPosition, situation: Situation) -> PlayResult {
-    let deep_enough = flight.landing.length() >= SACRIFICE_DEPTH;
-    if situation.runner_on_third && situation.outs < 2 && deep_enough {
-        return PlayResult::SacrificeFly;
-    }
+fn catch_kind(flight: &Flight, by: PlayerPosition) -> PlayResult {
     if flight.apex < LINE_DRIVE_APEX {
         return PlayResult::Lineout;
     }
@@ -116,6 +131,73 @@ fn catch_kind(flight: &Flight, by: PlayerPosition, situation: Situation) -> Play
     PlayResult::Flyout
 }
 
+/// Writes up a race for the rules engine. `caught` is how the catch would be
+/// scored if the ball was caught in the air.
+///
+/// Where the runners did exactly what a named play would have them do, the named
+/// play is used, so the banner can say "DOUBLE PLAY!" rather than "OUT".
+/// Anything else is spelled out runner by runner. A runner thrown out stretching
+/// a hit in the outfield does not cost the batter his hit; one retired on an
+/// infield play makes it a fielder's choice.
+fn scored(race: &Race, situation: Situation, in_infield: bool, caught: Option<PlayResult>) -> PlayResult {
+    let end = |from: Base| match race.route(from) {
+        Some(route) if route.is_out() => Destination::Out,
+        Some(route) => Destination::Safe(route.to),
+        None => Destination::Safe(from),
+    };
+    // Retiring the batter for the third out ends the inning before anyone else
+    // can count.
+    if situation.outs == 2 && end(Base::Home) == Destination::Out {
+        return caught.unwrap_or(PlayResult::Groundout);
+    }
+    let on_base: Vec<Base> = [Base::First, Base::Second, Base::Third]
+        .into_iter()
+        .filter(|&base| situation.runner(base).is_some())
+        .collect();
+    let all = |expected: &dyn Fn(Base) -> Destination| on_base.iter().all(|&base| end(base) == expected(base));
+
+    let held = |base: Base| Destination::Safe(base);
+    let forced_up = |base: Base| match base {
+        Base::First => Destination::Out,
+        _ if situation.is_forced(base) => Destination::Safe(base.next()),
+        _ => Destination::Safe(base),
+    };
+    let up = |bases: u8| move |base: Base| Destination::Safe(base.advance_by(bases));
+    let runner_out = on_base.iter().any(|&base| end(base) == Destination::Out);
+
+    let (scoring, named) = match end(Base::Home) {
+        Destination::Out => match caught {
+            Some(kind) if all(&held) => (Scoring::Out, Some(kind)),
+            Some(_) if on_base.iter().any(|&base| end(base) == Destination::Safe(Base::Home)) => {
+                let sacrifice_fly = |base: Base| if base == Base::Third { up(1)(base) } else { held(base) };
+                (
+                    Scoring::Sacrifice,
+                    all(&sacrifice_fly).then_some(PlayResult::SacrificeFly),
+                )
+            }
+            Some(_) => (Scoring::Out, None),
+            None if all(&held) => (Scoring::Out, Some(PlayResult::Groundout)),
+            None => (Scoring::Out, all(&forced_up).then_some(PlayResult::DoublePlay)),
+        },
+        Destination::Safe(Base::First) if runner_out && in_infield => (
+            Scoring::FieldersChoice,
+            all(&forced_up).then_some(PlayResult::FieldersChoice),
+        ),
+        Destination::Safe(Base::First) => (Scoring::Single, all(&up(1)).then_some(PlayResult::Single)),
+        Destination::Safe(Base::Second) => (Scoring::Double, all(&up(2)).then_some(PlayResult::Double)),
+        Destination::Safe(_) => (Scoring::Triple, all(&up(3)).then_some(PlayResult::Triple)),
+    };
+
+    named.unwrap_or_else(|| {
+        let advance = on_base
+            .iter()
+            .fold(RunnerAdvance::new().with_batter(end(Base::Home)), |advance, &base| {
+                advance.with_runner(base, end(base))
+            });
+        PlayResult::Advanced(scoring, advance)
+    })
+}
+
 /// Works out what a batted ball was worth.
 pub fn resolve(flight: &Flight, situation: Situation) -> Resolution {
     if flight.home_run {
@@ -123,18 +205,22 @@ pub fn resolve(flight: &Flight, situation: Situation) -> Resolution {
             outcome: PitchOutcome::HomeRun,
             fielder: None,
             intercept: None,
+            race: Race::default(),
         };
     }
 
     let (position, intercept) = best_fielder(flight);
+    let in_infield = intercept.point.length() < INFIELD_RADIUS;
 
     // A fly ball caught on the fly is an out wherever it was caught — a foul pop
     // to the catcher retires the batter just the same as one in fair territory.
     if intercept.in_air() {
+        let race = baserunning::race(intercept, true, in_infield, situation);
         return Resolution {
-            outcome: PitchOutcome::InPlay(catch_kind(flight, position, situation)),
+            outcome: PitchOutcome::InPlay(scored(&race, situation, in_infield, Some(catch_kind(flight, position)))),
             fielder: Some(position),
             intercept: Some(intercept),
+            race,
         };
     }
 
@@ -144,76 +230,47 @@ pub fn resolve(flight: &Flight, situation: Situation) -> Resolution {
             outcome: PitchOutcome::Foul,
             fielder: Some(position),
             intercept: Some(intercept),
+            race: Race::default(),
         };
     }
 
-    let ready_at = intercept.time + field::FIELDING_DELAY;
-    let throw_to_first = ready_at + intercept.point.distance(field::FIRST) / field::THROW_SPEED;
-    let in_infield = intercept.point.length() < INFIELD_RADIUS;
-
-    // The throw beat the batter. An outfielder never will, so this branch is
-    // effectively the infield without needing to say so.
-    if throw_to_first <= field::RUN_TO_FIRST {
-        let turns_two = situation.runner_on_first
-            && situation.outs < 2
-            && in_infield
-            && throw_to_first <= field::RUN_TO_FIRST - DOUBLE_PLAY_MARGIN;
-
-        let result = if turns_two {
-            PlayResult::DoublePlay
-        } else {
-            PlayResult::Groundout
-        };
-        return Resolution {
-            outcome: PitchOutcome::InPlay(result),
-            fielder: Some(position),
-            intercept: Some(intercept),
-        };
-    }
-
-    // The batter beat the throw to first. If there was a runner forced at second,
-    // the defence may still have taken the sure out there instead.
-    if situation.runner_on_first && situation.outs < 2 && in_infield {
-        let throw_to_second = ready_at + intercept.point.distance(field::SECOND) / field::THROW_SPEED;
-        if throw_to_second <= field::RUN_PER_BASE {
-            return Resolution {
-                outcome: PitchOutcome::InPlay(PlayResult::FieldersChoice),
-                fielder: Some(position),
-                intercept: Some(intercept),
-            };
-        }
-    }
-
-    let result = match bases_taken(intercept.point, ready_at) {
-        0 | 1 => PlayResult::Single,
-        2 => PlayResult::Double,
-        _ => PlayResult::Triple,
-    };
-
+    let race = baserunning::race(intercept, false, in_infield, situation);
     Resolution {
-        outcome: PitchOutcome::InPlay(result),
+        outcome: PitchOutcome::InPlay(scored(&race, situation, in_infield, None)),
         fielder: Some(position),
         intercept: Some(intercept),
+        race,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{super::ball, *};
+    use bevy::prelude::*;
+
+    use super::{
+        super::{ball, park::Park, weather::Weather},
+        *,
+    };
 
     fn deg(d: f32) -> f32 {
         d.to_radians()
     }
 
     fn hit(mph: f32, launch: f32, spray: f32) -> Flight {
-        ball::simulate(mph * ball::MPH_TO_FPS, deg(launch), deg(spray))
+        ball::simulate(
+            &Park::default(),
+            Weather::STILL,
+            mph * ball::MPH_TO_FPS,
+            deg(launch),
+            deg(spray),
+        )
     }
 
     fn play(mph: f32, launch: f32, spray: f32) -> Resolution {
         resolve(&hit(mph, launch, spray), Situation::default())
     }
 
-    fn result_of(resolution: Resolution) -> PlayResult {
+    fn result_of(resolution: &Resolution) -> PlayResult {
         match resolution.outcome {
             PitchOutcome::InPlay(result) => result,
             other => panic!("expected a ball in play, got {other:?}"),
@@ -232,11 +289,11 @@ mod tests {
         let resolution = play(88.0, 35.0, 0.0);
         assert!(
             matches!(
-                result_of(resolution),
+                result_of(&resolution),
                 PlayResult::Flyout | PlayResult::Lineout | PlayResult::Popout
             ),
             "expected an out, got {:?}",
-            result_of(resolution)
+            result_of(&resolution)
         );
         assert!(resolution.intercept.expect("someone caught it").in_air());
     }
@@ -244,13 +301,13 @@ mod tests {
     #[test]
     fn a_towering_infield_pop_up_is_a_popout() {
         let resolution = play(66.0, 74.0, 0.0);
-        assert_eq!(result_of(resolution), PlayResult::Popout);
+        assert_eq!(result_of(&resolution), PlayResult::Popout);
     }
 
     #[test]
     fn a_soft_grounder_to_the_infield_is_an_out_at_first() {
         let resolution = play(72.0, 2.0, deg(6.0).to_degrees());
-        assert_eq!(result_of(resolution), PlayResult::Groundout);
+        assert_eq!(result_of(&resolution), PlayResult::Groundout);
         let intercept = resolution.intercept.expect("fielded on the ground");
         assert!(!intercept.in_air());
     }
@@ -282,11 +339,11 @@ mod tests {
         let resolution = play(104.0, 14.0, -20.0);
         assert!(
             matches!(
-                result_of(resolution),
+                result_of(&resolution),
                 PlayResult::Single | PlayResult::Double | PlayResult::Triple
             ),
             "expected a hit, got {:?}",
-            result_of(resolution)
+            result_of(&resolution)
         );
     }
 
@@ -297,7 +354,7 @@ mod tests {
         let deep = play(102.0, 28.0, 0.0);
         let shallow = play(74.0, 16.0, 0.0);
 
-        let deep_bases = match result_of(deep) {
+        let deep_bases = match result_of(&deep) {
             PlayResult::Single => 1,
             PlayResult::Double => 2,
             PlayResult::Triple => 3,
@@ -305,7 +362,7 @@ mod tests {
         };
         // The shallow one may well be caught or fielded for an out; all that
         // matters is that it is never worth more than the ball off the wall.
-        let shallow_bases = match result_of(shallow) {
+        let shallow_bases = match result_of(&shallow) {
             PlayResult::Single => 1,
             PlayResult::Double => 2,
             PlayResult::Triple => 3,
@@ -325,15 +382,15 @@ mod tests {
         let forced = resolve(
             &flight,
             Situation {
-                runner_on_first: true,
+                runners: [Some(0.5), None, None],
                 outs: 0,
                 ..Situation::default()
             },
         );
 
-        assert_eq!(result_of(empty), PlayResult::Groundout, "nobody to force");
+        assert_eq!(result_of(&empty), PlayResult::Groundout, "nobody to force");
         assert_eq!(
-            result_of(forced),
+            result_of(&forced),
             PlayResult::DoublePlay,
             "with a runner on first the same ball is two"
         );
@@ -345,13 +402,13 @@ mod tests {
         let resolution = resolve(
             &flight,
             Situation {
-                runner_on_first: true,
+                runners: [Some(0.5), None, None],
                 outs: 2,
                 ..Situation::default()
             },
         );
         assert_eq!(
-            result_of(resolution),
+            result_of(&resolution),
             PlayResult::Groundout,
             "the third out ends it; there is no second out to get"
         );
@@ -363,38 +420,77 @@ mod tests {
         let sac = resolve(
             &flight,
             Situation {
-                runner_on_third: true,
+                runners: [None, None, Some(0.5)],
                 outs: 1,
                 ..Situation::default()
             },
         );
-        assert_eq!(result_of(sac), PlayResult::SacrificeFly);
+        assert_eq!(result_of(&sac), PlayResult::SacrificeFly);
 
         // With two out there is nothing to sacrifice: the inning is over.
         let two_out = resolve(
             &flight,
             Situation {
-                runner_on_third: true,
+                runners: [None, None, Some(0.5)],
                 outs: 2,
                 ..Situation::default()
             },
         );
-        assert_ne!(result_of(two_out), PlayResult::SacrificeFly);
+        assert_ne!(result_of(&two_out), PlayResult::SacrificeFly);
     }
 
     #[test]
     fn a_shallow_fly_is_not_deep_enough_to_sacrifice() {
         let flight = hit(68.0, 40.0, 0.0);
-        assert!(flight.landing.length() < SACRIFICE_DEPTH);
         let resolution = resolve(
             &flight,
             Situation {
-                runner_on_third: true,
+                runners: [None, None, Some(0.5)],
                 outs: 0,
                 ..Situation::default()
             },
         );
-        assert_ne!(result_of(resolution), PlayResult::SacrificeFly);
+        assert_ne!(result_of(&resolution), PlayResult::SacrificeFly);
+        assert!(
+            resolution
+                .race
+                .route(Base::Third)
+                .is_none_or(baserunning::Route::is_out),
+            "the runner on third holds, or is thrown out if he tags: {:?}",
+            resolution.race
+        );
+    }
+
+    fn runner_on_second() -> Situation {
+        Situation {
+            runners: [None, Some(0.5), None],
+            ..Situation::default()
+        }
+    }
+
+    #[test]
+    fn a_runner_on_second_scores_on_a_single_through_the_outfield() {
+        let resolution = resolve(&hit(90.0, 12.0, 20.0), runner_on_second());
+        assert_eq!(result_of(&resolution).scoring(), Scoring::Single);
+        let runner = resolution.race.route(Base::Second).expect("he goes on a ball through");
+        assert_eq!((runner.to, runner.is_out()), (Base::Home, false));
+    }
+
+    #[test]
+    fn a_runner_thrown_out_at_the_plate_does_not_cost_the_batter_his_hit() {
+        // Straight at the centre fielder, who is charging: the runner is sent
+        // anyway and the throw beats him.
+        let resolution = resolve(&hit(100.0, 8.0, 0.0), runner_on_second());
+        assert_eq!(
+            result_of(&resolution),
+            PlayResult::Advanced(
+                Scoring::Single,
+                RunnerAdvance::new()
+                    .with_batter(Destination::Safe(Base::First))
+                    .with_runner(Base::Second, Destination::Out)
+            )
+        );
+        assert!(result_of(&resolution).is_hit());
     }
 
     #[test]
@@ -423,26 +519,16 @@ mod tests {
         }
     }
 
-    /// Sweeps the whole space of pitches and swings a batter can actually produce
-    /// and checks the resulting mix of outcomes looks like baseball.
-    ///
-    /// This is the guard on a long chain of tuning — drag, exit velocity, fielder
-    /// speed, reaction time, defensive alignment. The first version of this model
-    /// put batting average on balls in play at .082, because the fielders reacted
-    /// instantly and ran at sprinter's pace, so almost everything hit in the air
-    /// was caught. Any of those constants drifting will show up here.
-    #[test]
-    fn the_mix_of_outcomes_resembles_real_baseball() {
+    /// Every contact an average batter can make: the whole space of pitch
+    /// locations, swing styles and timings, as exit velocity, launch and spray.
+    fn swept_contact() -> Vec<(f32, f32, f32)> {
         use super::super::{
             bat,
+            lineup::Hitter,
             pitch::{LivePitch, PitchKind, PitchPlan},
         };
 
-        let mut fair = 0;
-        let mut hits = 0;
-        let mut extra_base = 0;
-        let mut caught_in_air = 0;
-
+        let mut contact = Vec::new();
         for style in [bat::SwingStyle::Level, bat::SwingStyle::Normal, bat::SwingStyle::Lift] {
             for xi in -3..=3 {
                 for hi in 0..=6 {
@@ -452,44 +538,63 @@ mod tests {
                             kind: PitchKind::Fastball,
                             target,
                         });
-                        let bat::Contact::Struck {
+                        if let bat::Contact::Struck {
                             exit_velocity,
                             launch,
                             spray,
                             ..
                         } = bat::resolve(
+                            &Hitter::AVERAGE,
                             &pitch,
                             bat::Swing {
                                 timing: ti as f32 * 0.024,
                                 style,
                             },
-                        )
-                        else {
-                            continue;
-                        };
-
-                        let flight = ball::simulate(exit_velocity, launch, spray);
-                        let resolution = resolve(&flight, Situation::default());
-                        if resolution.outcome == PitchOutcome::Foul {
-                            continue;
-                        }
-                        fair += 1;
-
-                        if resolution.intercept.is_some_and(Intercept::in_air) {
-                            caught_in_air += 1;
-                        }
-                        match resolution.outcome {
-                            PitchOutcome::HomeRun | PitchOutcome::InPlay(PlayResult::Double | PlayResult::Triple) => {
-                                hits += 1;
-                                extra_base += 1;
-                            }
-                            PitchOutcome::InPlay(PlayResult::Single) => hits += 1,
-                            _ => {}
+                        ) {
+                            contact.push((exit_velocity, launch, spray));
                         }
                     }
                 }
             }
         }
+        contact
+    }
+
+    /// Sweeps the whole space of pitches and swings a batter can actually produce
+    /// and checks the resulting mix of outcomes looks like baseball.
+    ///
+    /// This is the guard on a long chain of tuning — drag, exit velocity, fielder
+    /// speed, reaction time, defensive alignment. The first version of this model
+    /// put batting average on balls in play at .082, because the fielders reacted
+    /// instantly and ran at sprinter's pace, so almost everything hit in the air
+    /// was caught. Any of those constants drifting will show up here.
+    #[test]
+    fn the_mix_of_outcomes_resembles_real_baseball() {
+        let mut fair = 0;
+        let mut hits = 0;
+        let mut extra_base = 0;
+        let mut caught_in_air = 0;
+
+        for (exit_velocity, launch, spray) in swept_contact() {
+            let flight = ball::simulate(&Park::default(), Weather::STILL, exit_velocity, launch, spray);
+            let resolution = resolve(&flight, Situation::default());
+            if resolution.outcome == PitchOutcome::Foul {
+                continue;
+            }
+            fair += 1;
+
+            if resolution.intercept.is_some_and(Intercept::in_air) {
+                caught_in_air += 1;
+            }
+            match resolution.outcome {
+                PitchOutcome::HomeRun | PitchOutcome::InPlay(PlayResult::Double | PlayResult::Triple) => {
+                    hits += 1;
+                    extra_base += 1;
+                }
+                PitchOutcome::InPlay(PlayResult::Single) => hits += 1,
+                _ => {}
+            }
+        }
 
         assert!(fair > 500, "the sweep should put plenty of balls in play, got {fair}");
 
@@ -514,6 +619,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn the_wind_blowing_out_at_a_small_park_changes_the_game() {
+        let wrigley = Park::preset("wrigley").expect("wrigley is a preset");
+        let wind = |toward_degrees: f32| Weather {
+            temperature: 85.0,
+            wind_speed: 15.0,
+            wind_toward: toward_degrees.to_radians(),
+            ..Weather::STILL
+        };
+        let home_runs = |weather: Weather| {
+            swept_contact()
+                .into_iter()
+                .filter(|&(exit_velocity, launch, spray)| {
+                    let flight = ball::simulate(&wrigley, weather, exit_velocity, launch, spray);
+                    resolve(&flight, Situation::default()).outcome == PitchOutcome::HomeRun
+                })
+                .count()
+        };
+
+        let still = home_runs(Weather::STILL);
+        let blowing_out = home_runs(wind(0.0));
+        let blowing_in = home_runs(wind(180.0));
+        assert!(
+            blowing_out >= still * 2 && blowing_out > still + 10,
+            "15 mph out only took the home runs from {still} to {blowing_out}"
+        );
+        assert!(
+            blowing_in * 2 <= still,
+            "15 mph in only took the home runs from {still} to {blowing_in}"
+        );
+    }
+
+    #[test]
+    fn a_fast_batter_beats_out_grounders_a_slow_one_is_thrown_out_on() {
+        let runner = |batter_speed: f32| Situation {
+            batter_speed,
+            ..Situation::default()
+        };
+        let (mut fast_hits, mut slow_hits) = (0, 0);
+        // Topped balls, beaten into the dirt all round the infield. They never
+        // get through, so whether the batter is safe is his legs against the
+        // throw and nothing else.
+        for spray in -20..=20 {
+            for mph in [60.0, 70.0, 80.0, 90.0] {
+                let flight = hit(mph, -2.0, spray as f32 * 2.0);
+                let safe = |situation| {
+                    matches!(
+                        resolve(&flight, situation).outcome,
+                        PitchOutcome::InPlay(PlayResult::Single | PlayResult::Double | PlayResult::Triple)
+                    )
+                };
+                let (fast, slow) = (safe(runner(0.95)), safe(runner(0.05)));
+                assert!(
+                    fast || !slow,
+                    "{mph} mph at {}°: safe when slow but out when fast",
+                    spray * 2
+                );
+                fast_hits += u32::from(fast);
+                slow_hits += u32::from(slow);
+            }
+        }
+        assert!(fast_hits > slow_hits, "speed made no difference: {fast_hits} hits each");
+    }
+
     #[test]
     fn every_batted_ball_resolves_to_something() {
         // No combination of contact should leave the game with nothing to apply,
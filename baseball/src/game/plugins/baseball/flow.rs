@@ -5,18 +5,31 @@
 //! in [`apply_outcome`], which is the single place a [`PitchOutcome`] is handed
 //! over — everything before that is presentation and physics.
 
-use baseball_game_rules::{PitchOutcome, PlayResult};
+use baseball_game_rules::{PitchOutcome, PlayResult, PlayerPosition};
 use bevy::prelude::*;
 
 use super::{
-    Banner, BatterIntent, Diamond, Fielder, Phase, PhaseTimer, RandomSource,
+    Banner, BatterIntent, Diamond, Fielder, Phase, PhaseTimer, RandomSource, Seats,
     ball::{self, LiveBall},
-    bat, effects, fielding, pitch,
+    baserunning::{self, Race},
+    bat,
+    controls::{self, Controls},
+    effects, field, fielding,
+    league::Matchup,
+    lineup::{Hitter, Lineups},
+    park::Ballpark,
+    pitch,
+    replay::{PitchRecord, Recording},
+    staff::{self, Staffs},
+    weather::Weather,
 };
 
 /// How long the AI takes to choose a pitch and wind up.
 const AI_WINDUP: f32 = 0.85;
 
+/// How long the field view lingers on the last runner reaching his bag.
+const RACE_DWELL: f32 = 0.4;
+
 /// How long a result stays on screen before the next batter.
 const RESOLVE_DWELL: f32 = 1.5;
 const BIG_RESULT_DWELL: f32 = 2.6;
@@ -24,6 +37,36 @@ const BIG_RESULT_DWELL: f32 = 2.6;
 /// How long the between-innings card is shown.
 pub const INNING_DWELL: f32 = 2.2;
 
+/// How long the loop lingers where nothing is being simulated: the AI's windup
+/// and a result on screen. A person watching needs them; a batch run does not.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct Pacing {
+    pub ai_windup: f32,
+    pub resolve: f32,
+    pub big_result: f32,
+}
+
+impl Default for Pacing {
+    fn default() -> Self {
+        Pacing {
+            ai_windup: AI_WINDUP,
+            resolve: RESOLVE_DWELL,
+            big_result: BIG_RESULT_DWELL,
+        }
+    }
+}
+
+impl Pacing {
+    /// Straight on to the next pitch.
+    pub fn instant() -> Self {
+        Pacing {
+            ai_windup: 0.0,
+            resolve: 0.0,
+            big_result: 0.0,
+        }
+    }
+}
+
 /// Bookkeeping for the pitch currently being played out.
 #[derive(Debug, Default, Resource)]
 pub struct PitchLoop {
@@ -37,14 +80,20 @@ pub struct PitchLoop {
     pub ai_swing: Option<bat::Swing>,
     /// Seconds the AI still needs before it throws.
     pub ai_windup: f32,
+    /// What the batter did with this pitch, and what came of it, kept for the
+    /// [`Recording`].
+    pub swing: Option<bat::Swing>,
+    pub flight: Option<ball::Flight>,
+    pub targets: Vec<(PlayerPosition, Vec2)>,
 }
 
 // ------------------------------------------------------------------ AI
 
-/// The AI's pitch selection. Mixes speeds and works off the plate often enough
-/// that the human has to be selective, which is what makes a walk reachable.
-fn ai_pitch_plan(rng: &mut RandomSource) -> pitch::PitchPlan {
-    let kind = rng.pick(&pitch::PITCH_KINDS);
+/// The AI's pitch selection from what its pitcher has. Mixes speeds and works off
+/// the plate often enough that the human has to be selective, which is what makes
+/// a walk reachable.
+fn ai_pitch_plan(rng: &mut RandomSource, repertoire: &[pitch::PitchKind]) -> pitch::PitchPlan {
+    let kind = rng.pick(repertoire);
     let target = if rng.chance(0.60) {
         Vec2::new(
             rng.range(-pitch::ZONE_HALF_WIDTH * 0.92, pitch::ZONE_HALF_WIDTH * 0.92),
@@ -58,29 +107,30 @@ fn ai_pitch_plan(rng: &mut RandomSource) -> pitch::PitchPlan {
 }
 
 /// Whether the AI batter offers at this pitch, and how well it times it.
-fn ai_batter_decision(rng: &mut RandomSource, live: &pitch::LivePitch) -> Option<bat::Swing> {
+fn ai_batter_decision(rng: &mut RandomSource, batter: &Hitter, live: &pitch::LivePitch) -> Option<bat::Swing> {
     let outside = pitch::distance_outside(live.crossing());
 
     // Happy to swing at strikes, increasingly unwilling the further off the plate
-    // the pitch finishes.
+    // the pitch finishes. A good eye lays off more of the bad ones.
     let willingness = if outside <= 0.0 {
         0.84
     } else {
-        (0.70 - outside * 0.58).max(0.05)
+        ((0.70 - outside * 0.58) * (1.4 - 0.8 * batter.eye)).max(0.05)
     };
     if !rng.chance(willingness) {
         return None;
     }
 
-    // Harder pitches to read produce sloppier timing.
+    // Harder pitches to read produce sloppier timing, and so do weaker hitters.
     let spread = match live.kind {
         pitch::PitchKind::Fastball => 0.072,
         pitch::PitchKind::Changeup => 0.092,
         pitch::PitchKind::Slider => 0.088,
         pitch::PitchKind::Curveball => 0.104,
-    };
+    } * (1.3 - 0.6 * batter.contact);
 
-    let style = if rng.chance(0.28) {
+    // Sluggers sell out for the fences more often.
+    let style = if rng.chance(0.28 + 0.3 * (batter.power - 0.5)) {
         bat::SwingStyle::Lift
     } else if rng.chance(0.25) {
         bat::SwingStyle::Level
@@ -99,101 +149,103 @@ fn ai_batter_decision(rng: &mut RandomSource, live: &pitch::LivePitch) -> Option
 pub fn begin_windup(
     mut loop_state: ResMut<PitchLoop>,
     mut live_ball: ResMut<LiveBall>,
+    mut race: ResMut<Race>,
     mut plan: ResMut<pitch::PitchPlan>,
     mut rng: ResMut<RandomSource>,
-    diamond: Res<Diamond>,
+    mut staffs: ResMut<Staffs>,
+    mut diamond: ResMut<Diamond>,
+    matchup: Res<Matchup>,
+    seats: Res<Seats>,
+    pacing: Res<Pacing>,
     mut fielders: Query<&mut Fielder>,
 ) {
     live_ball.clear();
+    *race = Race::default();
     loop_state.pending = None;
     loop_state.after_resolve = None;
     loop_state.ai_swing = None;
+    loop_state.swing = None;
+    loop_state.flight = None;
+    loop_state.targets.clear();
 
     // Everyone back to their spot for the next pitch.
     for mut fielder in fielders.iter_mut() {
         fielder.target = None;
     }
 
-    if diamond.human_is_batting() {
-        // The AI is pitching: choose now, throw after a beat.
-        *plan = ai_pitch_plan(&mut rng);
-        loop_state.ai_windup = AI_WINDUP;
-    } else {
+    let staff = staffs.fielding_mut(&diamond);
+    if seats.is_pitching(&diamond) {
         loop_state.ai_windup = 0.0;
+        // A new pitcher may not have what the last one was about to throw.
+        let pitcher = staff.current();
+        if !pitcher.throws(plan.kind) {
+            plan.kind = pitcher.ratings.repertoire[0];
+        }
+    } else {
+        if staff::ai_wants_a_change(staff, &diamond) && staff.call_bullpen().is_some() {
+            diamond.change_pitcher(*matchup, staff.on_mound);
+        }
+        // The AI is pitching: choose now, throw after a beat.
+        *plan = ai_pitch_plan(&mut rng, staff.current().ratings.repertoire);
+        loop_state.ai_windup = pacing.ai_windup;
     }
 }
 
 pub fn windup_input(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
-    diamond: Res<Diamond>,
+    controls: Res<Controls>,
+    mut diamond: ResMut<Diamond>,
+    matchup: Res<Matchup>,
+    seats: Res<Seats>,
+    mut staffs: ResMut<Staffs>,
     mut loop_state: ResMut<PitchLoop>,
     mut plan: ResMut<pitch::PitchPlan>,
     mut intent: ResMut<BatterIntent>,
     mut next: ResMut<NextState<Phase>>,
 ) {
-    if diamond.human_is_batting() {
+    if let Some(batter) = seats.batter(&diamond) {
         // Batting: pick how to swing while waiting for the pitch.
-        read_swing_style(&keys, &mut intent);
+        intent.style = controls.pad(batter).swing_style();
+    }
 
+    let Some(pitcher) = seats.pitcher(&diamond) else {
         loop_state.ai_windup -= time.delta_secs();
         if loop_state.ai_windup <= 0.0 {
             next.set(Phase::Pitch);
         }
         return;
-    }
+    };
 
     // Pitching: choose a pitch and a spot, then throw it.
-    let aim_step = 1.9 * time.delta_secs();
-    let mut nudge = Vec2::ZERO;
-    if keys.pressed(KeyCode::ArrowLeft) {
-        nudge.x -= aim_step;
-    }
-    if keys.pressed(KeyCode::ArrowRight) {
-        nudge.x += aim_step;
-    }
-    if keys.pressed(KeyCode::ArrowUp) {
-        nudge.y += aim_step;
-    }
-    if keys.pressed(KeyCode::ArrowDown) {
-        nudge.y -= aim_step;
-    }
+    let pad = controls.pad(pitcher);
+    let nudge = pad.stick * 1.9 * time.delta_secs();
     if nudge != Vec2::ZERO {
         plan.aim(nudge);
     }
 
-    for (key, kind) in [
-        (KeyCode::Digit1, pitch::PitchKind::Fastball),
-        (KeyCode::Digit2, pitch::PitchKind::Slider),
-        (KeyCode::Digit3, pitch::PitchKind::Curveball),
-        (KeyCode::Digit4, pitch::PitchKind::Changeup),
-    ] {
-        if keys.just_pressed(key) {
-            plan.kind = kind;
+    // Like the AI's manager, a person only makes a change between batters.
+    let can_change = staff::between_batters(&diamond);
+    let staff = staffs.fielding_mut(&diamond);
+    if pad.bullpen && can_change && staff.call_bullpen().is_some() {
+        diamond.change_pitcher(*matchup, staff.on_mound);
+        if !staff.current().throws(plan.kind) {
+            plan.kind = staff.current().ratings.repertoire[0];
         }
     }
-    if keys.just_pressed(KeyCode::KeyE) {
-        plan.cycle_kind(true);
+
+    let pitcher = staff.current();
+    if let Some(kind) = pad.pick.filter(|&kind| pitcher.throws(kind)) {
+        plan.kind = kind;
     }
-    if keys.just_pressed(KeyCode::KeyQ) {
-        plan.cycle_kind(false);
+    if let Some(forward) = pad.cycle {
+        plan.cycle_kind(forward, pitcher.ratings.repertoire);
     }
 
-    if keys.just_pressed(KeyCode::Space) {
+    if pad.act {
         next.set(Phase::Pitch);
     }
 }
 
-fn read_swing_style(keys: &ButtonInput<KeyCode>, intent: &mut BatterIntent) {
-    if keys.pressed(KeyCode::ArrowUp) {
-        intent.style = bat::SwingStyle::Lift;
-    } else if keys.pressed(KeyCode::ArrowDown) {
-        intent.style = bat::SwingStyle::Level;
-    } else {
-        intent.style = bat::SwingStyle::Normal;
-    }
-}
-
 // ------------------------------------------------------------------ the pitch
 
 pub fn release_pitch(
@@ -201,41 +253,54 @@ pub fn release_pitch(
     mut live: ResMut<pitch::LivePitch>,
     mut loop_state: ResMut<PitchLoop>,
     mut rng: ResMut<RandomSource>,
+    mut staffs: ResMut<Staffs>,
+    lineups: Res<Lineups>,
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
 ) {
-    *live = pitch::LivePitch::thrown(*plan);
+    // The pitch that comes out is the pitcher's, not quite the one asked for, and
+    // every one of them counts against him.
+    let pitcher = staffs.fielding_mut(&diamond).current_mut();
+    *live = pitcher.deliver(*plan, &mut rng);
+    pitcher.pitches += 1;
 
     // The AI batter commits now and acts on it when the ball arrives, so its
     // decision cannot depend on anything it should not have seen.
-    loop_state.ai_swing = if diamond.human_is_batting() {
+    loop_state.ai_swing = if seats.is_batting(&diamond) {
         None
     } else {
-        ai_batter_decision(&mut rng, &live)
+        ai_batter_decision(&mut rng, lineups.batting(&diamond), &live)
     };
 }
 
 pub fn advance_pitch(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    controls: Res<Controls>,
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
+    lineups: Res<Lineups>,
+    ballpark: Res<Ballpark>,
+    weather: Res<Weather>,
     mut live: ResMut<pitch::LivePitch>,
     mut intent: ResMut<BatterIntent>,
     mut loop_state: ResMut<PitchLoop>,
     mut live_ball: ResMut<LiveBall>,
+    mut race: ResMut<Race>,
     mut splashes: MessageWriter<effects::Splash>,
     mut fielders: Query<&mut Fielder>,
     mut next: ResMut<NextState<Phase>>,
 ) {
     live.elapsed += time.delta_secs();
 
-    let human_batting = diamond.human_is_batting();
-    if human_batting {
-        read_swing_style(&keys, &mut intent);
+    let hitter = lineups.batting(&diamond);
+    let batter = seats.batter(&diamond).map(|seat| controls.pad(seat));
+    if let Some(pad) = batter {
+        intent.style = pad.swing_style();
     }
 
     // A swing, from whichever side is batting.
-    let swing = if human_batting {
-        (!live.swung && keys.just_pressed(KeyCode::Space)).then(|| bat::Swing {
+    let swing = if let Some(pad) = batter {
+        (!live.swung && pad.act).then(|| bat::Swing {
             timing: live.elapsed - live.flight,
             style: intent.style,
         })
@@ -247,7 +312,8 @@ pub fn advance_pitch(
 
     if let Some(swing) = swing {
         live.swung = true;
-        match bat::resolve(&live, swing) {
+        loop_state.swing = Some(swing);
+        match bat::resolve(hitter, &live, swing) {
             bat::Contact::Whiff => {
                 loop_state.pending = Some(PitchOutcome::Strike);
                 splashes.write(effects::Splash::Whiff);
@@ -259,28 +325,28 @@ pub fn advance_pitch(
                 spray,
                 quality,
             } => {
-                let flight = ball::simulate(exit_velocity, launch, spray);
-                let resolution = fielding::resolve(&flight, diamond.situation());
-
-                // Send the credited fielder to the exact spot the outcome was
-                // computed from, and let two neighbours shade that way, so the
-                // defence looks like it is reacting to the ball it is reacting to.
-                if let Some(intercept) = resolution.intercept {
-                    for mut fielder in fielders.iter_mut() {
-                        fielder.target = if Some(fielder.position) == resolution.fielder {
-                            Some(intercept.point)
-                        } else {
-                            let toward = fielder.home.lerp(intercept.point, 0.30);
-                            (fielder.home.distance(intercept.point) < 150.0).then_some(toward)
-                        };
-                    }
+                let flight = ball::simulate(&ballpark, *weather, exit_velocity, launch, spray);
+                let resolution = fielding::resolve(&flight, diamond.situation(&lineups));
+
+                let targets = fielder_targets(&resolution);
+                for mut fielder in fielders.iter_mut() {
+                    fielder.target = targets
+                        .iter()
+                        .find(|&&(position, _)| position == fielder.position)
+                        .map(|&(_, target)| target);
                 }
 
-                loop_state.done_at = match resolution.intercept {
+                // The play is over once the ball is dead and every runner is safe
+                // or out.
+                let settled = match resolution.intercept {
                     Some(intercept) => intercept.time + 0.55,
                     None => flight.time_to_land + 0.9,
                 };
+                loop_state.done_at = settled.max(resolution.race.over_at() + RACE_DWELL);
                 loop_state.pending = Some(resolution.outcome);
+                loop_state.flight = Some(flight);
+                loop_state.targets = targets;
+                *race = resolution.race;
                 live_ball.hit(exit_velocity, launch, spray);
                 splashes.write(effects::Splash::Contact { quality });
                 next.set(Phase::BallInPlay);
@@ -292,7 +358,7 @@ pub fn advance_pitch(
     // Nobody offered. The umpire calls it once the ball is past.
     if !live.swung && live.past_catcher() {
         let crossing = live.crossing();
-        loop_state.pending = Some(if pitch::hits_batter(crossing) {
+        loop_state.pending = Some(if pitch::hits_batter(crossing, hitter.bats.side()) {
             PitchOutcome::HitByPitch
         } else if pitch::in_zone(crossing) {
             PitchOutcome::Strike
@@ -303,6 +369,31 @@ pub fn advance_pitch(
     }
 }
 
+/// Where each fielder who moves on a batted ball is sent. The credited fielder
+/// goes to the exact spot the outcome was computed from and his near neighbours
+/// shade that way, so the defence looks like it is reacting to the ball it is
+/// reacting to; whoever has to take a throw goes to his bag for it.
+pub fn fielder_targets(resolution: &fielding::Resolution) -> Vec<(PlayerPosition, Vec2)> {
+    let mut targets = Vec::new();
+    if let Some(intercept) = resolution.intercept {
+        for &(position, home) in &field::FIELDER_HOMES {
+            if Some(position) == resolution.fielder {
+                targets.push((position, intercept.point));
+            } else if home.distance(intercept.point) < 150.0 {
+                targets.push((position, home.lerp(intercept.point, 0.30)));
+            }
+        }
+    }
+    if let Some(by) = resolution.fielder {
+        for throw in &resolution.race.throws {
+            let cover = baserunning::covering(throw.to, by);
+            targets.retain(|&(position, _)| position != cover);
+            targets.push((cover, field::base_position(throw.to)));
+        }
+    }
+    targets
+}
+
 // ------------------------------------------------------------------ ball in play
 
 pub fn begin_ball_in_play(mut live_ball: ResMut<LiveBall>) {
@@ -312,17 +403,26 @@ pub fn begin_ball_in_play(mut live_ball: ResMut<LiveBall>) {
 pub fn advance_ball_in_play(
     time: Res<Time>,
     mut live_ball: ResMut<LiveBall>,
+    race: Res<Race>,
+    ballpark: Res<Ballpark>,
+    weather: Res<Weather>,
     loop_state: Res<PitchLoop>,
     mut next: ResMut<NextState<Phase>>,
 ) {
     let dt = time.delta_secs();
     live_ball.elapsed += dt;
 
-    // Integrated with the same function the prediction used, so what the player
-    // watches is what the outcome was read from.
-    let (pos, vel) = ball::step(live_ball.pos, live_ball.vel, dt);
-    live_ball.pos = pos;
-    live_ball.vel = vel;
+    if let Some(pos) = race.ball_at(live_ball.elapsed) {
+        // A fielder has it: it goes where the race says he threw it.
+        live_ball.pos = pos;
+        live_ball.vel = Vec3::ZERO;
+    } else {
+        // Integrated with the same function the prediction used, so what the
+        // player watches is what the outcome was read from.
+        let (pos, vel) = ball::step(ball::Air::at(&ballpark, *weather), live_ball.pos, live_ball.vel, dt);
+        live_ball.pos = pos;
+        live_ball.vel = vel;
+    }
 
     if live_ball.elapsed >= loop_state.done_at {
         next.set(Phase::Resolve);
@@ -337,6 +437,11 @@ pub fn apply_outcome(
     mut loop_state: ResMut<PitchLoop>,
     mut banner: ResMut<Banner>,
     mut timer: ResMut<PhaseTimer>,
+    pacing: Res<Pacing>,
+    live: Res<pitch::LivePitch>,
+    race: Res<Race>,
+    lineups: Res<Lineups>,
+    mut recording: ResMut<Recording>,
     mut live_ball: ResMut<LiveBall>,
     mut splashes: MessageWriter<effects::Splash>,
 ) {
@@ -349,6 +454,12 @@ pub fn apply_outcome(
         return;
     };
 
+    // Written down before the game moves on, while the bases and the batter are
+    // still the ones the pitch was thrown to.
+    if let Some(record) = record_pitch(&diamond, &lineups, &mut loop_state, *live, &race, outcome) {
+        recording.pitches.push(record);
+    }
+
     let before = diamond
         .game()
         .map(|game| (game.current_half_inning().half(), game.current_inning().as_number()));
@@ -370,11 +481,11 @@ pub fn apply_outcome(
         splashes.write(effects::Splash::HomeRun);
     }
 
-    let big = matches!(
-        outcome,
-        PitchOutcome::HomeRun | PitchOutcome::InPlay(PlayResult::Triple | PlayResult::DoublePlay)
-    );
-    timer.set(if big { BIG_RESULT_DWELL } else { RESOLVE_DWELL });
+    timer.set(if is_big_play(outcome) {
+        pacing.big_result
+    } else {
+        pacing.resolve
+    });
 
     loop_state.after_resolve = Some(if diamond.outcome.is_complete() {
         Phase::GameOver
@@ -385,7 +496,46 @@ pub fn apply_outcome(
     });
 }
 
-fn describe(outcome: PitchOutcome) -> (String, bool) {
+/// The plays worth dwelling on, and worth seeing again.
+pub fn is_big_play(outcome: PitchOutcome) -> bool {
+    matches!(
+        outcome,
+        PitchOutcome::HomeRun
+            | PitchOutcome::InPlay(PlayResult::Triple | PlayResult::DoublePlay | PlayResult::TriplePlay)
+    )
+}
+
+/// Everything about the pitch just played that a replay needs, taking the swing
+/// and the flight out of the loop's bookkeeping. `None` once the game is over.
+fn record_pitch(
+    diamond: &Diamond,
+    lineups: &Lineups,
+    loop_state: &mut PitchLoop,
+    pitch: pitch::LivePitch,
+    race: &Race,
+    outcome: PitchOutcome,
+) -> Option<PitchRecord> {
+    let game = diamond.game()?;
+    let batter = lineups.batting(diamond);
+    let flight = loop_state.flight.take();
+    Some(PitchRecord {
+        inning: game.current_inning().as_number(),
+        half: game.current_half_inning().half(),
+        batter: batter.name,
+        bats: batter.bats,
+        bases: diamond.situation(lineups).runners.map(|runner| runner.is_some()),
+        pitch,
+        swing: loop_state.swing.take(),
+        in_play_for: if flight.is_some() { loop_state.done_at } else { 0.0 },
+        flight,
+        targets: std::mem::take(&mut loop_state.targets),
+        race: race.clone(),
+        outcome,
+    })
+}
+
+/// The headline for an outcome, and whether it went the batter's way.
+pub fn describe(outcome: PitchOutcome) -> (String, bool) {
     match outcome {
         PitchOutcome::HomeRun => ("HOME RUN!".to_string(), true),
         PitchOutcome::Ball => ("BALL".to_string(), true),
@@ -393,6 +543,7 @@ fn describe(outcome: PitchOutcome) -> (String, bool) {
         PitchOutcome::Foul => ("FOUL BALL".to_string(), false),
         PitchOutcome::HitByPitch => ("HIT BY PITCH".to_string(), true),
         PitchOutcome::InPlay(play) => (play.label().to_string(), play.is_hit()),
+        PitchOutcome::Baserunning(event) => (event.label().to_string(), !event.is_out()),
     }
 }
 
@@ -422,8 +573,21 @@ pub fn advance_resolve(
     }
 }
 
-pub fn advance_inning_break(time: Res<Time>, mut timer: ResMut<PhaseTimer>, mut next: ResMut<NextState<Phase>>) {
-    if timer.0.tick(time.delta()).just_finished() {
+/// With two people playing, the card waits for one of them to say they have
+/// swapped round; otherwise it goes when its dwell is up.
+pub fn advance_inning_break(
+    time: Res<Time>,
+    seats: Res<Seats>,
+    controls: Res<Controls>,
+    mut timer: ResMut<PhaseTimer>,
+    mut next: ResMut<NextState<Phase>>,
+) {
+    let ready = if seats.is_two_player() {
+        controls.anyone_acted()
+    } else {
+        timer.0.tick(time.delta()).just_finished()
+    };
+    if ready {
         next.set(Phase::Windup);
     }
 }
@@ -432,28 +596,32 @@ pub fn advance_inning_break(time: Res<Time>, mut timer: ResMut<PhaseTimer>, mut
 
 pub fn toggle_pause(
     keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
     mut paused: ResMut<super::Paused>,
     mut next: ResMut<NextState<Phase>>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
+    if keys.just_pressed(KeyCode::Escape) || controls::any_pad_pressed(&gamepads, GamepadButton::Start) {
         paused.0 = !paused.0;
     }
-    if paused.0 && keys.just_pressed(KeyCode::KeyT) {
+    if paused.0 && (keys.just_pressed(KeyCode::KeyT) || controls::any_pad_pressed(&gamepads, GamepadButton::Select)) {
         paused.0 = false;
         next.set(Phase::Title);
     }
 }
 
-/// Where a fielder should be standing right now, used by the tests below to check
-/// the defence is being sent to the same place the outcome came from.
+/// Where the credited fielder is sent, used by the tests below to check the
+/// defence is being sent to the same place the outcome came from.
 #[cfg(test)]
 fn credited_target(resolution: &fielding::Resolution) -> Option<Vec2> {
-    resolution.intercept.map(|intercept| intercept.point)
+    fielder_targets(resolution)
+        .into_iter()
+        .find(|&(position, _)| Some(position) == resolution.fielder)
+        .map(|(_, target)| target)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{super::field, *};
+    use super::{super::park::Park, *};
 
     fn rng() -> RandomSource {
         RandomSource::default()
@@ -465,7 +633,7 @@ mod tests {
         let mut strikes = 0;
         let total = 400;
         for _ in 0..total {
-            let plan = ai_pitch_plan(&mut rng);
+            let plan = ai_pitch_plan(&mut rng, &pitch::PITCH_KINDS);
             if pitch::in_zone(plan.target) {
                 strikes += 1;
             }
@@ -483,16 +651,25 @@ mod tests {
         let mut rng = rng();
         let mut seen = std::collections::HashSet::new();
         for _ in 0..200 {
-            seen.insert(ai_pitch_plan(&mut rng).kind);
+            seen.insert(ai_pitch_plan(&mut rng, &pitch::PITCH_KINDS).kind);
         }
         assert_eq!(seen.len(), pitch::PITCH_KINDS.len(), "some pitch is never thrown");
     }
 
+    #[test]
+    fn the_ai_pitcher_only_throws_what_it_has() {
+        let mut rng = rng();
+        let repertoire = [pitch::PitchKind::Fastball, pitch::PitchKind::Slider];
+        for _ in 0..200 {
+            assert!(repertoire.contains(&ai_pitch_plan(&mut rng, &repertoire).kind));
+        }
+    }
+
     #[test]
     fn the_ai_pitcher_never_aims_somewhere_unreachable() {
         let mut rng = rng();
         for _ in 0..300 {
-            let target = ai_pitch_plan(&mut rng).target;
+            let target = ai_pitch_plan(&mut rng, &pitch::PITCH_KINDS).target;
             assert!(target.x.abs() <= pitch::AIM_LIMIT_X, "aimed at {target:?}");
             assert!(
                 (pitch::AIM_LIMIT_LOW..=pitch::AIM_LIMIT_HIGH).contains(&target.y),
@@ -509,7 +686,9 @@ mod tests {
                 kind: pitch::PitchKind::Fastball,
                 target,
             });
-            let swings = (0..400).filter(|_| ai_batter_decision(rng, &live).is_some()).count();
+            let swings = (0..400)
+                .filter(|_| ai_batter_decision(rng, &Hitter::AVERAGE, &live).is_some())
+                .count();
             swings as f32 / 400.0
         };
 
@@ -525,6 +704,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn a_batter_with_a_good_eye_chases_less() {
+        let mut rng = rng();
+        let live = pitch::LivePitch::thrown(pitch::PitchPlan {
+            kind: pitch::PitchKind::Slider,
+            target: Vec2::new(pitch::ZONE_HALF_WIDTH + 0.3, pitch::ZONE_BOTTOM),
+        });
+        let mut chases = |eye: f32| {
+            let batter = Hitter { eye, ..Hitter::AVERAGE };
+            (0..1000)
+                .filter(|_| ai_batter_decision(&mut rng, &batter, &live).is_some())
+                .count()
+        };
+        let patient = chases(0.9);
+        let free_swinger = chases(0.1);
+        assert!(
+            patient * 3 < free_swinger * 2,
+            "{patient} chases with a good eye, {free_swinger} without"
+        );
+    }
+
     #[test]
     fn the_ai_batter_times_a_curveball_worse_than_a_fastball() {
         let mut rng = rng();
@@ -535,7 +735,7 @@ mod tests {
             });
             let mut worst = 0.0_f32;
             for _ in 0..500 {
-                if let Some(swing) = ai_batter_decision(rng, &live) {
+                if let Some(swing) = ai_batter_decision(rng, &Hitter::AVERAGE, &live) {
                     worst = worst.max(swing.timing.abs());
                 }
             }
@@ -559,7 +759,7 @@ mod tests {
         });
         let mut styles = std::collections::HashSet::new();
         for _ in 0..300 {
-            if let Some(swing) = ai_batter_decision(&mut rng, &live) {
+            if let Some(swing) = ai_batter_decision(&mut rng, &Hitter::AVERAGE, &live) {
                 styles.insert(swing.style);
             }
         }
@@ -597,7 +797,13 @@ mod tests {
         // Guards the link between the simulation and the animation: the defender
         // who runs to the ball has to be the defender the result was computed for.
         for (mph, launch, spray) in [(88.0_f32, 35.0_f32, 0.0_f32), (95.0, 4.0, -18.0), (78.0, 22.0, 33.0)] {
-            let flight = ball::simulate(mph * ball::MPH_TO_FPS, f32::to_radians(launch), f32::to_radians(spray));
+            let flight = ball::simulate(
+                &Park::default(),
+                Weather::STILL,
+                mph * ball::MPH_TO_FPS,
+                f32::to_radians(launch),
+                f32::to_radians(spray),
+            );
             let resolution = fielding::resolve(&flight, fielding::Situation::default());
             if resolution.outcome == PitchOutcome::HomeRun {
                 continue;
@@ -0,0 +1,263 @@
+//! The pitch loop with nobody at the controls and nothing on screen.
+//!
+//! [`HeadlessBaseballPlugin`] is [`PitchLoopPlugin`] with the AI on both sides,
+//! no dwell between pitches, and a clock that moves by the same step on every
+//! update, so a run is reproducible from its seed. A finished game is kept in
+//! [`Played`] and the next one starts straight away. It needs nothing more than
+//! `MinimalPlugins`:
+//!
+//! ```ignore
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, HeadlessBaseballPlugin::default()));
+//! while app.world().resource::<Played>().games.len() < 100 {
+//!     app.update();
+//! }
+//! ```
+
+use std::{fmt, time::Duration};
+
+use baseball_game_rules::{GameOutcome, GameSummary};
+use bevy::{prelude::*, state::app::StatesPlugin, time::TimeUpdateStrategy};
+
+use super::{
+    Diamond, Phase, PitchLoopPlugin, RandomSource, Seats, flow, league::Matchup, lineup::Lineups, replay::Recording,
+    staff::Staffs,
+};
+
+/// One frame at 60 Hz.
+const STEP: Duration = Duration::from_nanos(16_666_667);
+
+/// Updates [`play_matchup`] gives a game before calling it stalled. A game is a
+/// few thousand at its step, so this only trips if the loop has stopped moving.
+pub const MAX_STEPS: usize = 50_000;
+
+pub struct HeadlessBaseballPlugin {
+    /// Seeds the one RNG both AIs draw from.
+    pub seed: u64,
+    /// How far the clock moves on every update. A batted ball's fate is settled
+    /// at contact, so a coarser step only makes the flight after it choppier.
+    pub step: Duration,
+    /// Which two clubs play, every game.
+    pub matchup: Matchup,
+}
+
+impl Default for HeadlessBaseballPlugin {
+    fn default() -> Self {
+        HeadlessBaseballPlugin {
+            seed: 0,
+            step: STEP,
+            matchup: Matchup::default(),
+        }
+    }
+}
+
+/// Every game the headless loop has finished, in the order they ended.
+#[derive(Debug, Default, Resource)]
+pub struct Played {
+    pub games: Vec<GameSummary>,
+}
+
+impl Plugin for HeadlessBaseballPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        app.add_plugins(PitchLoopPlugin)
+            .insert_resource(RandomSource::seeded(self.seed))
+            .insert_resource(Seats::WATCH)
+            .insert_resource(self.matchup)
+            .insert_resource(Diamond::between(self.matchup))
+            .insert_resource(Lineups::between(self.matchup))
+            .insert_resource(Staffs::between(self.matchup))
+            .insert_resource(flow::Pacing::instant())
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.step))
+            // Nobody is at the keys, but the pitch loop still looks at them for
+            // whichever side a person would be playing.
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Played>()
+            .add_systems(OnEnter(Phase::Title), play_ball)
+            .add_systems(OnEnter(Phase::InningBreak), play_ball)
+            .add_systems(OnEnter(Phase::GameOver), (bank_the_game, play_ball).chain());
+    }
+}
+
+/// Straight to the next pitch: there is no title screen to wait on and no
+/// inning card to read.
+fn play_ball(mut next: ResMut<NextState<Phase>>) {
+    next.set(Phase::Windup);
+}
+
+/// Keeps the finished game and clears the diamond, the pitching staffs and the
+/// recording for the next one between the same two clubs.
+fn bank_the_game(
+    matchup: Res<Matchup>,
+    mut diamond: ResMut<Diamond>,
+    mut staffs: ResMut<Staffs>,
+    mut recording: ResMut<Recording>,
+    mut played: ResMut<Played>,
+) {
+    let finished = std::mem::replace(&mut *diamond, Diamond::between(*matchup));
+    if let GameOutcome::Complete(summary) = finished.outcome {
+        played.games.push(summary);
+    }
+    *staffs = Staffs::between(*matchup);
+    *recording = Recording::default();
+}
+
+/// A headless game that was still going after [`MAX_STEPS`] updates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stalled;
+
+impl fmt::Display for Stalled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the game was still going after {MAX_STEPS} updates")
+    }
+}
+
+impl std::error::Error for Stalled {}
+
+/// Plays one game between two clubs with nobody watching, for the games in a
+/// season the player is not in. The step is coarse for speed: the result is
+/// settled at contact, and nobody sees the flight.
+pub fn play_matchup(matchup: Matchup, seed: u64) -> Result<GameSummary, Stalled> {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        HeadlessBaseballPlugin {
+            seed,
+            step: Duration::from_millis(50),
+            matchup,
+        },
+    ));
+    app.finish();
+    app.cleanup();
+
+    for _ in 0..MAX_STEPS {
+        app.update();
+        if let Some(game) = app.world_mut().resource_mut::<Played>().games.pop() {
+            return Ok(game);
+        }
+    }
+    Err(Stalled)
+}
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::PitchOutcome;
+
+    use super::{super::league, *};
+
+    fn app(seed: u64) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            HeadlessBaseballPlugin {
+                seed,
+                step: Duration::from_millis(50),
+                ..default()
+            },
+        ));
+        app.finish();
+        app.cleanup();
+        app
+    }
+
+    /// Updates until `games` games have finished, giving each [`MAX_STEPS`].
+    fn play(app: &mut App, games: usize) -> &[GameSummary] {
+        for _ in 0..games * MAX_STEPS {
+            if app.world().resource::<Played>().games.len() >= games {
+                break;
+            }
+            app.update();
+        }
+        let played = &app.world().resource::<Played>().games;
+        assert!(played.len() >= games, "the loop stalled after {} games", played.len());
+        played
+    }
+
+    #[test]
+    fn a_game_plays_to_a_final_with_nobody_at_the_controls() {
+        let mut app = app(1);
+        let game = &play(&mut app, 1)[0];
+        assert!(game.line_score().away_innings().len() >= 9, "{:?}", game.line_score());
+        assert_ne!(game.final_score().away(), game.final_score().home());
+    }
+
+    #[test]
+    fn a_matchup_credits_each_club_with_its_own_players() {
+        let matchup = Matchup { away: 2, home: 5 };
+        let game = play_matchup(matchup, 4).expect("finishes");
+        for (team, club) in [(game.box_score().away(), 2), (game.box_score().home(), 5)] {
+            for line in team.batting() {
+                assert_eq!(line.player.map(league::club_of), Some(club), "{line:?}");
+            }
+            for line in team.pitching() {
+                assert_eq!(line.player.map(league::club_of), Some(club), "{line:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn the_same_seed_plays_the_same_games() {
+        let (mut first, mut second) = (app(7), app(7));
+        let first: Vec<_> = play(&mut first, 2)
+            .iter()
+            .map(|game| game.line_score().clone())
+            .collect();
+        let second: Vec<_> = play(&mut second, 2)
+            .iter()
+            .map(|game| game.line_score().clone())
+            .collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn the_physics_put_up_a_box_score_that_looks_like_baseball() {
+        let mut app = app(3);
+        let games = play(&mut app, 6);
+        let lines = games
+            .iter()
+            .flat_map(|game| [game.box_score().away(), game.box_score().home()])
+            .flat_map(|team| team.batting());
+        let (mut at_bats, mut hits, mut strikeouts) = (0u32, 0u32, 0u32);
+        for line in lines {
+            at_bats += u32::from(line.at_bats);
+            hits += u32::from(line.hits);
+            strikeouts += u32::from(line.strikeouts);
+        }
+        let runs: u32 = games
+            .iter()
+            .map(|game| u32::from(game.final_score().away()) + u32::from(game.final_score().home()))
+            .sum();
+
+        let average = f64::from(hits) / f64::from(at_bats);
+        let runs_per_side = f64::from(runs) / 12.0;
+        assert!((0.150..0.400).contains(&average), "hit .{:03.0}", average * 1000.0);
+        assert!((0.5..12.0).contains(&runs_per_side), "{runs_per_side} runs a side");
+        assert!(strikeouts > 0, "nobody struck out in six games");
+    }
+
+    #[test]
+    fn every_pitch_is_recorded_with_a_flight_only_if_it_was_hit() {
+        let mut app = app(5);
+        for _ in 0..50_000 {
+            if app.world().resource::<Recording>().pitches.len() >= 80 {
+                break;
+            }
+            app.update();
+        }
+        let pitches = &app.world().resource::<Recording>().pitches;
+        assert!(pitches.len() >= 80, "only {} pitches recorded", pitches.len());
+        for record in pitches {
+            let struck = matches!(
+                record.outcome,
+                PitchOutcome::InPlay(_) | PitchOutcome::HomeRun | PitchOutcome::Foul
+            );
+            assert_eq!(record.flight.is_some(), struck, "{:?}", record.outcome);
+            if record.flight.is_some() {
+                assert!(record.swing.is_some(), "a ball was put in play without a swing");
+                assert!(record.in_play_for > 0.0);
+            }
+        }
+    }
+}
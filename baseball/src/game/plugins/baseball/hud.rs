@@ -3,12 +3,22 @@
 //! Laid out like a broadcast graphic: a strip across the bottom with both teams,
 //! the inning, count lamps, outs and a little base diamond. It is `bevy_ui` rather
 //! than world-space text so it stays put when the view cuts between the plate and
-//! the field.
+//! the field. The end of the strip says where the game is being played and
+//! what the weather is doing, because both change how far a fly ball goes.
 
 use baseball_game_rules::{Base, GameOutcome, InningHalf};
 use bevy::prelude::*;
 
-use super::{BatterIntent, Diamond, GameScoped, Phase, pitch, theme};
+use super::{
+    BatterIntent, Diamond, GameScoped, Phase, Seat, Seats, controls,
+    league::{CLUBS, Matchup},
+    lineup::Lineups,
+    park::Ballpark,
+    pitch,
+    staff::Staffs,
+    theme,
+    weather::Weather,
+};
 
 #[derive(Debug, Component)]
 pub struct ScoreBug;
@@ -20,9 +30,21 @@ pub struct TeamRuns(pub InningHalf);
 #[derive(Debug, Component)]
 pub struct BattingMarker(pub InningHalf);
 
+/// A team's name on the score bug, lit up if a person is playing it.
+#[derive(Debug, Component)]
+pub struct TeamName(pub InningHalf);
+
 #[derive(Debug, Component)]
 pub struct InningLabel;
 
+/// Who is on the mound and how many he has thrown.
+#[derive(Debug, Component)]
+pub struct PitcherLabel;
+
+/// Who is at the plate and which side he bats from.
+#[derive(Debug, Component)]
+pub struct BatterLabel;
+
 #[derive(Debug, Component)]
 pub struct CountLamp {
     pub kind: LampKind,
@@ -39,6 +61,14 @@ pub enum LampKind {
 #[derive(Debug, Component)]
 pub struct BasePip(pub Base);
 
+/// One line of the conditions at the end of the bug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub enum ConditionsLine {
+    Park,
+    Weather,
+    Wind,
+}
+
 #[derive(Debug, Component)]
 pub struct PitchPanel;
 
@@ -81,9 +111,9 @@ fn lit_colour(kind: LampKind) -> Color {
 }
 
 /// Builds the score bug. Spawned once per game.
-pub fn spawn_score_bug(commands: &mut Commands, fonts: &super::Fonts) {
-    let row = |half: InningHalf, name: &str| -> (String, InningHalf) { (name.to_string(), half) };
-    let rows = [row(InningHalf::Top, "AWAY"), row(InningHalf::Bottom, "HOME")];
+pub fn spawn_score_bug(commands: &mut Commands, fonts: &super::Fonts, matchup: Matchup) {
+    let row = |half: InningHalf| -> (String, InningHalf) { (CLUBS[matchup.batting(half)].short.to_string(), half) };
+    let rows = [row(InningHalf::Top), row(InningHalf::Bottom)];
 
     commands.spawn((
         Node {
@@ -136,7 +166,10 @@ pub fn spawn_score_bug(commands: &mut Commands, fonts: &super::Fonts) {
                                         width: Val::Px(52.0),
                                         ..default()
                                     },
-                                    children![label(name.clone(), fonts_medium.clone(), 15.0, theme::TEXT_DIM)],
+                                    children![(
+                                        label(name.clone(), fonts_medium.clone(), 15.0, theme::TEXT_DIM),
+                                        TeamName(half),
+                                    )],
                                 ),
                                 (
                                     Node {
@@ -151,14 +184,20 @@ pub fn spawn_score_bug(commands: &mut Commands, fonts: &super::Fonts) {
                     }
                 }))),
             ),
-            // Inning.
+            // Inning, and the pitcher and batter under it.
             (
                 Node {
-                    width: Val::Px(76.0),
-                    justify_content: JustifyContent::Center,
+                    width: Val::Px(112.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(2.0),
                     ..default()
                 },
-                children![(label("TOP 1", fonts.bold.clone(), 16.0, theme::BUG_ACCENT), InningLabel,)],
+                children![
+                    (label("TOP 1", fonts.bold.clone(), 16.0, theme::BUG_ACCENT), InningLabel),
+                    (label("", fonts.medium.clone(), 11.0, theme::TEXT_DIM), PitcherLabel),
+                    (label("", fonts.medium.clone(), 11.0, theme::TEXT_DIM), BatterLabel),
+                ],
             ),
             // Count and outs lamps.
             (
@@ -238,6 +277,25 @@ pub fn spawn_score_bug(commands: &mut Commands, fonts: &super::Fonts) {
                     base_pip(Base::First, 30.0, 15.0),
                 ],
             ),
+            // Where, and in what.
+            (
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                children![
+                    (label("", fonts.bold.clone(), 12.0, theme::TEXT), ConditionsLine::Park),
+                    (
+                        label("", fonts.medium.clone(), 11.0, theme::TEXT_DIM),
+                        ConditionsLine::Weather
+                    ),
+                    (
+                        label("", fonts.medium.clone(), 11.0, theme::BUG_ACCENT),
+                        ConditionsLine::Wind
+                    ),
+                ],
+            ),
         ],
     ));
 }
@@ -284,8 +342,30 @@ pub fn spawn_pitch_panel(commands: &mut Commands, fonts: &super::Fonts) {
 /// Refreshes every number and lamp on the bug.
 pub fn update_score_bug(
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
+    staffs: Res<Staffs>,
+    lineups: Res<Lineups>,
     mut runs: Query<(&TeamRuns, &mut Text)>,
+    mut names: Query<(&TeamName, &mut TextColor)>,
     mut inning: Query<&mut Text, (With<InningLabel>, Without<TeamRuns>)>,
+    mut pitcher: Query<
+        (&mut Text, &mut TextColor),
+        (
+            With<PitcherLabel>,
+            Without<TeamRuns>,
+            Without<InningLabel>,
+            Without<TeamName>,
+        ),
+    >,
+    mut batter: Query<
+        &mut Text,
+        (
+            With<BatterLabel>,
+            Without<TeamRuns>,
+            Without<InningLabel>,
+            Without<PitcherLabel>,
+        ),
+    >,
     mut markers: Query<(&BattingMarker, &mut BackgroundColor), Without<CountLamp>>,
     mut lamps: Query<(&CountLamp, &mut BackgroundColor), Without<BattingMarker>>,
     mut pips: Query<(&BasePip, &mut BackgroundColor), (Without<CountLamp>, Without<BattingMarker>)>,
@@ -326,6 +406,38 @@ pub fn update_score_bug(
         };
     }
 
+    if let Ok((mut text, mut colour)) = pitcher.single_mut() {
+        let on_mound = staffs.fielding(&diamond).current();
+        **text = if diamond.game().is_some() {
+            format!("{}  P {}", on_mound.ratings.name, on_mound.pitches)
+        } else {
+            String::new()
+        };
+        // Going red is the cue that it is time to think about the bullpen.
+        colour.0 = if on_mound.fatigue() > 0.5 {
+            theme::BANNER_BAD
+        } else {
+            theme::TEXT_DIM
+        };
+    }
+
+    if let Ok(mut text) = batter.single_mut() {
+        let at_plate = lineups.batting(&diamond);
+        **text = if diamond.game().is_some() {
+            format!("{} ({})", at_plate.name, at_plate.bats.letter())
+        } else {
+            String::new()
+        };
+    }
+
+    for (name, mut colour) in names.iter_mut() {
+        colour.0 = if seats.seat(name.0).is_some() {
+            theme::BUG_ACCENT
+        } else {
+            theme::TEXT_DIM
+        };
+    }
+
     let batting = diamond.batting_half();
     for (marker, mut colour) in markers.iter_mut() {
         colour.0 = if Some(marker.0) == batting {
@@ -374,9 +486,27 @@ pub fn update_score_bug(
     }
 }
 
+/// The park and the weather. Both are settled before the first pitch, but the
+/// park file can be edited mid-game.
+pub fn update_conditions(
+    ballpark: Res<Ballpark>,
+    weather: Res<Weather>,
+    mut lines: Query<(&ConditionsLine, &mut Text)>,
+) {
+    for (line, mut text) in &mut lines {
+        let words = match line {
+            ConditionsLine::Park => ballpark.name.to_uppercase(),
+            ConditionsLine::Weather => weather.report(),
+            ConditionsLine::Wind => weather.wind_report(),
+        };
+        text.set_if_neq(Text::new(words));
+    }
+}
+
 /// Tells whoever is at the controls what they are about to do.
 pub fn update_pitch_panel(
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
     phase: Res<State<Phase>>,
     plan: Res<pitch::PitchPlan>,
     intent: Res<BatterIntent>,
@@ -387,19 +517,29 @@ pub fn update_pitch_panel(
         return;
     };
 
-    **text = if diamond.human_is_batting() {
-        match phase.get() {
-            Phase::Windup | Phase::Pitch => format!("SWING: {}   [SPACE]", intent.style.label()),
-            _ => String::new(),
-        }
-    } else {
-        match phase.get() {
-            Phase::Windup => format!("{}   [SPACE TO PITCH]", plan.kind.label()),
-            Phase::Pitch => plan.kind.label().to_string(),
-            _ => String::new(),
+    // With two people playing, the prompt says whose it is and which key is theirs.
+    let prompt = |seat: Seat, action: String, verb: &str| {
+        let key = controls::layout(*seats, seat).act_name;
+        if seats.is_two_player() {
+            format!("P{} {action}   [{key}{verb}]", seat.number())
+        } else {
+            format!("{action}   [{key}{verb}]")
         }
     };
 
+    // The pitcher goes first, so with two people playing the windup prompt is
+    // theirs and the batter gets the panel once the ball is on its way. Both
+    // share the screen then, so the pitch being chosen is never shown.
+    let swing = |batter| prompt(batter, format!("SWING: {}", intent.style.label()), "");
+    **text = match (*phase.get(), seats.batter(&diamond), seats.pitcher(&diamond)) {
+        (Phase::Windup, Some(batter), None) | (Phase::Pitch, Some(batter), _) => swing(batter),
+        (Phase::Windup, _, Some(_)) if seats.is_two_player() => String::new(),
+        (Phase::Windup, _, Some(pitcher)) => prompt(pitcher, plan.kind.label().to_string(), " TO PITCH"),
+        (Phase::Pitch, None, Some(_)) => plan.kind.label().to_string(),
+        // Nobody at the controls, or nothing to do with them.
+        _ => String::new(),
+    };
+
     // An empty panel is an empty box floating over the field, so hide the frame
     // along with the text.
     if let Ok(mut visibility) = frame.single_mut() {
@@ -414,23 +554,47 @@ pub fn update_pitch_panel(
 /// Builds the HUD if it is not already there, so a restart is just a despawn.
 /// The UI has to be told which camera it belongs to, because with three cameras
 /// in play `bevy_ui` cannot pick one for itself.
-pub fn ensure_hud(mut commands: Commands, fonts: Res<super::Fonts>, existing: Query<(), With<ScoreBug>>) {
+pub fn ensure_hud(
+    mut commands: Commands,
+    fonts: Res<super::Fonts>,
+    matchup: Res<Matchup>,
+    existing: Query<(), With<ScoreBug>>,
+) {
     if !existing.is_empty() {
         return;
     }
-    spawn_score_bug(&mut commands, &fonts);
+    spawn_score_bug(&mut commands, &fonts, *matchup);
     spawn_pitch_panel(&mut commands, &fonts);
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{super::PLAYER_HALF, *};
+    use super::*;
+
+    #[test]
+    fn the_human_starts_out_as_the_home_team() {
+        // The title screen opens on this side, and the score bug lights the row
+        // that matches it.
+        assert_eq!(Seats::default(), Seats::solo(InningHalf::Bottom));
+    }
+
+    #[test]
+    fn a_spectator_is_neither_batting_nor_pitching() {
+        let diamond = Diamond::default();
+        assert!(!Seats::WATCH.is_batting(&diamond));
+        assert!(!Seats::WATCH.is_pitching(&diamond));
+
+        // The game opens in the top half, so the away side is up and home is on
+        // the mound.
+        assert!(Seats::solo(InningHalf::Top).is_batting(&diamond));
+        assert!(Seats::solo(InningHalf::Bottom).is_pitching(&diamond));
+    }
 
     #[test]
-    fn the_human_is_the_home_team() {
-        // The score bug marks a row as "yours"; if this ever disagreed with the
-        // half the player bats in, the wrong line would be highlighted.
-        assert_eq!(PLAYER_HALF, InningHalf::Bottom);
+    fn two_players_face_each_other_every_pitch() {
+        let diamond = Diamond::default();
+        assert_eq!(Seats::HOT_SEAT.batter(&diamond), Some(Seat::One));
+        assert_eq!(Seats::HOT_SEAT.pitcher(&diamond), Some(Seat::Two));
     }
 
     #[test]
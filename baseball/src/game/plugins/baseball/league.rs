@@ -0,0 +1,314 @@
+//! The clubs: who plays for whom, and the lineup card each hands the umpire.
+//!
+//! Every player gets a [`PlayerId`] from their club and their place on it, so the
+//! rules engine's box score can say who did what without knowing anything about
+//! ratings. Lineup cards always use a designated hitter, as [`RuleSet::default`]
+//! does: the nine in a club's order bat, and its five pitchers only pitch.
+//!
+//! The first two clubs are the exhibition's away and home sides. Every club has
+//! a home park, and the home club's is where the game is played.
+//!
+//! [`RuleSet::default`]: baseball_game_rules::RuleSet
+
+use baseball_game_rules::{
+    InningHalf, Lineup, LineupSlot, Player, PlayerId,
+    PlayerPosition::{
+        self, Catcher as C, CenterField as CF, DesignatedHitter as DH, FirstBase as B1, LeftField as LF,
+        RightField as RF, SecondBase as B2, Shortstop as SS, ThirdBase as B3,
+    },
+    Roster,
+};
+use bevy::prelude::*;
+
+use super::{
+    lineup::{self, Bats, Hitter, bat},
+    staff::{self, ALL, HARD, Ratings, SINK, SOFT, arm},
+};
+
+/// One team in the league.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Club {
+    pub name: &'static str,
+    /// Three letters, for the score bug and the standings.
+    pub short: &'static str,
+    /// The batting order, leadoff first.
+    pub hitters: [Hitter; 9],
+    /// Where each hitter in the order plays, in the same order.
+    pub positions: [PlayerPosition; 9],
+    /// The staff, starter first.
+    pub arms: [Ratings; 5],
+    /// The home park, by the name of its preset.
+    pub park: &'static str,
+}
+
+impl Club {
+    /// The card this club hands in before a game, as club `index` of [`CLUBS`].
+    pub fn card(&self, index: usize) -> Lineup {
+        let hitters = self
+            .hitters
+            .iter()
+            .enumerate()
+            .map(|(slot, hitter)| Player::new(hitter_id(index, slot), hitter.name));
+        let arms = self
+            .arms
+            .iter()
+            .enumerate()
+            .map(|(order, ratings)| Player::new(pitcher_id(index, order), ratings.name));
+        let roster = hitters.chain(arms).fold(Roster::new(), Roster::with_player);
+        let slots = std::array::from_fn(|slot| LineupSlot {
+            player: hitter_id(index, slot),
+            position: self.positions[slot],
+        });
+        Lineup::new(roster, slots, pitcher_id(index, 0)).expect("every club's card is checked by a test")
+    }
+}
+
+/// Which two clubs are playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct Matchup {
+    pub away: usize,
+    pub home: usize,
+}
+
+impl Default for Matchup {
+    fn default() -> Self {
+        Matchup { away: 0, home: 1 }
+    }
+}
+
+impl Matchup {
+    /// The club that bats in `half`.
+    pub fn batting(self, half: InningHalf) -> usize {
+        match half {
+            InningHalf::Top => self.away,
+            InningHalf::Bottom => self.home,
+        }
+    }
+
+    /// The club in the field in `half`.
+    pub fn fielding(self, half: InningHalf) -> usize {
+        match half {
+            InningHalf::Top => self.home,
+            InningHalf::Bottom => self.away,
+        }
+    }
+}
+
+/// Ids run in hundreds by club: the order is `1` to `9`, the staff from `11`.
+const PER_CLUB: u32 = 100;
+const FIRST_ARM: u32 = 11;
+
+pub fn hitter_id(club: usize, slot: usize) -> PlayerId {
+    PlayerId(club as u32 * PER_CLUB + 1 + slot as u32)
+}
+
+pub fn pitcher_id(club: usize, order: usize) -> PlayerId {
+    PlayerId(club as u32 * PER_CLUB + FIRST_ARM + order as u32)
+}
+
+/// The club a player belongs to.
+pub fn club_of(player: PlayerId) -> usize {
+    (player.0 / PER_CLUB) as usize
+}
+
+/// A player's name, or `None` for an id no club hands out.
+pub fn name(player: PlayerId) -> Option<&'static str> {
+    let club = CLUBS.get(club_of(player))?;
+    let number = (player.0 % PER_CLUB) as usize;
+    match number {
+        1..=9 => Some(club.hitters[number - 1].name),
+        _ => club
+            .arms
+            .get(number.checked_sub(FIRST_ARM as usize)?)
+            .map(|arm| arm.name),
+    }
+}
+
+pub const CLUBS: [Club; 6] = [
+    Club {
+        name: "RIVERMEN",
+        short: "RIV",
+        hitters: lineup::AWAY_LINEUP,
+        positions: [CF, B2, RF, B1, DH, B3, LF, C, SS],
+        arms: staff::AWAY_STAFF,
+        park: "wrigley",
+    },
+    Club {
+        name: "PILOTS",
+        short: "PIL",
+        hitters: lineup::HOME_LINEUP,
+        positions: [CF, B2, RF, B1, B3, DH, LF, SS, C],
+        arms: staff::HOME_STAFF,
+        park: "standard",
+    },
+    // All bat and no glove: three sluggers in a row and a staff that has to
+    // outslug the other side's.
+    Club {
+        name: "COMETS",
+        short: "COM",
+        hitters: [
+            bat("ESPINOZA", 0.60, 0.45, 0.50, 0.80, Bats::Right),
+            bat("LARKIN", 0.55, 0.55, 0.60, 0.50, Bats::Left),
+            bat("MWANGI", 0.50, 0.85, 0.45, 0.40, Bats::Right),
+            bat("STROUD", 0.40, 0.95, 0.35, 0.25, Bats::Left),
+            bat("VALENTE", 0.50, 0.80, 0.40, 0.35, Bats::Right),
+            bat("GALLAGHER", 0.50, 0.55, 0.45, 0.45, Bats::Switch),
+            bat("NOVAK", 0.45, 0.50, 0.40, 0.50, Bats::Right),
+            bat("QUINTERO", 0.40, 0.40, 0.35, 0.40, Bats::Right),
+            bat("BASS", 0.40, 0.35, 0.40, 0.55, Bats::Left),
+        ],
+        positions: [SS, CF, DH, B1, LF, RF, B3, C, B2],
+        arms: [
+            arm("HOLLOWAY", 94.0, 0.55, 0.95, 90, ALL),
+            arm("DRUMMOND", 97.0, 0.45, 0.90, 25, HARD),
+            arm("IBARRA", 92.0, 0.60, 1.05, 35, SINK),
+            arm("SKOV", 90.0, 0.62, 1.10, 30, SOFT),
+            arm("PEMBERTON", 91.0, 0.60, 0.95, 60, ALL),
+        ],
+        park: "coors",
+    },
+    // Slap, run and pitch: nobody hits it out, everybody gets on.
+    Club {
+        name: "FOXES",
+        short: "FOX",
+        hitters: [
+            bat("AMARO", 0.75, 0.20, 0.70, 0.95, Bats::Left),
+            bat("PELLETIER", 0.70, 0.30, 0.65, 0.80, Bats::Switch),
+            bat("OKONJO", 0.65, 0.50, 0.60, 0.65, Bats::Right),
+            bat("WINSLOW", 0.60, 0.55, 0.55, 0.55, Bats::Left),
+            bat("FUKUDA", 0.65, 0.40, 0.60, 0.70, Bats::Right),
+            bat("RENNER", 0.60, 0.35, 0.55, 0.60, Bats::Right),
+            bat("CASTILLO", 0.55, 0.30, 0.50, 0.75, Bats::Left),
+            bat("DOYLE", 0.50, 0.30, 0.45, 0.35, Bats::Right),
+            bat("SEGURA", 0.55, 0.20, 0.50, 0.85, Bats::Switch),
+        ],
+        positions: [CF, B2, RF, B1, B3, LF, DH, C, SS],
+        arms: [
+            arm("BRANDT", 92.0, 0.78, 1.15, 105, ALL),
+            arm("OSEI", 96.0, 0.55, 1.00, 25, HARD),
+            arm("VILLANUEVA", 89.0, 0.80, 1.30, 35, SOFT),
+            arm("LUNDGREN", 93.0, 0.65, 1.10, 30, SINK),
+            arm("CHATTERJEE", 90.0, 0.72, 1.05, 60, ALL),
+        ],
+        park: "fenway",
+    },
+    // Fireballers and a lineup that strikes out as often as it connects.
+    Club {
+        name: "MINERS",
+        short: "MIN",
+        hitters: [
+            bat("TRAN", 0.60, 0.40, 0.55, 0.75, Bats::Right),
+            bat("HOLT", 0.50, 0.65, 0.45, 0.50, Bats::Left),
+            bat("BAPTISTE", 0.45, 0.85, 0.40, 0.45, Bats::Right),
+            bat("KRUGER", 0.40, 0.80, 0.30, 0.30, Bats::Right),
+            bat("OROZCO", 0.50, 0.60, 0.45, 0.45, Bats::Left),
+            bat("ACHTERBERG", 0.45, 0.55, 0.40, 0.40, Bats::Right),
+            bat("MURPHY", 0.50, 0.45, 0.45, 0.55, Bats::Switch),
+            bat("SOLANO", 0.45, 0.35, 0.40, 0.60, Bats::Right),
+            bat("WEBB", 0.40, 0.30, 0.45, 0.35, Bats::Left),
+        ],
+        positions: [CF, SS, RF, DH, LF, B1, B3, B2, C],
+        arms: [
+            arm("RASMUSSEN", 98.0, 0.50, 0.95, 90, ALL),
+            arm("NAKAMURA", 100.0, 0.40, 0.90, 20, HARD),
+            arm("GUERRERO", 97.0, 0.45, 1.00, 30, HARD),
+            arm("FLETCHER", 95.0, 0.55, 1.05, 30, SINK),
+            arm("ODUYA", 93.0, 0.60, 1.00, 55, ALL),
+        ],
+        park: "yankee",
+    },
+    // No stars, no holes: the league average in nine bodies.
+    Club {
+        name: "GULLS",
+        short: "GUL",
+        hitters: [
+            bat("MARCHETTI", 0.60, 0.35, 0.60, 0.70, Bats::Left),
+            bat("ABARA", 0.60, 0.45, 0.55, 0.60, Bats::Right),
+            bat("SORENSEN", 0.55, 0.60, 0.55, 0.50, Bats::Left),
+            bat("DE LA CRUZ", 0.50, 0.70, 0.50, 0.40, Bats::Right),
+            bat("HUXLEY", 0.50, 0.55, 0.50, 0.45, Bats::Switch),
+            bat("YAMAMOTO", 0.55, 0.45, 0.50, 0.55, Bats::Right),
+            bat("OLUWOLE", 0.50, 0.45, 0.45, 0.60, Bats::Right),
+            bat("PRZYBYLSKI", 0.45, 0.40, 0.45, 0.35, Bats::Left),
+            bat("MCNALLY", 0.45, 0.30, 0.50, 0.65, Bats::Right),
+        ],
+        positions: [LF, B2, RF, B1, DH, CF, B3, C, SS],
+        arms: [
+            arm("EKSTROM", 94.0, 0.65, 1.00, 100, ALL),
+            arm("BALOGUN", 97.0, 0.50, 0.95, 25, HARD),
+            arm("FAIRWEATHER", 91.0, 0.70, 1.20, 35, SOFT),
+            arm("MORIARTY", 93.0, 0.60, 1.05, 30, SINK),
+            arm("ZIELINSKI", 91.0, 0.66, 1.00, 60, ALL),
+        ],
+        park: "oracle",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::{Game, GameOutcome, PitchOutcome, RuleSet};
+
+    use super::{super::pitch::PitchKind, *};
+
+    #[test]
+    fn every_club_hands_in_a_legal_card() {
+        for (index, club) in CLUBS.iter().enumerate() {
+            let card = club.card(index);
+            assert!(card.uses_designated_hitter(), "{} has its pitcher batting", club.name);
+            assert_eq!(card.pitcher(), pitcher_id(index, 0));
+        }
+    }
+
+    #[test]
+    fn every_id_names_the_player_it_was_given_to() {
+        for (index, club) in CLUBS.iter().enumerate() {
+            for (slot, hitter) in club.hitters.iter().enumerate() {
+                assert_eq!(name(hitter_id(index, slot)), Some(hitter.name));
+                assert_eq!(club_of(hitter_id(index, slot)), index);
+            }
+            for (order, ratings) in club.arms.iter().enumerate() {
+                assert_eq!(name(pitcher_id(index, order)), Some(ratings.name));
+            }
+        }
+        assert_eq!(name(PlayerId(10)), None, "between the order and the staff");
+        assert_eq!(name(PlayerId(99_999)), None);
+    }
+
+    #[test]
+    fn the_box_score_credits_players_by_name() {
+        let game =
+            Game::with_batting_orders(RuleSet::default(), CLUBS[2].card(2), CLUBS[3].card(3)).expect("two legal cards");
+        let outcome = GameOutcome::InProgress(game).advance(PitchOutcome::HomeRun);
+        let game = outcome.game_ref().expect("one pitch in");
+        let leadoff = game.box_score().away().batting()[0];
+        assert_eq!(leadoff.player.and_then(name), Some(CLUBS[2].hitters[0].name));
+        assert_eq!(leadoff.home_runs, 1);
+    }
+
+    #[test]
+    fn the_away_club_bats_first_and_the_home_club_takes_the_field() {
+        let matchup = Matchup { away: 2, home: 3 };
+        assert_eq!(matchup.batting(InningHalf::Top), 2);
+        assert_eq!(matchup.fielding(InningHalf::Top), 3);
+        assert_eq!(matchup.batting(InningHalf::Bottom), 3);
+    }
+
+    #[test]
+    fn every_club_has_a_full_staff_and_both_kinds_of_hitter() {
+        for club in &CLUBS {
+            assert!(
+                club.hitters.iter().any(|hitter| hitter.bats.stands_left()),
+                "{}",
+                club.name
+            );
+            assert!(
+                club.hitters.iter().any(|hitter| !hitter.bats.stands_left()),
+                "{}",
+                club.name
+            );
+            for ratings in &club.arms {
+                assert_eq!(ratings.repertoire[0], PitchKind::Fastball, "{}", ratings.name);
+            }
+        }
+    }
+}
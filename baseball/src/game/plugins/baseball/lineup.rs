@@ -0,0 +1,186 @@
+//! The bats: nine hitters a side, and what each of them is good at.
+//!
+//! A [`Hitter`] does not decide anything on his own. His ratings stretch or shrink
+//! the numbers the rest of the loop already uses: contact widens the timing
+//! window, power raises the best exit velocity he can produce, eye keeps the AI's
+//! bat on its shoulder when the pitch is off the plate, and speed shortens the run
+//! the fielders are racing. An average hitter, every rating `0.5`, is exactly the
+//! batter the physics were tuned against.
+//!
+//! Which side of the plate he stands on matters as much. A left-handed batter
+//! pulls the ball to right field, and a pitch that would hit a righty is one he
+//! can let go by.
+
+use baseball_game_rules::{BattingPosition, InningHalf};
+use bevy::prelude::*;
+
+use super::{
+    Diamond,
+    league::{CLUBS, Matchup},
+};
+
+/// Which side of the plate a batter hits from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bats {
+    Left,
+    Right,
+    /// Either, and always the one facing away from the pitcher's arm. Every
+    /// pitcher in the game throws right-handed, so a switch hitter bats left.
+    Switch,
+}
+
+impl Bats {
+    /// Whether he stands in the left-handed box.
+    pub fn stands_left(self) -> bool {
+        matches!(self, Bats::Left | Bats::Switch)
+    }
+
+    /// `1.0` for a right-handed stance and `-1.0` for a left-handed one: the sign
+    /// that turns "pull side" into a direction on the field.
+    pub fn side(self) -> f32 {
+        if self.stands_left() { -1.0 } else { 1.0 }
+    }
+
+    /// How the box score would write it.
+    pub fn letter(self) -> &'static str {
+        match self {
+            Bats::Left => "L",
+            Bats::Right => "R",
+            Bats::Switch => "S",
+        }
+    }
+}
+
+/// What a batter is. Every rating runs from `0.0` to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hitter {
+    pub name: &'static str,
+    /// How forgiving his timing is.
+    pub contact: f32,
+    /// How hard he hits the ball when he squares it up.
+    pub power: f32,
+    /// How rarely he chases a pitch out of the zone.
+    pub eye: f32,
+    /// How quickly he gets down the line.
+    pub speed: f32,
+    pub bats: Bats,
+}
+
+impl Hitter {
+    /// The batter the physics were tuned against.
+    pub const AVERAGE: Hitter = bat("", 0.5, 0.5, 0.5, 0.5, Bats::Right);
+}
+
+/// Both teams' batting orders, leadoff first.
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Lineups {
+    pub away: [Hitter; 9],
+    pub home: [Hitter; 9],
+}
+
+impl Default for Lineups {
+    fn default() -> Self {
+        Lineups::between(Matchup::default())
+    }
+}
+
+impl Lineups {
+    pub fn between(matchup: Matchup) -> Self {
+        Lineups {
+            away: CLUBS[matchup.away].hitters,
+            home: CLUBS[matchup.home].hitters,
+        }
+    }
+
+    /// The man at the plate: the away team's in the top half. Once the game is
+    /// over there is nobody, and the average hitter stands in.
+    pub fn batting(&self, diamond: &Diamond) -> &Hitter {
+        let Some(game) = diamond.game() else {
+            return &Hitter::AVERAGE;
+        };
+        let half = game.current_half_inning();
+        self.hitter(half.half(), half.current_batter())
+    }
+
+    /// Whoever bats `at` in the order for the team batting in `half`.
+    pub fn hitter(&self, half: InningHalf, at: BattingPosition) -> &Hitter {
+        let order = match half {
+            InningHalf::Top => &self.away,
+            InningHalf::Bottom => &self.home,
+        };
+        &order[usize::from(at.num() - 1)]
+    }
+}
+
+pub const fn bat(name: &'static str, contact: f32, power: f32, eye: f32, speed: f32, bats: Bats) -> Hitter {
+    Hitter {
+        name,
+        contact,
+        power,
+        eye,
+        speed,
+        bats,
+    }
+}
+
+/// A slap-hitting switch leadoff man, the usual mix through the middle, and the
+/// catcher and shortstop at the bottom for their gloves.
+pub const AWAY_LINEUP: [Hitter; 9] = [
+    bat("DELGADO", 0.70, 0.25, 0.65, 0.90, Bats::Switch),
+    bat("MCBRIDE", 0.65, 0.40, 0.60, 0.60, Bats::Left),
+    bat("OYELARAN", 0.60, 0.75, 0.55, 0.50, Bats::Right),
+    bat("HARGROVE", 0.45, 0.90, 0.45, 0.30, Bats::Left),
+    bat("SANTOS", 0.55, 0.65, 0.40, 0.45, Bats::Right),
+    bat("FINNEGAN", 0.50, 0.50, 0.50, 0.55, Bats::Right),
+    bat("PARK", 0.55, 0.35, 0.55, 0.60, Bats::Left),
+    bat("BRENNAN", 0.40, 0.40, 0.35, 0.20, Bats::Right),
+    bat("ITO", 0.45, 0.20, 0.45, 0.75, Bats::Right),
+];
+
+pub const HOME_LINEUP: [Hitter; 9] = [
+    bat("WHITAKER", 0.65, 0.30, 0.70, 0.85, Bats::Left),
+    bat("ROJAS", 0.70, 0.45, 0.55, 0.55, Bats::Right),
+    bat("ADEYEMI", 0.55, 0.80, 0.60, 0.45, Bats::Right),
+    bat("KLINE", 0.40, 0.95, 0.40, 0.25, Bats::Right),
+    bat("NAVARRO", 0.55, 0.60, 0.50, 0.50, Bats::Switch),
+    bat("DUBOIS", 0.50, 0.55, 0.45, 0.40, Bats::Left),
+    bat("HALVORSEN", 0.55, 0.40, 0.50, 0.55, Bats::Right),
+    bat("MBEKI", 0.45, 0.30, 0.40, 0.65, Bats::Right),
+    bat("CORCORAN", 0.40, 0.25, 0.50, 0.30, Bats::Left),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_leadoff_man_bats_first_for_the_away_team() {
+        let diamond = Diamond::default();
+        assert_eq!(Lineups::default().batting(&diamond).name, AWAY_LINEUP[0].name);
+    }
+
+    #[test]
+    fn a_switch_hitter_bats_left_against_a_right_hander() {
+        assert!(Bats::Switch.stands_left());
+        assert!(Bats::Left.stands_left());
+        assert!(!Bats::Right.stands_left());
+        assert!((Bats::Left.side() + Bats::Right.side()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn every_rating_is_a_fraction() {
+        for hitter in AWAY_LINEUP.iter().chain(&HOME_LINEUP) {
+            for rating in [hitter.contact, hitter.power, hitter.eye, hitter.speed] {
+                assert!((0.0..=1.0).contains(&rating), "{} is rated {rating}", hitter.name);
+            }
+        }
+    }
+
+    #[test]
+    fn both_sides_of_the_plate_are_covered() {
+        for order in [AWAY_LINEUP, HOME_LINEUP] {
+            assert!(order.iter().any(|hitter| hitter.bats.stands_left()));
+            assert!(order.iter().any(|hitter| !hitter.bats.stands_left()));
+        }
+    }
+}
@@ -1,8 +1,19 @@
 //! A playable nine innings.
 //!
-//! The player is the home team: they bat in the bottom of every inning and pitch
-//! in the top. One pitch is one trip round [`Phase`], and the game ends when the
-//! rules engine says it has.
+//! Sides are picked on the title screen — one person home or away, two people
+//! against each other, or nobody at all — and whoever has a team bats in its half
+//! of every inning and pitches in the other. One pitch is one trip round
+//! [`Phase`], and the game ends when the rules engine says it has.
+//!
+//! Every game is between two clubs of the league, in the home club's park. An
+//! exhibition is always the first two; a season plays a schedule against all of
+//! them, a game a day, with the standings and everyone's numbers saved to disk
+//! between days.
+//!
+//! [`PitchLoopPlugin`] is that loop on its own — the rules engine, the physics and
+//! the AI — and [`BaseballPlugin`] puts the ballpark, the HUD and the title screen on
+//! top of it. [`HeadlessBaseballPlugin`] runs the loop with the AI on both sides
+//! and nothing drawn.
 //!
 //! Two views share the world. The at-bat view looks in over the catcher's shoulder
 //! and is where pitching and hitting happen; the field view is a wide overhead shot
@@ -11,21 +22,34 @@
 //! coordinates and neither has to know the other exists.
 
 mod ball;
+mod baserunning;
 mod bat;
+mod controls;
 mod effects;
 mod field;
 mod fielding;
 mod flow;
+mod headless;
 mod hud;
+mod league;
+mod lineup;
+mod park;
 mod pitch;
+mod replay;
 mod scene;
 mod screens;
+mod season;
+mod staff;
 mod theme;
 mod view;
+mod weather;
+
+pub use headless::{HeadlessBaseballPlugin, Played};
 
-use baseball_game_rules::{Game, GameOutcome, InningHalf, PlayerPosition};
+use baseball_game_rules::{BattingPosition, Game, GameOutcome, InningHalf, PlayerPosition, RuleSet, Substitution};
 use bevy::prelude::*;
-use rand::RngExt;
+use league::Matchup;
+use rand::{RngExt, SeedableRng};
 
 /// Seeded RNG, so a session is reproducible from its seed.
 #[derive(Debug, Resource)]
@@ -38,6 +62,10 @@ impl Default for RandomSource {
 }
 
 impl RandomSource {
+    pub fn seeded(seed: u64) -> Self {
+        RandomSource(rand_chacha::ChaCha8Rng::seed_from_u64(seed))
+    }
+
     /// Uniform in `[min, max)`.
     pub fn range(&mut self, min: f32, max: f32) -> f32 {
         min + (max - min) * self.0.random_range(0.0..1.0f32)
@@ -55,15 +83,108 @@ impl RandomSource {
     }
 }
 
-/// The half the human plays on offence. Everything else follows from it: they
-/// pitch in the other half, and the score bug knows which line is theirs.
-pub const PLAYER_HALF: InningHalf = InningHalf::Bottom;
+/// One of the two people who can be at the controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Seat {
+    One,
+    Two,
+}
+
+impl Seat {
+    /// As printed on screen: player 1 or player 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Seat::One => 1,
+            Seat::Two => 2,
+        }
+    }
+}
+
+/// Who plays each team: a person in one of the seats, or the AI. Everything
+/// else follows from it — a team's seat bats in its half and pitches in the
+/// other, and the score bug knows which lines belong to people. Chosen on the
+/// title screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct Seats {
+    pub away: Option<Seat>,
+    pub home: Option<Seat>,
+}
+
+impl Default for Seats {
+    fn default() -> Self {
+        // The home team, so the title screen opens on the side with the last word.
+        Seats::solo(InningHalf::Bottom)
+    }
+}
+
+impl Seats {
+    /// The AI on both sides.
+    pub const WATCH: Seats = Seats { away: None, home: None };
+
+    /// Player 1 away, player 2 at home.
+    pub const HOT_SEAT: Seats = Seats {
+        away: Some(Seat::One),
+        home: Some(Seat::Two),
+    };
+
+    /// One person, batting in the given half.
+    pub const fn solo(half: InningHalf) -> Self {
+        match half {
+            InningHalf::Top => Seats {
+                away: Some(Seat::One),
+                home: None,
+            },
+            InningHalf::Bottom => Seats {
+                away: None,
+                home: Some(Seat::One),
+            },
+        }
+    }
+
+    /// Whether both teams are in people's hands.
+    pub fn is_two_player(self) -> bool {
+        self.away.is_some() && self.home.is_some()
+    }
+
+    /// Who plays the team that bats in the given half.
+    pub fn seat(self, half: InningHalf) -> Option<Seat> {
+        match half {
+            InningHalf::Top => self.away,
+            InningHalf::Bottom => self.home,
+        }
+    }
+
+    /// Who is at the plate right now, if it is a person.
+    pub fn batter(self, diamond: &Diamond) -> Option<Seat> {
+        diamond.batting_half().and_then(|half| self.seat(half))
+    }
+
+    /// Who is on the mound right now, if it is a person.
+    pub fn pitcher(self, diamond: &Diamond) -> Option<Seat> {
+        diamond.batting_half().and_then(|half| match half {
+            InningHalf::Top => self.home,
+            InningHalf::Bottom => self.away,
+        })
+    }
+
+    /// Whether a person is at the plate right now.
+    pub fn is_batting(self, diamond: &Diamond) -> bool {
+        self.batter(diamond).is_some()
+    }
+
+    /// Whether a person is on the mound right now.
+    pub fn is_pitching(self, diamond: &Diamond) -> bool {
+        self.pitcher(diamond).is_some()
+    }
+}
 
 /// One pitch, start to finish.
 #[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Phase {
     #[default]
     Title,
+    /// Between games of a season: the standings and the next game.
+    Season,
     /// Defence set, pitcher choosing what to throw.
     Windup,
     /// Ball on its way to the plate.
@@ -75,6 +196,8 @@ pub enum Phase {
     /// Card between half innings.
     InningBreak,
     GameOver,
+    /// Watching a recorded play again.
+    Replay,
 }
 
 impl Phase {
@@ -97,13 +220,46 @@ pub struct Diamond {
 
 impl Default for Diamond {
     fn default() -> Self {
-        Self {
-            outcome: GameOutcome::InProgress(Game::new()),
-        }
+        Diamond::between(Matchup::default())
     }
 }
 
 impl Diamond {
+    /// A new game between two clubs, with both lineup cards handed in, so the
+    /// box score knows every player by name.
+    pub fn between(matchup: Matchup) -> Self {
+        let away = league::CLUBS[matchup.away].card(matchup.away);
+        let home = league::CLUBS[matchup.home].card(matchup.home);
+        let game =
+            Game::with_batting_orders(RuleSet::default(), away, home).expect("every club's card is checked by a test");
+        Diamond {
+            outcome: GameOutcome::InProgress(game),
+        }
+    }
+
+    /// Tells the rules engine the fielding club has brought in pitcher `order` of
+    /// its staff, so the box score charges what follows to him.
+    pub fn change_pitcher(&mut self, matchup: Matchup, order: usize) {
+        let GameOutcome::InProgress(game) = &self.outcome else {
+            return;
+        };
+        let batting = game.current_half_inning().half();
+        let (club, own_half) = match batting {
+            InningHalf::Top => (matchup.home, InningHalf::Bottom),
+            InningHalf::Bottom => (matchup.away, InningHalf::Top),
+        };
+        let player = league::pitcher_id(club, order);
+        // The staff only brings in arms that are on the card and have not pitched,
+        // so the engine has no reason to refuse; if it somehow did, the box score
+        // would just keep the old name, which is no reason to stop the game.
+        if let Ok(next) = game
+            .clone()
+            .substitute(own_half, Substitution::PitchingChange { player })
+        {
+            self.outcome = GameOutcome::InProgress(next);
+        }
+    }
+
     pub fn game(&self) -> Option<&Game> {
         self.outcome.game_ref()
     }
@@ -113,21 +269,19 @@ impl Diamond {
         self.game().map(|game| game.current_half_inning().half())
     }
 
-    pub fn human_is_batting(&self) -> bool {
-        self.batting_half() == Some(PLAYER_HALF)
-    }
-
-    /// What the defence needs to know to price a batted ball.
-    pub fn situation(&self) -> fielding::Situation {
+    /// What the defence needs to know to price a batted ball, with everyone's
+    /// speed read from the lineups.
+    pub fn situation(&self, lineups: &lineup::Lineups) -> fielding::Situation {
         let Some(game) = self.game() else {
             return fielding::Situation::default();
         };
         let half = game.current_half_inning();
         let runners = half.baserunners();
+        let speed = |runner: Option<BattingPosition>| runner.map(|at| lineups.hitter(half.half(), at).speed);
         fielding::Situation {
-            runner_on_first: runners.first().is_some(),
-            runner_on_third: runners.third().is_some(),
+            runners: [speed(runners.first()), speed(runners.second()), speed(runners.third())],
             outs: half.outs().as_number(),
+            batter_speed: lineups.batting(self).speed,
         }
     }
 }
@@ -202,9 +356,10 @@ fn playing(phase: Res<State<Phase>>, paused: Res<Paused>) -> bool {
     phase.get().is_live() && !paused.0
 }
 
-/// Run condition: anything that should keep drawing, including while paused.
+/// Run condition: anything that should keep drawing, including while paused and
+/// while a replay is being watched.
 fn on_the_field(phase: Res<State<Phase>>) -> bool {
-    phase.get().is_live() || *phase.get() == Phase::InningBreak
+    phase.get().is_live() || matches!(phase.get(), Phase::InningBreak | Phase::Replay)
 }
 
 /// Run condition: a live game, paused or not, for the pause toggle itself.
@@ -219,27 +374,93 @@ fn despawn_all<T: Component>(mut commands: Commands, entities: Query<Entity, Wit
     }
 }
 
-pub struct BaseballPlugin;
+/// The game with nothing drawn: the rules engine, the phases of a pitch, the
+/// physics and the AI. Whoever adds it decides who plays which side and how the
+/// game gets from the title to the first pitch and from the last out to the next
+/// game.
+pub struct PitchLoopPlugin;
 
-impl Plugin for BaseballPlugin {
+impl Plugin for PitchLoopPlugin {
     fn build(&self, app: &mut App) {
-        let fonts = load_fonts(app);
-        app.insert_resource(fonts)
-            .init_resource::<RandomSource>()
+        app.init_resource::<RandomSource>()
+            .init_resource::<Matchup>()
             .init_resource::<Diamond>()
+            .init_resource::<Seats>()
+            .init_resource::<controls::Controls>()
             .init_resource::<Paused>()
             .init_resource::<BatterIntent>()
+            .init_resource::<staff::Staffs>()
+            .init_resource::<lineup::Lineups>()
             .init_resource::<Banner>()
             .init_resource::<PhaseTimer>()
             .init_resource::<pitch::PitchPlan>()
             .init_resource::<pitch::LivePitch>()
             .init_resource::<ball::LiveBall>()
-            .init_resource::<effects::ScreenShake>()
+            .init_resource::<baserunning::Race>()
             .init_resource::<flow::PitchLoop>()
+            .init_resource::<flow::Pacing>()
+            .init_resource::<replay::Recording>()
+            .init_resource::<park::Ballpark>()
+            .init_resource::<weather::Weather>()
             .init_state::<Phase>()
             .add_message::<effects::Splash>()
+            .add_systems(
+                OnEnter(Phase::Windup),
+                (weather::forecast_new_game, flow::begin_windup).chain(),
+            )
+            .add_systems(OnEnter(Phase::Pitch), flow::release_pitch)
+            .add_systems(OnEnter(Phase::BallInPlay), flow::begin_ball_in_play)
+            .add_systems(OnEnter(Phase::Resolve), flow::apply_outcome)
+            .add_systems(Update, park::follow_matchup.run_if(resource_changed::<Matchup>))
+            // The controls are read once a frame, before anything acts on them.
+            .add_systems(
+                Update,
+                (
+                    controls::read_controls,
+                    (
+                        (
+                            flow::windup_input.run_if(in_state(Phase::Windup)),
+                            flow::advance_pitch.run_if(in_state(Phase::Pitch)),
+                            flow::advance_ball_in_play.run_if(in_state(Phase::BallInPlay)),
+                            flow::advance_resolve.run_if(in_state(Phase::Resolve)),
+                        )
+                            .run_if(playing),
+                        flow::advance_inning_break.run_if(in_state(Phase::InningBreak)),
+                    ),
+                )
+                    .chain(),
+            );
+    }
+}
+
+pub struct BaseballPlugin;
+
+impl Plugin for BaseballPlugin {
+    fn build(&self, app: &mut App) {
+        let fonts = load_fonts(app);
+        app.add_plugins(PitchLoopPlugin)
+            .insert_resource(fonts)
+            .init_resource::<effects::ScreenShake>()
+            .init_resource::<replay::Playback>()
+            .init_resource::<screens::SeasonPage>()
+            .init_resource::<park::HomePark>()
+            .init_asset::<park::Park>()
+            .init_asset_loader::<park::ParkLoader>()
             .insert_resource(ClearColor(theme::SKY))
             .add_systems(Startup, view::spawn_cameras)
+            // --- the ballpark ---
+            // The park file overrides the built-in preset once it has loaded, and
+            // the outfield is redrawn whichever of them moved the wall.
+            .add_systems(
+                Update,
+                (
+                    park::load_home_park.run_if(resource_changed::<Matchup>),
+                    park::apply_park_file,
+                    scene::redraw_park.run_if(resource_changed::<park::Ballpark>),
+                )
+                    .chain()
+                    .after(park::follow_matchup),
+            )
             // --- title ---
             .add_systems(
                 OnEnter(Phase::Title),
@@ -247,51 +468,86 @@ impl Plugin for BaseballPlugin {
                     despawn_all::<GameScoped>,
                     despawn_all::<screens::PauseUi>,
                     screens::spawn_title,
+                    season::close_season,
                 ),
             )
             .add_systems(OnExit(Phase::Title), despawn_all::<screens::TitleUi>)
-            .add_systems(Update, screens::title_input.run_if(in_state(Phase::Title)))
-            // --- phase entries ---
-            // Chained: the scene has to exist before anything reads from it, and
+            .add_systems(
+                Update,
+                (screens::title_input, screens::sync_team_choice)
+                    .chain()
+                    .run_if(in_state(Phase::Title)),
+            )
+            // --- season ---
+            // The last game's scene and score bug go, so the next game builds its
+            // own with the right clubs on it.
+            .add_systems(
+                OnEnter(Phase::Season),
+                (despawn_all::<GameScoped>, despawn_all::<screens::PauseUi>),
+            )
+            .add_systems(OnExit(Phase::Season), despawn_all::<screens::SeasonUi>)
+            .add_systems(
+                Update,
+                (screens::season_input, screens::sync_season_screen)
+                    .chain()
+                    .run_if(in_state(Phase::Season)),
+            )
+            // --- phase entries, around the pitch loop's own ---
+            // The scene has to exist before the windup sends the fielders home, and
             // the at-bat view has to be showing before the pitcher winds up.
             .add_systems(
                 OnEnter(Phase::Windup),
                 (
-                    scene::ensure_scene,
-                    hud::ensure_hud,
-                    flow::begin_windup,
-                    view::show_at_bat,
-                )
-                    .chain(),
+                    (scene::ensure_scene, hud::ensure_hud)
+                        .chain()
+                        .before(flow::begin_windup),
+                    view::show_at_bat.after(flow::begin_windup),
+                ),
             )
-            .add_systems(OnEnter(Phase::Pitch), flow::release_pitch)
             .add_systems(
                 OnEnter(Phase::BallInPlay),
-                (flow::begin_ball_in_play, view::show_field).chain(),
+                view::show_field.after(flow::begin_ball_in_play),
             )
-            // Chained: the outcome has to be banked before the banner reads the
-            // count it is going to print.
+            // The outcome has to be banked before the banner reads the count it is
+            // going to print.
             .add_systems(
                 OnEnter(Phase::Resolve),
-                (flow::apply_outcome, screens::spawn_result_banner).chain(),
+                (screens::spawn_result_banner, replay::queue_instant_replay).after(flow::apply_outcome),
             )
             .add_systems(OnEnter(Phase::InningBreak), screens::spawn_inning_card)
             .add_systems(OnExit(Phase::InningBreak), despawn_all::<screens::InningUi>)
-            .add_systems(OnEnter(Phase::GameOver), screens::spawn_game_over)
+            // A season game is banked before the final screen prints the record.
+            .add_systems(
+                OnEnter(Phase::GameOver),
+                (
+                    season::bank_season_game.run_if(resource_exists::<season::Season>),
+                    screens::spawn_game_over,
+                )
+                    .chain(),
+            )
             .add_systems(OnExit(Phase::GameOver), despawn_all::<screens::GameOverUi>)
+            .add_systems(
+                Update,
+                season::finish_rest_of_day.run_if(resource_exists::<season::RestOfDay>),
+            )
             .add_systems(Update, screens::game_over_input.run_if(in_state(Phase::GameOver)))
-            // --- the pitch loop ---
+            // --- replays ---
+            .add_systems(OnEnter(Phase::Replay), screens::spawn_replay_overlay)
+            .add_systems(
+                OnExit(Phase::Replay),
+                (despawn_all::<screens::ReplayUi>, replay::end_playback),
+            )
+            // Input first, so a scrub or a skip shows on the frame it was asked for.
             .add_systems(
                 Update,
                 (
-                    flow::windup_input.run_if(in_state(Phase::Windup)),
-                    flow::advance_pitch.run_if(in_state(Phase::Pitch)),
-                    flow::advance_ball_in_play.run_if(in_state(Phase::BallInPlay)),
-                    flow::advance_resolve.run_if(in_state(Phase::Resolve)),
+                    replay::replay_input,
+                    replay::drive_playback,
+                    screens::update_replay_caption,
                 )
-                    .run_if(playing),
+                    .chain()
+                    .run_if(in_state(Phase::Replay)),
             )
-            .add_systems(Update, flow::advance_inning_break.run_if(in_state(Phase::InningBreak)))
             // Chained: the overlay must reconcile after the toggle, or pausing and
             // quitting to the title in one frame leaves the overlay behind.
             .add_systems(
@@ -312,6 +568,7 @@ impl Plugin for BaseballPlugin {
                     scene::draw_at_bat,
                     hud::update_score_bug,
                     hud::update_pitch_panel,
+                    hud::update_conditions,
                 )
                     .run_if(on_the_field),
             )
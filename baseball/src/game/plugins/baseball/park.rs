@@ -0,0 +1,268 @@
+//! Ballparks: how far away the wall is, how tall it is, how much foul ground
+//! there is before the stands, and how thin the air is.
+//!
+//! A park is an asset, described in a `.park.json` file under `assets/parks/` and
+//! loaded through the asset server, so editing one while the game runs moves the
+//! wall under the next fly ball. The infield is the same everywhere — the rules
+//! fix it — which is why the bases stay constants in [`field`].
+//!
+//! The presets are built into the binary as well. The headless runner has no
+//! asset server, and a season's other games are played in the same parks as the
+//! ones the player watches.
+//!
+//! [`field`]: super::field
+
+use std::io;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    field,
+    league::{CLUBS, Matchup},
+};
+
+/// Every preset, by the name its file goes by. The standard park is the one the
+/// physics were tuned in.
+pub const PRESETS: [(&str, &str); 6] = [
+    ("standard", include_str!("../../../../assets/parks/standard.park.json")),
+    ("fenway", include_str!("../../../../assets/parks/fenway.park.json")),
+    ("coors", include_str!("../../../../assets/parks/coors.park.json")),
+    ("yankee", include_str!("../../../../assets/parks/yankee.park.json")),
+    ("oracle", include_str!("../../../../assets/parks/oracle.park.json")),
+    ("wrigley", include_str!("../../../../assets/parks/wrigley.park.json")),
+];
+
+/// Feet of altitude over which the air thins by a factor of `e`. Denver's mile
+/// leaves a little over four fifths of the air a ball meets at sea level.
+const SCALE_HEIGHT: f32 = 27_000.0;
+
+/// One post along the wall. Between posts the distance and the height are
+/// interpolated, so a wall that changes height abruptly — the Green Monster
+/// giving way to the center-field fence — is two posts a degree apart.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Fence {
+    /// Degrees from dead center, negative to left field, as [`field::spray_angle`]
+    /// but in degrees because that is what a person editing the file thinks in.
+    pub spray: f32,
+    /// Feet from home plate.
+    pub distance: f32,
+    /// Feet a ball has to be above the grass to clear it.
+    pub height: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Asset, TypePath, Serialize, Deserialize)]
+pub struct Park {
+    pub name: String,
+    /// Feet above sea level. Thinner air drags less, so the ball carries.
+    pub altitude: f32,
+    /// Feet of foul ground between the foul lines and the stands, and between the
+    /// plate and the backstop. A foul pop beyond it is in the seats.
+    pub foul_territory: f32,
+    /// The wall from the left-field pole round to the right-field pole, in order.
+    fence: Vec<Fence>,
+}
+
+impl Default for Park {
+    fn default() -> Self {
+        Park::preset("standard").expect("every preset is checked by a test")
+    }
+}
+
+impl Park {
+    /// Reads a park file, refusing one whose wall does not run pole to pole.
+    pub fn parse(bytes: &[u8]) -> io::Result<Park> {
+        let park: Park = serde_json::from_slice(bytes)?;
+        let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {reason}", park.name));
+
+        let poles = field::FOUL_LINE_ANGLE.to_degrees();
+        match (park.fence.first(), park.fence.last()) {
+            (Some(left), Some(right)) if park.fence.len() >= 2 && left.spray <= -poles && right.spray >= poles => {}
+            _ => return Err(invalid("the fence has to run from one foul pole to the other")),
+        }
+        if park.fence.windows(2).any(|pair| pair[0].spray >= pair[1].spray) {
+            return Err(invalid("fence posts have to go from left field to right"));
+        }
+        if park.fence.iter().any(|post| post.distance <= 0.0 || post.height < 0.0) {
+            return Err(invalid("a fence post is behind the plate or underground"));
+        }
+        if park.foul_territory < 0.0 {
+            return Err(invalid("foul territory cannot be negative"));
+        }
+        Ok(park)
+    }
+
+    /// One of the [`PRESETS`], by name.
+    pub fn preset(key: &str) -> Option<Park> {
+        let (_, text) = PRESETS.iter().find(|(name, _)| *name == key)?;
+        Park::parse(text.as_bytes()).ok()
+    }
+
+    /// Where a club plays its home games.
+    pub fn home_of(matchup: Matchup) -> Park {
+        Park::preset(CLUBS[matchup.home].park).unwrap_or_default()
+    }
+
+    /// The wall along a spray angle in radians, interpolated between posts.
+    pub fn fence_at(&self, spray: f32) -> Fence {
+        let degrees = spray.to_degrees();
+        let after = self
+            .fence
+            .partition_point(|post| post.spray < degrees)
+            .clamp(1, self.fence.len() - 1);
+        let (near, far) = (self.fence[after - 1], self.fence[after]);
+        let t = ((degrees - near.spray) / (far.spray - near.spray)).clamp(0.0, 1.0);
+        Fence {
+            spray: degrees,
+            distance: near.distance + (far.distance - near.distance) * t,
+            height: near.height + (far.height - near.height) * t,
+        }
+    }
+
+    /// How much of the air at sea level there is to push through here.
+    pub fn air_density(&self) -> f32 {
+        (-self.altitude / SCALE_HEIGHT).exp()
+    }
+}
+
+/// Loads `.park.json` files.
+#[derive(Debug, Default, TypePath)]
+pub struct ParkLoader;
+
+impl AssetLoader for ParkLoader {
+    type Asset = Park;
+    type Settings = ();
+    type Error = io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Park, io::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Park::parse(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["park.json"]
+    }
+}
+
+/// The park today's game is being played in. The physics read it for every
+/// flight; the scene redraws the outfield whenever it changes.
+#[derive(Debug, Clone, Default, PartialEq, Resource, Deref)]
+pub struct Ballpark(pub Park);
+
+/// The home club's park file, for as long as it is the home club.
+#[derive(Debug, Default, Resource)]
+pub struct HomePark(Handle<Park>);
+
+/// Moves the game to the home club's park as soon as the matchup changes. The
+/// built-in preset is used straight away, so nothing is ever played in the last
+/// game's park while the file loads.
+pub fn follow_matchup(matchup: Res<Matchup>, mut ballpark: ResMut<Ballpark>) {
+    ballpark.set_if_neq(Ballpark(Park::home_of(*matchup)));
+}
+
+pub fn load_home_park(matchup: Res<Matchup>, server: Res<AssetServer>, mut home: ResMut<HomePark>) {
+    home.0 = server.load(format!("parks/{}.park.json", CLUBS[matchup.home].park));
+}
+
+/// Swaps in the park file once it has loaded, and again every time it is saved.
+/// A file loaded for an earlier game does not load again, so a new home club is
+/// enough on its own.
+pub fn apply_park_file(
+    home: Res<HomePark>,
+    parks: Res<Assets<Park>>,
+    mut events: MessageReader<AssetEvent<Park>>,
+    mut ballpark: ResMut<Ballpark>,
+) {
+    let mut touched = home.is_changed();
+    for event in events.read() {
+        touched |= event.is_loaded_with_dependencies(&home.0) || event.is_modified(&home.0);
+    }
+    if !touched {
+        return;
+    }
+    if let Some(park) = parks.get(&home.0) {
+        ballpark.set_if_neq(Ballpark(park.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presets() -> impl Iterator<Item = Park> {
+        PRESETS.iter().map(|(key, text)| {
+            Park::parse(text.as_bytes()).unwrap_or_else(|error| panic!("the {key} preset does not load: {error}"))
+        })
+    }
+
+    #[test]
+    fn every_preset_loads() {
+        assert_eq!(presets().count(), PRESETS.len());
+    }
+
+    #[test]
+    fn every_club_plays_in_a_preset() {
+        for club in CLUBS {
+            assert!(
+                Park::preset(club.park).is_some(),
+                "{} play in a park nobody built",
+                club.name
+            );
+        }
+    }
+
+    #[test]
+    fn the_wall_is_interpolated_between_posts_and_held_past_the_poles() {
+        let park = Park::parse(
+            br#"{ "name": "Test", "altitude": 0, "foul_territory": 40, "fence": [
+                { "spray": -45, "distance": 300, "height": 10 },
+                { "spray": 45, "distance": 400, "height": 20 }
+            ] }"#,
+        )
+        .expect("a two-post park is a park");
+
+        let middle = park.fence_at(0.0);
+        assert!((middle.distance - 350.0).abs() < 1e-3);
+        assert!((middle.height - 15.0).abs() < 1e-3);
+        assert!((park.fence_at(-1.2).distance - 300.0).abs() < 1e-3);
+        assert!((park.fence_at(1.2).distance - 400.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn a_wall_that_stops_short_of_a_pole_is_refused() {
+        let short = br#"{ "name": "Half", "altitude": 0, "foul_territory": 40, "fence": [
+            { "spray": -45, "distance": 300, "height": 10 },
+            { "spray": 10, "distance": 400, "height": 10 }
+        ] }"#;
+        assert_eq!(
+            Park::parse(short).map_err(|error| error.kind()),
+            Err(io::ErrorKind::InvalidData)
+        );
+    }
+
+    #[test]
+    fn the_green_monster_is_tall_and_the_right_field_corner_is_not() {
+        let fenway = Park::preset("fenway").expect("fenway is a preset");
+        let left = fenway.fence_at(-40.0_f32.to_radians());
+        let right = fenway.fence_at(42.0_f32.to_radians());
+        assert!(left.height > 30.0, "the Monster is {} feet", left.height);
+        assert!(right.height < 5.0, "the right-field wall is {} feet", right.height);
+    }
+
+    #[test]
+    fn the_air_is_thinner_a_mile_up() {
+        let coors = Park::preset("coors").expect("coors is a preset");
+        let density = coors.air_density();
+        assert!((0.8..0.86).contains(&density), "Denver has {density} of sea-level air");
+        assert!((Park::default().air_density() - 1.0).abs() < 1e-6);
+    }
+}
@@ -84,11 +84,11 @@ impl PitchKind {
             PitchKind::Changeup => "CHANGEUP",
         }
     }
+}
 
-    /// Seconds from release until the ball reaches the plate.
-    pub fn flight_time(self) -> f32 {
-        RELEASE_DISTANCE / (self.speed() * super::ball::MPH_TO_FPS)
-    }
+/// Seconds from release until a pitch at this many miles per hour reaches the plate.
+pub fn flight_time(speed: f32) -> f32 {
+    RELEASE_DISTANCE / (speed * super::ball::MPH_TO_FPS)
 }
 
 /// A pitch chosen but not yet thrown.
@@ -115,15 +115,16 @@ impl PitchPlan {
         self.target.y = (self.target.y + delta.y).clamp(AIM_LIMIT_LOW, AIM_LIMIT_HIGH);
     }
 
-    pub fn cycle_kind(&mut self, forward: bool) {
-        let index = PITCH_KINDS.iter().position(|&k| k == self.kind).unwrap_or(0);
-        let count = PITCH_KINDS.len();
+    /// Steps through the pitches in a repertoire, which must not be empty.
+    pub fn cycle_kind(&mut self, forward: bool, repertoire: &[PitchKind]) {
+        let index = repertoire.iter().position(|&k| k == self.kind).unwrap_or(0);
+        let count = repertoire.len();
         let next = if forward {
             (index + 1) % count
         } else {
             (index + count - 1) % count
         };
-        self.kind = PITCH_KINDS[next];
+        self.kind = repertoire[next];
     }
 }
 
@@ -133,6 +134,8 @@ pub struct LivePitch {
     pub kind: PitchKind,
     /// Where it will actually cross the plate.
     pub target: Vec2,
+    /// How far it breaks, in feet, which is the pitcher's as much as the pitch's.
+    pub bend: Vec2,
     pub elapsed: f32,
     pub flight: f32,
     /// Set once the batter has committed, so a second press cannot swing twice.
@@ -141,23 +144,24 @@ pub struct LivePitch {
 
 impl Default for LivePitch {
     fn default() -> Self {
-        Self {
-            kind: PitchKind::Fastball,
-            target: Vec2::new(0.0, ZONE_MID),
-            elapsed: 0.0,
-            flight: PitchKind::Fastball.flight_time(),
-            swung: false,
-        }
+        Self::thrown(PitchPlan::default())
     }
 }
 
 impl LivePitch {
+    /// The pitch as written down in [`PitchKind`], by an average arm.
     pub fn thrown(plan: PitchPlan) -> Self {
+        Self::thrown_at(plan, plan.kind.speed(), plan.kind.break_vector())
+    }
+
+    /// The pitch at a given speed, in miles per hour, and with a given break.
+    pub fn thrown_at(plan: PitchPlan, speed: f32, bend: Vec2) -> Self {
         Self {
             kind: plan.kind,
             target: plan.target,
+            bend,
             elapsed: 0.0,
-            flight: plan.kind.flight_time(),
+            flight: flight_time(speed),
             swung: false,
         }
     }
@@ -175,9 +179,8 @@ impl LivePitch {
     /// like it was going there. That late deviation is the whole difficulty of a
     /// breaking ball.
     pub fn spot_at(&self, progress: f32) -> Vec2 {
-        let bend = self.kind.break_vector();
-        let apparent = self.target - bend;
-        RELEASE.lerp(apparent, progress) + bend * progress * progress
+        let apparent = self.target - self.bend;
+        RELEASE.lerp(apparent, progress) + self.bend * progress * progress
     }
 
     /// Where the ball crosses the plate, which is what the umpire judges.
@@ -201,9 +204,11 @@ pub fn in_zone(spot: Vec2) -> bool {
     spot.x.abs() <= ZONE_HALF_WIDTH && (ZONE_BOTTOM..=ZONE_TOP).contains(&spot.y)
 }
 
-/// Whether the pitch got away far enough inside to hit the batter.
-pub fn hits_batter(spot: Vec2) -> bool {
-    spot.x <= HBP_X && spot.y < 5.0
+/// Whether the pitch got away far enough inside to hit the batter. `side` is
+/// [`Bats::side`](super::lineup::Bats::side): a left-hander stands on the other
+/// side of the plate, so inside is the other way.
+pub fn hits_batter(spot: Vec2, side: f32) -> bool {
+    spot.x * side <= HBP_X && spot.y < 5.0
 }
 
 /// How far outside the zone a spot is, in feet. Zero inside. Drives how likely a
@@ -279,9 +284,9 @@ mod tests {
 
     #[test]
     fn a_curveball_takes_longer_to_arrive_than_a_fastball() {
-        assert!(PitchKind::Curveball.flight_time() > PitchKind::Fastball.flight_time());
+        assert!(flight_time(PitchKind::Curveball.speed()) > flight_time(PitchKind::Fastball.speed()));
         // Sanity: a big-league fastball reaches the plate in under half a second.
-        let fastball = PitchKind::Fastball.flight_time();
+        let fastball = flight_time(PitchKind::Fastball.speed());
         assert!(
             (0.35..=0.45).contains(&fastball),
             "fastball flight time of {fastball}s is not realistic"
@@ -313,9 +318,11 @@ mod tests {
 
     #[test]
     fn a_pitch_way_inside_hits_the_batter_but_a_strike_never_does() {
-        assert!(hits_batter(Vec2::new(-2.0, 2.5)));
-        assert!(!hits_batter(Vec2::new(0.0, ZONE_MID)));
-        assert!(!hits_batter(Vec2::new(1.5, 2.5)), "outside cannot hit a righty");
+        assert!(hits_batter(Vec2::new(-2.0, 2.5), 1.0));
+        assert!(!hits_batter(Vec2::new(0.0, ZONE_MID), 1.0));
+        assert!(!hits_batter(Vec2::new(1.5, 2.5), 1.0), "outside cannot hit a righty");
+        assert!(hits_batter(Vec2::new(2.0, 2.5), -1.0), "but it can hit a lefty");
+        assert!(!hits_batter(Vec2::new(-2.0, 2.5), -1.0));
     }
 
     #[test]
@@ -337,14 +344,24 @@ mod tests {
         let mut plan = PitchPlan::default();
         let start = plan.kind;
         for _ in 0..PITCH_KINDS.len() {
-            plan.cycle_kind(true);
+            plan.cycle_kind(true, &PITCH_KINDS);
         }
         assert_eq!(plan.kind, start, "a full cycle forward returns to the start");
 
-        plan.cycle_kind(false);
+        plan.cycle_kind(false, &PITCH_KINDS);
         assert_eq!(plan.kind, PITCH_KINDS[PITCH_KINDS.len() - 1], "backwards wraps");
     }
 
+    #[test]
+    fn cycling_skips_pitches_the_pitcher_does_not_have() {
+        let mut plan = PitchPlan::default();
+        let repertoire = [PitchKind::Fastball, PitchKind::Changeup];
+        plan.cycle_kind(true, &repertoire);
+        assert_eq!(plan.kind, PitchKind::Changeup);
+        plan.cycle_kind(true, &repertoire);
+        assert_eq!(plan.kind, PitchKind::Fastball);
+    }
+
     #[test]
     fn a_pitch_reaches_the_plate_before_it_reaches_the_catcher() {
         let mut pitch = LivePitch::thrown(PitchPlan::default());
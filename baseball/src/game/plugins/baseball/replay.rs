@@ -0,0 +1,529 @@
+//! Every pitch of the game, kept so it can be watched again.
+//!
+//! The live resources only ever hold the pitch in progress: [`LiveBall`] is
+//! cleared at the next windup, and the [`Flight`] a batted ball was read from is
+//! dropped once its outcome is banked. [`Recording`] keeps a [`PitchRecord`] of
+//! each pitch instead: the pitch as thrown, the swing, the batted ball's whole
+//! path, the race on the bases and what the rules engine made of it. A record is
+//! plain data with no entities or asset handles in it, so it outlives the scene
+//! and is what a saved highlight would be written from.
+//!
+//! [`Playback`] plays a record by writing it back into the same live resources
+//! the game draws from, so a replay is drawn by exactly the code that drew the
+//! play. A big play gets an instant replay in slow motion before the game moves
+//! on, and the game-over screen opens the whole game to be scrubbed through.
+
+use baseball_game_rules::{InningHalf, PitchOutcome, PlayerPosition};
+use bevy::prelude::*;
+
+use super::{
+    Phase,
+    ball::{Flight, LiveBall},
+    baserunning::Race,
+    bat::Swing,
+    controls::{self, Controls},
+    field,
+    flow::{self, PitchLoop},
+    lineup::Bats,
+    pitch::LivePitch,
+    view::{self, FieldCamera, HudCamera},
+};
+
+/// The instant replay runs at this fraction of real time.
+const INSTANT_SPEED: f32 = 0.6;
+
+/// How much of the pitch the instant replay shows before the bat meets it.
+const INSTANT_LEAD: f32 = 0.6;
+
+/// Seconds of play a held scrub covers per second.
+const SCRUB_RATE: f32 = 3.0;
+
+/// One pitch, with everything needed to draw it again.
+#[derive(Debug, Clone)]
+pub struct PitchRecord {
+    pub inning: u8,
+    pub half: InningHalf,
+    pub batter: &'static str,
+    pub bats: Bats,
+    /// Whether first, second and third were occupied when it was thrown.
+    pub bases: [bool; 3],
+    /// The pitch as thrown. Its whole track is [`LivePitch::spot_at`], and its
+    /// `elapsed` is how long it had been in the air when it was hit or over.
+    pub pitch: LivePitch,
+    pub swing: Option<Swing>,
+    /// The batted ball, if there was one, from contact until it was dead.
+    pub flight: Option<Flight>,
+    /// Where each fielder was sent after it.
+    pub targets: Vec<(PlayerPosition, Vec2)>,
+    pub race: Race,
+    /// Seconds from contact to the end of the play.
+    pub in_play_for: f32,
+    pub outcome: PitchOutcome,
+}
+
+/// A record at one moment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frame {
+    /// The pitch on its way, `elapsed` seconds after release.
+    Pitch { elapsed: f32 },
+    /// The ball in play, `elapsed` seconds after contact.
+    InPlay { elapsed: f32, ball: Vec3 },
+}
+
+impl PitchRecord {
+    /// Seconds from release to contact, or to the end of a pitch nobody hit.
+    pub fn contact_at(&self) -> f32 {
+        self.pitch.elapsed
+    }
+
+    /// Seconds from release to the end of the play.
+    pub fn length(&self) -> f32 {
+        self.contact_at() + if self.flight.is_some() { self.in_play_for } else { 0.0 }
+    }
+
+    pub fn frame(&self, time: f32) -> Frame {
+        let Some(flight) = self.flight.as_ref().filter(|_| time >= self.contact_at()) else {
+            return Frame::Pitch {
+                elapsed: time.clamp(0.0, self.contact_at()),
+            };
+        };
+        let elapsed = (time - self.contact_at()).min(self.in_play_for);
+        // Once a fielder has the ball, it goes where the race sent it rather than
+        // where the flight would have taken it.
+        let ball = self
+            .race
+            .ball_at(elapsed)
+            .unwrap_or_else(|| flight.position_at(elapsed));
+        Frame::InPlay { elapsed, ball }
+    }
+
+    /// Where every fielder is standing in `frame`: at his spot until contact,
+    /// then running toward wherever he was sent at the speed he ran there live.
+    pub fn fielders_at(&self, frame: Frame) -> Vec<(PlayerPosition, Vec2)> {
+        let since_contact = match frame {
+            Frame::Pitch { .. } => 0.0,
+            Frame::InPlay { elapsed, .. } => elapsed,
+        };
+        field::FIELDER_HOMES
+            .iter()
+            .map(|&(position, home)| {
+                let target = self
+                    .targets
+                    .iter()
+                    .find(|&&(sent, _)| sent == position)
+                    .map_or(home, |&(_, spot)| spot);
+                (
+                    position,
+                    home.move_towards(target, field::FIELDER_SPEED * since_contact),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Every pitch of the game in progress, first to last.
+#[derive(Debug, Default, Resource)]
+pub struct Recording {
+    pub pitches: Vec<PitchRecord>,
+}
+
+impl Recording {
+    /// The nearest pitch after `from` that was put in play, or before it when
+    /// going back.
+    pub fn next_in_play(&self, from: usize, forward: bool) -> Option<usize> {
+        let in_play = |&index: &usize| self.pitches[index].flight.is_some();
+        if forward {
+            (from + 1..self.pitches.len()).find(in_play)
+        } else {
+            (0..from.min(self.pitches.len())).rev().find(in_play)
+        }
+    }
+}
+
+/// What kind of replay is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The play just made, in slow motion, before going on to `then`.
+    Instant { then: Phase },
+    /// The whole game, for as long as the player wants to watch it.
+    Game,
+}
+
+/// What a replay has on screen, for the drawing systems to use in place of the
+/// live game's state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shown {
+    /// [`Phase::Pitch`] or [`Phase::BallInPlay`]: the part of the play showing.
+    pub stage: Phase,
+    pub bases: [bool; 3],
+    pub bats: Bats,
+    pub fielders: Vec<(PlayerPosition, Vec2)>,
+}
+
+/// The replay in progress, if there is one.
+#[derive(Debug, Default, Resource)]
+pub struct Playback {
+    pub mode: Option<Mode>,
+    /// Which record is showing, and how far into it.
+    pub index: usize,
+    pub time: f32,
+    pub paused: bool,
+    /// Filled in every frame while a replay runs.
+    pub shown: Option<Shown>,
+}
+
+impl Playback {
+    /// Slow motion of the last play, from just before contact. `None` if there
+    /// is nothing recorded.
+    pub fn instant(recording: &Recording, then: Phase) -> Option<Self> {
+        let index = recording.pitches.len().checked_sub(1)?;
+        Some(Playback {
+            mode: Some(Mode::Instant { then }),
+            index,
+            time: (recording.pitches[index].contact_at() - INSTANT_LEAD).max(0.0),
+            ..Playback::default()
+        })
+    }
+
+    /// The whole game from the first pitch. `None` if there is nothing recorded.
+    pub fn whole_game(recording: &Recording) -> Option<Self> {
+        (!recording.pitches.is_empty()).then(|| Playback {
+            mode: Some(Mode::Game),
+            ..Playback::default()
+        })
+    }
+
+    /// The phase the drawing systems should draw as: the part of the play the
+    /// replay is showing, or `phase` itself when no replay is.
+    pub fn stage(&self, phase: Phase) -> Phase {
+        match &self.shown {
+            Some(shown) if phase == Phase::Replay => shown.stage,
+            _ => phase,
+        }
+    }
+
+    /// Runs the clock on by `dt` seconds of real time. Returns `true` once an
+    /// instant replay has shown the whole play; the whole-game replay runs from
+    /// one pitch into the next and waits at the end of the last.
+    pub fn advance(&mut self, recording: &Recording, dt: f32) -> bool {
+        let Some(record) = recording.pitches.get(self.index) else {
+            return true;
+        };
+        match self.mode {
+            None => true,
+            Some(Mode::Instant { .. }) => {
+                self.time += dt * INSTANT_SPEED;
+                self.time >= record.length()
+            }
+            Some(Mode::Game) => {
+                if !self.paused {
+                    self.scrub(recording, dt);
+                    let last = self.index + 1 == recording.pitches.len();
+                    if last && self.time >= recording.pitches[self.index].length() {
+                        self.paused = true;
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    /// Moves `seconds` forward or back through the game, running on into the
+    /// next pitch or back into the last one at either end of this one.
+    pub fn scrub(&mut self, recording: &Recording, seconds: f32) {
+        self.time += seconds;
+        while self.time < 0.0 && self.index > 0 {
+            self.index -= 1;
+            self.time += recording.pitches[self.index].length();
+        }
+        while self.index + 1 < recording.pitches.len() && self.time > recording.pitches[self.index].length() {
+            self.time -= recording.pitches[self.index].length();
+            self.index += 1;
+        }
+        let length = recording.pitches.get(self.index).map_or(0.0, PitchRecord::length);
+        self.time = self.time.clamp(0.0, length);
+    }
+
+    /// Straight to the start of pitch `index`.
+    pub fn jump_to(&mut self, recording: &Recording, index: usize) {
+        self.index = index.min(recording.pitches.len().saturating_sub(1));
+        self.time = 0.0;
+    }
+}
+
+// ------------------------------------------------------------------ systems
+
+/// Puts an instant replay of a big play in front of wherever the loop was going
+/// next.
+pub fn queue_instant_replay(
+    recording: Res<Recording>,
+    mut loop_state: ResMut<PitchLoop>,
+    mut playback: ResMut<Playback>,
+) {
+    let Some(last) = recording.pitches.last() else {
+        return;
+    };
+    if last.flight.is_none() || !flow::is_big_play(last.outcome) {
+        return;
+    }
+    let then = loop_state.after_resolve.unwrap_or(Phase::Windup);
+    if let Some(instant) = Playback::instant(&recording, then) {
+        *playback = instant;
+        loop_state.after_resolve = Some(Phase::Replay);
+    }
+}
+
+/// Writes the record being replayed into the live resources, so the ordinary
+/// drawing systems draw it, and switches to whichever view that part of the play
+/// belongs in.
+pub fn drive_playback(
+    time: Res<Time>,
+    recording: Res<Recording>,
+    mut playback: ResMut<Playback>,
+    mut live_pitch: ResMut<LivePitch>,
+    mut live_ball: ResMut<LiveBall>,
+    mut race: ResMut<Race>,
+    mut cameras: Query<(&mut Camera, Has<FieldCamera>), Without<HudCamera>>,
+    mut next: ResMut<NextState<Phase>>,
+) {
+    if playback.advance(&recording, time.delta_secs()) {
+        next.set(match playback.mode {
+            Some(Mode::Instant { then }) => then,
+            _ => Phase::GameOver,
+        });
+        return;
+    }
+    let Some(record) = recording.pitches.get(playback.index) else {
+        return;
+    };
+
+    let frame = record.frame(playback.time);
+    let stage = match frame {
+        Frame::Pitch { elapsed } => {
+            *live_pitch = LivePitch {
+                elapsed,
+                swung: false,
+                ..record.pitch
+            };
+            live_ball.live = false;
+            Phase::Pitch
+        }
+        Frame::InPlay { elapsed, ball } => {
+            live_ball.pos = ball;
+            live_ball.elapsed = elapsed;
+            live_ball.live = true;
+            Phase::BallInPlay
+        }
+    };
+    if *race != record.race {
+        *race = record.race.clone();
+    }
+    if playback.shown.as_ref().is_none_or(|shown| shown.stage != stage) {
+        view::activate(stage == Phase::BallInPlay, &mut cameras);
+    }
+    playback.shown = Some(Shown {
+        stage,
+        bases: record.bases,
+        bats: record.bats,
+        fielders: record.fielders_at(frame),
+    });
+}
+
+/// Any button skips an instant replay. The whole-game replay scrubs with
+/// left and right, steps a pitch at a time with up and down, jumps between balls
+/// in play with page up and page down, and pauses with space.
+pub fn replay_input(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    controls: Res<Controls>,
+    recording: Res<Recording>,
+    mut playback: ResMut<Playback>,
+    mut next: ResMut<NextState<Phase>>,
+) {
+    let tapped = |key, button| keys.just_pressed(key) || controls::any_pad_pressed(&gamepads, button);
+    let held = |key, button| keys.pressed(key) || gamepads.iter().any(|pad| pad.pressed(button));
+
+    match playback.mode {
+        Some(Mode::Instant { then }) => {
+            if controls.anyone_acted() {
+                next.set(then);
+            }
+        }
+        Some(Mode::Game) => {
+            if tapped(KeyCode::Escape, GamepadButton::East) {
+                next.set(Phase::GameOver);
+                return;
+            }
+            if tapped(KeyCode::Space, GamepadButton::South) {
+                playback.paused = !playback.paused;
+            }
+
+            let index = playback.index;
+            let step = if tapped(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+                Some(index.saturating_sub(1))
+            } else if tapped(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+                Some(index + 1)
+            } else if tapped(KeyCode::PageUp, GamepadButton::LeftTrigger) {
+                recording.next_in_play(index, false)
+            } else if tapped(KeyCode::PageDown, GamepadButton::RightTrigger) {
+                recording.next_in_play(index, true)
+            } else {
+                None
+            };
+            if let Some(to) = step {
+                playback.jump_to(&recording, to);
+            }
+
+            let scrub = f32::from(u8::from(held(KeyCode::ArrowRight, GamepadButton::DPadRight)))
+                - f32::from(u8::from(held(KeyCode::ArrowLeft, GamepadButton::DPadLeft)));
+            if scrub != 0.0 {
+                // Scrubbing holds the picture where it is let go.
+                playback.paused = true;
+                playback.scrub(&recording, scrub * SCRUB_RATE * time.delta_secs());
+            }
+        }
+        None => {}
+    }
+}
+
+/// Hands the live resources back to the game.
+pub fn end_playback(mut playback: ResMut<Playback>, mut live_ball: ResMut<LiveBall>, mut race: ResMut<Race>) {
+    *playback = Playback::default();
+    live_ball.clear();
+    *race = Race::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::PlayResult;
+
+    use super::{
+        super::{ball, park::Park, pitch::PitchPlan, weather::Weather},
+        *,
+    };
+
+    fn taken(flight: Option<Flight>) -> PitchRecord {
+        PitchRecord {
+            inning: 1,
+            half: InningHalf::Top,
+            batter: "",
+            bats: Bats::Right,
+            bases: [false; 3],
+            pitch: LivePitch {
+                elapsed: 0.5,
+                ..LivePitch::thrown(PitchPlan::default())
+            },
+            swing: None,
+            in_play_for: if flight.is_some() { 3.0 } else { 0.0 },
+            outcome: if flight.is_some() {
+                PitchOutcome::InPlay(PlayResult::Single)
+            } else {
+                PitchOutcome::Ball
+            },
+            flight,
+            targets: Vec::new(),
+            race: Race::default(),
+        }
+    }
+
+    fn recording() -> Recording {
+        let flight = ball::simulate(&Park::default(), Weather::STILL, 90.0 * ball::MPH_TO_FPS, 0.3, 0.1);
+        Recording {
+            pitches: vec![taken(None), taken(Some(flight)), taken(None)],
+        }
+    }
+
+    #[test]
+    fn a_batted_ball_is_replayed_from_the_plate_along_its_path() {
+        let recording = recording();
+        let record = &recording.pitches[1];
+        assert_eq!(record.frame(0.2), Frame::Pitch { elapsed: 0.2 });
+        let Frame::InPlay { elapsed, ball } = record.frame(record.contact_at() + 1.0) else {
+            panic!("the ball should be in play a second after contact");
+        };
+        assert!((elapsed - 1.0).abs() < 1e-4);
+        assert_eq!(ball, record.flight.as_ref().expect("hit").position_at(1.0));
+        assert!((record.length() - 3.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn a_pitch_nobody_hit_never_leaves_the_at_bat_view() {
+        let record = &recording().pitches[0];
+        assert_eq!(record.frame(10.0), Frame::Pitch { elapsed: 0.5 });
+        assert!((record.length() - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn fielders_wait_for_contact_then_run_at_their_live_speed() {
+        let mut record = recording().pitches[1].clone();
+        let (position, home) = field::FIELDER_HOMES[4];
+        record.targets = vec![(position, home + Vec2::new(0.0, 100.0))];
+        let at = |frame| {
+            record
+                .fielders_at(frame)
+                .into_iter()
+                .find(|&(p, _)| p == position)
+                .map(|(_, spot)| spot)
+                .expect("every fielder is placed")
+        };
+        assert_eq!(at(Frame::Pitch { elapsed: 0.4 }), home);
+        let running = at(Frame::InPlay {
+            elapsed: 1.0,
+            ball: Vec3::ZERO,
+        });
+        assert!((running.distance(home) - field::FIELDER_SPEED).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scrubbing_runs_across_pitches_and_stops_at_either_end() {
+        let recording = recording();
+        let mut playback = Playback::whole_game(&recording).expect("something recorded");
+        playback.scrub(&recording, 1.0);
+        assert_eq!((playback.index, (playback.time * 100.0).round()), (1, 50.0));
+        playback.scrub(&recording, -5.0);
+        assert_eq!((playback.index, playback.time), (0, 0.0));
+        playback.scrub(&recording, 100.0);
+        assert_eq!(playback.index, 2);
+        assert!((playback.time - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn the_whole_game_plays_on_and_waits_at_the_end() {
+        let recording = recording();
+        let mut playback = Playback::whole_game(&recording).expect("something recorded");
+        for _ in 0..100 {
+            assert!(!playback.advance(&recording, 0.1), "only an instant replay finishes");
+        }
+        assert_eq!(playback.index, 2);
+        assert!(playback.paused);
+    }
+
+    #[test]
+    fn an_instant_replay_shows_the_last_play_once_in_slow_motion() {
+        let recording = recording();
+        let mut playback = Playback::instant(&recording, Phase::Windup).expect("something recorded");
+        assert_eq!(playback.index, 2);
+        let mut frames = 0;
+        while !playback.advance(&recording, 0.1) {
+            frames += 1;
+        }
+        assert!(frames > 5, "slow motion takes longer than the pitch did");
+    }
+
+    #[test]
+    fn stepping_between_balls_in_play_skips_the_rest() {
+        let recording = recording();
+        assert_eq!(recording.next_in_play(0, true), Some(1));
+        assert_eq!(recording.next_in_play(1, true), None);
+        assert_eq!(recording.next_in_play(2, false), Some(1));
+        assert_eq!(recording.next_in_play(1, false), None);
+    }
+
+    #[test]
+    fn nothing_recorded_means_nothing_to_replay() {
+        let empty = Recording::default();
+        assert!(Playback::whole_game(&empty).is_none());
+        assert!(Playback::instant(&empty, Phase::Windup).is_none());
+    }
+}
@@ -14,7 +14,17 @@ use bevy::{
     prelude::*,
 };
 
-use super::{Diamond, Fielder, GameScoped, Phase, ball::LiveBall, field, pitch, theme, view};
+use super::{
+    Diamond, Fielder, GameScoped, Phase, Seats,
+    ball::LiveBall,
+    baserunning::Race,
+    field,
+    lineup::Lineups,
+    park::{Ballpark, Park},
+    pitch,
+    replay::Playback,
+    theme, view,
+};
 
 // Depth ordering for the field view.
 const Z_FOUL_GROUND: f32 = -6.0;
@@ -35,6 +45,12 @@ const Z_BALL: f32 = 3.0;
 #[derive(Debug, Component)]
 pub struct FieldScene;
 
+/// Everything in the field view drawn from the park's dimensions: the grass out
+/// to the wall, the track, the wall itself, the foul lines and the front row of
+/// the stands. Rebuilt on its own whenever the [`Ballpark`] changes.
+#[derive(Debug, Component)]
+pub struct ParkOutline;
+
 /// Root of the behind-the-plate scene.
 #[derive(Debug, Component)]
 pub struct AtBatScene;
@@ -48,6 +64,13 @@ pub struct BallShadow;
 #[derive(Debug, Component)]
 pub struct AtBatBall;
 
+/// The batter in the at-bat view, drawn standing in the right-handed box and
+/// flipped for a left-hander.
+#[derive(Debug, Component)]
+pub struct AtBatBatter;
+
+/// A runner in the field view, named for the base he started the pitch on:
+/// [`Base::Home`] is the batter.
 #[derive(Debug, Component)]
 pub struct RunnerPip(pub Base);
 
@@ -105,11 +128,11 @@ fn band(
 }
 
 /// The arc of fair territory, out to the wall.
-fn fair_territory_mesh(segments: usize) -> Mesh {
+fn fair_territory_mesh(park: &Park, segments: usize) -> Mesh {
     let boundary: Vec<Vec2> = (0..=segments)
         .map(|step| {
             let angle = -field::FOUL_LINE_ANGLE + 2.0 * field::FOUL_LINE_ANGLE * step as f32 / segments as f32;
-            field::point_at(angle, field::fence_distance(angle))
+            field::point_at(angle, field::fence_distance(park, angle))
         })
         .collect();
     fan(field::HOME, &boundary)
@@ -128,26 +151,31 @@ fn line_between(from: Vec2, to: Vec2, width: f32, color: Color, z: f32, layer: u
 
 // ---------------------------------------------------------------- field scene
 
-fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &mut Assets<ColorMaterial>) {
+fn build_outline(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    park: &Park,
+    root: Entity,
+) {
     let layer = view::LAYER_FIELD;
-    let root = commands
-        .spawn((FieldScene, GameScoped, Transform::default(), Visibility::default()))
+    let outline = commands
+        .spawn((ParkOutline, Transform::default(), Visibility::default(), ChildOf(root)))
         .id();
 
     let mut spawn = |bundle: (Mesh2d, MeshMaterial2d<ColorMaterial>, Transform)| {
-        commands.spawn((bundle.0, bundle.1, bundle.2, RenderLayers::layer(layer), ChildOf(root)));
+        commands.spawn((
+            bundle.0,
+            bundle.1,
+            bundle.2,
+            RenderLayers::layer(layer),
+            ChildOf(outline),
+        ));
     };
 
-    // Foul ground, covering the whole view behind everything else.
-    spawn((
-        Mesh2d(meshes.add(Rectangle::new(field::VIEW_WIDTH * 1.4, field::VIEW_HEIGHT * 1.6))),
-        MeshMaterial2d(materials.add(ColorMaterial::from(theme::scale(theme::GRASS, 0.72)))),
-        Transform::from_xyz(0.0, field::VIEW_CENTER_Y, Z_FOUL_GROUND),
-    ));
-
     // Fair territory.
     spawn((
-        Mesh2d(meshes.add(fair_territory_mesh(64))),
+        Mesh2d(meshes.add(fair_territory_mesh(park, 64))),
         MeshMaterial2d(materials.add(ColorMaterial::from(theme::GRASS))),
         Transform::from_xyz(0.0, 0.0, Z_GRASS),
     ));
@@ -166,7 +194,7 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
                 -field::FOUL_LINE_ANGLE,
                 field::FOUL_LINE_ANGLE,
                 move |_| inner,
-                move |angle| outer.min(field::fence_distance(angle) - 14.0),
+                move |angle| outer.min(field::fence_distance(park, angle) - 14.0),
                 48,
             ))),
             MeshMaterial2d(stripe_material.clone()),
@@ -179,8 +207,8 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
         Mesh2d(meshes.add(band(
             -field::FOUL_LINE_ANGLE,
             field::FOUL_LINE_ANGLE,
-            |angle| field::fence_distance(angle) - 14.0,
-            field::fence_distance,
+            |angle| field::fence_distance(park, angle) - 14.0,
+            |angle| field::fence_distance(park, angle),
             64,
         ))),
         MeshMaterial2d(materials.add(ColorMaterial::from(theme::WARNING_TRACK))),
@@ -190,8 +218,8 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
         Mesh2d(meshes.add(band(
             -field::FOUL_LINE_ANGLE,
             field::FOUL_LINE_ANGLE,
-            field::fence_distance,
-            |angle| field::fence_distance(angle) + 7.0,
+            |angle| field::fence_distance(park, angle),
+            |angle| field::fence_distance(park, angle) + 7.0,
             64,
         ))),
         MeshMaterial2d(materials.add(ColorMaterial::from(theme::WALL))),
@@ -201,14 +229,64 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
         Mesh2d(meshes.add(band(
             -field::FOUL_LINE_ANGLE,
             field::FOUL_LINE_ANGLE,
-            |angle| field::fence_distance(angle) + 7.0,
-            |angle| field::fence_distance(angle) + 9.5,
+            |angle| field::fence_distance(park, angle) + 7.0,
+            |angle| field::fence_distance(park, angle) + 9.5,
             64,
         ))),
         MeshMaterial2d(materials.add(ColorMaterial::from(theme::WALL_CAP))),
         Transform::from_xyz(0.0, 0.0, Z_WALL + 0.1),
     ));
 
+    // The front row of the stands, as far from the lines and the plate as the
+    // park's foul ground goes: a pop-up beyond it is in the seats.
+    let stands = park.foul_territory;
+    spawn((
+        Mesh2d(meshes.add(band(
+            3.0 * field::FOUL_LINE_ANGLE,
+            5.0 * field::FOUL_LINE_ANGLE,
+            |_| stands - 1.5,
+            |_| stands + 1.5,
+            16,
+        ))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme::WALL))),
+        Transform::from_xyz(0.0, 0.0, Z_WALL),
+    ));
+
+    // Foul lines, drawn all the way to the wall.
+    for side in [-1.0, 1.0] {
+        let angle = side * field::FOUL_LINE_ANGLE;
+        let end = field::point_at(angle, field::fence_distance(park, angle));
+        commands.spawn((
+            line_between(field::HOME, end, 1.6, theme::CHALK, Z_LINE, layer),
+            ChildOf(outline),
+        ));
+        let out = field::point_at(side * 3.0 * field::FOUL_LINE_ANGLE, stands);
+        commands.spawn((
+            line_between(field::HOME + out, end + out, 3.0, theme::WALL, Z_WALL, layer),
+            ChildOf(outline),
+        ));
+    }
+}
+
+fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &mut Assets<ColorMaterial>, park: &Park) {
+    let layer = view::LAYER_FIELD;
+    let root = commands
+        .spawn((FieldScene, GameScoped, Transform::default(), Visibility::default()))
+        .id();
+
+    let mut spawn = |bundle: (Mesh2d, MeshMaterial2d<ColorMaterial>, Transform)| {
+        commands.spawn((bundle.0, bundle.1, bundle.2, RenderLayers::layer(layer), ChildOf(root)));
+    };
+
+    // Foul ground, covering the whole view behind everything else.
+    spawn((
+        Mesh2d(meshes.add(Rectangle::new(field::VIEW_WIDTH * 1.4, field::VIEW_HEIGHT * 1.6))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme::scale(theme::GRASS, 0.72)))),
+        Transform::from_xyz(0.0, field::VIEW_CENTER_Y, Z_FOUL_GROUND),
+    ));
+
+    build_outline(commands, meshes, materials, park, root);
+
     // Infield dirt, bounded by the foul lines, with the grass diamond inside it.
     spawn((
         Mesh2d(
@@ -246,16 +324,6 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
         Transform::from_xyz(field::MOUND.x, field::MOUND.y, Z_DIRT + 0.1),
     ));
 
-    // Foul lines, drawn all the way to the wall.
-    for side in [-1.0, 1.0] {
-        let angle = side * field::FOUL_LINE_ANGLE;
-        let end = field::point_at(angle, field::fence_distance(angle));
-        commands.spawn((
-            line_between(field::HOME, end, 1.6, theme::CHALK, Z_LINE, layer),
-            ChildOf(root),
-        ));
-    }
-
     // Bases, and the plate.
     for base in [Base::First, Base::Second, Base::Third] {
         let spot = field::base_position(base);
@@ -293,10 +361,11 @@ fn build_field(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &m
         ));
     }
 
-    // Baserunner pips, hidden until somebody is standing there.
+    // Baserunner pips, hidden until somebody is standing there. The one at home
+    // is the batter, who only appears once he has put the ball in play.
     let runner_mesh = meshes.add(Circle::new(4.6));
     let runner_material = materials.add(ColorMaterial::from(theme::BASE_OCCUPIED));
-    for base in [Base::First, Base::Second, Base::Third] {
+    for base in [Base::Home, Base::First, Base::Second, Base::Third] {
         let spot = field::base_position(base);
         commands.spawn((
             Mesh2d(runner_mesh.clone()),
@@ -338,12 +407,12 @@ const WALL_TOP: f32 = 48.0;
 
 /// Spawns a flat coloured rectangle into the at-bat scene. A function rather than
 /// a closure so it does not hold a borrow of `Commands` across the whole builder.
-fn at_bat_flat(commands: &mut Commands, root: Entity, sprite: Sprite, transform: Transform) {
+fn at_bat_flat(commands: &mut Commands, parent: Entity, sprite: Sprite, transform: Transform) {
     commands.spawn((
         sprite,
         transform,
         RenderLayers::layer(view::LAYER_AT_BAT),
-        ChildOf(root),
+        ChildOf(parent),
     ));
 }
 
@@ -483,21 +552,26 @@ fn build_at_bat(commands: &mut Commands, meshes: &mut Assets<Mesh>, materials: &
     }
 
     // --- the batter, on the third-base side, and the catcher crouched below ---
+    // The batter hangs off his own parent at the plate, so mirroring it in x puts
+    // a left-hander in the other box with the bat over the other shoulder.
+    let batter = commands
+        .spawn((AtBatBatter, Transform::default(), Visibility::default(), ChildOf(root)))
+        .id();
     at_bat_flat(
         commands,
-        root,
+        batter,
         Sprite::from_color(theme::HOME_UNIFORM, Vec2::new(17.0, 56.0)),
         Transform::from_xyz(-64.0, -74.0, -2.0),
     );
     at_bat_flat(
         commands,
-        root,
+        batter,
         Sprite::from_color(theme::HOME_TRIM, Vec2::new(17.0, 7.0)),
         Transform::from_xyz(-64.0, -48.0, -1.9),
     );
     at_bat_flat(
         commands,
-        root,
+        batter,
         Sprite::from_color(Color::srgb(0.62, 0.44, 0.24), Vec2::new(4.0, 48.0)),
         Transform::from_xyz(-47.0, -44.0, -1.8).with_rotation(Quat::from_rotation_z(-0.5)),
     );
@@ -549,15 +623,35 @@ pub fn ensure_scene(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    ballpark: Res<Ballpark>,
     existing: Query<Entity, With<FieldScene>>,
 ) {
     if !existing.is_empty() {
         return;
     }
-    build_field(&mut commands, &mut meshes, &mut materials);
+    build_field(&mut commands, &mut meshes, &mut materials, &ballpark);
     build_at_bat(&mut commands, &mut meshes, &mut materials);
 }
 
+/// Redraws the outfield when the park changes under a scene that is already
+/// built: a new home club, or the park file saved while the game is running.
+pub fn redraw_park(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    ballpark: Res<Ballpark>,
+    scene: Query<Entity, With<FieldScene>>,
+    outline: Query<Entity, With<ParkOutline>>,
+) {
+    let Ok(root) = scene.single() else {
+        return;
+    };
+    for entity in &outline {
+        commands.entity(entity).despawn();
+    }
+    build_outline(&mut commands, &mut meshes, &mut materials, &ballpark, root);
+}
+
 // ---------------------------------------------------------------- drawing
 
 /// Places the ball in the field view, with a shadow whose offset and size tell
@@ -565,10 +659,11 @@ pub fn ensure_scene(
 pub fn draw_ball(
     live: Res<LiveBall>,
     phase: Res<State<Phase>>,
+    playback: Res<Playback>,
     mut ball: Query<(&mut Transform, &mut Visibility), (With<FieldBall>, Without<BallShadow>)>,
     mut shadow: Query<(&mut Transform, &mut Visibility), (With<BallShadow>, Without<FieldBall>)>,
 ) {
-    let visible = live.live && *phase.get() == Phase::BallInPlay;
+    let visible = live.live && playback.stage(*phase.get()) == Phase::BallInPlay;
 
     if let Ok((mut transform, mut visibility)) = ball.single_mut() {
         *visibility = if visible {
@@ -597,9 +692,20 @@ pub fn draw_ball(
     }
 }
 
-/// Walks each fielder towards wherever they are meant to be.
-pub fn draw_fielders(time: Res<Time>, mut fielders: Query<(&Fielder, &mut Transform)>) {
+/// Walks each fielder towards wherever they are meant to be. A replay puts them
+/// exactly where they were at that moment instead, since it can be scrubbed back.
+pub fn draw_fielders(time: Res<Time>, playback: Res<Playback>, mut fielders: Query<(&Fielder, &mut Transform)>) {
     for (fielder, mut transform) in fielders.iter_mut() {
+        let recorded = playback.shown.as_ref().and_then(|shown| {
+            shown
+                .fielders
+                .iter()
+                .find(|&&(position, _)| position == fielder.position)
+        });
+        if let Some(&(_, spot)) = recorded {
+            transform.translation = spot.extend(Z_FIELDER);
+            continue;
+        }
         let goal = fielder.target.unwrap_or(fielder.home);
         let here = transform.translation.truncate();
         let step = field::FIELDER_SPEED * time.delta_secs();
@@ -612,34 +718,63 @@ pub fn draw_fielders(time: Res<Time>, mut fielders: Query<(&Fielder, &mut Transf
     }
 }
 
-/// Shows a pip on each occupied base.
-pub fn draw_runners(diamond: Res<Diamond>, mut pips: Query<(&RunnerPip, &mut Visibility)>) {
-    let runners = diamond.game().map(|game| game.current_half_inning().baserunners());
-    for (pip, mut visibility) in pips.iter_mut() {
-        let occupied = runners.is_some_and(|state| match pip.0 {
-            Base::First => state.first().is_some(),
-            Base::Second => state.second().is_some(),
-            Base::Third => state.third().is_some(),
+/// Shows a pip on each occupied base. While the ball is in play each runner
+/// follows his route from the race instead, and one thrown out disappears the
+/// moment the ball beats him.
+pub fn draw_runners(
+    diamond: Res<Diamond>,
+    phase: Res<State<Phase>>,
+    playback: Res<Playback>,
+    race: Res<Race>,
+    live: Res<LiveBall>,
+    mut pips: Query<(&RunnerPip, &mut Transform, &mut Visibility)>,
+) {
+    // A replay shows the bases as they were for the pitch being watched.
+    let bases = match &playback.shown {
+        Some(shown) => Some(shown.bases),
+        None => diamond.game().map(|game| {
+            let state = game.current_half_inning().baserunners();
+            [
+                state.first().is_some(),
+                state.second().is_some(),
+                state.third().is_some(),
+            ]
+        }),
+    };
+    let in_play = playback.stage(*phase.get()) == Phase::BallInPlay;
+    for (pip, mut transform, mut visibility) in pips.iter_mut() {
+        let occupied = bases.is_some_and(|bases| match pip.0 {
+            Base::First => bases[0],
+            Base::Second => bases[1],
+            Base::Third => bases[2],
             Base::Home => false,
         });
-        *visibility = if occupied {
-            Visibility::Visible
-        } else {
-            Visibility::Hidden
+        let (spot, shown) = match race.route(pip.0).filter(|_| in_play) {
+            Some(route) => (
+                route.position_at(live.elapsed),
+                route.out_at.is_none_or(|out| live.elapsed < out),
+            ),
+            None => (field::base_position(pip.0), occupied),
         };
+        transform.translation = spot.extend(Z_RUNNER);
+        *visibility = if shown { Visibility::Visible } else { Visibility::Hidden };
     }
 }
 
 /// Draws the incoming pitch and the pitcher's target in the at-bat view.
 pub fn draw_at_bat(
     phase: Res<State<Phase>>,
+    playback: Res<Playback>,
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
+    lineups: Res<Lineups>,
     plan: Res<pitch::PitchPlan>,
     live_pitch: Res<pitch::LivePitch>,
     mut ball: Query<(&mut Transform, &mut Visibility), (With<AtBatBall>, Without<PitchTarget>)>,
     mut target: Query<(&mut Transform, &mut Visibility), (With<PitchTarget>, Without<AtBatBall>)>,
+    mut batter: Query<&mut Transform, (With<AtBatBatter>, Without<AtBatBall>, Without<PitchTarget>)>,
 ) {
-    let in_flight = *phase.get() == Phase::Pitch;
+    let in_flight = playback.stage(*phase.get()) == Phase::Pitch;
 
     if let Ok((mut transform, mut visibility)) = ball.single_mut() {
         *visibility = if in_flight {
@@ -665,8 +800,9 @@ pub fn draw_at_bat(
         }
     }
 
-    // The reticle only helps the player who is choosing the pitch.
-    let show_target = *phase.get() == Phase::Windup && !diamond.human_is_batting();
+    // The reticle only helps the player who is choosing the pitch, and would
+    // give it away to a second player watching the same screen.
+    let show_target = *phase.get() == Phase::Windup && seats.is_pitching(&diamond) && !seats.is_two_player();
     for (mut transform, mut visibility) in target.iter_mut() {
         *visibility = if show_target {
             Visibility::Visible
@@ -676,11 +812,19 @@ pub fn draw_at_bat(
         let spot = view::at_bat_point(plan.target);
         transform.translation = Vec3::new(spot.x, spot.y, 4.0);
     }
+
+    if let Ok(mut transform) = batter.single_mut() {
+        let bats = playback
+            .shown
+            .as_ref()
+            .map_or_else(|| lineups.batting(&diamond).bats, |shown| shown.bats);
+        transform.scale.x = bats.side();
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::{super::park::PRESETS, *};
 
     #[test]
     fn a_fan_mesh_has_one_triangle_per_boundary_edge() {
@@ -705,7 +849,8 @@ mod tests {
 
     #[test]
     fn the_fair_territory_mesh_reaches_the_wall_in_every_direction() {
-        let mesh = fair_territory_mesh(32);
+        let park = Park::default();
+        let mesh = fair_territory_mesh(&park, 32);
         let positions = mesh
             .attribute(Mesh::ATTRIBUTE_POSITION)
             .expect("positions")
@@ -715,7 +860,7 @@ mod tests {
         // Skip the apex at home plate; every other vertex should be on the wall.
         for point in positions.iter().skip(1) {
             let spot = Vec2::new(point[0], point[1]);
-            let expected = field::fence_distance(field::spray_angle(spot));
+            let expected = field::fence_distance(&park, field::spray_angle(spot));
             assert!(
                 (spot.length() - expected).abs() < 0.5,
                 "vertex at {spot:?} is {} from home, wall is {expected}",
@@ -738,10 +883,15 @@ mod tests {
         // The dirt arc is drawn at a fixed radius; if it ever reached the wall the
         // outfield would vanish underneath it.
         let dirt = field::INFIELD_DIRT_RADIUS + 32.0;
-        let nearest_wall = field::fence_distance(field::FOUL_LINE_ANGLE);
-        assert!(
-            dirt < nearest_wall,
-            "infield dirt at {dirt} reaches the {nearest_wall} wall"
-        );
+        for (key, _) in PRESETS {
+            let park = Park::preset(key).expect("presets load");
+            let nearest_wall = field::fence_distance(&park, field::FOUL_LINE_ANGLE)
+                .min(field::fence_distance(&park, -field::FOUL_LINE_ANGLE));
+            assert!(
+                dirt < nearest_wall,
+                "infield dirt at {dirt} reaches the {nearest_wall} wall in {}",
+                park.name
+            );
+        }
     }
 }
@@ -1,17 +1,45 @@
-//! Full-screen furniture: the title, the pause overlay, the card between innings,
-//! and the box score at the end.
+//! Full-screen furniture: the title, the season's standings and leaders, the
+//! pause overlay, the card between innings, the box score at the end, and the
+//! caption over a replay.
 //!
 //! All of it is `bevy_ui`, pinned to the HUD camera so it sits on top of whichever
 //! view happens to be showing.
 
 use baseball_game_rules::{GameOutcome, GameWinner, InningHalf, LineScore};
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 
-use super::{Banner, Diamond, Fonts, GameScoped, PLAYER_HALF, Paused, Phase, PhaseTimer, theme};
+use super::{
+    Banner, Diamond, Fonts, GameScoped, Paused, Phase, PhaseTimer, Seat, Seats, controls, flow,
+    league::{self, CLUBS, Matchup},
+    lineup::Lineups,
+    replay::{Mode, Playback, Recording},
+    season::{self, Category, Season},
+    staff::Staffs,
+    theme,
+};
 
 #[derive(Debug, Component)]
 pub struct TitleUi;
 
+#[derive(Debug, Component)]
+pub struct SeasonUi;
+
+/// Which page of the season screen is up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub enum SeasonPage {
+    #[default]
+    Standings,
+    Leaders,
+}
+
+/// One of the sides on offer on the title screen.
+#[derive(Debug, Component)]
+pub struct TeamOption(pub Seats);
+
+/// The line under the team select that spells out what the choice means.
+#[derive(Debug, Component)]
+pub struct TeamBlurb;
+
 #[derive(Debug, Component)]
 pub struct PauseUi;
 
@@ -21,6 +49,16 @@ pub struct InningUi;
 #[derive(Debug, Component)]
 pub struct GameOverUi;
 
+#[derive(Debug, Component)]
+pub struct ReplayUi;
+
+/// The lines of the replay caption that change as it plays.
+#[derive(Debug, Component)]
+pub struct ReplayCaption;
+
+#[derive(Debug, Component)]
+pub struct ReplayHelp;
+
 /// Text that fades in and out to draw the eye.
 #[derive(Debug, Component)]
 pub struct Blinker;
@@ -56,9 +94,49 @@ fn overlay(wash: f32) -> impl Bundle {
     )
 }
 
+/// Everything a new game between two clubs starts from, so the title, the final
+/// screen and the season all set one up the same way.
+#[derive(SystemParam)]
+pub struct NewGame<'w> {
+    matchup: ResMut<'w, Matchup>,
+    diamond: ResMut<'w, Diamond>,
+    staffs: ResMut<'w, Staffs>,
+    lineups: ResMut<'w, Lineups>,
+    recording: ResMut<'w, Recording>,
+}
+
+impl NewGame<'_> {
+    fn start(&mut self, matchup: Matchup) {
+        *self.matchup = matchup;
+        *self.diamond = Diamond::between(matchup);
+        *self.staffs = Staffs::between(matchup);
+        *self.lineups = Lineups::between(matchup);
+        *self.recording = Recording::default();
+    }
+}
+
 // ------------------------------------------------------------------ title
 
-pub fn spawn_title(mut commands: Commands, fonts: Res<Fonts>) {
+/// The sides on the title screen, left to right.
+const SIDES: [(Seats, &str); 4] = [
+    (Seats::solo(InningHalf::Top), "AWAY"),
+    (Seats::solo(InningHalf::Bottom), "HOME"),
+    (Seats::HOT_SEAT, "2 PLAYERS"),
+    (Seats::WATCH, "WATCH"),
+];
+
+fn side_blurb(side: Seats) -> &'static str {
+    match (side.away, side.home) {
+        (Some(_), Some(_)) => "PLAYER 1 IS AWAY, PLAYER 2 IS HOME",
+        (Some(_), None) => "YOU BAT IN THE TOP HALF",
+        (None, Some(_)) => "YOU BAT IN THE BOTTOM HALF",
+        (None, None) => "THE AI PLAYS BOTH SIDES",
+    }
+}
+
+pub fn spawn_title(mut commands: Commands, fonts: Res<Fonts>, seats: Res<Seats>) {
+    let bold = fonts.bold.clone();
+    let chosen = *seats;
     commands.spawn((
         overlay(0.82),
         TitleUi,
@@ -70,19 +148,54 @@ pub fn spawn_title(mut commands: Commands, fonts: Res<Fonts>) {
                 18.0,
                 theme::TEXT_DIM
             ),
+            // Team select.
             (
                 Node {
-                    margin: UiRect::top(Val::Px(28.0)),
+                    margin: UiRect::top(Val::Px(24.0)),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(28.0),
+                    ..default()
+                },
+                Children::spawn(bevy::ecs::spawn::SpawnIter(SIDES.into_iter().map(
+                    move |(side, name)| {
+                        let colour = if side == chosen {
+                            theme::BUG_ACCENT
+                        } else {
+                            theme::TEXT_DIM
+                        };
+                        (text(name, bold.clone(), 22.0, colour), TeamOption(side))
+                    }
+                ))),
+            ),
+            (
+                text(side_blurb(chosen), fonts.medium.clone(), 15.0, theme::BUG_ACCENT),
+                TeamBlurb,
+            ),
+            (
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
                     flex_direction: FlexDirection::Column,
                     row_gap: Val::Px(4.0),
                     ..default()
                 },
                 children![
                     text(
-                        "YOU BAT IN THE BOTTOM HALF",
+                        "  LEFT / RIGHT  pick a side",
+                        fonts.medium.clone(),
+                        13.0,
+                        theme::TEXT_DIM
+                    ),
+                    text(
+                        "  S             play a season against the league",
                         fonts.medium.clone(),
-                        15.0,
-                        theme::BUG_ACCENT
+                        13.0,
+                        theme::TEXT_DIM
+                    ),
+                    text(
+                        "  TWO PLAYERS   P1 on W A S D and SPACE, P2 on the arrows and ENTER",
+                        fonts.medium.clone(),
+                        13.0,
+                        theme::TEXT_DIM
                     ),
                     text("BATTING", fonts.bold.clone(), 14.0, theme::TEXT),
                     text("  SPACE      swing", fonts.medium.clone(), 13.0, theme::TEXT_DIM),
@@ -101,6 +214,12 @@ pub fn spawn_title(mut commands: Commands, fonts: Res<Fonts>) {
                     ),
                     text("  ARROWS     aim", fonts.medium.clone(), 13.0, theme::TEXT_DIM),
                     text("  SPACE      throw", fonts.medium.clone(), 13.0, theme::TEXT_DIM),
+                    text(
+                        "  B          call the bullpen between batters",
+                        fonts.medium.clone(),
+                        13.0,
+                        theme::TEXT_DIM
+                    ),
                     text("  ESC        pause", fonts.medium.clone(), 13.0, theme::TEXT_DIM),
                 ],
             ),
@@ -116,10 +235,320 @@ pub fn spawn_title(mut commands: Commands, fonts: Res<Fonts>) {
     ));
 }
 
-pub fn title_input(keys: Res<ButtonInput<KeyCode>>, mut diamond: ResMut<Diamond>, mut next: ResMut<NextState<Phase>>) {
-    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space) {
-        *diamond = Diamond::default();
+/// Left and right move along the sides; the choice sticks between games, so
+/// `T` from the final screen comes back to whatever was picked last. An
+/// exhibition is always the first two clubs; `S` opens the season instead, with
+/// any games it left behind played first.
+pub fn title_input(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut seats: ResMut<Seats>,
+    mut game: NewGame,
+    rest: Option<Res<season::RestOfDay>>,
+    mut next: ResMut<NextState<Phase>>,
+) {
+    let pressed = |key, button| keys.just_pressed(key) || controls::any_pad_pressed(&gamepads, button);
+    let current = SIDES.iter().position(|(side, _)| *side == *seats).unwrap_or(1);
+    if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+        *seats = SIDES[(current + SIDES.len() - 1) % SIDES.len()].0;
+    } else if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+        *seats = SIDES[(current + 1) % SIDES.len()].0;
+    }
+
+    if pressed(KeyCode::Enter, GamepadButton::Start) || pressed(KeyCode::Space, GamepadButton::South) {
+        game.start(Matchup::default());
         next.set(Phase::Windup);
+    } else if pressed(KeyCode::KeyS, GamepadButton::North) {
+        let mut season = season::open();
+        if rest.is_none() {
+            season::catch_up(&mut season);
+        }
+        commands.insert_resource(season);
+        next.set(Phase::Season);
+    }
+}
+
+/// Highlights the chosen side and rewrites the line under it.
+pub fn sync_team_choice(
+    seats: Res<Seats>,
+    mut options: Query<(&TeamOption, &mut TextColor)>,
+    mut blurb: Query<&mut Text, With<TeamBlurb>>,
+) {
+    if !seats.is_changed() {
+        return;
+    }
+    for (option, mut colour) in options.iter_mut() {
+        colour.0 = if option.0 == *seats {
+            theme::BUG_ACCENT
+        } else {
+            theme::TEXT_DIM
+        };
+    }
+    if let Ok(mut text) = blurb.single_mut() {
+        **text = side_blurb(*seats).to_string();
+    }
+}
+
+// ------------------------------------------------------------------ season
+
+/// Rows of fixed-width cells, the first a dim header. `lit` picks out one row
+/// in the accent colour.
+fn table(rows: Vec<Vec<String>>, widths: &'static [f32], fonts: &Fonts, lit: Option<usize>) -> impl Bundle {
+    let medium = fonts.medium.clone();
+    let bold = fonts.bold.clone();
+    (
+        Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(3.0),
+            ..default()
+        },
+        Children::spawn(bevy::ecs::spawn::SpawnIter(rows.into_iter().enumerate().map(
+            move |(index, cells)| {
+                let (font, colour) = match index {
+                    0 => (medium.clone(), theme::TEXT_DIM),
+                    _ if Some(index) == lit => (bold.clone(), theme::BUG_ACCENT),
+                    _ => (bold.clone(), theme::TEXT),
+                };
+                (
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    },
+                    Children::spawn(bevy::ecs::spawn::SpawnIter(cells.into_iter().zip(widths).map(
+                        move |(value, &width)| {
+                            (
+                                text(value, font.clone(), 14.0, colour),
+                                Node {
+                                    width: Val::Px(width),
+                                    ..default()
+                                },
+                            )
+                        },
+                    ))),
+                )
+            },
+        ))),
+    )
+}
+
+/// The standings, with the person's club lit.
+fn standings_table(season: &Season, fonts: &Fonts) -> impl Bundle {
+    let header = ["", "W", "L", "PCT", "GB", "RS", "RA"].map(String::from).to_vec();
+    let standings = season.standings();
+    let lit = standings.iter().position(|standing| standing.club == season.club);
+    let rows = standings.into_iter().map(|standing| {
+        let record = standing.record;
+        let games_back = if standing.games_back > 0.0 {
+            format!("{:.1}", standing.games_back)
+        } else {
+            "-".to_string()
+        };
+        vec![
+            CLUBS[standing.club].name.to_string(),
+            record.wins.to_string(),
+            record.losses.to_string(),
+            season::rate(record.percentage()),
+            games_back,
+            record.runs_scored.to_string(),
+            record.runs_allowed.to_string(),
+        ]
+    });
+    let rows = std::iter::once(header).chain(rows).collect();
+    table(
+        rows,
+        &[150.0, 40.0, 40.0, 64.0, 50.0, 44.0, 44.0],
+        fonts,
+        lit.map(|row| row + 1),
+    )
+}
+
+/// The top five in every category, side by side.
+fn leaders_board(season: &Season, fonts: &Fonts) -> impl Bundle {
+    let boards: Vec<_> = Category::ALL
+        .into_iter()
+        .map(|category| {
+            let header = vec![category.label().to_string(), String::new(), String::new()];
+            let mut rows = vec![header];
+            rows.extend(season.leaders(category, 5).into_iter().map(|leader| {
+                vec![
+                    league::name(leader.player).unwrap_or("?").to_string(),
+                    CLUBS[league::club_of(leader.player)].short.to_string(),
+                    category.show(leader.value),
+                ]
+            }));
+            if rows.len() == 1 {
+                rows.push(vec!["-".to_string(), String::new(), String::new()]);
+            }
+            table(rows, &[150.0, 44.0, 56.0], fonts, None)
+        })
+        .collect();
+    (
+        Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            max_width: Val::Px(860.0),
+            column_gap: Val::Px(36.0),
+            row_gap: Val::Px(18.0),
+            ..default()
+        },
+        Children::spawn(bevy::ecs::spawn::SpawnIter(boards.into_iter())),
+    )
+}
+
+/// What the top of the season screen says: whose season it is, and how far in.
+fn season_headline(season: &Season) -> (String, String) {
+    let club = &CLUBS[season.club];
+    let record = season.record(season.club);
+    if season.is_over() {
+        let champion = CLUBS[season.standings()[0].club].name;
+        return (
+            "FINAL STANDINGS".to_string(),
+            format!(
+                "{champion} WIN THE PENNANT    {} {}-{}",
+                club.name, record.wins, record.losses
+            ),
+        );
+    }
+    let next = season.next_game().map(|index| season.fixtures[index]);
+    let detail = match next {
+        Some(fixture) => format!(
+            "DAY {} OF {}    {}-{}    NEXT: {} AT {}",
+            fixture.day + 1,
+            season.days(),
+            record.wins,
+            record.losses,
+            CLUBS[fixture.away].name,
+            CLUBS[fixture.home].name,
+        ),
+        None => String::new(),
+    };
+    let name = if season.has_begun() {
+        club.name.to_string()
+    } else {
+        format!("<  {}  >", club.name)
+    };
+    (name, detail)
+}
+
+fn season_prompt(season: &Season, page: SeasonPage) -> String {
+    let pick = if season.has_begun() {
+        ""
+    } else {
+        "LEFT / RIGHT pick a club    "
+    };
+    let play = if season.is_over() {
+        "ENTER new season"
+    } else {
+        "ENTER play ball"
+    };
+    let flip = match page {
+        SeasonPage::Standings => "L leaders",
+        SeasonPage::Leaders => "L standings",
+    };
+    format!("{pick}{play}    {flip}    T for the title")
+}
+
+/// Redraws the season screen whenever the season or the page changes. There is
+/// little enough on it that starting again is simpler than patching it up.
+pub fn sync_season_screen(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    season: Res<Season>,
+    page: Res<SeasonPage>,
+    existing: Query<Entity, With<SeasonUi>>,
+) {
+    if !existing.is_empty() && !season.is_changed() && !page.is_changed() {
+        return;
+    }
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    let (headline, detail) = season_headline(&season);
+    let root = commands
+        .spawn((
+            overlay(0.9),
+            SeasonUi,
+            children![
+                text(headline, fonts.bold.clone(), 48.0, theme::TEXT),
+                text(detail, fonts.medium.clone(), 16.0, theme::BUG_ACCENT),
+            ],
+        ))
+        .id();
+    let margin = Node {
+        margin: UiRect::top(Val::Px(18.0)),
+        ..default()
+    };
+    match *page {
+        SeasonPage::Standings => {
+            commands.spawn((standings_table(&season, &fonts), ChildOf(root)));
+        }
+        SeasonPage::Leaders => {
+            commands.spawn((leaders_board(&season, &fonts), ChildOf(root)));
+        }
+    }
+    commands.spawn((
+        text(
+            season_prompt(&season, *page),
+            fonts.medium.clone(),
+            16.0,
+            theme::TEXT_DIM,
+        ),
+        margin,
+        Blinker,
+        ChildOf(root),
+    ));
+}
+
+/// Left and right choose the club until the first game is played. Enter plays
+/// the next game with the person on their club's side, once every earlier game
+/// is in, or starts over once the season is done.
+pub fn season_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut season: ResMut<Season>,
+    mut page: ResMut<SeasonPage>,
+    mut seats: ResMut<Seats>,
+    mut game: NewGame,
+    rest: Option<Res<season::RestOfDay>>,
+    mut next: ResMut<NextState<Phase>>,
+) {
+    let pressed = |key, button| keys.just_pressed(key) || controls::any_pad_pressed(&gamepads, button);
+    if !season.has_begun() {
+        if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+            season.club = (season.club + CLUBS.len() - 1) % CLUBS.len();
+        } else if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+            season.club = (season.club + 1) % CLUBS.len();
+        }
+    }
+
+    if pressed(KeyCode::KeyL, GamepadButton::West) {
+        *page = match *page {
+            SeasonPage::Standings => SeasonPage::Leaders,
+            SeasonPage::Leaders => SeasonPage::Standings,
+        };
+    } else if pressed(KeyCode::KeyT, GamepadButton::Select) {
+        next.set(Phase::Title);
+    } else if pressed(KeyCode::Enter, GamepadButton::Start) || pressed(KeyCode::Space, GamepadButton::South) {
+        if season.is_over() {
+            *season = Season::new(season.club, rand::random());
+        } else if let Some(index) = season.next_game() {
+            if rest.is_none() {
+                season::catch_up(&mut season);
+            }
+            let fixture = season.fixtures[index];
+            let half = if fixture.home == season.club {
+                InningHalf::Bottom
+            } else {
+                InningHalf::Top
+            };
+            *seats = Seats::solo(half);
+            game.start(fixture.matchup());
+            season.playing = Some(index);
+            next.set(Phase::Windup);
+        }
     }
 }
 
@@ -159,25 +588,39 @@ pub fn spawn_inning_card(
     mut commands: Commands,
     fonts: Res<Fonts>,
     diamond: Res<Diamond>,
+    seats: Res<Seats>,
     mut timer: ResMut<PhaseTimer>,
 ) {
     timer.set(super::flow::INNING_DWELL);
 
-    let (headline, detail) = match diamond.game() {
+    let (headline, detail, handover) = match diamond.game() {
         Some(game) => {
             let half = match game.current_half_inning().half() {
                 InningHalf::Top => "TOP",
                 InningHalf::Bottom => "BOTTOM",
             };
             let inning = ordinal(game.current_inning().as_number());
-            let side = if game.current_half_inning().half() == PLAYER_HALF {
-                "YOU'RE UP"
-            } else {
-                "TAKE THE MOUND"
+            let side = match (seats.batter(&diamond), seats.pitcher(&diamond)) {
+                (Some(batter), Some(pitcher)) => {
+                    format!("PLAYER {} BATS, PLAYER {} PITCHES", batter.number(), pitcher.number())
+                }
+                (Some(_), None) => "YOU'RE UP".to_string(),
+                (None, Some(_)) => "TAKE THE MOUND".to_string(),
+                (None, None) => match game.current_half_inning().half() {
+                    InningHalf::Top => "AWAY BATS".to_string(),
+                    InningHalf::Bottom => "HOME BATS".to_string(),
+                },
             };
-            (format!("{half} {inning}"), side.to_string())
+            (format!("{half} {inning}"), side, seats.is_two_player())
         }
-        None => ("FINAL".to_string(), String::new()),
+        None => ("FINAL".to_string(), String::new(), false),
+    };
+
+    // Two people swap from bat to ball here, and the card waits until they have.
+    let prompt = if handover {
+        "PASS THE CONTROLS    SPACE, ENTER OR A WHEN READY"
+    } else {
+        ""
     };
 
     commands.spawn((
@@ -186,6 +629,14 @@ pub fn spawn_inning_card(
         children![
             text(headline, fonts.bold.clone(), 52.0, theme::TEXT),
             text(detail, fonts.medium.clone(), 20.0, theme::BUG_ACCENT),
+            (
+                text(prompt, fonts.medium.clone(), 16.0, theme::TEXT_DIM),
+                Node {
+                    margin: UiRect::top(Val::Px(18.0)),
+                    ..default()
+                },
+                Blinker,
+            ),
         ],
     ));
 }
@@ -202,7 +653,14 @@ fn ordinal(number: u8) -> String {
 
 // ------------------------------------------------------------------ game over
 
-pub fn spawn_game_over(mut commands: Commands, fonts: Res<Fonts>, diamond: Res<Diamond>) {
+pub fn spawn_game_over(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    diamond: Res<Diamond>,
+    seats: Res<Seats>,
+    matchup: Res<Matchup>,
+    season: Option<Res<Season>>,
+) {
     let summary = match &diamond.outcome {
         GameOutcome::Complete(summary) => summary,
         // Reaching game over without a completed game should not happen, but a
@@ -218,10 +676,22 @@ pub fn spawn_game_over(mut commands: Commands, fonts: Res<Fonts>, diamond: Res<D
     };
 
     let score = summary.final_score();
-    let verdict = match summary.winner() {
-        GameWinner::Home if PLAYER_HALF == InningHalf::Bottom => "YOU WIN",
-        GameWinner::Away if PLAYER_HALF == InningHalf::Top => "YOU WIN",
-        _ => "YOU LOSE",
+    let verdict = verdict(summary.winner(), *seats);
+    let names = [CLUBS[matchup.away].short, CLUBS[matchup.home].short];
+    // In a season the record so far goes under the score, and the way on is back
+    // to the standings rather than straight into another game.
+    let (record, prompt) = match season {
+        Some(season) => {
+            let record = season.record(season.club);
+            (
+                format!("{} {}-{}", CLUBS[season.club].name, record.wins, record.losses),
+                "ENTER back to the season    V to watch it again    T for the title",
+            )
+        }
+        None => (
+            String::new(),
+            "R to play again    V to watch it again    T for the title",
+        ),
     };
 
     commands.spawn((
@@ -230,19 +700,15 @@ pub fn spawn_game_over(mut commands: Commands, fonts: Res<Fonts>, diamond: Res<D
         children![
             text(verdict, fonts.bold.clone(), 62.0, theme::BUG_ACCENT),
             text(
-                format!("AWAY {}    HOME {}", score.away(), score.home()),
+                format!("{} {}    {} {}", names[0], score.away(), names[1], score.home()),
                 fonts.bold.clone(),
                 26.0,
                 theme::TEXT
             ),
-            line_score_grid(summary.line_score(), &fonts, score.away(), score.home()),
+            text(record, fonts.medium.clone(), 16.0, theme::BUG_ACCENT),
+            line_score_grid(summary.line_score(), &fonts, names, score.away(), score.home()),
             (
-                text(
-                    "R to play again    T for the title",
-                    fonts.medium.clone(),
-                    16.0,
-                    theme::TEXT_DIM
-                ),
+                text(prompt, fonts.medium.clone(), 16.0, theme::TEXT_DIM),
                 Node {
                     margin: UiRect::top(Val::Px(22.0)),
                     ..default()
@@ -253,29 +719,58 @@ pub fn spawn_game_over(mut commands: Commands, fonts: Res<Fonts>, diamond: Res<D
     ));
 }
 
+/// The headline over the final score: whose game it was, from the point of view
+/// of whoever was playing.
+fn verdict(winner: GameWinner, seats: Seats) -> &'static str {
+    let (won, lost) = match winner {
+        GameWinner::Tie => return "TIE GAME",
+        GameWinner::Away => (seats.away, seats.home),
+        GameWinner::Home => (seats.home, seats.away),
+    };
+    match (won, lost) {
+        (Some(Seat::One), Some(_)) => "PLAYER 1 WINS",
+        (Some(Seat::Two), Some(_)) => "PLAYER 2 WINS",
+        (Some(_), None) => "YOU WIN",
+        (None, Some(_)) => "YOU LOSE",
+        (None, None) if matches!(winner, GameWinner::Home) => "HOME WINS",
+        (None, None) => "AWAY WINS",
+    }
+}
+
 /// The line score, laid out as a grid of inning columns like a real box score.
-fn line_score_grid(line: &LineScore, fonts: &Fonts, away_total: u8, home_total: u8) -> impl Bundle {
+fn line_score_grid(
+    line: &LineScore,
+    fonts: &Fonts,
+    [away, home]: [&str; 2],
+    away_total: u8,
+    home_total: u8,
+) -> impl Bundle {
     let columns = line.columns();
 
-    // Header row, then one row per team: name, each inning, runs, hits.
-    let rows: Vec<(String, Vec<String>, String, String)> = vec![
+    // Header row, then one row per team: name, each inning, runs, hits, errors.
+    let rows: Vec<(String, Vec<String>, [String; 3])> = vec![
         (
             String::new(),
             (1..=columns).map(|inning| inning.to_string()).collect(),
-            "R".to_string(),
-            "H".to_string(),
+            ["R".to_string(), "H".to_string(), "E".to_string()],
         ),
         (
-            "AWAY".to_string(),
+            away.to_string(),
             cells(line.away_innings(), columns),
-            away_total.to_string(),
-            line.away_hits().to_string(),
+            [
+                away_total.to_string(),
+                line.away_hits().to_string(),
+                line.away_errors().to_string(),
+            ],
         ),
         (
-            "HOME".to_string(),
+            home.to_string(),
             cells(line.home_innings(), columns),
-            home_total.to_string(),
-            line.home_hits().to_string(),
+            [
+                home_total.to_string(),
+                line.home_hits().to_string(),
+                line.home_errors().to_string(),
+            ],
         ),
     ];
 
@@ -290,15 +785,14 @@ fn line_score_grid(line: &LineScore, fonts: &Fonts, away_total: u8, home_total:
             ..default()
         },
         Children::spawn(bevy::ecs::spawn::SpawnIter(rows.into_iter().enumerate().map(
-            move |(index, (name, per_inning, runs, hits))| {
+            move |(index, (name, per_inning, totals))| {
                 let is_header = index == 0;
                 let colour = if is_header { theme::TEXT_DIM } else { theme::TEXT };
                 let font = if is_header { medium.clone() } else { bold.clone() };
-                let mut cells: Vec<String> = Vec::with_capacity(per_inning.len() + 3);
+                let mut cells: Vec<String> = Vec::with_capacity(per_inning.len() + 4);
                 cells.push(name);
                 cells.extend(per_inning);
-                cells.push(runs);
-                cells.push(hits);
+                cells.extend(totals);
 
                 (
                     Node {
@@ -350,17 +844,100 @@ fn cells(runs: &[u8], columns: usize) -> Vec<String> {
 
 pub fn game_over_input(
     keys: Res<ButtonInput<KeyCode>>,
-    mut diamond: ResMut<Diamond>,
+    gamepads: Query<&Gamepad>,
+    season: Option<Res<Season>>,
+    mut game: NewGame,
+    mut playback: ResMut<Playback>,
     mut next: ResMut<NextState<Phase>>,
 ) {
-    if keys.just_pressed(KeyCode::KeyR) {
-        *diamond = Diamond::default();
+    let pressed = |key, button| keys.just_pressed(key) || controls::any_pad_pressed(&gamepads, button);
+    let again = pressed(KeyCode::KeyR, GamepadButton::South);
+    if season.is_some() && (again || pressed(KeyCode::Enter, GamepadButton::Start)) {
+        next.set(Phase::Season);
+    } else if again {
+        let matchup = *game.matchup;
+        game.start(matchup);
         next.set(Phase::Windup);
-    } else if keys.just_pressed(KeyCode::KeyT) {
+    } else if pressed(KeyCode::KeyV, GamepadButton::West) {
+        if let Some(whole_game) = Playback::whole_game(&game.recording) {
+            *playback = whole_game;
+            next.set(Phase::Replay);
+        }
+    } else if pressed(KeyCode::KeyT, GamepadButton::Select) {
         next.set(Phase::Title);
     }
 }
 
+// ------------------------------------------------------------------ replay
+
+/// A strip across the top of the screen, leaving the play itself in view.
+pub fn spawn_replay_overlay(mut commands: Commands, fonts: Res<Fonts>) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            top: Val::Px(0.0),
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(2.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.7)),
+        ReplayUi,
+        children![
+            (text("", fonts.bold.clone(), 20.0, theme::BUG_ACCENT), ReplayCaption),
+            (text("", fonts.medium.clone(), 13.0, theme::TEXT_DIM), ReplayHelp),
+        ],
+    ));
+}
+
+pub fn update_replay_caption(
+    recording: Res<Recording>,
+    playback: Res<Playback>,
+    mut caption: Query<&mut Text, (With<ReplayCaption>, Without<ReplayHelp>)>,
+    mut help: Query<&mut Text, (With<ReplayHelp>, Without<ReplayCaption>)>,
+) {
+    // Only touched when the words change, so the layout is not redone every frame.
+    let (headline, hint) = replay_caption(&recording, &playback);
+    if let Ok(mut text) = caption.single_mut() {
+        text.set_if_neq(Text::new(headline));
+    }
+    if let Ok(mut text) = help.single_mut() {
+        text.set_if_neq(Text::new(hint));
+    }
+}
+
+/// What the replay strip says: which pitch this is and what came of it, and
+/// which keys do what.
+fn replay_caption(recording: &Recording, playback: &Playback) -> (String, &'static str) {
+    match playback.mode {
+        Some(Mode::Instant { .. }) => ("INSTANT REPLAY".to_string(), "ANY BUTTON TO SKIP"),
+        Some(Mode::Game) => {
+            let Some(record) = recording.pitches.get(playback.index) else {
+                return ("REPLAY".to_string(), "");
+            };
+            let half = match record.half {
+                InningHalf::Top => "TOP",
+                InningHalf::Bottom => "BOTTOM",
+            };
+            let (outcome, _) = flow::describe(record.outcome);
+            let paused = if playback.paused { "    PAUSED" } else { "" };
+            (
+                format!(
+                    "REPLAY    {half} {}    {}    PITCH {} OF {}    {outcome}{paused}",
+                    ordinal(record.inning),
+                    record.batter,
+                    playback.index + 1,
+                    recording.pitches.len(),
+                ),
+                "SPACE pause    LEFT / RIGHT scrub    UP / DOWN pitch    PG UP / PG DN ball in play    ESC back",
+            )
+        }
+        None => (String::new(), ""),
+    }
+}
+
 // ------------------------------------------------------------------ banner
 
 /// The big word in the middle of the screen after a pitch.
@@ -421,10 +998,16 @@ mod tests {
     /// exists. Every test here would have caught that.
     fn harness() -> World {
         let mut world = World::new();
+        world.insert_resource(Matchup::default());
         world.insert_resource(Diamond::default());
+        world.insert_resource(Seats::default());
+        world.insert_resource(Staffs::default());
+        world.insert_resource(Lineups::default());
         world.insert_resource(Paused::default());
         world.insert_resource(Banner::default());
         world.insert_resource(PhaseTimer::default());
+        world.insert_resource(Recording::default());
+        world.insert_resource(Playback::default());
         world.insert_resource(Fonts {
             bold: Handle::default(),
             medium: Handle::default(),
@@ -540,6 +1123,184 @@ mod tests {
         );
     }
 
+    #[test]
+    fn a_spectator_is_told_who_won_rather_than_whether_they_did() {
+        assert_eq!(verdict(GameWinner::Home, Seats::WATCH), "HOME WINS");
+        assert_eq!(verdict(GameWinner::Away, Seats::WATCH), "AWAY WINS");
+        assert_eq!(verdict(GameWinner::Tie, Seats::WATCH), "TIE GAME");
+
+        let away = Seats::solo(InningHalf::Top);
+        assert_eq!(verdict(GameWinner::Away, away), "YOU WIN");
+        assert_eq!(verdict(GameWinner::Home, away), "YOU LOSE");
+    }
+
+    #[test]
+    fn two_players_are_told_which_of_them_won() {
+        assert_eq!(verdict(GameWinner::Away, Seats::HOT_SEAT), "PLAYER 1 WINS");
+        assert_eq!(verdict(GameWinner::Home, Seats::HOT_SEAT), "PLAYER 2 WINS");
+    }
+
+    #[test]
+    fn the_inning_card_asks_two_players_to_swap() {
+        let mut world = harness();
+        world.insert_resource(Seats::HOT_SEAT);
+        world.run_system_once(spawn_inning_card).expect("system should run");
+
+        let mut query = world.query::<&Text>();
+        let shown: Vec<String> = query.iter(&world).map(|text| text.0.clone()).collect();
+        assert!(
+            shown.iter().any(|line| line == "PLAYER 1 BATS, PLAYER 2 PITCHES"),
+            "{shown:?}"
+        );
+        assert!(
+            shown.iter().any(|line| line.starts_with("PASS THE CONTROLS")),
+            "{shown:?}"
+        );
+    }
+
+    #[test]
+    fn left_and_right_walk_round_the_sides_on_the_title() {
+        let mut world = harness();
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(NextState::<Phase>::default());
+        world.run_system_once(spawn_title).expect("system should run");
+
+        let press = |world: &mut World, key: KeyCode| {
+            let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+            keys.reset_all();
+            keys.press(key);
+            world.run_system_once(title_input).expect("system should run");
+            world.run_system_once(sync_team_choice).expect("system should run");
+            *world.resource::<Seats>()
+        };
+
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), Seats::HOT_SEAT);
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), Seats::WATCH);
+        assert_eq!(press(&mut world, KeyCode::ArrowRight), Seats::solo(InningHalf::Top));
+        assert_eq!(press(&mut world, KeyCode::ArrowLeft), Seats::WATCH);
+
+        let mut query = world.query_filtered::<&Text, With<TeamBlurb>>();
+        assert_eq!(query.single(&world).expect("one blurb").0, "THE AI PLAYS BOTH SIDES");
+    }
+
+    #[test]
+    fn there_is_nothing_to_watch_again_until_something_is_recorded() {
+        let mut world = harness();
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(NextState::<Phase>::default());
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyV);
+        world.run_system_once(game_over_input).expect("system should run");
+
+        assert!(world.resource::<Playback>().mode.is_none());
+        assert!(matches!(world.resource::<NextState<Phase>>(), NextState::Unchanged));
+    }
+
+    /// Presses one key and runs the season screen's input and redraw.
+    fn press_in_season(world: &mut World, key: KeyCode) {
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.reset_all();
+        keys.press(key);
+        world.run_system_once(season_input).expect("system should run");
+        world.run_system_once(sync_season_screen).expect("system should run");
+    }
+
+    fn season_harness(season: Season) -> World {
+        let mut world = harness();
+        world.insert_resource(ButtonInput::<KeyCode>::default());
+        world.insert_resource(NextState::<Phase>::default());
+        world.insert_resource(SeasonPage::default());
+        world.insert_resource(season);
+        world.run_system_once(sync_season_screen).expect("system should run");
+        world
+    }
+
+    fn shown(world: &mut World) -> Vec<String> {
+        let mut query = world.query::<&Text>();
+        query.iter(world).map(|text| text.0.clone()).collect()
+    }
+
+    #[test]
+    fn the_season_screen_flips_between_the_standings_and_the_leaders() {
+        let mut world = season_harness(Season::new(0, 0));
+        assert_eq!(count::<SeasonUi>(&mut world), 1);
+        assert!(
+            shown(&mut world).iter().any(|line| line == "PCT"),
+            "{:?}",
+            shown(&mut world)
+        );
+
+        press_in_season(&mut world, KeyCode::KeyL);
+        assert_eq!(count::<SeasonUi>(&mut world), 1, "the old page was left behind");
+        assert!(
+            shown(&mut world).iter().any(|line| line == "ERA"),
+            "{:?}",
+            shown(&mut world)
+        );
+    }
+
+    #[test]
+    fn the_club_can_only_be_changed_before_opening_day() {
+        let mut world = season_harness(Season::new(0, 0));
+        press_in_season(&mut world, KeyCode::ArrowLeft);
+        assert_eq!(world.resource::<Season>().club, CLUBS.len() - 1);
+
+        world.resource_mut::<Season>().fixtures[0].result = Some(season::Final { away: 1, home: 0 });
+        press_in_season(&mut world, KeyCode::ArrowRight);
+        assert_eq!(world.resource::<Season>().club, CLUBS.len() - 1);
+    }
+
+    #[test]
+    fn a_season_game_puts_the_person_on_their_clubs_side() {
+        let mut world = season_harness(Season::new(2, 0));
+        press_in_season(&mut world, KeyCode::Enter);
+
+        let season = world.resource::<Season>();
+        let index = season.playing.expect("a game is under way");
+        let fixture = season.fixtures[index];
+        assert!(fixture.involves(2));
+        assert_eq!(*world.resource::<Matchup>(), fixture.matchup());
+        let seats = *world.resource::<Seats>();
+        let ours = if fixture.home == 2 { seats.home } else { seats.away };
+        assert_eq!(ours, Some(Seat::One));
+        assert!(!seats.is_two_player());
+    }
+
+    #[test]
+    fn a_season_game_ends_with_the_clubs_by_name_and_the_way_back_to_the_standings() {
+        let mut world = season_harness(Season::new(0, 0));
+        world.insert_resource(Matchup { away: 4, home: 0 });
+        world.insert_resource(Diamond {
+            outcome: finished_game(3, 2),
+        });
+        world.run_system_once(spawn_game_over).expect("system should run");
+
+        let shown = shown(&mut world);
+        assert!(shown.iter().any(|line| line == "MIN 3    RIV 2"), "{shown:?}");
+        assert!(
+            shown.iter().any(|line| line.starts_with("ENTER back to the season")),
+            "{shown:?}"
+        );
+
+        world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        world.run_system_once(game_over_input).expect("system should run");
+        assert!(matches!(
+            world.resource::<NextState<Phase>>(),
+            NextState::Pending(Phase::Season)
+        ));
+    }
+
+    #[test]
+    fn the_replay_strip_spawns_and_names_the_instant_replay() {
+        let mut world = harness();
+        world.resource_mut::<Playback>().mode = Some(Mode::Instant { then: Phase::Windup });
+        world.run_system_once(spawn_replay_overlay).expect("system should run");
+        world.run_system_once(update_replay_caption).expect("system should run");
+
+        assert_eq!(count::<ReplayUi>(&mut world), 1);
+        let mut query = world.query_filtered::<&Text, With<ReplayCaption>>();
+        assert_eq!(query.single(&world).expect("one caption").0, "INSTANT REPLAY");
+    }
+
     #[test]
     fn ordinals_read_correctly() {
         assert_eq!(ordinal(1), "1ST");
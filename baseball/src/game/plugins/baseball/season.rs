@@ -0,0 +1,910 @@
+//! A season: a schedule against the rest of the league, the standings, and every
+//! player's numbers added up across it.
+//!
+//! The person picks a club and plays its games; everyone else's games that day
+//! are played headlessly, in the background, once theirs is over. After every
+//! game day the whole season is written to disk, so it picks up where it left
+//! off next time the game is started. The file is JSON in a versioned wrapper,
+//! like the rules engine's `SavedGame`: a later layout adds a variant, and old
+//! saves still load.
+
+use std::{
+    cmp::Ordering,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use baseball_game_rules::{BattingLine, Decision, GameOutcome, GameSummary, InningHalf, PitchingLine, PlayerId, Runs};
+use bevy::{
+    prelude::*,
+    tasks::{AsyncComputeTaskPool, Task, block_on, futures::check_ready},
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Diamond,
+    headless::{self, Stalled},
+    league::{self, CLUBS, Matchup},
+};
+
+/// Times through the round robin: every club hosts every other twice.
+const LAPS: usize = 4;
+
+/// Where the season is saved unless `BASEBALL_SAVE` says otherwise.
+const SAVE_FILE: &str = "baseball-season.json";
+
+/// Seeds a headless game is tried from before it is called off.
+const ATTEMPTS: u64 = 3;
+
+/// Plate appearances per team game to qualify for the batting title...
+const QUALIFYING_PA: f64 = 3.1;
+/// ...and outs per team game for the ERA title: an inning a game.
+const QUALIFYING_OUTS: u32 = 3;
+
+/// One game on the schedule, and its score once it has been played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub day: usize,
+    pub away: usize,
+    pub home: usize,
+    pub result: Option<Final>,
+}
+
+/// A game that never finished is called off and kept as 0-0, which counts for
+/// neither club.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Final {
+    pub away: Runs,
+    pub home: Runs,
+}
+
+impl Fixture {
+    pub fn matchup(&self) -> Matchup {
+        Matchup {
+            away: self.away,
+            home: self.home,
+        }
+    }
+
+    pub fn involves(&self, club: usize) -> bool {
+        self.away == club || self.home == club
+    }
+}
+
+/// Every club plays every day. The circle method pairs them off: club 0 stays
+/// put and the rest turn one place a round, so after `clubs - 1` rounds everyone
+/// has met once. Home and away alternate down the pairs and flip on every other
+/// lap, which leaves each pair two games at each park.
+fn schedule() -> Vec<Fixture> {
+    let clubs = CLUBS.len();
+    let rounds = clubs - 1;
+    let mut fixtures = Vec::with_capacity(rounds * LAPS * clubs / 2);
+    for day in 0..rounds * LAPS {
+        let round = day % rounds;
+        let flipped = (day / rounds) % 2 == 1;
+        let seat = |place: usize| match place {
+            0 => 0,
+            _ => 1 + (place - 1 + round) % rounds,
+        };
+        for pair in 0..clubs / 2 {
+            let (one, other) = (seat(pair), seat(clubs - 1 - pair));
+            let (away, home) = if (round + pair).is_multiple_of(2) == flipped {
+                (one, other)
+            } else {
+                (other, one)
+            };
+            fixtures.push(Fixture {
+                day,
+                away,
+                home,
+                result: None,
+            });
+        }
+    }
+    fixtures
+}
+
+/// A hitter's season so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Batting {
+    pub player: PlayerId,
+    pub games: u32,
+    pub plate_appearances: u32,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub runs_batted_in: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+}
+
+impl Batting {
+    fn new(player: PlayerId) -> Self {
+        Batting {
+            player,
+            games: 0,
+            plate_appearances: 0,
+            at_bats: 0,
+            runs: 0,
+            hits: 0,
+            doubles: 0,
+            triples: 0,
+            home_runs: 0,
+            runs_batted_in: 0,
+            walks: 0,
+            strikeouts: 0,
+        }
+    }
+
+    fn add(&mut self, line: &BattingLine) {
+        self.games += 1;
+        self.plate_appearances += u32::from(line.plate_appearances);
+        self.at_bats += u32::from(line.at_bats);
+        self.runs += u32::from(line.runs);
+        self.hits += u32::from(line.hits);
+        self.doubles += u32::from(line.doubles);
+        self.triples += u32::from(line.triples);
+        self.home_runs += u32::from(line.home_runs);
+        self.runs_batted_in += u32::from(line.runs_batted_in);
+        self.walks += u32::from(line.walks);
+        self.strikeouts += u32::from(line.strikeouts);
+    }
+
+    pub fn average(&self) -> f64 {
+        match self.at_bats {
+            0 => 0.0,
+            at_bats => f64::from(self.hits) / f64::from(at_bats),
+        }
+    }
+}
+
+/// A pitcher's season so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pitching {
+    pub player: PlayerId,
+    pub games: u32,
+    pub outs_recorded: u32,
+    pub hits: u32,
+    pub walks: u32,
+    pub strikeouts: u32,
+    pub runs: u32,
+    pub earned_runs: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub saves: u32,
+}
+
+impl Pitching {
+    fn new(player: PlayerId) -> Self {
+        Pitching {
+            player,
+            games: 0,
+            outs_recorded: 0,
+            hits: 0,
+            walks: 0,
+            strikeouts: 0,
+            runs: 0,
+            earned_runs: 0,
+            wins: 0,
+            losses: 0,
+            saves: 0,
+        }
+    }
+
+    fn add(&mut self, line: &PitchingLine) {
+        self.games += 1;
+        self.outs_recorded += u32::from(line.outs_recorded);
+        self.hits += u32::from(line.hits);
+        self.walks += u32::from(line.walks);
+        self.strikeouts += u32::from(line.strikeouts);
+        self.runs += u32::from(line.runs);
+        self.earned_runs += u32::from(line.earned_runs);
+        match line.decision {
+            Some(Decision::Win) => self.wins += 1,
+            Some(Decision::Loss) => self.losses += 1,
+            Some(Decision::Save) => self.saves += 1,
+            None => {}
+        }
+    }
+
+    /// Earned runs per nine innings.
+    pub fn era(&self) -> f64 {
+        match self.outs_recorded {
+            0 => 0.0,
+            outs => 27.0 * f64::from(self.earned_runs) / f64::from(outs),
+        }
+    }
+}
+
+/// A club's won-lost record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Record {
+    pub wins: u32,
+    pub losses: u32,
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+}
+
+impl Record {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses
+    }
+
+    pub fn percentage(&self) -> f64 {
+        match self.games() {
+            0 => 0.0,
+            games => f64::from(self.wins) / f64::from(games),
+        }
+    }
+
+    pub fn differential(&self) -> i64 {
+        i64::from(self.runs_scored) - i64::from(self.runs_allowed)
+    }
+
+    fn record(&mut self, scored: Runs, allowed: Runs) {
+        self.runs_scored += u32::from(scored);
+        self.runs_allowed += u32::from(allowed);
+        // Extra innings are played out, so the only tie is a game called off.
+        match scored.cmp(&allowed) {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Less => self.losses += 1,
+            Ordering::Equal => {}
+        }
+    }
+}
+
+/// One line of the standings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Standing {
+    pub club: usize,
+    pub record: Record,
+    /// Behind the leader, where a game is a win for one and a loss for the other.
+    pub games_back: f64,
+}
+
+/// The columns on the leaders page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Average,
+    HomeRuns,
+    RunsBattedIn,
+    EarnedRunAverage,
+    Strikeouts,
+    Wins,
+}
+
+impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::Average,
+        Category::HomeRuns,
+        Category::RunsBattedIn,
+        Category::EarnedRunAverage,
+        Category::Strikeouts,
+        Category::Wins,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::Average => "AVG",
+            Category::HomeRuns => "HR",
+            Category::RunsBattedIn => "RBI",
+            Category::EarnedRunAverage => "ERA",
+            Category::Strikeouts => "SO",
+            Category::Wins => "W",
+        }
+    }
+
+    /// The number as a box score prints it: `.312`, `3.45`, `17`.
+    pub fn show(self, value: f64) -> String {
+        match self {
+            Category::Average => rate(value),
+            Category::EarnedRunAverage => format!("{value:.2}"),
+            _ => format!("{value:.0}"),
+        }
+    }
+
+    /// Whether the lowest number leads, as it does for ERA.
+    fn lowest_first(self) -> bool {
+        matches!(self, Category::EarnedRunAverage)
+    }
+}
+
+/// A rate the way baseball prints one: `.312`, or `1.000` for a perfect one.
+pub fn rate(value: f64) -> String {
+    let shown = format!("{value:.3}");
+    match shown.strip_prefix('0') {
+        Some(rest) => rest.to_string(),
+        None => shown,
+    }
+}
+
+/// One name on the leaders page.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Leader {
+    pub player: PlayerId,
+    pub value: f64,
+}
+
+/// A season in progress, or finished.
+#[derive(Debug, Clone, PartialEq, Resource, Serialize, Deserialize)]
+pub struct Season {
+    /// The club the person plays, as an index into [`CLUBS`].
+    pub club: usize,
+    /// Seeds the games the person is not in.
+    pub seed: u64,
+    pub fixtures: Vec<Fixture>,
+    pub batting: Vec<Batting>,
+    pub pitching: Vec<Pitching>,
+    /// The fixture being played right now, if any. Not saved: a game abandoned
+    /// halfway is simply played again.
+    #[serde(skip)]
+    pub playing: Option<usize>,
+}
+
+impl Season {
+    pub fn new(club: usize, seed: u64) -> Self {
+        Season {
+            club,
+            seed,
+            fixtures: schedule(),
+            batting: Vec::new(),
+            pitching: Vec::new(),
+            playing: None,
+        }
+    }
+
+    /// The person's next game, as an index into the fixtures.
+    pub fn next_game(&self) -> Option<usize> {
+        self.fixtures
+            .iter()
+            .position(|fixture| fixture.result.is_none() && fixture.involves(self.club))
+    }
+
+    /// Whether a ball has been thrown yet. The club is only up for grabs before.
+    pub fn has_begun(&self) -> bool {
+        self.fixtures.iter().any(|fixture| fixture.result.is_some())
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.fixtures.iter().all(|fixture| fixture.result.is_some())
+    }
+
+    pub fn days(&self) -> usize {
+        self.fixtures.last().map_or(0, |fixture| fixture.day + 1)
+    }
+
+    /// Writes a finished game into the schedule and its box score into
+    /// everyone's season totals.
+    pub fn bank(&mut self, index: usize, summary: &GameSummary) {
+        let score = summary.final_score();
+        self.fixtures[index].result = Some(Final {
+            away: score.away(),
+            home: score.home(),
+        });
+        for half in [InningHalf::Top, InningHalf::Bottom] {
+            let team = summary.box_score().team(half);
+            for line in team.batting() {
+                if let Some(player) = line.player {
+                    self.batting_mut(player).add(line);
+                }
+            }
+            for line in team.pitching() {
+                if let Some(player) = line.player {
+                    self.pitching_mut(player).add(line);
+                }
+            }
+        }
+    }
+
+    /// The day's other games, the ones on the same day as `index` that have not
+    /// been played yet.
+    pub fn rest_of_day(&self, index: usize) -> Vec<(usize, Matchup, u64)> {
+        let day = self.fixtures[index].day;
+        self.unplayed(|fixture| fixture.day == day)
+    }
+
+    /// The games left behind on a day before the person's next one, or anywhere
+    /// once the person's games are done: a day cut short by quitting, say.
+    pub fn overdue(&self) -> Vec<(usize, Matchup, u64)> {
+        let today = self.next_game().map_or(usize::MAX, |index| self.fixtures[index].day);
+        self.unplayed(|fixture| fixture.day < today)
+    }
+
+    /// Unplayed fixtures that pass `filter`. Each is seeded from the season and
+    /// its place in the schedule, so the same season always turns out the same
+    /// way.
+    fn unplayed(&self, filter: impl Fn(&Fixture) -> bool) -> Vec<(usize, Matchup, u64)> {
+        (0..self.fixtures.len())
+            .filter(|&index| self.fixtures[index].result.is_none() && filter(&self.fixtures[index]))
+            .map(|index| (index, self.fixtures[index].matchup(), self.seed ^ index as u64))
+            .collect()
+    }
+
+    /// Banks the games [`play_day`] played. One that stalled on every seed is
+    /// called off, so it cannot hold up the end of the season; one banked in
+    /// the meantime is not banked twice.
+    fn bank_day(&mut self, played: Vec<(usize, Result<GameSummary, Stalled>)>) {
+        for (index, result) in played {
+            if self.fixtures[index].result.is_some() {
+                continue;
+            }
+            match result {
+                Ok(summary) => self.bank(index, &summary),
+                Err(stalled) => {
+                    let fixture = self.fixtures[index];
+                    warn!(
+                        "{} at {}: {stalled}; called off",
+                        CLUBS[fixture.away].short, CLUBS[fixture.home].short
+                    );
+                    self.fixtures[index].result = Some(Final { away: 0, home: 0 });
+                }
+            }
+        }
+    }
+
+    fn batting_mut(&mut self, player: PlayerId) -> &mut Batting {
+        let at = if let Some(at) = self.batting.iter().position(|line| line.player == player) {
+            at
+        } else {
+            self.batting.push(Batting::new(player));
+            self.batting.len() - 1
+        };
+        &mut self.batting[at]
+    }
+
+    fn pitching_mut(&mut self, player: PlayerId) -> &mut Pitching {
+        let at = if let Some(at) = self.pitching.iter().position(|line| line.player == player) {
+            at
+        } else {
+            self.pitching.push(Pitching::new(player));
+            self.pitching.len() - 1
+        };
+        &mut self.pitching[at]
+    }
+
+    pub fn record(&self, club: usize) -> Record {
+        let mut record = Record::default();
+        for fixture in &self.fixtures {
+            let Some(result) = fixture.result else {
+                continue;
+            };
+            if fixture.away == club {
+                record.record(result.away, result.home);
+            } else if fixture.home == club {
+                record.record(result.home, result.away);
+            }
+        }
+        record
+    }
+
+    /// First place to last. Ties go to the better run differential.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut order: Vec<(usize, Record)> = (0..CLUBS.len()).map(|club| (club, self.record(club))).collect();
+        order.sort_by(|(_, a), (_, b)| {
+            b.percentage()
+                .total_cmp(&a.percentage())
+                .then_with(|| b.differential().cmp(&a.differential()))
+        });
+        let (_, leader) = order[0];
+        let games_back = |record: &Record| {
+            let behind =
+                i64::from(leader.wins) - i64::from(record.wins) + i64::from(record.losses) - i64::from(leader.losses);
+            behind as f64 / 2.0
+        };
+        order
+            .into_iter()
+            .map(|(club, record)| Standing {
+                club,
+                record,
+                games_back: games_back(&record),
+            })
+            .collect()
+    }
+
+    /// The best `count` in a category. Rate stats only count players with enough
+    /// playing time for their club's games so far, and nobody leads a counting
+    /// stat with none.
+    pub fn leaders(&self, category: Category, count: usize) -> Vec<Leader> {
+        let team_games = |player: PlayerId| f64::from(self.record(league::club_of(player)).games());
+        let mut leaders: Vec<Leader> = match category {
+            Category::Average => self
+                .batting
+                .iter()
+                .filter(|line| f64::from(line.plate_appearances) >= QUALIFYING_PA * team_games(line.player))
+                .filter(|line| line.plate_appearances > 0)
+                .map(|line| Leader {
+                    player: line.player,
+                    value: line.average(),
+                })
+                .collect(),
+            Category::HomeRuns | Category::RunsBattedIn => self
+                .batting
+                .iter()
+                .map(|line| Leader {
+                    player: line.player,
+                    value: f64::from(match category {
+                        Category::HomeRuns => line.home_runs,
+                        _ => line.runs_batted_in,
+                    }),
+                })
+                .filter(|leader| leader.value > 0.0)
+                .collect(),
+            Category::EarnedRunAverage => self
+                .pitching
+                .iter()
+                .filter(|line| f64::from(line.outs_recorded) >= f64::from(QUALIFYING_OUTS) * team_games(line.player))
+                .filter(|line| line.outs_recorded > 0)
+                .map(|line| Leader {
+                    player: line.player,
+                    value: line.era(),
+                })
+                .collect(),
+            Category::Strikeouts | Category::Wins => self
+                .pitching
+                .iter()
+                .map(|line| Leader {
+                    player: line.player,
+                    value: f64::from(match category {
+                        Category::Strikeouts => line.strikeouts,
+                        _ => line.wins,
+                    }),
+                })
+                .filter(|leader| leader.value > 0.0)
+                .collect(),
+        };
+        leaders.sort_by(|a, b| {
+            let order = if category.lowest_first() {
+                a.value.total_cmp(&b.value)
+            } else {
+                b.value.total_cmp(&a.value)
+            };
+            order.then_with(|| a.player.cmp(&b.player))
+        });
+        leaders.truncate(count);
+        leaders
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&SavedSeason::V1(self.clone()))?;
+        // Written alongside and moved into place, so a crash mid-write cannot
+        // leave half a season behind.
+        let partial = path.with_extension("partial");
+        fs::write(&partial, json)?;
+        fs::rename(partial, path)
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let saved: SavedSeason = serde_json::from_str(&json)?;
+        Ok(saved.into_season())
+    }
+}
+
+/// The save file's format. Add a variant for a new layout and convert it in
+/// [`SavedSeason::into_season`]; never change an existing one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "version", content = "season")]
+enum SavedSeason {
+    #[serde(rename = "1")]
+    V1(Season),
+}
+
+impl SavedSeason {
+    fn into_season(self) -> Season {
+        match self {
+            SavedSeason::V1(season) => season,
+        }
+    }
+}
+
+/// `BASEBALL_SAVE`, or a file in the working directory.
+pub fn save_path() -> PathBuf {
+    std::env::var_os("BASEBALL_SAVE").map_or_else(|| PathBuf::from(SAVE_FILE), PathBuf::from)
+}
+
+/// The season on disk, or a new one if there is none. A file that will not load
+/// is moved aside to `.bad`, so a new season saved over it cannot destroy it, and
+/// reported rather than stopping the game.
+pub fn open() -> Season {
+    let path = save_path();
+    match Season::load(&path) {
+        Ok(season) => season,
+        Err(error) => {
+            if error.kind() != io::ErrorKind::NotFound {
+                let mut aside = path.clone().into_os_string();
+                aside.push(".bad");
+                let aside = PathBuf::from(aside);
+                match fs::rename(&path, &aside) {
+                    Ok(()) => warn!(
+                        "could not load the season from {}: {error}; kept it as {} and starting a new one",
+                        path.display(),
+                        aside.display()
+                    ),
+                    Err(moving) => warn!(
+                        "could not load the season from {}: {error}, nor move it aside: {moving}; starting a new one",
+                        path.display()
+                    ),
+                }
+            }
+            Season::new(Matchup::default().home, rand::random())
+        }
+    }
+}
+
+/// Plays the [`Season::overdue`] games there and then, and saves if there were
+/// any. Only worth calling with no [`RestOfDay`] under way, since that day's
+/// games are overdue until it comes in.
+pub fn catch_up(season: &mut Season) {
+    let games = season.overdue();
+    if games.is_empty() {
+        return;
+    }
+    season.bank_day(play_day(games));
+    save(season);
+}
+
+/// Plays `games` with nobody watching, each from its seed, and from another if
+/// it stalls.
+fn play_day(games: Vec<(usize, Matchup, u64)>) -> Vec<(usize, Result<GameSummary, Stalled>)> {
+    games
+        .into_iter()
+        .map(|(index, matchup, seed)| {
+            let result = (0..ATTEMPTS)
+                .map(|attempt| headless::play_matchup(matchup, seed ^ (attempt << 32)))
+                .find(Result::is_ok)
+                .unwrap_or(Err(Stalled));
+            (index, result)
+        })
+        .collect()
+}
+
+/// The rest of a season day, being played on another thread. `seed` is the
+/// season's, so the results are never banked into a different season.
+#[derive(Resource)]
+pub struct RestOfDay {
+    seed: u64,
+    task: Task<Vec<(usize, Result<GameSummary, Stalled>)>>,
+}
+
+fn save(season: &Season) {
+    let path = save_path();
+    if let Err(error) = season.save(&path) {
+        warn!("could not save the season to {}: {error}", path.display());
+    }
+}
+
+/// Banks the game the person just finished, saves, and starts the rest of the
+/// day playing in the background so the final score goes up straight away.
+pub fn bank_season_game(
+    mut commands: Commands,
+    diamond: Res<Diamond>,
+    mut season: ResMut<Season>,
+    earlier: Option<ResMut<RestOfDay>>,
+) {
+    let (Some(index), GameOutcome::Complete(summary)) = (season.playing.take(), &diamond.outcome) else {
+        return;
+    };
+    // A day still being played from before is waited for, not dropped.
+    if let Some(mut earlier) = earlier
+        && earlier.seed == season.seed
+    {
+        season.bank_day(block_on(&mut earlier.task));
+    }
+    season.bank(index, summary);
+    save(&season);
+
+    let games = season.rest_of_day(index);
+    commands.insert_resource(RestOfDay {
+        seed: season.seed,
+        task: AsyncComputeTaskPool::get().spawn(async move { play_day(games) }),
+    });
+}
+
+/// Banks the rest of the day once it has been played, and saves. A season left
+/// for the title in the meantime is loaded back from disk to take them; a new
+/// season started in the meantime has no use for them.
+pub fn finish_rest_of_day(mut commands: Commands, mut rest: ResMut<RestOfDay>, season: Option<ResMut<Season>>) {
+    let Some(played) = check_ready(&mut rest.task) else {
+        return;
+    };
+    commands.remove_resource::<RestOfDay>();
+
+    let mut closed = None;
+    let season = match season {
+        Some(season) => season.into_inner(),
+        None => closed.insert(open()),
+    };
+    if season.seed == rest.seed {
+        season.bank_day(played);
+        save(season);
+    }
+}
+
+/// Leaving for the title leaves the season; it is on disk, and the title loads
+/// it again.
+pub fn close_season(mut commands: Commands) {
+    commands.remove_resource::<Season>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_club_plays_every_day_and_each_pair_twice_at_each_park() {
+        let fixtures = schedule();
+        let days = (CLUBS.len() - 1) * LAPS;
+        for day in 0..days {
+            let mut seen: Vec<usize> = fixtures
+                .iter()
+                .filter(|fixture| fixture.day == day)
+                .flat_map(|fixture| [fixture.away, fixture.home])
+                .collect();
+            seen.sort_unstable();
+            assert_eq!(seen, (0..CLUBS.len()).collect::<Vec<_>>(), "day {day}");
+        }
+        for away in 0..CLUBS.len() {
+            for home in (0..CLUBS.len()).filter(|&home| home != away) {
+                let hosted = fixtures
+                    .iter()
+                    .filter(|fixture| fixture.away == away && fixture.home == home)
+                    .count();
+                assert_eq!(hosted, LAPS / 2, "{away} at {home}");
+            }
+        }
+    }
+
+    #[test]
+    fn a_banked_game_counts_in_the_standings_and_the_stat_lines() {
+        let mut season = Season::new(0, 1);
+        let index = season.next_game().expect("a new season has games to play");
+        let summary = headless::play_matchup(season.fixtures[index].matchup(), 2).expect("finishes");
+        season.bank(index, &summary);
+
+        let fixture = season.fixtures[index];
+        let (winner, loser) = if summary.final_score().away() > summary.final_score().home() {
+            (fixture.away, fixture.home)
+        } else {
+            (fixture.home, fixture.away)
+        };
+        assert_eq!(season.record(winner).wins, 1);
+        assert_eq!(season.record(loser).losses, 1);
+        assert_eq!(season.standings()[0].club, winner);
+        assert!(season.standings()[0].games_back.abs() < 1e-9);
+
+        let hits: u32 = season.batting.iter().map(|line| line.hits).sum();
+        let allowed: u32 = season.pitching.iter().map(|line| line.hits).sum();
+        assert_eq!(hits, allowed, "every hit is off some pitcher");
+        assert_eq!(season.batting.len(), 18, "both nines");
+        let wins: u32 = season.pitching.iter().map(|line| line.wins).sum();
+        assert_eq!(wins, 1);
+        assert!(season.has_begun());
+        assert_ne!(season.next_game(), Some(index));
+    }
+
+    #[test]
+    fn the_rest_of_the_day_is_played_and_the_next_day_is_not() {
+        let mut season = Season::new(3, 5);
+        let index = season.next_game().expect("a new season has games to play");
+        season.bank_day(play_day(season.rest_of_day(index)));
+
+        let day = season.fixtures[index].day;
+        for fixture in &season.fixtures {
+            assert_eq!(fixture.result.is_some(), fixture.day == day, "{fixture:?}");
+        }
+    }
+
+    #[test]
+    fn games_left_behind_on_an_earlier_day_are_caught_up_and_the_season_ends() {
+        let mut season = Season::new(1, 7);
+        let last = season.days() - 1;
+        let behind = |fixture: &Fixture| fixture.day == 4 && !fixture.involves(1);
+        for fixture in &mut season.fixtures {
+            if !behind(fixture) && fixture.day != last {
+                fixture.result = Some(Final { away: 2, home: 1 });
+            }
+        }
+        let left: Vec<usize> = (0..season.fixtures.len())
+            .filter(|&index| behind(&season.fixtures[index]))
+            .collect();
+
+        let overdue: Vec<usize> = season.overdue().into_iter().map(|(index, _, _)| index).collect();
+        assert_eq!(overdue, left, "the last day is still to come");
+        season.bank_day(play_day(season.overdue()));
+        assert!(season.overdue().is_empty());
+
+        let index = season.next_game().expect("the last day");
+        season.fixtures[index].result = Some(Final { away: 3, home: 0 });
+        let stalled = season
+            .rest_of_day(index)
+            .into_iter()
+            .map(|(index, _, _)| (index, Err(Stalled)));
+        season.bank_day(stalled.collect());
+        assert!(season.is_over(), "a game that never finished is called off");
+    }
+
+    #[test]
+    fn a_season_saved_to_disk_loads_back_exactly() {
+        let mut season = Season::new(2, 9);
+        season.fixtures[0].result = Some(Final { away: 4, home: 3 });
+        season.batting.push(Batting {
+            hits: 2,
+            ..Batting::new(league::hitter_id(2, 0))
+        });
+        season.playing = Some(0);
+
+        let path = std::env::temp_dir().join(format!("baseball-season-{}.json", std::process::id()));
+        season.save(&path).expect("saves");
+        let loaded = Season::load(&path).expect("loads");
+        fs::remove_file(&path).expect("cleans up");
+
+        assert_eq!(loaded.playing, None, "a game in progress is not saved");
+        season.playing = None;
+        assert_eq!(loaded, season);
+    }
+
+    #[test]
+    fn the_save_file_names_its_version() {
+        let json = serde_json::to_value(SavedSeason::V1(Season::new(0, 0))).expect("serializes");
+        assert_eq!(json["version"], "1");
+        assert_eq!(json["season"]["club"], 0);
+    }
+
+    #[test]
+    fn leaders_need_playing_time_for_a_rate_and_something_to_count() {
+        let mut season = Season::new(0, 0);
+        // Club 0 has played twice, so 6.2 plate appearances qualify.
+        season.fixtures[0].result = Some(Final { away: 1, home: 0 });
+        season.fixtures[3].result = Some(Final { away: 1, home: 0 });
+        assert_eq!(season.record(0).games(), 2);
+
+        let regular = league::hitter_id(0, 0);
+        let cameo = league::hitter_id(0, 1);
+        season.batting.push(Batting {
+            plate_appearances: 8,
+            at_bats: 8,
+            hits: 3,
+            home_runs: 1,
+            ..Batting::new(regular)
+        });
+        season.batting.push(Batting {
+            plate_appearances: 1,
+            at_bats: 1,
+            hits: 1,
+            ..Batting::new(cameo)
+        });
+
+        let average = season.leaders(Category::Average, 5);
+        assert_eq!(average.len(), 1, "one at-bat does not win a batting title");
+        assert_eq!(average[0].player, regular);
+        assert_eq!(Category::Average.show(average[0].value), ".375");
+
+        let homers = season.leaders(Category::HomeRuns, 5);
+        assert_eq!(homers.iter().map(|leader| leader.player).collect::<Vec<_>>(), [regular]);
+    }
+
+    #[test]
+    fn the_lowest_era_leads() {
+        let mut season = Season::new(0, 0);
+        season.fixtures[0].result = Some(Final { away: 1, home: 0 });
+        let (ace, other) = (league::pitcher_id(0, 0), league::pitcher_id(0, 1));
+        season.pitching.push(Pitching {
+            outs_recorded: 27,
+            earned_runs: 4,
+            ..Pitching::new(other)
+        });
+        season.pitching.push(Pitching {
+            outs_recorded: 27,
+            earned_runs: 1,
+            ..Pitching::new(ace)
+        });
+
+        let leaders = season.leaders(Category::EarnedRunAverage, 5);
+        assert_eq!(leaders[0].player, ace);
+        assert_eq!(Category::EarnedRunAverage.show(leaders[0].value), "1.00");
+        assert_eq!(Category::EarnedRunAverage.show(leaders[1].value), "4.00");
+    }
+}
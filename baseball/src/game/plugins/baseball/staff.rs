@@ -0,0 +1,364 @@
+//! The arms: who is on the mound, what he throws, and how much he has left.
+//!
+//! A [`Pitcher`] turns the pitch the player (or the AI) asked for into the one
+//! that actually comes out of his hand. His ratings set how hard he throws, how
+//! much his breaking stuff moves and how close to the glove it finishes; his pitch
+//! count wears all three down. Somewhere past the fifth inning a starter is
+//! missing by a foot and losing miles an hour, and the bullpen is the answer.
+
+use baseball_game_rules::InningHalf;
+use bevy::prelude::*;
+
+use super::{
+    Diamond, RandomSource,
+    league::{CLUBS, Matchup},
+    pitch::{self, PitchKind, PitchPlan},
+};
+
+/// The fastball speed [`PitchKind::speed`] is written for. A pitcher's velocity
+/// rating moves every pitch in his repertoire by the difference.
+const REFERENCE_VELOCITY: f32 = 94.0;
+
+/// Fatigue starts this far into a pitcher's stamina, as a fraction of it...
+const FATIGUE_ONSET: f32 = 0.7;
+/// ...and is total this much further on.
+const FATIGUE_RAMP: f32 = 0.6;
+
+/// What a completely spent arm loses.
+const TIRED_SPEED_LOSS: f32 = 5.0;
+const TIRED_MOVEMENT_LOSS: f32 = 0.25;
+
+/// How far a pitch strays from the target, in feet, before any fatigue: even
+/// perfect control is not perfect, and poor control adds to it.
+const MISS_FLOOR: f32 = 0.10;
+const MISS_PER_CONTROL: f32 = 0.40;
+const MISS_TIRED: f32 = 0.50;
+
+/// How tired the AI lets its pitcher get before going to the bullpen.
+const AI_HOOK: f32 = 0.55;
+
+/// What a pitcher is, as opposed to how he is doing today.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ratings {
+    pub name: &'static str,
+    /// Fastball speed when fresh, in miles per hour.
+    pub velocity: f32,
+    /// `0.0` to `1.0`: how near the target his pitches finish.
+    pub control: f32,
+    /// Scales the break on every pitch he throws; `1.0` is average.
+    pub movement: f32,
+    /// Pitches he can throw before he starts to tire in earnest.
+    pub stamina: u16,
+    /// The pitches he has. Always starts with his fastball.
+    pub repertoire: &'static [PitchKind],
+}
+
+/// A pitcher and his workload in this game.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pitcher {
+    pub ratings: Ratings,
+    pub pitches: u16,
+    /// Whether he has been in the game. Once out, a pitcher cannot come back.
+    pub used: bool,
+}
+
+impl Pitcher {
+    pub fn new(ratings: Ratings) -> Self {
+        Pitcher {
+            ratings,
+            pitches: 0,
+            used: false,
+        }
+    }
+
+    /// `0.0` fresh to `1.0` running on fumes.
+    pub fn fatigue(&self) -> f32 {
+        let workload = f32::from(self.pitches) / f32::from(self.ratings.stamina.max(1));
+        ((workload - FATIGUE_ONSET) / FATIGUE_RAMP).clamp(0.0, 1.0)
+    }
+
+    pub fn throws(&self, kind: PitchKind) -> bool {
+        self.ratings.repertoire.contains(&kind)
+    }
+
+    /// Miles per hour this pitch leaves his hand at.
+    pub fn speed(&self, kind: PitchKind) -> f32 {
+        kind.speed() + (self.ratings.velocity - REFERENCE_VELOCITY) - TIRED_SPEED_LOSS * self.fatigue()
+    }
+
+    /// How far this pitch breaks, in feet, for this arm today.
+    pub fn bend(&self, kind: PitchKind) -> Vec2 {
+        kind.break_vector() * self.ratings.movement * (1.0 - TIRED_MOVEMENT_LOSS * self.fatigue())
+    }
+
+    /// Typical distance between where a pitch is aimed and where it goes, in feet.
+    pub fn miss(&self) -> f32 {
+        MISS_FLOOR + MISS_PER_CONTROL * (1.0 - self.ratings.control) + MISS_TIRED * self.fatigue()
+    }
+
+    /// Where the pitch really goes: the target, give or take his command. The sum
+    /// of two uniforms is a cheap bell curve, so most pitches land near the glove
+    /// and the odd one gets away.
+    pub fn deliver(&self, plan: PitchPlan, rng: &mut RandomSource) -> pitch::LivePitch {
+        let mut wobble = || rng.range(-1.0, 1.0) + rng.range(-1.0, 1.0);
+        let scatter = Vec2::new(wobble(), wobble()) * self.miss();
+        let aimed = PitchPlan {
+            target: plan.target + scatter,
+            ..plan
+        };
+        pitch::LivePitch::thrown_at(aimed, self.speed(plan.kind), self.bend(plan.kind))
+    }
+}
+
+/// One team's pitchers, starter first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Staff {
+    pub pitchers: Vec<Pitcher>,
+    pub on_mound: usize,
+}
+
+impl Staff {
+    pub fn new(arms: &[Ratings]) -> Self {
+        let mut pitchers: Vec<_> = arms.iter().copied().map(Pitcher::new).collect();
+        pitchers[0].used = true;
+        Staff { pitchers, on_mound: 0 }
+    }
+
+    pub fn current(&self) -> &Pitcher {
+        &self.pitchers[self.on_mound]
+    }
+
+    pub fn current_mut(&mut self) -> &mut Pitcher {
+        &mut self.pitchers[self.on_mound]
+    }
+
+    /// The next reliever who has not been in yet, if anyone is left.
+    pub fn next_reliever(&self) -> Option<usize> {
+        self.pitchers.iter().position(|pitcher| !pitcher.used)
+    }
+
+    /// Brings the next reliever in. Returns his name, or `None` if the bullpen is
+    /// empty and the man on the mound has to finish what he started.
+    pub fn call_bullpen(&mut self) -> Option<&'static str> {
+        let next = self.next_reliever()?;
+        self.pitchers[next].used = true;
+        self.on_mound = next;
+        Some(self.current().ratings.name)
+    }
+}
+
+/// Both teams' staffs, reset with every new game.
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Staffs {
+    pub away: Staff,
+    pub home: Staff,
+}
+
+impl Default for Staffs {
+    fn default() -> Self {
+        Staffs::between(Matchup::default())
+    }
+}
+
+impl Staffs {
+    pub fn between(matchup: Matchup) -> Self {
+        Staffs {
+            away: Staff::new(&CLUBS[matchup.away].arms),
+            home: Staff::new(&CLUBS[matchup.home].arms),
+        }
+    }
+
+    /// The staff whose man is pitching right now: the home team's in the top half.
+    pub fn fielding(&self, diamond: &Diamond) -> &Staff {
+        match diamond.batting_half() {
+            Some(InningHalf::Bottom) => &self.away,
+            _ => &self.home,
+        }
+    }
+
+    pub fn fielding_mut(&mut self, diamond: &Diamond) -> &mut Staff {
+        match diamond.batting_half() {
+            Some(InningHalf::Bottom) => &mut self.away,
+            _ => &mut self.home,
+        }
+    }
+}
+
+/// Whether the plate appearance hasn't started yet: no pitch has been thrown to
+/// the batter at the plate. Pitchers are only changed here, by either manager.
+pub fn between_batters(diamond: &Diamond) -> bool {
+    diamond.game().is_some_and(|game| {
+        let count = game.current_half_inning().current_plate_appearance().count();
+        count.balls().as_number() == 0 && count.strikes().as_number() == 0
+    })
+}
+
+/// The AI's manager goes to the bullpen between batters once his pitcher is
+/// tiring, and only if there is someone to bring in.
+pub fn ai_wants_a_change(staff: &Staff, diamond: &Diamond) -> bool {
+    between_batters(diamond) && staff.current().fatigue() >= AI_HOOK && staff.next_reliever().is_some()
+}
+
+pub const ALL: &[PitchKind] = &pitch::PITCH_KINDS;
+pub const HARD: &[PitchKind] = &[PitchKind::Fastball, PitchKind::Slider];
+pub const SOFT: &[PitchKind] = &[PitchKind::Fastball, PitchKind::Curveball, PitchKind::Changeup];
+pub const SINK: &[PitchKind] = &[PitchKind::Fastball, PitchKind::Slider, PitchKind::Changeup];
+
+pub const fn arm(
+    name: &'static str,
+    velocity: f32,
+    control: f32,
+    movement: f32,
+    stamina: u16,
+    repertoire: &'static [PitchKind],
+) -> Ratings {
+    Ratings {
+        name,
+        velocity,
+        control,
+        movement,
+        stamina,
+        repertoire,
+    }
+}
+
+/// A starter who goes deep, two short relievers with a big weapon each, a
+/// finesse arm, and a long man.
+pub const AWAY_STAFF: [Ratings; 5] = [
+    arm("MORALES", 95.0, 0.62, 1.00, 95, ALL),
+    arm("KOWALSKI", 98.0, 0.45, 0.90, 25, HARD),
+    arm("ABERNATHY", 91.0, 0.72, 1.20, 35, SOFT),
+    arm("TANAKA", 93.0, 0.60, 1.10, 30, SINK),
+    arm("REYES", 90.0, 0.68, 1.00, 60, ALL),
+];
+
+pub const HOME_STAFF: [Ratings; 5] = [
+    arm("CALLAHAN", 93.0, 0.70, 1.05, 100, ALL),
+    arm("OKAFOR", 99.0, 0.40, 0.95, 25, HARD),
+    arm("LINDQVIST", 90.0, 0.75, 1.25, 35, SOFT),
+    arm("PRUITT", 94.0, 0.58, 1.05, 30, SINK),
+    arm("VOSS", 91.0, 0.65, 0.95, 60, ALL),
+];
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::PitchOutcome;
+
+    use super::*;
+
+    fn starter() -> Pitcher {
+        Pitcher::new(AWAY_STAFF[0])
+    }
+
+    #[test]
+    fn a_fresh_arm_feels_nothing_and_a_spent_one_feels_everything() {
+        let mut pitcher = starter();
+        assert!(pitcher.fatigue().abs() < f32::EPSILON);
+
+        pitcher.pitches = pitcher.ratings.stamina / 2;
+        assert!(pitcher.fatigue().abs() < f32::EPSILON, "tired after half his stamina");
+
+        pitcher.pitches = pitcher.ratings.stamina * 2;
+        assert!((pitcher.fatigue() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn a_tired_pitcher_throws_slower_and_misses_by_more() {
+        let fresh = starter();
+        let mut tired = starter();
+        tired.pitches = 130;
+
+        assert!(tired.speed(PitchKind::Fastball) < fresh.speed(PitchKind::Fastball) - 2.0);
+        assert!(tired.miss() > fresh.miss() * 1.8);
+        assert!(tired.bend(PitchKind::Curveball).length() < fresh.bend(PitchKind::Curveball).length());
+    }
+
+    #[test]
+    fn the_miss_is_centred_on_the_target_and_grows_with_fatigue() {
+        let mut rng = RandomSource::seeded(11);
+        let plan = PitchPlan::default();
+        let spread = |pitcher: &Pitcher, rng: &mut RandomSource| {
+            let misses: Vec<Vec2> = (0..2000)
+                .map(|_| pitcher.deliver(plan, rng).crossing() - plan.target)
+                .collect();
+            let mean = misses.iter().copied().sum::<Vec2>() / misses.len() as f32;
+            let typical = misses.iter().map(|miss| miss.length()).sum::<f32>() / misses.len() as f32;
+            (mean, typical)
+        };
+
+        let (mean, fresh) = spread(&starter(), &mut rng);
+        assert!(mean.length() < 0.05, "the miss is biased: {mean:?}");
+
+        let mut tired = starter();
+        tired.pitches = 150;
+        let (_, gassed) = spread(&tired, &mut rng);
+        assert!(gassed > fresh * 1.8, "fresh {fresh:.2} ft, gassed {gassed:.2} ft");
+    }
+
+    #[test]
+    fn a_harder_thrower_gets_the_ball_there_sooner() {
+        let soft = Pitcher::new(HOME_STAFF[2]);
+        let hard = Pitcher::new(HOME_STAFF[1]);
+        let mut rng = RandomSource::seeded(3);
+        let plan = PitchPlan::default();
+        assert!(hard.deliver(plan, &mut rng).flight < soft.deliver(plan, &mut rng).flight);
+    }
+
+    #[test]
+    fn every_pitcher_has_a_fastball_and_something_else() {
+        for ratings in AWAY_STAFF.iter().chain(&HOME_STAFF) {
+            assert_eq!(ratings.repertoire[0], PitchKind::Fastball, "{}", ratings.name);
+            assert!(ratings.repertoire.len() >= 2, "{} has one pitch", ratings.name);
+        }
+    }
+
+    #[test]
+    fn the_bullpen_runs_out_and_nobody_comes_back() {
+        let mut staff = Staff::new(&AWAY_STAFF);
+        let mut seen = vec![staff.current().ratings.name];
+        while let Some(name) = staff.call_bullpen() {
+            assert!(!seen.contains(&name), "{name} pitched twice");
+            seen.push(name);
+        }
+        assert_eq!(seen.len(), AWAY_STAFF.len());
+        assert_eq!(
+            staff.current().ratings.name,
+            AWAY_STAFF[4].name,
+            "the last man finishes"
+        );
+    }
+
+    #[test]
+    fn the_ai_only_makes_a_change_between_batters_when_its_man_is_tiring() {
+        let diamond = Diamond::default();
+        let mut staff = Staff::new(&HOME_STAFF);
+        assert!(!ai_wants_a_change(&staff, &diamond));
+
+        staff.current_mut().pitches = 140;
+        assert!(ai_wants_a_change(&staff, &diamond));
+
+        while staff.call_bullpen().is_some() {}
+        staff.current_mut().pitches = 140;
+        assert!(!ai_wants_a_change(&staff, &diamond), "nobody left to bring in");
+    }
+
+    #[test]
+    fn nobody_is_changed_in_the_middle_of_a_plate_appearance() {
+        let mut diamond = Diamond::default();
+        assert!(between_batters(&diamond));
+
+        diamond.outcome = diamond.outcome.clone().advance(PitchOutcome::Ball);
+        assert!(!between_batters(&diamond), "ball one has been thrown");
+
+        let mut staff = Staff::new(&HOME_STAFF);
+        staff.current_mut().pitches = 140;
+        assert!(!ai_wants_a_change(&staff, &diamond));
+    }
+
+    #[test]
+    fn the_home_staff_pitches_the_top_half() {
+        let staffs = Staffs::default();
+        let diamond = Diamond::default();
+        assert_eq!(staffs.fielding(&diamond).current().ratings.name, HOME_STAFF[0].name);
+    }
+}
@@ -148,7 +148,7 @@ pub fn spawn_cameras(mut commands: Commands) {
 }
 
 /// Which of the two scene cameras is live.
-fn activate(field_on: bool, cameras: &mut Query<(&mut Camera, Has<FieldCamera>), Without<HudCamera>>) {
+pub fn activate(field_on: bool, cameras: &mut Query<(&mut Camera, Has<FieldCamera>), Without<HudCamera>>) {
     for (mut camera, is_field) in cameras.iter_mut() {
         camera.is_active = is_field == field_on;
     }
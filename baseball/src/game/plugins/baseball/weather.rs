@@ -0,0 +1,195 @@
+//! The day's weather, and what it does to a ball in the air.
+//!
+//! Warm air and humid air are both thinner than cool dry air, so a ball carries
+//! further through them; the wind pushes it along or holds it up. Neither is much
+//! on its own, but a hot day with the wind blowing out at a small park turns warning
+//! track outs into home runs. The [`Park`] supplies the altitude, and the two are
+//! combined into the [`Air`] the flight is integrated through.
+//!
+//! [`Park`]: super::park::Park
+//! [`Air`]: super::ball::Air
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use super::{RandomSource, ball::MPH_TO_FPS, field, replay::Recording};
+
+/// Air pressure at sea level, in hectopascals.
+const SEA_LEVEL_PRESSURE: f32 = 1013.25;
+
+/// Conditions for one game, settled before the first pitch.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct Weather {
+    /// Degrees Fahrenheit.
+    pub temperature: f32,
+    /// Relative humidity, from `0` to `1`.
+    pub humidity: f32,
+    /// Miles per hour.
+    pub wind_speed: f32,
+    /// The spray angle the wind is blowing toward, in radians: `0` is straight out
+    /// to center field, `±π` straight in toward the plate.
+    pub wind_toward: f32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Weather::STILL
+    }
+}
+
+impl Weather {
+    /// A mild day without a breath of wind: the conditions [`DRAG`] was tuned in.
+    ///
+    /// [`DRAG`]: super::ball::DRAG
+    pub const STILL: Weather = Weather {
+        temperature: 70.0,
+        humidity: 0.5,
+        wind_speed: 0.0,
+        wind_toward: 0.0,
+    };
+
+    /// A day's weather at the ballpark: anything from a cold April night to a
+    /// sticky August afternoon, with a wind more often than not.
+    pub fn forecast(rng: &mut RandomSource) -> Weather {
+        let temperature = rng.range(45.0, 98.0);
+        let humidity = rng.range(0.2, 0.9);
+        let (wind_speed, wind_toward) = if rng.chance(0.2) {
+            (0.0, 0.0)
+        } else {
+            (rng.range(2.0, 18.0), rng.range(-PI, PI))
+        };
+        Weather {
+            temperature,
+            humidity,
+            wind_speed,
+            wind_toward,
+        }
+    }
+
+    /// How thick the air is compared with [`Weather::STILL`] at the same altitude.
+    /// Density goes down as the temperature goes up, and water vapour is lighter
+    /// than the air it displaces, so humidity thins it too — a little.
+    pub fn air_density(self) -> f32 {
+        let absolute = |weather: Weather| {
+            let celsius = (weather.temperature - 32.0) / 1.8;
+            // Saturation vapour pressure over water, in hectopascals (Magnus).
+            let saturation = 6.1094 * (17.625 * celsius / (celsius + 243.04)).exp();
+            let vapour = weather.humidity.clamp(0.0, 1.0) * saturation;
+            (1.0 - 0.378 * vapour / SEA_LEVEL_PRESSURE) / (celsius + 273.15)
+        };
+        absolute(self) / absolute(Weather::STILL)
+    }
+
+    /// The wind's velocity over the field, in feet per second. It blows level.
+    pub fn wind(self) -> Vec3 {
+        field::point_at(self.wind_toward, self.wind_speed * MPH_TO_FPS).extend(0.0)
+    }
+
+    /// The wind the way a broadcast gives it: how hard, and out, in or across.
+    pub fn wind_report(self) -> String {
+        let speed = self.wind_speed.round();
+        if speed < 1.0 {
+            return "WIND CALM".to_string();
+        }
+        let octant = (self.wind_toward / (PI / 4.0)).round() as i32;
+        let direction = match octant {
+            0 => "OUT TO CF",
+            -1 => "OUT TO LF",
+            1 => "OUT TO RF",
+            -2 => "R TO L",
+            2 => "L TO R",
+            -3 => "IN FROM RF",
+            3 => "IN FROM LF",
+            _ => "IN FROM CF",
+        };
+        format!("WIND {speed:.0} {direction}")
+    }
+
+    /// Temperature and humidity, for the HUD.
+    pub fn report(self) -> String {
+        format!("{:.0}°F  {:.0}% HUMIDITY", self.temperature, self.humidity * 100.0)
+    }
+}
+
+/// Draws the weather for a new game. Runs on every windup but only acts before
+/// the first pitch, which both the title screen and the headless loop reach by
+/// starting a fresh recording.
+pub fn forecast_new_game(recording: Res<Recording>, mut rng: ResMut<RandomSource>, mut weather: ResMut<Weather>) {
+    if recording.pitches.is_empty() {
+        *weather = Weather::forecast(&mut rng);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(temperature: f32, humidity: f32) -> Weather {
+        Weather {
+            temperature,
+            humidity,
+            ..Weather::STILL
+        }
+    }
+
+    fn wind(speed: f32, toward_degrees: f32) -> Weather {
+        Weather {
+            wind_speed: speed,
+            wind_toward: toward_degrees.to_radians(),
+            ..Weather::STILL
+        }
+    }
+
+    #[test]
+    fn still_air_is_the_reference() {
+        assert!((Weather::STILL.air_density() - 1.0).abs() < 1e-6);
+        assert_eq!(Weather::STILL.wind(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn hot_air_is_thinner_than_cold_air() {
+        let hot = day(95.0, 0.5).air_density();
+        let cold = day(45.0, 0.5).air_density();
+        assert!(hot < 1.0 && cold > 1.0, "hot {hot}, cold {cold}");
+        // About one percent for every ten degrees, as the gas law has it.
+        assert!((0.08..0.11).contains(&(cold - hot)), "hot {hot}, cold {cold}");
+    }
+
+    #[test]
+    fn humid_air_is_thinner_not_thicker() {
+        let muggy = day(90.0, 0.95).air_density();
+        let dry = day(90.0, 0.05).air_density();
+        assert!(muggy < dry, "humid {muggy} should be thinner than dry {dry}");
+        assert!(dry - muggy < 0.03, "but only by a little: {muggy} against {dry}");
+    }
+
+    #[test]
+    fn a_wind_blowing_out_points_at_the_outfield() {
+        let out = wind(10.0, 0.0).wind();
+        assert!(out.y > 14.0 && out.x.abs() < 1e-3 && out.z == 0.0, "{out:?}");
+        let toward_left = wind(10.0, -45.0).wind();
+        assert!(toward_left.x < 0.0 && toward_left.y > 0.0, "{toward_left:?}");
+    }
+
+    #[test]
+    fn the_wind_report_reads_like_a_broadcast() {
+        assert_eq!(Weather::STILL.wind_report(), "WIND CALM");
+        assert_eq!(wind(12.0, 5.0).wind_report(), "WIND 12 OUT TO CF");
+        assert_eq!(wind(8.0, -40.0).wind_report(), "WIND 8 OUT TO LF");
+        assert_eq!(wind(15.0, 90.0).wind_report(), "WIND 15 L TO R");
+        assert_eq!(wind(6.0, 140.0).wind_report(), "WIND 6 IN FROM LF");
+        assert_eq!(wind(6.0, -178.0).wind_report(), "WIND 6 IN FROM CF");
+    }
+
+    #[test]
+    fn a_forecast_is_plausible_weather_for_a_ballgame() {
+        let mut rng = RandomSource::seeded(19);
+        for _ in 0..200 {
+            let weather = Weather::forecast(&mut rng);
+            assert!((40.0..=100.0).contains(&weather.temperature), "{weather:?}");
+            assert!((0.0..=1.0).contains(&weather.humidity), "{weather:?}");
+            assert!((0.0..=20.0).contains(&weather.wind_speed), "{weather:?}");
+        }
+    }
+}
@@ -1,6 +1,7 @@
+use baseball_game_rules::GameSummary;
 use bevy::{log::LogPlugin, prelude::*, window::WindowResolution};
 
-use crate::game::plugins::baseball::BaseballPlugin;
+use crate::game::plugins::baseball::{BaseballPlugin, HeadlessBaseballPlugin, Played};
 
 pub fn run() {
     tracingx::init_dev();
@@ -34,3 +35,19 @@ pub fn run() {
 
     app.run();
 }
+
+/// Plays `games` whole games with the AI on both sides and no window, and hands
+/// back how each one finished. The same seed plays the same games.
+pub fn play_headless(games: usize, seed: u64) -> Vec<GameSummary> {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, HeadlessBaseballPlugin { seed, ..default() }));
+    app.finish();
+    app.cleanup();
+
+    while app.world().resource::<Played>().games.len() < games {
+        app.update();
+    }
+    let mut played = std::mem::take(&mut app.world_mut().resource_mut::<Played>().games);
+    played.truncate(games);
+    played
+}
@@ -11,4 +11,4 @@
 
 mod game;
 
-pub use game::start::run;
+pub use game::start::{play_headless, run};
@@ -0,0 +1,17 @@
+-- Persistent scrape queue
+-- One row per queued game. The worker moves each game through
+-- pending -> fetched -> parsed -> imported (or failed), so an interrupted
+-- run resumes where it stopped instead of starting over.
+
+CREATE TABLE scrape_jobs (
+    id SERIAL PRIMARY KEY,
+    bbref_game_id VARCHAR(20) UNIQUE NOT NULL,
+    path VARCHAR(100) NOT NULL,
+    state VARCHAR(10) NOT NULL DEFAULT 'pending'
+        CHECK (state IN ('pending', 'fetched', 'parsed', 'imported', 'failed')),
+    last_error TEXT,
+    queued_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_scrape_jobs_state ON scrape_jobs(state);
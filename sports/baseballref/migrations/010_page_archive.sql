@@ -0,0 +1,17 @@
+-- Raw page archive
+-- Every page the scraper fetches, gzipped and keyed by URL path, so games can
+-- be re-parsed after a parser fix without fetching them again. Each distinct
+-- version of a page (by SHA-256 of its body) is stored once.
+
+CREATE TABLE page_archive (
+    id SERIAL PRIMARY KEY,
+    path VARCHAR(100) NOT NULL,
+    bbref_game_id VARCHAR(20),
+    status INTEGER NOT NULL,
+    content_hash CHAR(64) NOT NULL,
+    body BYTEA NOT NULL,
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (path, content_hash)
+);
+
+CREATE INDEX idx_page_archive_game ON page_archive(bbref_game_id, fetched_at DESC);
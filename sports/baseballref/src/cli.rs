@@ -1,12 +1,24 @@
-use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
+use chrono::Datelike;
 use clap::Subcommand;
+use sqlx::PgPool;
 use tracing::{error, info, warn};
 
 use crate::{
-    db::{BoxScoreInserter, FailedScrapesDb, create_pool, run_migrations},
-    parser::BoxScore,
-    scraper::{BoxScoreUrl, ScrapeResult, Scraper, extract_boxscore_urls, extract_boxscore_urls_from_html},
+    db::{
+        BoxScoreInserter, FailedScrapesDb, PageArchiveDb, QueueLock, Rebuild, ReplayDb, ScrapeJob, ScrapeJobsDb,
+        create_pool, imported_game_ids, recent_game_ids, run_migrations,
+    },
+    parser::{BoxScore, ParseError},
+    replay::{ReplayReport, replay, replay_box_score, rules_for},
+    scraper::{
+        ArchivingFetcher, BoxScoreUrl, HttpFetcher, ScrapeResult, Scraper, extract_boxscore_urls,
+        extract_boxscore_urls_from_html,
+    },
 };
 
 /// Extracts the date portion (YYYYMMDD) from a game ID like "CHN202503180".
@@ -84,6 +96,81 @@ fn summarize_results(results: &[ScrapeResult], title: &str) -> ScrapeSummary {
     }
 }
 
+/// The scraper the commands use: every page it fetches goes to the page archive
+type ArchivingScraper<'a> = Scraper<ArchivingFetcher<HttpFetcher, PageArchiveDb<'a>>>;
+
+/// Build a scraper that archives every page in `page_archive`, and also saves
+/// them to `output_dir` if one is given.
+fn archiving_scraper<'a>(pool: &'a PgPool, output_dir: Option<&Path>) -> std::io::Result<ArchivingScraper<'a>> {
+    let scraper = Scraper::with_fetcher(ArchivingFetcher::new(HttpFetcher::new(), PageArchiveDb::new(pool)));
+
+    match output_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)?;
+            Ok(scraper.with_output_dir(dir))
+        }
+        None => Ok(scraper),
+    }
+}
+
+/// Take the scrape queue lock, failing if another run holds it
+async fn lock_queue(pool: &PgPool) -> anyhow::Result<QueueLock> {
+    ScrapeJobsDb::new(pool)
+        .try_lock()
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Another run is already working through the scrape queue"))
+}
+
+/// Work through the persistent scrape queue. Games an interrupted run left
+/// half-done are requeued first, so this picks up wherever the last run stopped.
+/// With `only`, just those games are worked and the rest of the queue is left
+/// for a later run.
+async fn run_scrape_queue(
+    _lock: &QueueLock,
+    pool: &PgPool,
+    scraper: &ArchivingScraper<'_>,
+    concurrency: usize,
+    only: Option<&[String]>,
+    limit: Option<usize>,
+) -> anyhow::Result<Vec<ScrapeResult>> {
+    let jobs = ScrapeJobsDb::new(pool);
+
+    let recovered = jobs.recover_interrupted().await?;
+    if recovered > 0 {
+        info!("Requeued {recovered} games left unfinished by an interrupted run");
+    }
+
+    let limit = limit.map(i64::try_from).transpose()?;
+    let urls: Vec<BoxScoreUrl> = jobs.pending(only, limit).await?.iter().map(ScrapeJob::url).collect();
+    info!("Scraping {} queued games ({concurrency} at a time)", urls.len());
+
+    let inserter = BoxScoreInserter::new(pool);
+    let failed_db = FailedScrapesDb::new(pool);
+    let results = scraper
+        .scrape_queue(&urls, &inserter, Some(&failed_db), &jobs, concurrency)
+        .await;
+
+    info!("");
+    info!("Queue:");
+    for (state, count) in jobs.counts().await? {
+        info!("  {state}: {count}");
+    }
+
+    Ok(results)
+}
+
+/// Log a replay's discrepancies, if it has any. Returns whether it was clean.
+fn log_replay(game_id: &str, report: &ReplayReport) -> bool {
+    if report.is_clean() {
+        return true;
+    }
+    warn!("{game_id}: {} discrepancies", report.discrepancies.len());
+    for discrepancy in &report.discrepancies {
+        warn!("  {discrepancy}");
+    }
+    false
+}
+
 #[derive(Subcommand)]
 pub enum BaseballCommands {
     /// Parse a box score file and print a summary
@@ -92,6 +179,13 @@ pub enum BaseballCommands {
         file: PathBuf,
     },
 
+    /// Replay a box score file's play-by-play through the rules engine and
+    /// check it against the line score
+    Replay {
+        /// Path to the HTML file
+        file: PathBuf,
+    },
+
     /// Parse a box score file and import it to the database
     Import {
         /// Path to the HTML file
@@ -163,13 +257,65 @@ pub enum BaseballCommands {
         #[arg(short, long)]
         output_dir: Option<PathBuf>,
 
-        /// Maximum number of games to scrape per year (for testing)
+        /// Maximum number of games to queue per year (for testing)
         #[arg(short = 'n', long)]
         limit: Option<usize>,
 
         /// Skip games that are in the future (based on game ID date)
         #[arg(long, default_value = "true")]
         skip_future: bool,
+
+        /// Number of games to scrape at once; all of them share one rate limit
+        #[arg(short = 'j', long, default_value = "1")]
+        concurrency: usize,
+    },
+
+    /// Import the season's newly completed games and re-check recent ones for
+    /// corrections. Safe to run from cron: a run that finds another still
+    /// going exits without doing anything.
+    Update {
+        /// Database URL (or set `SPORTS_DATABASE_URL` env var)
+        #[arg(short, long, env = "SPORTS_DATABASE_URL")]
+        database_url: String,
+
+        /// Season to update (defaults to the current year)
+        #[arg(short, long)]
+        season: Option<i32>,
+
+        /// Re-check games played within this many days for corrections
+        #[arg(long, default_value = "3")]
+        recheck_days: i64,
+
+        /// Directory to save downloaded HTML files
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+
+        /// Number of games to scrape at once; all of them share one rate limit
+        #[arg(short = 'j', long, default_value = "1")]
+        concurrency: usize,
+    },
+
+    /// Scrape the games queued by `scrape-years`, resuming an interrupted run
+    ScrapeQueue {
+        /// Database URL (or set `SPORTS_DATABASE_URL` env var)
+        #[arg(short, long, env = "SPORTS_DATABASE_URL")]
+        database_url: String,
+
+        /// Directory to save downloaded HTML files
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+
+        /// Number of games to scrape at once; all of them share one rate limit
+        #[arg(short = 'j', long, default_value = "1")]
+        concurrency: usize,
+
+        /// Requeue games that failed in earlier runs
+        #[arg(long)]
+        retry_failed: bool,
+
+        /// Maximum number of games to scrape
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
     },
 
     /// Delete and re-scrape specific games listed by bbref game id
@@ -268,6 +414,58 @@ pub enum BaseballCommands {
         #[arg(short = 'n', long)]
         limit: Option<usize>,
     },
+
+    /// Rebuild games from their archived pages with the current parser and list
+    /// the games whose rows changed
+    Reparse {
+        /// Database URL (or set `SPORTS_DATABASE_URL` env var)
+        #[arg(short, long, env = "SPORTS_DATABASE_URL")]
+        database_url: String,
+
+        /// Only re-parse this game (bbref game id)
+        #[arg(short = 'g', long)]
+        game_id: Option<String>,
+
+        /// Only re-parse games from this season
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        /// Maximum number of games to re-parse
+        #[arg(short = 'n', long)]
+        limit: Option<i64>,
+
+        /// Report which games would change without rebuilding them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Parse saved box score pages and summarise the parser's warnings, to
+    /// catch bbref layout changes before they reach the database
+    ParseReport {
+        /// Directory containing .shtml files
+        #[arg(short = 'i', long, default_value = "sports/data/bbref")]
+        input_dir: PathBuf,
+
+        /// Maximum number of files to parse
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+
+    /// Replay imported games through the rules engine and list those whose
+    /// play-by-play disagrees with their line score
+    ReplayGames {
+        /// Database URL (or set `SPORTS_DATABASE_URL` env var)
+        #[arg(short, long, env = "SPORTS_DATABASE_URL")]
+        database_url: String,
+
+        /// Only replay games from this season
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        /// Maximum number of games to replay
+        #[arg(short = 'n', long)]
+        limit: Option<i64>,
+    },
 }
 
 /// # Panics
@@ -326,6 +524,19 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             }
         }
 
+        BaseballCommands::Replay { file } => {
+            let box_score = BoxScore::from_file(&file)?;
+            let report = replay_box_score(&box_score);
+            info!(
+                "Replayed {} of {} events",
+                report.rows_replayed,
+                box_score.play_by_play.len()
+            );
+            if log_replay(&box_score.game_info.bbref_game_id, &report) {
+                info!("{}: agrees with its line score", box_score.game_info.bbref_game_id);
+            }
+        }
+
         BaseballCommands::Import {
             file,
             database_url,
@@ -559,12 +770,7 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             info!("Migrations complete");
 
             // Create scraper
-            let scraper = if let Some(ref dir) = output_dir {
-                std::fs::create_dir_all(dir)?;
-                Scraper::new().with_output_dir(dir)
-            } else {
-                Scraper::new()
-            };
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
 
             // Create inserter
             let inserter = BoxScoreInserter::new(&pool);
@@ -617,13 +823,7 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             info!("Migrations complete");
 
             // Create scraper
-            let scraper = if let Some(ref dir) = output_dir {
-                // Create output directory if it doesn't exist
-                std::fs::create_dir_all(dir)?;
-                Scraper::new().with_output_dir(dir)
-            } else {
-                Scraper::new()
-            };
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
 
             // Create inserter
             let inserter = BoxScoreInserter::new(&pool);
@@ -646,6 +846,7 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             output_dir,
             limit,
             skip_future,
+            concurrency,
         } => {
             let end_year = end_year.unwrap_or(start_year);
 
@@ -664,25 +865,12 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             info!("Migrations complete");
 
             // Create scraper
-            let scraper = if let Some(ref dir) = output_dir {
-                std::fs::create_dir_all(dir)?;
-                Scraper::new().with_output_dir(dir)
-            } else {
-                Scraper::new()
-            };
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
 
-            // Create inserter
-            let inserter = BoxScoreInserter::new(&pool);
-
-            // Create failed scrapes tracker
-            let failed_db = FailedScrapesDb::new(&pool);
-
-            // Track overall statistics
-            let mut total_imported = 0;
-            let mut total_skipped = 0;
-            let mut total_failed = 0;
+            let lock = lock_queue(&pool).await?;
+            let jobs = ScrapeJobsDb::new(&pool);
 
-            // Process each year
+            // Queue each year's games; games queued by an earlier run keep their state
             for year in start_year..=end_year {
                 info!("");
                 info!("=== Year {year} ===");
@@ -692,7 +880,6 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
                     Ok(html) => html,
                     Err(e) => {
                         error!("Failed to fetch schedule for {year}: {e}");
-                        info!("Failed to fetch schedule for {year}: {e}");
                         continue;
                     }
                 };
@@ -703,34 +890,155 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
 
                 filter_and_limit(&mut urls, skip_future, limit);
 
-                if urls.is_empty() {
-                    info!("No games to scrape for {year}");
-                    continue;
-                }
+                let queued = jobs.enqueue(&urls).await?;
+                info!("Queued {queued} new games from {year}");
+            }
 
-                info!("Scraping {} games from {year}", urls.len());
+            let results = run_scrape_queue(&lock, &pool, &scraper, concurrency, None, None).await?;
 
-                // Scrape all games for this year with failure tracking
-                let results = scraper
-                    .scrape_all_with_tracking(&urls, &inserter, Some(&failed_db))
-                    .await;
+            let summary = summarize_results(&results, &format!("Overall Summary ({start_year}-{end_year})"));
+            if summary.failed > 0 {
+                info!("\nFailed games saved for retry with `failed-retry` command.");
+            }
+        }
 
-                let summary = summarize_results(&results, &format!("Year {year} Summary"));
-                total_imported += summary.imported;
-                total_skipped += summary.skipped;
-                total_failed += summary.failed;
+        BaseballCommands::Update {
+            database_url,
+            season,
+            recheck_days,
+            output_dir,
+            concurrency,
+        } => {
+            let today = chrono::Utc::now().date_naive();
+            let season = season.unwrap_or_else(|| today.year());
+
+            // Connect to database
+            let pool = create_pool(&database_url).await?;
+            info!("Connected to database");
+
+            // Run migrations
+            run_migrations(&pool).await?;
+            info!("Migrations complete");
+
+            let jobs = ScrapeJobsDb::new(&pool);
+            let Some(lock) = jobs.try_lock().await? else {
+                info!("Another run is already working through the scrape queue; nothing to do");
+                return Ok(());
+            };
+
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
+
+            // The schedule only links box scores for games that have finished
+            let schedule_html = scraper.fetch_schedule(season).await?;
+            let mut urls = extract_boxscore_urls_from_html(&schedule_html);
+            filter_and_limit(&mut urls, true, None);
+
+            let game_ids: Vec<String> = urls.iter().map(|url| url.game_id.clone()).collect();
+            let imported: HashSet<String> = imported_game_ids(&pool, &game_ids).await?.into_iter().collect();
+            urls.retain(|url| !imported.contains(&url.game_id));
+            info!(
+                "{} completed games in {season}, {} not yet imported",
+                game_ids.len(),
+                urls.len()
+            );
+
+            // New games go through the queue whatever state an earlier run left them in,
+            // so a game whose page wasn't up yesterday is tried again today. Only these
+            // are worked; anything else queued is left for `scrape-queue`
+            jobs.requeue(&urls).await?;
+            let new_games: Vec<String> = urls.iter().map(|url| url.game_id.clone()).collect();
+            let results = run_scrape_queue(&lock, &pool, &scraper, concurrency, Some(&new_games), None).await?;
+            summarize_results(&results, "New Games");
+
+            // bbref corrects box scores for a few days after a game; re-parse the
+            // recent ones and rebuild any whose rows would change
+            let since = today - chrono::Duration::days(recheck_days);
+            let just_imported: HashSet<&str> = results
+                .iter()
+                .filter_map(|result| match result {
+                    ScrapeResult::Imported { game_id, .. } => Some(game_id.as_str()),
+                    _ => None,
+                })
+                .collect();
+            let recent: Vec<String> = recent_game_ids(&pool, since)
+                .await?
+                .into_iter()
+                .filter(|id| !just_imported.contains(id.as_str()))
+                .collect();
+            info!("");
+            info!("Re-checking {} games played since {since}", recent.len());
+
+            let inserter = BoxScoreInserter::new(&pool);
+            let mut corrected = Vec::new();
+            let mut failed = Vec::new();
+            for game_id in &recent {
+                let Some(url) = BoxScoreUrl::for_game(game_id) else {
+                    continue;
+                };
+                let box_score = match scraper.fetch_with_backoff(&url).await {
+                    Ok(html) => match BoxScore::from_html(&html, game_id) {
+                        Ok(bs) => bs,
+                        Err(e) => {
+                            failed.push((game_id, format!("Parse error: {e}")));
+                            continue;
+                        }
+                    },
+                    Err(e) => {
+                        failed.push((game_id, e));
+                        continue;
+                    }
+                };
+
+                match inserter.rebuild(&box_score, false).await {
+                    Ok(Rebuild::Unchanged) => {}
+                    Ok(rebuild) => {
+                        info!("Rebuilt {game_id}: {}", rebuild.as_str());
+                        corrected.push((game_id, rebuild.as_str()));
+                    }
+                    Err(e) => failed.push((game_id, format!("Insert error: {e}"))),
+                }
             }
 
-            // Overall summary
             info!("");
-            info!("{}", "=".repeat(50));
-            info!("=== Overall Summary ({start_year}-{end_year}) ===");
-            info!("Total Imported: {total_imported}");
-            info!("Total Skipped: {total_skipped}");
-            info!("Total Failed: {total_failed}");
-            if total_failed > 0 {
-                info!("\nFailed games saved for retry with `failed-retry` command.");
+            info!("=== Re-check Summary ===");
+            info!("Unchanged: {}", recent.len() - corrected.len() - failed.len());
+            info!("Rebuilt: {}", corrected.len());
+            for (game_id, reason) in &corrected {
+                info!("  {game_id}: {reason}");
+            }
+            info!("Failed: {}", failed.len());
+            for (game_id, error) in &failed {
+                info!("  {game_id}: {error}");
+            }
+        }
+
+        BaseballCommands::ScrapeQueue {
+            database_url,
+            output_dir,
+            concurrency,
+            retry_failed,
+            limit,
+        } => {
+            // Connect to database
+            let pool = create_pool(&database_url).await?;
+            info!("Connected to database");
+
+            // Run migrations
+            run_migrations(&pool).await?;
+            info!("Migrations complete");
+
+            // Create scraper
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
+
+            let lock = lock_queue(&pool).await?;
+
+            if retry_failed {
+                let requeued = ScrapeJobsDb::new(&pool).retry_failed().await?;
+                info!("Requeued {requeued} failed games");
             }
+
+            let results = run_scrape_queue(&lock, &pool, &scraper, concurrency, None, limit).await?;
+            summarize_results(&results, "Scrape Summary");
         }
 
         BaseballCommands::RescrapeGames {
@@ -753,12 +1061,7 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
             info!("Connected to database");
             run_migrations(&pool).await?;
 
-            let scraper = if let Some(ref dir) = output_dir {
-                std::fs::create_dir_all(dir)?;
-                Scraper::new().with_output_dir(dir)
-            } else {
-                Scraper::new()
-            };
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
             let inserter = BoxScoreInserter::new(&pool);
             let failed_db = FailedScrapesDb::new(&pool);
 
@@ -886,12 +1189,7 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
                 .collect();
 
             // Create scraper
-            let scraper = if let Some(ref dir) = output_dir {
-                std::fs::create_dir_all(dir)?;
-                Scraper::new().with_output_dir(dir)
-            } else {
-                Scraper::new()
-            };
+            let scraper = archiving_scraper(&pool, output_dir.as_deref())?;
 
             // Create inserter
             let inserter = BoxScoreInserter::new(&pool);
@@ -916,6 +1214,198 @@ pub async fn handle_command(command: BaseballCommands) -> anyhow::Result<()> {
 
             summarize_results(&results, "Retry Summary");
         }
+
+        BaseballCommands::Reparse {
+            database_url,
+            game_id,
+            year,
+            limit,
+            dry_run,
+        } => {
+            let pool = create_pool(&database_url).await?;
+            info!("Connected to database");
+            run_migrations(&pool).await?;
+
+            let archive = PageArchiveDb::new(&pool);
+            let inserter = BoxScoreInserter::new(&pool);
+            let game_ids = match game_id {
+                Some(id) => vec![id],
+                None => archive.archived_games(year, limit).await?,
+            };
+            info!("Re-parsing {} archived games", game_ids.len());
+
+            let total = game_ids.len();
+            let mut unchanged = 0;
+            let mut changed = Vec::new();
+            let mut failed = Vec::new();
+
+            for (i, game_id) in game_ids.iter().enumerate() {
+                let Some(page) = archive.latest_box_score(game_id).await? else {
+                    warn!("[{}/{total}] {game_id}: no archived box score", i + 1);
+                    failed.push((game_id, "No archived box score".to_string()));
+                    continue;
+                };
+
+                let parsed = page
+                    .html()
+                    .map_err(ParseError::from)
+                    .and_then(|html| BoxScore::from_html(&html, game_id));
+                let box_score = match parsed {
+                    Ok(bs) => bs,
+                    Err(e) => {
+                        warn!("[{}/{total}] {game_id}: {e}", i + 1);
+                        failed.push((game_id, e.to_string()));
+                        continue;
+                    }
+                };
+
+                // Games whose rebuilt rows match the stored ones are left alone
+                match inserter.rebuild(&box_score, dry_run).await {
+                    Ok(Rebuild::Unchanged) => unchanged += 1,
+                    Ok(rebuild) => {
+                        info!("[{}/{total}] {game_id}: {}", i + 1, rebuild.as_str());
+                        changed.push((game_id, rebuild.as_str()));
+                    }
+                    Err(e) => {
+                        warn!("[{}/{total}] {game_id}: Insert error: {e}", i + 1);
+                        failed.push((game_id, format!("Insert error: {e}")));
+                    }
+                }
+            }
+
+            info!("");
+            info!("=== Reparse Summary ===");
+            info!("Unchanged: {unchanged}");
+            info!(
+                "{}: {}",
+                if dry_run { "Would rebuild" } else { "Rebuilt" },
+                changed.len()
+            );
+            for (game_id, reason) in &changed {
+                info!("  {game_id}: {reason}");
+            }
+            info!("Failed: {}", failed.len());
+            for (game_id, error) in &failed {
+                info!("  {game_id}: {error}");
+            }
+        }
+
+        BaseballCommands::ParseReport { input_dir, limit } => {
+            // Find all .shtml files (excluding schedule files)
+            let mut files: Vec<PathBuf> = std::fs::read_dir(&input_dir)?
+                .filter_map(std::result::Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension().is_some_and(|ext| ext == "shtml")
+                        && !path.file_name().unwrap().to_string_lossy().ends_with("-schedule.shtml")
+                })
+                .collect();
+            files.sort();
+            if let Some(n) = limit {
+                files.truncate(n);
+            }
+            info!("Parsing {} files from {}", files.len(), input_dir.display());
+
+            let mut parsed = 0;
+            let mut failed = Vec::new();
+            let mut with_warnings = 0;
+            let mut drifted = Vec::new();
+            // Warnings grouped by where they were found and what kind they
+            // are, with the first one seen as an example
+            let mut groups = HashMap::new();
+
+            for file in &files {
+                let name = file.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                let box_score = match BoxScore::from_file(file) {
+                    Ok(bs) => bs,
+                    Err(e) => {
+                        warn!("{name}: {e}");
+                        failed.push((name, e.to_string()));
+                        continue;
+                    }
+                };
+                parsed += 1;
+
+                if !box_score.warnings.is_empty() {
+                    with_warnings += 1;
+                }
+                if box_score.has_layout_drift() {
+                    drifted.push(name.clone());
+                }
+                for warning in box_score.warnings {
+                    let key = (warning.section, warning.column.clone(), warning.kind.label());
+                    groups.entry(key).or_insert_with(|| (0, name.clone(), warning)).0 += 1;
+                }
+            }
+
+            let mut groups: Vec<_> = groups.into_iter().collect();
+            groups.sort_by(|(a_key, (a_count, ..)), (b_key, (b_count, ..))| {
+                b_count.cmp(a_count).then_with(|| a_key.cmp(b_key))
+            });
+
+            info!("");
+            info!("=== Parse Report ===");
+            info!("Parsed: {parsed}");
+            info!("With warnings: {with_warnings}");
+            info!("Layout drift: {}", drifted.len());
+            for name in &drifted {
+                info!("  {name}");
+            }
+            info!("Failed: {}", failed.len());
+            for (name, error) in &failed {
+                info!("  {name}: {error}");
+            }
+            if !groups.is_empty() {
+                info!("");
+                info!("Warnings:");
+                for ((section, column, label), (count, example_page, example)) in &groups {
+                    let drift = if example.kind.is_layout_drift() {
+                        " [layout]"
+                    } else {
+                        ""
+                    };
+                    info!(
+                        "  {count:>6}  {section} {}: {label}{drift}",
+                        column.as_deref().unwrap_or("-")
+                    );
+                    info!("          e.g. {example_page}: {example}");
+                }
+            }
+        }
+
+        BaseballCommands::ReplayGames {
+            database_url,
+            year,
+            limit,
+        } => {
+            let pool = create_pool(&database_url).await?;
+            let replay_db = ReplayDb::new(&pool);
+            let games = replay_db.list_games(year, limit).await?;
+            info!("Replaying {} games...\n", games.len());
+
+            let mut flagged = Vec::new();
+            for game in &games {
+                let rows = replay_db.play_rows(game.id).await?;
+                let (away, home) = replay_db.line_scores(game).await?;
+                let report = replay(&rows, rules_for(&game.bbref_game_id, &rows), &away, &home);
+                if !log_replay(&game.bbref_game_id, &report) {
+                    flagged.push(game.bbref_game_id.as_str());
+                }
+            }
+
+            info!("");
+            info!("=== Replay Summary ===");
+            info!("Replayed: {}", games.len());
+            info!("Clean: {}", games.len() - flagged.len());
+            info!("Flagged: {}", flagged.len());
+            if !flagged.is_empty() {
+                info!("");
+                info!("Flagged games:");
+                for game_id in flagged {
+                    info!("  {game_id}");
+                }
+            }
+        }
     }
 
     Ok(())
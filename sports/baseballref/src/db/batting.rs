@@ -1,9 +1,9 @@
-use sqlx::PgPool;
+use sqlx::PgConnection;
 
 use crate::models::NewBattingLine;
 
 /// Insert batting lines for a game
-pub async fn insert_batting_lines(pool: &PgPool, lines: &[NewBattingLine]) -> Result<(), sqlx::Error> {
+pub async fn insert_batting_lines(conn: &mut PgConnection, lines: &[NewBattingLine]) -> Result<(), sqlx::Error> {
     for line in lines {
         sqlx::query!(
             r"
@@ -67,7 +67,7 @@ pub async fn insert_batting_lines(pool: &PgPool, lines: &[NewBattingLine]) -> Re
             line.counts.hbp,
             line.counts.ibb,
         )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
@@ -1,9 +1,10 @@
-use sqlx::PgPool;
+use chrono::NaiveDate;
+use sqlx::{PgConnection, PgPool};
 
 use crate::models::{Game, NewGame, NewGameLineScore, NewGameUmpire};
 
 /// Insert a new game, returning the game with its ID
-pub async fn insert_game(pool: &PgPool, game: &NewGame) -> Result<Game, sqlx::Error> {
+pub async fn insert_game(conn: &mut PgConnection, game: &NewGame) -> Result<Game, sqlx::Error> {
     sqlx::query_as!(
         Game,
         r"
@@ -36,7 +37,7 @@ pub async fn insert_game(pool: &PgPool, game: &NewGame) -> Result<Game, sqlx::Er
         game.losing_pitcher_id,
         game.save_pitcher_id,
     )
-    .fetch_one(pool)
+    .fetch_one(conn)
     .await
 }
 
@@ -54,8 +55,83 @@ pub async fn game_exists(pool: &PgPool, bbref_game_id: &str) -> Result<bool, sql
     Ok(result.unwrap_or(0) > 0)
 }
 
+/// The games among `bbref_game_ids` that are already imported
+pub async fn imported_game_ids(pool: &PgPool, bbref_game_ids: &[String]) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r"
+        SELECT bbref_game_id FROM games WHERE bbref_game_id = ANY($1)
+        ",
+        bbref_game_ids,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Imported games played on or after `since`, oldest first
+pub async fn recent_game_ids(pool: &PgPool, since: NaiveDate) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r"
+        SELECT bbref_game_id FROM games
+        WHERE game_date >= $1
+        ORDER BY game_date, bbref_game_id
+        ",
+        since,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Delete a game and, through their foreign keys, all of its rows
+pub async fn delete_game(conn: &mut PgConnection, bbref_game_id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r"
+        DELETE FROM games WHERE bbref_game_id = $1
+        ",
+        bbref_game_id,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// MD5 of everything stored for a game: its row and its umpires, line scores,
+/// batting and pitching lines and play-by-play, leaving out generated ids and
+/// timestamps. Two imports of a game hash the same exactly when they stored
+/// the same rows. None if the game isn't imported.
+pub async fn stored_rows_hash(conn: &mut PgConnection, bbref_game_id: &str) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r"
+        WITH game AS (SELECT * FROM games WHERE bbref_game_id = $1),
+        stored (kind, row) AS (
+            SELECT 'game', to_jsonb(g) - 'id' - 'created_at' FROM game g
+            UNION ALL
+            SELECT 'umpire', to_jsonb(u) - 'id' - 'game_id'
+            FROM game_umpires u JOIN game ON game.id = u.game_id
+            UNION ALL
+            SELECT 'line score', to_jsonb(l) - 'id' - 'game_id'
+            FROM game_line_scores l JOIN game ON game.id = l.game_id
+            UNION ALL
+            SELECT 'batting', to_jsonb(b) - 'id' - 'game_id'
+            FROM batting_lines b JOIN game ON game.id = b.game_id
+            UNION ALL
+            SELECT 'pitching', to_jsonb(p) - 'id' - 'game_id'
+            FROM pitching_lines p JOIN game ON game.id = p.game_id
+            UNION ALL
+            SELECT 'play', to_jsonb(e) - 'id' - 'game_id'
+            FROM play_by_play e JOIN game ON game.id = e.game_id
+        )
+        SELECT md5(string_agg(kind || ' ' || row::text, E'\n' ORDER BY kind, row::text))
+        FROM stored
+        ",
+        bbref_game_id,
+    )
+    .fetch_one(conn)
+    .await
+}
+
 /// Insert umpires for a game
-pub async fn insert_umpires(pool: &PgPool, umpires: &[NewGameUmpire]) -> Result<(), sqlx::Error> {
+pub async fn insert_umpires(conn: &mut PgConnection, umpires: &[NewGameUmpire]) -> Result<(), sqlx::Error> {
     for umpire in umpires {
         sqlx::query!(
             r"
@@ -66,7 +142,7 @@ pub async fn insert_umpires(pool: &PgPool, umpires: &[NewGameUmpire]) -> Result<
             umpire.position,
             umpire.name,
         )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
@@ -74,7 +150,7 @@ pub async fn insert_umpires(pool: &PgPool, umpires: &[NewGameUmpire]) -> Result<
 }
 
 /// Insert line scores for a game
-pub async fn insert_line_scores(pool: &PgPool, line_scores: &[NewGameLineScore]) -> Result<(), sqlx::Error> {
+pub async fn insert_line_scores(conn: &mut PgConnection, line_scores: &[NewGameLineScore]) -> Result<(), sqlx::Error> {
     for ls in line_scores {
         sqlx::query!(
             r"
@@ -87,7 +163,7 @@ pub async fn insert_line_scores(pool: &PgPool, line_scores: &[NewGameLineScore])
             ls.inning,
             ls.runs,
         )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
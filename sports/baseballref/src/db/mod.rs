@@ -2,13 +2,19 @@ mod batting;
 mod box_score;
 mod failed_scrapes;
 mod games;
+mod page_archive;
 mod pitching;
 mod play_by_play;
 mod players;
 mod pool;
+mod replay;
+mod scrape_jobs;
 mod teams;
 
-pub use box_score::{BoxScoreInserter, InsertError};
+pub use box_score::{BoxScoreInserter, InsertError, Rebuild};
 pub use failed_scrapes::{FailedScrape, FailedScrapesDb};
-pub use games::game_exists;
+pub use games::{game_exists, imported_game_ids, recent_game_ids};
+pub use page_archive::{ArchivedPage, PageArchiveDb};
 pub use pool::{create_pool, run_migrations};
+pub use replay::{ReplayDb, StoredGame};
+pub use scrape_jobs::{JobState, QueueLock, ScrapeJob, ScrapeJobsDb};
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use crate::scraper::{BoxScoreUrl, Page, compress, content_hash, decompress};
+
+/// A page as it was fetched, from the archive
+#[derive(Debug, Clone, FromRow)]
+pub struct ArchivedPage {
+    pub id: i32,
+    pub path: String,
+    pub bbref_game_id: Option<String>,
+    pub status: i32,
+    pub content_hash: String,
+    /// The gzipped page body; see [`ArchivedPage::html`]
+    pub body: Vec<u8>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ArchivedPage {
+    /// The page body as it was fetched
+    pub fn html(&self) -> Result<String, std::io::Error> {
+        decompress(&self.body)
+    }
+}
+
+/// Database operations for the raw page archive
+pub struct PageArchiveDb<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> PageArchiveDb<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Archive a fetched page. Each distinct version of a page is kept once:
+    /// fetching the same content again only moves its fetch time forward.
+    pub async fn store(&self, path: &str, page: &Page) -> Result<(), sqlx::Error> {
+        let game_id = BoxScoreUrl::from_path(path).map(|url| url.game_id);
+        let body = compress(&page.body).map_err(sqlx::Error::Io)?;
+
+        sqlx::query!(
+            r"
+            INSERT INTO page_archive (path, bbref_game_id, status, content_hash, body)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (path, content_hash)
+            DO UPDATE SET status = EXCLUDED.status, fetched_at = NOW()
+            ",
+            path,
+            game_id,
+            i32::from(page.status),
+            content_hash(&page.body),
+            body,
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Games with an archived box score, optionally from one season only, in
+    /// game id order
+    pub async fn archived_games(&self, year: Option<i32>, limit: Option<i64>) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT bbref_game_id AS "bbref_game_id!"
+            FROM page_archive
+            WHERE bbref_game_id IS NOT NULL
+                AND status = 200
+                AND ($1::INT IS NULL OR SUBSTRING(bbref_game_id FROM 4 FOR 4) = $1::INT::TEXT)
+            ORDER BY 1
+            LIMIT $2
+            "#,
+            year,
+            limit,
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// The most recently fetched box score for a game
+    pub async fn latest_box_score(&self, game_id: &str) -> Result<Option<ArchivedPage>, sqlx::Error> {
+        sqlx::query_as!(
+            ArchivedPage,
+            r"
+            SELECT id, path, bbref_game_id, status, content_hash, body, fetched_at
+            FROM page_archive
+            WHERE bbref_game_id = $1 AND status = 200
+            ORDER BY fetched_at DESC, id DESC
+            LIMIT 1
+            ",
+            game_id,
+        )
+        .fetch_optional(self.pool)
+        .await
+    }
+}
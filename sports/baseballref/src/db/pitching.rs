@@ -1,9 +1,9 @@
-use sqlx::PgPool;
+use sqlx::PgConnection;
 
 use crate::models::NewPitchingLine;
 
 /// Insert pitching lines for a game
-pub async fn insert_pitching_lines(pool: &PgPool, lines: &[NewPitchingLine]) -> Result<(), sqlx::Error> {
+pub async fn insert_pitching_lines(conn: &mut PgConnection, lines: &[NewPitchingLine]) -> Result<(), sqlx::Error> {
     for line in lines {
         sqlx::query!(
             r"
@@ -57,7 +57,7 @@ pub async fn insert_pitching_lines(pool: &PgPool, lines: &[NewPitchingLine]) ->
             line.acli,
             line.re24,
         )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
@@ -1,9 +1,9 @@
-use sqlx::PgPool;
+use sqlx::PgConnection;
 
 use crate::models::NewPlayByPlay;
 
 /// Insert play-by-play events for a game
-pub async fn insert_play_by_play(pool: &PgPool, events: &[NewPlayByPlay]) -> Result<(), sqlx::Error> {
+pub async fn insert_play_by_play(conn: &mut PgConnection, events: &[NewPlayByPlay]) -> Result<(), sqlx::Error> {
     for event in events {
         sqlx::query!(
             r"
@@ -45,7 +45,7 @@ pub async fn insert_play_by_play(pool: &PgPool, events: &[NewPlayByPlay]) -> Res
             event.win_expectancy_after,
             event.play_description,
         )
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
@@ -1,9 +1,9 @@
-use sqlx::PgPool;
+use sqlx::PgConnection;
 
 use crate::models::{NewPlayer, Player};
 
 /// Upsert a player by `bbref_id`, returning the player with its ID
-pub async fn upsert_player(pool: &PgPool, player: &NewPlayer) -> Result<Player, sqlx::Error> {
+pub async fn upsert_player(conn: &mut PgConnection, player: &NewPlayer) -> Result<Player, sqlx::Error> {
     sqlx::query_as!(
         Player,
         r"
@@ -17,6 +17,6 @@ pub async fn upsert_player(pool: &PgPool, player: &NewPlayer) -> Result<Player,
         player.bbref_id,
         player.name,
     )
-    .fetch_one(pool)
+    .fetch_one(conn)
     .await
 }
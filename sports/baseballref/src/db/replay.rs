@@ -0,0 +1,89 @@
+use sqlx::{FromRow, PgPool};
+
+use crate::replay::{PlayRow, ScrapedLine};
+
+/// A stored game with its final score, as the replay check needs it
+#[derive(Debug, Clone, FromRow)]
+pub struct StoredGame {
+    pub id: i32,
+    pub bbref_game_id: String,
+    pub away_score: Option<i32>,
+    pub home_score: Option<i32>,
+}
+
+/// Database reads for replaying stored games through the rules engine
+pub struct ReplayDb<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> ReplayDb<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List stored games in date order, optionally for one season only
+    pub async fn list_games(&self, year: Option<i32>, limit: Option<i64>) -> Result<Vec<StoredGame>, sqlx::Error> {
+        sqlx::query_as!(
+            StoredGame,
+            r#"
+            SELECT id, bbref_game_id, away_score, home_score
+            FROM games
+            WHERE $1::INTEGER IS NULL OR EXTRACT(YEAR FROM game_date)::INTEGER = $1
+            ORDER BY game_date, bbref_game_id
+            LIMIT $2
+            "#,
+            year,
+            limit,
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// The play-by-play of a game, in order, with each batter's name
+    pub async fn play_rows(&self, game_id: i32) -> Result<Vec<PlayRow>, sqlx::Error> {
+        sqlx::query_as!(
+            PlayRow,
+            r#"
+            SELECT pbp.event_num, pbp.inning, pbp.is_bottom, pl.name AS "batter_name!",
+                pbp.outs_before, pbp.runners_before,
+                pbp.score_batting_team, pbp.score_fielding_team,
+                pbp.pitch_sequence, pbp.play_description
+            FROM play_by_play pbp
+            JOIN players pl ON pl.id = pbp.batter_id
+            WHERE pbp.game_id = $1
+            ORDER BY pbp.event_num
+            "#,
+            game_id,
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// The away and home line scores of a game. The total is the stored final
+    /// score, falling back to the sum of the innings when there is none.
+    pub async fn line_scores(&self, game: &StoredGame) -> Result<(ScrapedLine, ScrapedLine), sqlx::Error> {
+        let rows = sqlx::query!(
+            r"
+            SELECT is_home, inning, runs
+            FROM game_line_scores
+            WHERE game_id = $1
+            ORDER BY inning
+            ",
+            game.id,
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let line = |is_home: bool, score: Option<i32>| {
+            let innings: Vec<i32> = rows
+                .iter()
+                .filter(|row| row.is_home == is_home)
+                .map(|row| row.runs)
+                .collect();
+            let total_runs = score.unwrap_or_else(|| innings.iter().sum());
+            ScrapedLine { innings, total_runs }
+        };
+
+        Ok((line(false, game.away_score), line(true, game.home_score)))
+    }
+}
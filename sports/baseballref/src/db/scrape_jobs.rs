@@ -0,0 +1,244 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, pool::PoolConnection};
+
+use crate::scraper::BoxScoreUrl;
+
+/// Where a queued game is in the scrape → parse → import pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Pending,
+    Fetched,
+    Parsed,
+    Imported,
+    Failed,
+}
+
+impl JobState {
+    /// The value stored in `scrape_jobs.state`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobState::Pending => "pending",
+            JobState::Fetched => "fetched",
+            JobState::Parsed => "parsed",
+            JobState::Imported => "imported",
+            JobState::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for JobState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A game in the scrape queue
+#[derive(Debug, Clone, FromRow)]
+pub struct ScrapeJob {
+    pub id: i32,
+    pub bbref_game_id: String,
+    pub path: String,
+    pub state: String,
+    pub last_error: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ScrapeJob {
+    pub fn url(&self) -> BoxScoreUrl {
+        BoxScoreUrl {
+            game_id: self.bbref_game_id.clone(),
+            path: self.path.clone(),
+        }
+    }
+}
+
+/// Proof that this process is the only one working the queue. The lock is
+/// released when this is dropped.
+pub struct QueueLock {
+    conn: Option<PoolConnection<Postgres>>,
+}
+
+impl Drop for QueueLock {
+    fn drop(&mut self) {
+        // The advisory lock belongs to the session: closing the connection
+        // releases it, returning it to the pool would not
+        if let Some(conn) = self.conn.take() {
+            drop(conn.detach());
+        }
+    }
+}
+
+/// Database operations for the persistent scrape queue
+pub struct ScrapeJobsDb<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> ScrapeJobsDb<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Queue games for scraping. Games already in the queue keep their state,
+    /// so re-queueing a season only adds the games that are new to it.
+    /// Returns the number of games added.
+    pub async fn enqueue(&self, urls: &[BoxScoreUrl]) -> Result<u64, sqlx::Error> {
+        let game_ids: Vec<String> = urls.iter().map(|url| url.game_id.clone()).collect();
+        let paths: Vec<String> = urls.iter().map(|url| url.path.clone()).collect();
+
+        let result = sqlx::query!(
+            r"
+            INSERT INTO scrape_jobs (bbref_game_id, path)
+            SELECT * FROM UNNEST($1::VARCHAR[], $2::VARCHAR[])
+            ON CONFLICT (bbref_game_id) DO NOTHING
+            ",
+            &game_ids,
+            &paths,
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Take the queue lock, or return None if another process holds it
+    pub async fn try_lock(&self) -> Result<Option<QueueLock>, sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+
+        let locked = sqlx::query_scalar!(
+            r#"
+            SELECT pg_try_advisory_lock(hashtext('baseballref.scrape_jobs')) AS "locked!"
+            "#
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(locked.then(|| QueueLock { conn: Some(conn) }))
+    }
+
+    /// Queue games to be scraped again, whatever state they were left in.
+    /// Returns the number of games queued.
+    pub async fn requeue(&self, urls: &[BoxScoreUrl]) -> Result<u64, sqlx::Error> {
+        let game_ids: Vec<String> = urls.iter().map(|url| url.game_id.clone()).collect();
+        let paths: Vec<String> = urls.iter().map(|url| url.path.clone()).collect();
+
+        let result = sqlx::query!(
+            r"
+            INSERT INTO scrape_jobs (bbref_game_id, path)
+            SELECT * FROM UNNEST($1::VARCHAR[], $2::VARCHAR[])
+            ON CONFLICT (bbref_game_id)
+            DO UPDATE SET state = 'pending', last_error = NULL, updated_at = NOW()
+            ",
+            &game_ids,
+            &paths,
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Put games a crashed run left half-done back to pending. A game that was
+    /// parsed but never marked imported may have been partially inserted, so
+    /// its rows are deleted first. Returns the number of games requeued.
+    ///
+    /// Only call this holding the [`QueueLock`]: another worker's in-flight
+    /// games look exactly like a crashed run's.
+    pub async fn recover_interrupted(&self) -> Result<u64, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r"
+            DELETE FROM games
+            WHERE bbref_game_id IN (SELECT bbref_game_id FROM scrape_jobs WHERE state = 'parsed')
+            ",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let result = sqlx::query!(
+            r"
+            UPDATE scrape_jobs
+            SET state = 'pending', updated_at = NOW()
+            WHERE state IN ('fetched', 'parsed')
+            ",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Pending games, in the order they were queued. With `only`, just those of
+    /// the given games that are pending.
+    pub async fn pending(&self, only: Option<&[String]>, limit: Option<i64>) -> Result<Vec<ScrapeJob>, sqlx::Error> {
+        sqlx::query_as!(
+            ScrapeJob,
+            r"
+            SELECT id, bbref_game_id, path, state, last_error, queued_at, updated_at
+            FROM scrape_jobs
+            WHERE state = 'pending'
+              AND ($1::VARCHAR[] IS NULL OR bbref_game_id = ANY($1))
+            ORDER BY id
+            LIMIT $2
+            ",
+            only,
+            limit,
+        )
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Move a game to a new state, recording the error for failures
+    pub async fn set_state(&self, game_id: &str, state: JobState, error: Option<&str>) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r"
+            UPDATE scrape_jobs
+            SET state = $2, last_error = $3, updated_at = NOW()
+            WHERE bbref_game_id = $1
+            ",
+            game_id,
+            state.as_str(),
+            error,
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Put every failed game back to pending. Returns the number requeued.
+    pub async fn retry_failed(&self) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            r"
+            UPDATE scrape_jobs
+            SET state = 'pending', last_error = NULL, updated_at = NOW()
+            WHERE state = 'failed'
+            "
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Number of games in each state
+    pub async fn counts(&self) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT state, COUNT(*) AS "count!"
+            FROM scrape_jobs
+            GROUP BY state
+            ORDER BY state
+            "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| (row.state, row.count)).collect())
+    }
+}
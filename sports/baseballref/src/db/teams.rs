@@ -1,9 +1,9 @@
-use sqlx::PgPool;
+use sqlx::PgConnection;
 
 use crate::models::{NewTeam, Team};
 
 /// Upsert a team by code, returning the team with its ID
-pub async fn upsert_team(pool: &PgPool, team: &NewTeam) -> Result<Team, sqlx::Error> {
+pub async fn upsert_team(conn: &mut PgConnection, team: &NewTeam) -> Result<Team, sqlx::Error> {
     sqlx::query_as!(
         Team,
         r"
@@ -15,6 +15,6 @@ pub async fn upsert_team(pool: &PgPool, team: &NewTeam) -> Result<Team, sqlx::Er
         team.code,
         team.name,
     )
-    .fetch_one(pool)
+    .fetch_one(conn)
     .await
 }
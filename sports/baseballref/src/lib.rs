@@ -5,4 +5,5 @@ pub mod cli;
 pub mod db;
 pub mod models;
 pub mod parser;
+pub mod replay;
 pub mod scraper;
@@ -4,7 +4,7 @@ use regex::Regex;
 use rust_decimal::Decimal;
 use scraper::{Html, Selector};
 
-use super::{get_attr, get_text, parse_decimal, parse_int};
+use super::{Layout, ParseWarning, Section, TableCheck, get_attr, get_text, parse_decimal, parse_int};
 
 /// Regex for compound position patterns like "LF-RF", "PH-1B", "SS-3B-2B"
 /// Matches a space followed by a position code, then one or more "-POSITION" suffixes
@@ -13,6 +13,31 @@ static COMPOUND_POSITION_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r" (DH|C|1B|2B|3B|SS|LF|CF|RF|P|PH|PR)(-(?:DH|C|1B|2B|3B|SS|LF|CF|RF|P|PH|PR))+$").expect("valid regex")
 });
 
+/// The batting table layout the parser reads
+const LAYOUT: Layout = Layout {
+    section: Section::Batting,
+    required: &["AB", "R", "H", "RBI", "BB", "SO", "PA"],
+    optional: &[
+        "batting_avg",
+        "onbase_perc",
+        "slugging_perc",
+        "onbase_plus_slugging",
+        "pitches",
+        "strikes_total",
+        "wpa_bat",
+        "leverage_index_avg",
+        "wpa_bat_pos",
+        "wpa_bat_neg",
+        "cwpa_bat",
+        "cli_avg",
+        "re24_bat",
+        "PO",
+        "A",
+        "details",
+    ],
+    ignored: &["player"],
+};
+
 /// Parsed batting line
 #[derive(Debug, Clone)]
 pub struct ParsedBattingLine {
@@ -50,12 +75,14 @@ pub struct ParsedBattingLine {
 ///
 /// Table IDs (`LosAngelesDodgersbatting`) contain team names, not codes, so
 /// teams are assigned by document order: bbref always renders the away team's
-/// table first (away bats first).
+/// table first (away bats first). Anything the parser works around is added to
+/// `warnings`.
 pub fn parse_batting_tables(
     doc: &Html,
     comments: &[Html],
     away_team_code: &str,
     home_team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
 ) -> Result<Vec<ParsedBattingLine>, String> {
     let tables = super::collect_team_tables(doc, comments, "batting")?;
 
@@ -63,29 +90,37 @@ pub fn parse_batting_tables(
         return Err(format!("expected 2 batting tables, found {}", tables.len()));
     }
 
-    let mut all_lines = parse_batting_table(tables[0], away_team_code)?;
-    all_lines.extend(parse_batting_table(tables[1], home_team_code)?);
+    let mut all_lines = parse_batting_table(tables[0], away_team_code, warnings)?;
+    all_lines.extend(parse_batting_table(tables[1], home_team_code, warnings)?);
     Ok(all_lines)
 }
 
-fn parse_batting_table(table: scraper::ElementRef<'_>, team_code: &str) -> Result<Vec<ParsedBattingLine>, String> {
+fn parse_batting_table(
+    table: scraper::ElementRef<'_>,
+    team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Vec<ParsedBattingLine>, String> {
     let mut lines = Vec::new();
 
     let row_selector = Selector::parse("tbody tr").map_err(|e| format!("{e:?}"))?;
     let thead_selector = Selector::parse("th").map_err(|e| format!("{e:?}"))?;
     let tdata_selector = Selector::parse("td").map_err(|e| format!("{e:?}"))?;
+    let cell_selector = Selector::parse("th, td").map_err(|e| format!("{e:?}"))?;
 
+    let mut check = TableCheck::new(table, &LAYOUT, warnings);
     let mut batting_order = 1;
 
-    for row in table.select(&row_selector) {
+    for (i, row) in table.select(&row_selector).enumerate() {
         // Skip spacer rows and total rows
         let class = get_attr(row, "class").unwrap_or("");
         if class.contains("spacer") || class.contains("thead") {
             continue;
         }
+        check.start_row(i, &row.select(&cell_selector).collect::<Vec<_>>());
 
         // Get player info from th
         let Some(th) = row.select(&thead_selector).next() else {
+            check.skip_row("no player cell");
             continue;
         };
 
@@ -96,9 +131,9 @@ fn parse_batting_table(table: scraper::ElementRef<'_>, team_code: &str) -> Resul
         }
 
         // Get player bbref_id from data-append-csv attribute
-        let player_bbref_id = match get_attr(th, "data-append-csv") {
-            Some(id) => id.to_string(),
-            None => continue, // Skip rows without player ID
+        let Some(player_bbref_id) = get_attr(th, "data-append-csv").map(str::to_string) else {
+            check.skip_row("no player id");
+            continue;
         };
 
         // Parse player name and position from th text
@@ -153,28 +188,28 @@ fn parse_batting_table(table: scraper::ElementRef<'_>, team_code: &str) -> Resul
             let value = get_text(cell);
 
             match stat_name {
-                "AB" => line.ab = parse_int(&value),
-                "R" => line.r = parse_int(&value),
-                "H" => line.h = parse_int(&value),
-                "RBI" => line.rbi = parse_int(&value),
-                "BB" => line.bb = parse_int(&value),
-                "SO" => line.so = parse_int(&value),
-                "PA" => line.pa = parse_int(&value),
-                "batting_avg" => line.batting_avg = parse_decimal(&value),
-                "onbase_perc" => line.obp = parse_decimal(&value),
-                "slugging_perc" => line.slg = parse_decimal(&value),
-                "onbase_plus_slugging" => line.ops = parse_decimal(&value),
-                "pitches" => line.pitches_seen = parse_int(&value),
-                "strikes_total" => line.strikes_seen = parse_int(&value),
-                "wpa_bat" => line.wpa = parse_decimal(&value),
-                "leverage_index_avg" => line.ali = parse_decimal(&value),
-                "wpa_bat_pos" => line.wpa_pos = parse_decimal(&value),
-                "wpa_bat_neg" => line.wpa_neg = parse_decimal(&value),
-                "cwpa_bat" => line.cwpa = parse_cwpa(&value),
-                "cli_avg" => line.acli = parse_decimal(&value),
-                "re24_bat" => line.re24 = parse_decimal(&value),
-                "PO" => line.po = parse_int(&value),
-                "A" => line.a = parse_int(&value),
+                "AB" => line.ab = check.value(stat_name, &value, parse_int(&value)),
+                "R" => line.r = check.value(stat_name, &value, parse_int(&value)),
+                "H" => line.h = check.value(stat_name, &value, parse_int(&value)),
+                "RBI" => line.rbi = check.value(stat_name, &value, parse_int(&value)),
+                "BB" => line.bb = check.value(stat_name, &value, parse_int(&value)),
+                "SO" => line.so = check.value(stat_name, &value, parse_int(&value)),
+                "PA" => line.pa = check.value(stat_name, &value, parse_int(&value)),
+                "batting_avg" => line.batting_avg = check.value(stat_name, &value, parse_decimal(&value)),
+                "onbase_perc" => line.obp = check.value(stat_name, &value, parse_decimal(&value)),
+                "slugging_perc" => line.slg = check.value(stat_name, &value, parse_decimal(&value)),
+                "onbase_plus_slugging" => line.ops = check.value(stat_name, &value, parse_decimal(&value)),
+                "pitches" => line.pitches_seen = check.value(stat_name, &value, parse_int(&value)),
+                "strikes_total" => line.strikes_seen = check.value(stat_name, &value, parse_int(&value)),
+                "wpa_bat" => line.wpa = check.value(stat_name, &value, parse_decimal(&value)),
+                "leverage_index_avg" => line.ali = check.value(stat_name, &value, parse_decimal(&value)),
+                "wpa_bat_pos" => line.wpa_pos = check.value(stat_name, &value, parse_decimal(&value)),
+                "wpa_bat_neg" => line.wpa_neg = check.value(stat_name, &value, parse_decimal(&value)),
+                "cwpa_bat" => line.cwpa = check.value(stat_name, &value, parse_cwpa(&value)),
+                "cli_avg" => line.acli = check.value(stat_name, &value, parse_decimal(&value)),
+                "re24_bat" => line.re24 = check.value(stat_name, &value, parse_decimal(&value)),
+                "PO" => line.po = check.value(stat_name, &value, parse_int(&value)),
+                "A" => line.a = check.value(stat_name, &value, parse_int(&value)),
                 "details" => {
                     if !value.is_empty() {
                         line.details = Some(value);
@@ -227,6 +262,7 @@ fn parse_cwpa(value: &str) -> Option<Decimal> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::WarningKind;
 
     fn batting_table(id: &str, player: &str) -> String {
         format!(
@@ -246,7 +282,7 @@ mod tests {
         );
         let doc = Html::parse_document(&html);
 
-        let lines = parse_batting_tables(&doc, &[], "SFG", "LAD").expect("parses");
+        let lines = parse_batting_tables(&doc, &[], "SFG", "LAD", &mut Vec::new()).expect("parses");
         assert_eq!(lines.len(), 2);
         assert_eq!(
             (lines[0].player_name.as_str(), lines[0].team_code.as_str()),
@@ -271,7 +307,7 @@ mod tests {
             batting_table("ChicagoCubsbatting", "Doc Home"),
         ));
 
-        let lines = parse_batting_tables(&doc, &[comment], "MIL", "CHC").expect("parses");
+        let lines = parse_batting_tables(&doc, &[comment], "MIL", "CHC", &mut Vec::new()).expect("parses");
         assert_eq!(lines.len(), 2);
         assert_eq!(lines[0].player_name, "Comment Away");
     }
@@ -281,10 +317,52 @@ mod tests {
         let html = format!("<html><body>{}</body></html>", batting_table("OnlyOnebatting", "Solo"));
         let doc = Html::parse_document(&html);
 
-        let err = parse_batting_tables(&doc, &[], "MIL", "CHC").expect_err("one table must not parse");
+        let err = parse_batting_tables(&doc, &[], "MIL", "CHC", &mut Vec::new()).expect_err("one table must not parse");
         assert!(err.contains("found 1"), "unexpected error: {err}");
     }
 
+    #[test]
+    fn test_skipped_rows_and_bad_values_are_warned_about() {
+        let away = r#"<table class="stats_table" id="MilwaukeeBrewersbatting">
+            <thead><tr><th data-stat="player"></th><th data-stat="AB"></th><th data-stat="R"></th><th data-stat="H"></th><th data-stat="RBI"></th><th data-stat="BB"></th><th data-stat="SO"></th><th data-stat="PA"></th></tr></thead>
+            <tbody>
+            <tr><th data-stat="player" data-append-csv="x01">Good Guy 2B</th><td data-stat="AB">4</td><td data-stat="R">0</td><td data-stat="H">?</td><td data-stat="RBI">0</td><td data-stat="BB">0</td><td data-stat="SO">1</td><td data-stat="PA">4</td></tr>
+            <tr><th data-stat="player">No Id Guy SS</th><td data-stat="AB">3</td><td data-stat="R">0</td><td data-stat="H">1</td><td data-stat="RBI">0</td><td data-stat="BB">0</td><td data-stat="SO">0</td><td data-stat="PA">3</td></tr>
+            </tbody></table>"#;
+        let doc = Html::parse_document(&format!(
+            "<html><body>{away}{}</body></html>",
+            batting_table("ChicagoCubsbatting", "Home Guy")
+        ));
+
+        let mut warnings = Vec::new();
+        let lines = parse_batting_tables(&doc, &[], "MIL", "CHC", &mut warnings).expect("parses");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].h, None);
+
+        let away_warnings: Vec<_> = warnings
+            .iter()
+            .filter(|w| w.table == "MilwaukeeBrewersbatting")
+            .map(|w| (w.row, w.column.as_deref(), &w.kind))
+            .collect();
+        assert_eq!(
+            away_warnings,
+            [
+                (
+                    Some(0),
+                    Some("H"),
+                    &WarningKind::UnparsedValue { value: "?".to_string() }
+                ),
+                (Some(1), None, &WarningKind::SkippedRow { reason: "no player id" }),
+            ]
+        );
+        // The home table has no header to check its layout against
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.table == "ChicagoCubsbatting" && w.kind == WarningKind::MissingHeader)
+        );
+    }
+
     #[test]
     fn test_parse_simple_position() {
         let (name, pos) = parse_player_name_position("Shohei Ohtani DH");
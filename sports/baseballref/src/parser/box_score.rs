@@ -4,6 +4,7 @@ use scraper::Html;
 use thiserror::Error;
 
 use super::{
+    ParseWarning, Section,
     batting::{ParsedBattingLine, parse_batting_tables},
     extract_commented_html,
     game_info::{ParsedGameInfo, ParsedUmpire, parse_game_info},
@@ -18,13 +19,19 @@ pub enum ParseError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
-    #[error("Parse error: {0}")]
-    Parse(String),
+    #[error("Failed to parse {section}: {message}")]
+    Parse { section: Section, message: String },
 
     #[error("Missing required data: {0}")]
     MissingData(String),
 }
 
+impl ParseError {
+    fn parse(section: Section, message: String) -> Self {
+        ParseError::Parse { section, message }
+    }
+}
+
 /// Complete parsed box score data
 #[derive(Debug, Clone)]
 pub struct BoxScore {
@@ -36,6 +43,9 @@ pub struct BoxScore {
     pub batting_lines: Vec<ParsedBattingLine>,
     pub pitching_lines: Vec<ParsedPitchingLine>,
     pub play_by_play: Vec<ParsedPlayByPlay>,
+    /// Problems the parser worked around, such as skipped rows or a table
+    /// whose columns no longer match what the parser expects
+    pub warnings: Vec<ParseWarning>,
 }
 
 impl BoxScore {
@@ -61,26 +71,30 @@ impl BoxScore {
         let comment_strings = extract_commented_html(html);
         let comments: Vec<Html> = comment_strings.iter().map(|s| Html::parse_fragment(s)).collect();
 
+        let mut warnings = Vec::new();
+
         // Parse game info
-        let (game_info, umpires) = parse_game_info(&doc, &comments, game_id)
-            .map_err(|e| ParseError::Parse(format!("Failed to parse game info: {e}")))?;
+        let (game_info, umpires) =
+            parse_game_info(&doc, &comments, game_id).map_err(|e| ParseError::parse(Section::GameInfo, e))?;
 
         // Parse line score
         let (away_line_score, home_line_score, pitching_decisions) =
-            parse_line_score(&doc).map_err(|e| ParseError::Parse(format!("Failed to parse line score: {e}")))?;
+            parse_line_score(&doc).map_err(|e| ParseError::parse(Section::LineScore, e))?;
+
+        let away = &game_info.away_team_code;
+        let home = &game_info.home_team_code;
 
         // Parse batting tables
-        let batting_lines = parse_batting_tables(&doc, &comments, &game_info.away_team_code, &game_info.home_team_code)
-            .map_err(|e| ParseError::Parse(format!("Failed to parse batting: {e}")))?;
+        let batting_lines = parse_batting_tables(&doc, &comments, away, home, &mut warnings)
+            .map_err(|e| ParseError::parse(Section::Batting, e))?;
 
         // Parse pitching tables
-        let pitching_lines =
-            parse_pitching_tables(&doc, &comments, &game_info.away_team_code, &game_info.home_team_code)
-                .map_err(|e| ParseError::Parse(format!("Failed to parse pitching: {e}")))?;
+        let pitching_lines = parse_pitching_tables(&doc, &comments, away, home, &mut warnings)
+            .map_err(|e| ParseError::parse(Section::Pitching, e))?;
 
         // Parse play-by-play
-        let play_by_play = parse_play_by_play(&doc, &comments, &game_info.away_team_code, &game_info.home_team_code)
-            .map_err(|e| ParseError::Parse(format!("Failed to parse play-by-play: {e}")))?;
+        let play_by_play = parse_play_by_play(&doc, &comments, away, home, &mut warnings)
+            .map_err(|e| ParseError::parse(Section::PlayByPlay, e))?;
 
         Ok(BoxScore {
             game_info,
@@ -91,9 +105,16 @@ impl BoxScore {
             batting_lines,
             pitching_lines,
             play_by_play,
+            warnings,
         })
     }
 
+    /// True if any table's layout no longer matches what the parser expects,
+    /// a sign bbref changed its pages
+    pub fn has_layout_drift(&self) -> bool {
+        self.warnings.iter().any(|w| w.kind.is_layout_drift())
+    }
+
     /// Get a summary of the parsed data
     pub fn summary(&self) -> String {
         format!(
@@ -0,0 +1,317 @@
+use std::fmt;
+
+use scraper::{ElementRef, Selector};
+
+use super::get_attr;
+
+/// The part of a box score page being parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Section {
+    GameInfo,
+    LineScore,
+    Batting,
+    Pitching,
+    PlayByPlay,
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Section::GameInfo => "game info",
+            Section::LineScore => "line score",
+            Section::Batting => "batting",
+            Section::Pitching => "pitching",
+            Section::PlayByPlay => "play-by-play",
+        })
+    }
+}
+
+/// What the parser noticed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarningKind {
+    /// The table has no header row to check its layout against
+    MissingHeader,
+    /// The header has a column the parser doesn't know
+    UnknownColumn,
+    /// The header lacks a column the parser reads
+    MissingColumn,
+    /// A cell sits under a header for a different column
+    MisalignedCell { expected: String, found: String },
+    /// A cell's value couldn't be read as its column's type
+    UnparsedValue { value: String },
+    /// A row that looked like data was left out
+    SkippedRow { reason: &'static str },
+}
+
+impl WarningKind {
+    /// True for the warnings that mean bbref changed the table's layout
+    pub fn is_layout_drift(&self) -> bool {
+        matches!(
+            self,
+            WarningKind::MissingHeader
+                | WarningKind::UnknownColumn
+                | WarningKind::MissingColumn
+                | WarningKind::MisalignedCell { .. }
+        )
+    }
+
+    /// Short name for grouping warnings in reports
+    pub fn label(&self) -> &'static str {
+        match self {
+            WarningKind::MissingHeader => "missing header",
+            WarningKind::UnknownColumn => "unknown column",
+            WarningKind::MissingColumn => "missing column",
+            WarningKind::MisalignedCell { .. } => "misaligned cell",
+            WarningKind::UnparsedValue { .. } => "unparsed value",
+            WarningKind::SkippedRow { .. } => "skipped row",
+        }
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningKind::MisalignedCell { expected, found } => {
+                write!(f, "misaligned cell: header expects {expected}, found {found}")
+            }
+            WarningKind::UnparsedValue { value } => write!(f, "unparsed value {value:?}"),
+            WarningKind::SkippedRow { reason } => write!(f, "skipped row: {reason}"),
+            kind => f.write_str(kind.label()),
+        }
+    }
+}
+
+/// A problem the parser worked around instead of failing on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    pub section: Section,
+    /// The table's HTML id (e.g. `ChicagoCubsbatting`)
+    pub table: String,
+    /// Index of the row within the table body
+    pub row: Option<usize>,
+    /// The column's `data-stat` name
+    pub column: Option<String>,
+    pub kind: WarningKind,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} table {}", self.section, self.table)?;
+        if let Some(row) = self.row {
+            write!(f, " row {row}")?;
+        }
+        if let Some(column) = &self.column {
+            write!(f, " column {column}")?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// The layout a table parser was written against, by `data-stat` name
+pub struct Layout {
+    pub section: Section,
+    /// Columns the parser reads that every page has
+    pub required: &'static [&'static str],
+    /// Columns the parser reads when present; older seasons lack them
+    pub optional: &'static [&'static str],
+    /// Columns the parser knowingly leaves alone
+    pub ignored: &'static [&'static str],
+}
+
+impl Layout {
+    fn knows(&self, column: &str) -> bool {
+        [self.required, self.optional, self.ignored]
+            .iter()
+            .any(|columns| columns.contains(&column))
+    }
+}
+
+/// Checks one table against its [`Layout`] while it is parsed, collecting
+/// warnings instead of letting mismatches pass silently.
+pub struct TableCheck<'w> {
+    section: Section,
+    table: String,
+    header: Vec<String>,
+    row: Option<usize>,
+    warnings: &'w mut Vec<ParseWarning>,
+}
+
+impl<'w> TableCheck<'w> {
+    /// Start checking `table`, comparing its header signature (the
+    /// `data-stat` of each header cell) against `layout`
+    pub fn new(table: ElementRef<'_>, layout: &Layout, warnings: &'w mut Vec<ParseWarning>) -> Self {
+        let mut check = Self {
+            section: layout.section,
+            table: get_attr(table, "id").unwrap_or("").to_string(),
+            header: header_signature(table),
+            row: None,
+            warnings,
+        };
+
+        if check.header.is_empty() {
+            check.warn(None, WarningKind::MissingHeader);
+            return check;
+        }
+
+        let unknown: Vec<String> = check.header.iter().filter(|col| !layout.knows(col)).cloned().collect();
+        for column in unknown {
+            check.warn(Some(column), WarningKind::UnknownColumn);
+        }
+
+        for &column in layout.required {
+            if !check.header.iter().any(|col| col == column) {
+                check.warn(Some(column.to_string()), WarningKind::MissingColumn);
+            }
+        }
+
+        check
+    }
+
+    /// Move to the next body row, checking each cell sits under the header
+    /// for its own column
+    pub fn start_row(&mut self, index: usize, cells: &[ElementRef<'_>]) {
+        self.row = Some(index);
+
+        if self.header.len() != cells.len() {
+            // Rows that span columns (or are missing some) can't be lined up
+            return;
+        }
+        let misaligned: Vec<(String, String)> = self
+            .header
+            .iter()
+            .zip(cells)
+            .filter_map(|(expected, cell)| {
+                let found = get_attr(*cell, "data-stat").unwrap_or("");
+                (expected != found).then(|| (expected.clone(), found.to_string()))
+            })
+            .collect();
+        for (expected, found) in misaligned {
+            self.warn(Some(expected.clone()), WarningKind::MisalignedCell { expected, found });
+        }
+    }
+
+    /// Note a row that is left out of the parse
+    pub fn skip_row(&mut self, reason: &'static str) {
+        self.warn(None, WarningKind::SkippedRow { reason });
+    }
+
+    /// Pass through a parsed cell value, warning if a non-empty cell didn't parse
+    pub fn value<T>(&mut self, column: &str, text: &str, parsed: Option<T>) -> Option<T> {
+        if parsed.is_none() && !text.trim().is_empty() {
+            self.unparsed(column, text);
+        }
+        parsed
+    }
+
+    /// Note a cell whose value couldn't be read
+    pub fn unparsed(&mut self, column: &str, text: &str) {
+        self.warn(
+            Some(column.to_string()),
+            WarningKind::UnparsedValue {
+                value: text.to_string(),
+            },
+        );
+    }
+
+    fn warn(&mut self, column: Option<String>, kind: WarningKind) {
+        self.warnings.push(ParseWarning {
+            section: self.section,
+            table: self.table.clone(),
+            row: self.row,
+            column,
+            kind,
+        });
+    }
+}
+
+/// The `data-stat` names of a table's column headers, in order. Multi-row
+/// headers are topped by group labels; the last row names the columns.
+fn header_signature(table: ElementRef<'_>) -> Vec<String> {
+    let row_selector = Selector::parse("thead tr").expect("valid selector");
+    let cell_selector = Selector::parse("th, td").expect("valid selector");
+
+    table
+        .select(&row_selector)
+        .last()
+        .map(|row| {
+            row.select(&cell_selector)
+                .map(|cell| get_attr(cell, "data-stat").unwrap_or("").to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use super::*;
+
+    const LAYOUT: Layout = Layout {
+        section: Section::Batting,
+        required: &["AB", "H"],
+        optional: &["pitches"],
+        ignored: &["player"],
+    };
+
+    fn check_table(html: &str) -> Vec<ParseWarning> {
+        let doc = Html::parse_fragment(html);
+        let table = doc.select(&Selector::parse("table").unwrap()).next().unwrap();
+        let cells_selector = Selector::parse("th, td").unwrap();
+        let rows_selector = Selector::parse("tbody tr").unwrap();
+
+        let mut warnings = Vec::new();
+        let mut check = TableCheck::new(table, &LAYOUT, &mut warnings);
+        for (i, row) in table.select(&rows_selector).enumerate() {
+            let cells: Vec<_> = row.select(&cells_selector).collect();
+            check.start_row(i, &cells);
+        }
+        warnings
+    }
+
+    #[test]
+    fn test_matching_layout_has_no_warnings() {
+        let warnings = check_table(
+            r#"<table id="t"><thead><tr><th data-stat="player"></th><th data-stat="AB"></th><th data-stat="H"></th></tr></thead>
+            <tbody><tr><th data-stat="player">A</th><td data-stat="AB">4</td><td data-stat="H">1</td></tr></tbody></table>"#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn test_header_signature_flags_a_renamed_column() {
+        let warnings = check_table(
+            r#"<table id="t"><thead><tr><th data-stat="player"></th><th data-stat="AB"></th><th data-stat="hits"></th></tr></thead>
+            <tbody><tr><th data-stat="player">A</th><td data-stat="AB">4</td><td data-stat="H">1</td></tr></tbody></table>"#,
+        );
+
+        let kinds: Vec<_> = warnings.iter().map(|w| (w.row, w.column.as_deref(), &w.kind)).collect();
+        assert_eq!(
+            kinds,
+            [
+                (None, Some("hits"), &WarningKind::UnknownColumn),
+                (None, Some("H"), &WarningKind::MissingColumn),
+                (
+                    Some(0),
+                    Some("hits"),
+                    &WarningKind::MisalignedCell {
+                        expected: "hits".to_string(),
+                        found: "H".to_string()
+                    }
+                ),
+            ]
+        );
+        assert!(warnings.iter().all(|w| w.kind.is_layout_drift()));
+        assert_eq!(
+            warnings[2].to_string(),
+            "batting table t row 0 column hits: misaligned cell: header expects hits, found H"
+        );
+    }
+
+    #[test]
+    fn test_table_without_header_is_flagged() {
+        let warnings = check_table(r#"<table id="t"><tbody><tr><td data-stat="AB">4</td></tr></tbody></table>"#);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, WarningKind::MissingHeader);
+    }
+}
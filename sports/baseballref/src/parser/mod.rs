@@ -1,5 +1,6 @@
 mod batting;
 mod box_score;
+mod diagnostics;
 mod game_info;
 mod line_score;
 mod pitching;
@@ -7,4 +8,8 @@ mod play_by_play;
 mod util;
 
 pub use box_score::{BoxScore, ParseError};
+pub(crate) use diagnostics::{Layout, TableCheck};
+pub use diagnostics::{ParseWarning, Section, WarningKind};
+pub use line_score::ParsedLineScore;
+pub use play_by_play::ParsedPlayByPlay;
 pub(crate) use util::*;
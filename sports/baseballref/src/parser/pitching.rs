@@ -1,7 +1,34 @@
 use rust_decimal::Decimal;
 use scraper::{Html, Selector};
 
-use super::{get_attr, get_text, parse_decimal, parse_int};
+use super::{Layout, ParseWarning, Section, TableCheck, get_attr, get_text, parse_decimal, parse_int};
+
+/// The pitching table layout the parser reads
+const LAYOUT: Layout = Layout {
+    section: Section::Pitching,
+    required: &["IP", "H", "R", "ER", "BB", "SO", "HR"],
+    optional: &[
+        "earned_run_avg",
+        "batters_faced",
+        "pitches",
+        "strikes_total",
+        "strikes_contact",
+        "strikes_swinging",
+        "strikes_looking",
+        "inplay_gb_total",
+        "inplay_fb_total",
+        "inplay_ld",
+        "game_score",
+        "inherited_runners",
+        "inherited_score",
+        "wpa_def",
+        "leverage_index_avg",
+        "cwpa_def",
+        "cli_avg",
+        "re24_def",
+    ],
+    ignored: &["player", "inplay_unk"],
+};
 
 /// Parsed pitching line
 #[derive(Debug, Clone)]
@@ -42,12 +69,14 @@ pub struct ParsedPitchingLine {
 ///
 /// Table IDs (`LosAngelesDodgerspitching`) contain team names, not codes, so
 /// teams are assigned by document order: bbref always renders the away team's
-/// table first (away bats first).
+/// table first (away bats first). Anything the parser works around is added to
+/// `warnings`.
 pub fn parse_pitching_tables(
     doc: &Html,
     comments: &[Html],
     away_team_code: &str,
     home_team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
 ) -> Result<Vec<ParsedPitchingLine>, String> {
     let tables = super::collect_team_tables(doc, comments, "pitching")?;
 
@@ -55,29 +84,37 @@ pub fn parse_pitching_tables(
         return Err(format!("expected 2 pitching tables, found {}", tables.len()));
     }
 
-    let mut all_lines = parse_pitching_table(tables[0], away_team_code)?;
-    all_lines.extend(parse_pitching_table(tables[1], home_team_code)?);
+    let mut all_lines = parse_pitching_table(tables[0], away_team_code, warnings)?;
+    all_lines.extend(parse_pitching_table(tables[1], home_team_code, warnings)?);
     Ok(all_lines)
 }
 
-fn parse_pitching_table(table: scraper::ElementRef<'_>, team_code: &str) -> Result<Vec<ParsedPitchingLine>, String> {
+fn parse_pitching_table(
+    table: scraper::ElementRef<'_>,
+    team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Vec<ParsedPitchingLine>, String> {
     let mut lines = Vec::new();
 
     let row_selector = Selector::parse("tbody tr").map_err(|e| format!("{e:?}"))?;
     let thead_selector = Selector::parse("th").map_err(|e| format!("{e:?}"))?;
     let tdata_selector = Selector::parse("td").map_err(|e| format!("{e:?}"))?;
+    let cell_selector = Selector::parse("th, td").map_err(|e| format!("{e:?}"))?;
 
+    let mut check = TableCheck::new(table, &LAYOUT, warnings);
     let mut pitch_order = 1;
 
-    for row in table.select(&row_selector) {
+    for (i, row) in table.select(&row_selector).enumerate() {
         // Skip spacer rows
         let class = get_attr(row, "class").unwrap_or("");
         if class.contains("spacer") || class.contains("thead") {
             continue;
         }
+        check.start_row(i, &row.select(&cell_selector).collect::<Vec<_>>());
 
         // Get player info from th
         let Some(th) = row.select(&thead_selector).next() else {
+            check.skip_row("no player cell");
             continue;
         };
 
@@ -88,9 +125,9 @@ fn parse_pitching_table(table: scraper::ElementRef<'_>, team_code: &str) -> Resu
         }
 
         // Get player bbref_id from data-append-csv attribute
-        let player_bbref_id = match get_attr(th, "data-append-csv") {
-            Some(id) => id.to_string(),
-            None => continue,
+        let Some(player_bbref_id) = get_attr(th, "data-append-csv").map(str::to_string) else {
+            check.skip_row("no player id");
+            continue;
         };
 
         // Parse player name and decision from th text
@@ -139,31 +176,31 @@ fn parse_pitching_table(table: scraper::ElementRef<'_>, team_code: &str) -> Resu
             let value = get_text(cell);
 
             match stat_name {
-                "IP" => line.ip = parse_ip(&value),
-                "H" => line.h = parse_int(&value),
-                "R" => line.r = parse_int(&value),
-                "ER" => line.er = parse_int(&value),
-                "BB" => line.bb = parse_int(&value),
-                "SO" => line.so = parse_int(&value),
-                "HR" => line.hr = parse_int(&value),
-                "earned_run_avg" => line.era = parse_decimal(&value),
-                "batters_faced" => line.batters_faced = parse_int(&value),
-                "pitches" => line.pitches = parse_int(&value),
-                "strikes_total" => line.strikes = parse_int(&value),
-                "strikes_contact" => line.strikes_contact = parse_int(&value),
-                "strikes_swinging" => line.strikes_swinging = parse_int(&value),
-                "strikes_looking" => line.strikes_looking = parse_int(&value),
-                "inplay_gb_total" => line.ground_balls = parse_int(&value),
-                "inplay_fb_total" => line.fly_balls = parse_int(&value),
-                "inplay_ld" => line.line_drives = parse_int(&value),
-                "game_score" => line.game_score = parse_int(&value),
-                "inherited_runners" => line.inherited_runners = parse_int(&value),
-                "inherited_score" => line.inherited_scored = parse_int(&value),
-                "wpa_def" => line.wpa = parse_decimal(&value),
-                "leverage_index_avg" => line.ali = parse_decimal(&value),
-                "cwpa_def" => line.cwpa = parse_cwpa(&value),
-                "cli_avg" => line.acli = parse_decimal(&value),
-                "re24_def" => line.re24 = parse_decimal(&value),
+                "IP" => line.ip = check.value(stat_name, &value, parse_ip(&value)),
+                "H" => line.h = check.value(stat_name, &value, parse_int(&value)),
+                "R" => line.r = check.value(stat_name, &value, parse_int(&value)),
+                "ER" => line.er = check.value(stat_name, &value, parse_int(&value)),
+                "BB" => line.bb = check.value(stat_name, &value, parse_int(&value)),
+                "SO" => line.so = check.value(stat_name, &value, parse_int(&value)),
+                "HR" => line.hr = check.value(stat_name, &value, parse_int(&value)),
+                "earned_run_avg" => line.era = check.value(stat_name, &value, parse_decimal(&value)),
+                "batters_faced" => line.batters_faced = check.value(stat_name, &value, parse_int(&value)),
+                "pitches" => line.pitches = check.value(stat_name, &value, parse_int(&value)),
+                "strikes_total" => line.strikes = check.value(stat_name, &value, parse_int(&value)),
+                "strikes_contact" => line.strikes_contact = check.value(stat_name, &value, parse_int(&value)),
+                "strikes_swinging" => line.strikes_swinging = check.value(stat_name, &value, parse_int(&value)),
+                "strikes_looking" => line.strikes_looking = check.value(stat_name, &value, parse_int(&value)),
+                "inplay_gb_total" => line.ground_balls = check.value(stat_name, &value, parse_int(&value)),
+                "inplay_fb_total" => line.fly_balls = check.value(stat_name, &value, parse_int(&value)),
+                "inplay_ld" => line.line_drives = check.value(stat_name, &value, parse_int(&value)),
+                "game_score" => line.game_score = check.value(stat_name, &value, parse_int(&value)),
+                "inherited_runners" => line.inherited_runners = check.value(stat_name, &value, parse_int(&value)),
+                "inherited_score" => line.inherited_scored = check.value(stat_name, &value, parse_int(&value)),
+                "wpa_def" => line.wpa = check.value(stat_name, &value, parse_decimal(&value)),
+                "leverage_index_avg" => line.ali = check.value(stat_name, &value, parse_decimal(&value)),
+                "cwpa_def" => line.cwpa = check.value(stat_name, &value, parse_cwpa(&value)),
+                "cli_avg" => line.acli = check.value(stat_name, &value, parse_decimal(&value)),
+                "re24_def" => line.re24 = check.value(stat_name, &value, parse_decimal(&value)),
                 _ => {}
             }
         }
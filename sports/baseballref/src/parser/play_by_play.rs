@@ -1,7 +1,29 @@
 use rust_decimal::Decimal;
 use scraper::{Html, Selector};
 
-use super::{get_attr, get_text, parse_int, parse_percentage};
+use super::{Layout, ParseWarning, Section, TableCheck, get_attr, get_text, parse_int, parse_percentage};
+
+/// The play-by-play table layout the parser reads
+const LAYOUT: Layout = Layout {
+    section: Section::PlayByPlay,
+    required: &[
+        "inning",
+        "outs",
+        "runners_on_bases_pbp",
+        "runs_outs_result",
+        "batter",
+        "pitcher",
+        "play_desc",
+    ],
+    optional: &[
+        "score_batting_team",
+        "pitches_pbp",
+        "batting_team_id",
+        "win_probability_added",
+        "win_expectancy_post",
+    ],
+    ignored: &[],
+};
 
 /// Parsed play-by-play event
 #[derive(Debug, Clone)]
@@ -27,12 +49,14 @@ pub struct ParsedPlayByPlay {
     pub play_description: Option<String>,
 }
 
-/// Parse play-by-play table
+/// Parse play-by-play table. Anything the parser works around is added to
+/// `warnings`.
 pub fn parse_play_by_play(
     _doc: &Html,
     comments: &[Html],
     away_team_code: &str,
     home_team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
 ) -> Result<Vec<ParsedPlayByPlay>, String> {
     let mut events = Vec::new();
 
@@ -41,7 +65,7 @@ pub fn parse_play_by_play(
         let table_selector = Selector::parse("#play_by_play").map_err(|e| format!("{e:?}"))?;
 
         if let Some(table) = comment_doc.select(&table_selector).next() {
-            events = parse_pbp_table(table, away_team_code, home_team_code)?;
+            events = parse_pbp_table(table, away_team_code, home_team_code, warnings)?;
             break;
         }
     }
@@ -53,30 +77,36 @@ fn parse_pbp_table(
     table: scraper::ElementRef<'_>,
     away_team_code: &str,
     home_team_code: &str,
+    warnings: &mut Vec<ParseWarning>,
 ) -> Result<Vec<ParsedPlayByPlay>, String> {
     let mut events = Vec::new();
 
     let row_selector = Selector::parse("tbody tr").map_err(|e| format!("{e:?}"))?;
     let thead_selector = Selector::parse("th").map_err(|e| format!("{e:?}"))?;
     let tdata_selector = Selector::parse("td").map_err(|e| format!("{e:?}"))?;
+    let cell_selector = Selector::parse("th, td").map_err(|e| format!("{e:?}"))?;
 
+    let mut check = TableCheck::new(table, &LAYOUT, warnings);
     let mut event_num = 0;
 
-    for row in table.select(&row_selector) {
+    for (i, row) in table.select(&row_selector).enumerate() {
         let class = get_attr(row, "class").unwrap_or("");
 
         // Skip summary rows
         if class.contains("pbp_summary") || class.contains("ingame_substitution") {
             continue;
         }
+        check.start_row(i, &row.select(&cell_selector).collect::<Vec<_>>());
 
         // Get inning from first th
         let Some(th) = row.select(&thead_selector).next() else {
+            check.skip_row("no inning cell");
             continue;
         };
 
         let inning_text = get_text(th);
         if inning_text.is_empty() {
+            check.skip_row("no inning");
             continue;
         }
 
@@ -86,6 +116,7 @@ fn parse_pbp_table(
         // Parse cells
         let cells: Vec<_> = row.select(&tdata_selector).collect();
         if cells.len() < 10 {
+            check.skip_row("fewer than 10 cells");
             continue;
         }
 
@@ -127,12 +158,14 @@ fn parse_pbp_table(
                     // Format: "0-0" or "1-0"
                     let parts: Vec<&str> = value.split('-').collect();
                     if parts.len() == 2 {
-                        event.score_batting_team = parse_int(parts[0]);
-                        event.score_fielding_team = parse_int(parts[1]);
+                        event.score_batting_team = check.value(stat_name, &value, parse_int(parts[0]));
+                        event.score_fielding_team = check.value(stat_name, &value, parse_int(parts[1]));
+                    } else if !value.is_empty() {
+                        check.unparsed(stat_name, &value);
                     }
                 }
                 "outs" => {
-                    event.outs_before = parse_int(&value);
+                    event.outs_before = check.value(stat_name, &value, parse_int(&value));
                 }
                 "runners_on_bases_pbp" => {
                     if !value.is_empty() && value != "---" {
@@ -157,15 +190,10 @@ fn parse_pbp_table(
                     match (matches_away, matches_home) {
                         (true, false) => event.batting_team_code = away_team_code.to_string(),
                         (false, true) => event.batting_team_code = home_team_code.to_string(),
+                        // Names neither team (or both); keep the inning half
                         _ => {
                             if !value.is_empty() {
-                                tracing::warn!(
-                                    event_num,
-                                    cell = %value,
-                                    away = %away_team_code,
-                                    home = %home_team_code,
-                                    "play-by-play batting-team cell names neither team; using inning half"
-                                );
+                                check.unparsed(stat_name, &value);
                             }
                         }
                     }
@@ -178,11 +206,11 @@ fn parse_pbp_table(
                 }
                 "win_probability_added" => {
                     // Format: "-2%" or "4%"
-                    event.wpa = parse_percentage(&value);
+                    event.wpa = check.value(stat_name, &value, parse_percentage(&value));
                 }
                 "win_expectancy_post" => {
                     // Format: "48%"
-                    if let Some(pct) = parse_percentage(&value) {
+                    if let Some(pct) = check.value(stat_name, &value, parse_percentage(&value)) {
                         // Convert to decimal (48% -> 0.48)
                         event.win_expectancy_after = Some(pct / Decimal::from(100));
                     }
@@ -197,7 +225,9 @@ fn parse_pbp_table(
         }
 
         // Only add events with valid data
-        if !event.batter_name.is_empty() {
+        if event.batter_name.is_empty() {
+            check.skip_row("no batter");
+        } else {
             events.push(event);
         }
     }
@@ -243,6 +273,7 @@ fn parse_pitch_info(text: &str) -> (Option<i32>, Option<String>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::WarningKind;
 
     fn pbp_row(half: &str, team_cell: &str, batter: &str) -> String {
         format!(
@@ -273,7 +304,7 @@ mod tests {
         let comment = Html::parse_fragment(&table);
         let doc = Html::parse_document("<html><body></body></html>");
 
-        let events = parse_play_by_play(&doc, &[comment], "SFG", "LAD").expect("parses");
+        let events = parse_play_by_play(&doc, &[comment], "SFG", "LAD", &mut Vec::new()).expect("parses");
         assert_eq!(events.len(), 2);
         assert!(!events[0].is_bottom);
         assert_eq!(events[0].batting_team_code, "SFG");
@@ -294,7 +325,7 @@ mod tests {
         let comment = Html::parse_fragment(&table);
         let doc = Html::parse_document("<html><body></body></html>");
 
-        let events = parse_play_by_play(&doc, &[comment], "SEA", "SDP").expect("parses");
+        let events = parse_play_by_play(&doc, &[comment], "SEA", "SDP", &mut Vec::new()).expect("parses");
         assert_eq!(events.len(), 2);
         assert_eq!(events[0].batting_team_code, "SDP");
         assert_eq!(events[1].batting_team_code, "SEA");
@@ -309,8 +340,20 @@ mod tests {
         let comment = Html::parse_fragment(&table);
         let doc = Html::parse_document("<html><body></body></html>");
 
-        let events = parse_play_by_play(&doc, &[comment], "SFG", "LAD").expect("parses");
+        let mut warnings = Vec::new();
+        let events = parse_play_by_play(&doc, &[comment], "SFG", "LAD", &mut warnings).expect("parses");
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].batting_team_code, "LAD");
+
+        let unparsed: Vec<_> = warnings.iter().filter(|w| !w.kind.is_layout_drift()).collect();
+        assert_eq!(unparsed.len(), 1);
+        assert_eq!(unparsed[0].row, Some(0));
+        assert_eq!(unparsed[0].column.as_deref(), Some("batting_team_id"));
+        assert_eq!(
+            unparsed[0].kind,
+            WarningKind::UnparsedValue {
+                value: "???".to_string()
+            }
+        );
     }
 }
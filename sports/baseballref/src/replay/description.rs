@@ -0,0 +1,339 @@
+use baseball_game_rules::{Base, Destination, PlayerPosition, RunnerEvent, Scoring};
+
+use super::TranslateError;
+
+/// One play-by-play description, split into what happened at the plate and
+/// where the runners named after it went.
+///
+/// Baseball Reference writes the batter's play first and then one clause per
+/// runner who moved, separated by semicolons:
+/// `"Single to CF (Line Drive); Freeman Scores; Betts to 3B/Adv on throw"`.
+/// Anyone it does not name stayed where they were.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Description {
+    pub event: Event,
+    pub runners: Vec<RunnerMove>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Event {
+    Strikeout,
+    Walk,
+    HitByPitch,
+    HomeRun,
+    /// A ball in play. `unnamed_outs` runners were retired without a clause
+    /// of their own, as in `"Ground Ball Double Play: SS-2B-1B"`; `forced_at`
+    /// is the base a `"Forceout at 2B"` was made at.
+    InPlay {
+        scoring: Scoring,
+        batter: Destination,
+        unnamed_outs: u8,
+        forced_at: Option<Base>,
+    },
+    /// Runners moving without the batter. Wild pitches, passed balls and
+    /// balks name every runner who moved; steals, caught stealing and pickoffs
+    /// leave the runner implied.
+    Running(Option<RunnerEvent>),
+}
+
+/// A runner clause: a surname and where that runner ended up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RunnerMove {
+    pub name: String,
+    pub destination: Destination,
+}
+
+impl Description {
+    pub fn parse(text: &str) -> Result<Self, TranslateError> {
+        let text = text.replace('\u{a0}', " ");
+        let mut clauses = text.split(';').map(str::trim);
+        let play = clauses.next().unwrap_or_default();
+        let event = parse_event(play).ok_or_else(|| TranslateError::UnrecognizedPlay(play.to_string()))?;
+        let runners = clauses
+            .filter(|clause| !clause.is_empty())
+            .map(parse_runner)
+            .collect::<Result<_, _>>()?;
+        Ok(Description { event, runners })
+    }
+}
+
+const OUTS: [&str; 8] = [
+    "Groundout",
+    "Flyball",
+    "Popfly",
+    "Lineout",
+    "Flyout",
+    "Popup",
+    "Bunt Groundout",
+    "Bunt Popfly",
+];
+
+fn parse_event(play: &str) -> Option<Event> {
+    let in_play = |scoring, batter| Event::InPlay {
+        scoring,
+        batter,
+        unnamed_outs: 0,
+        forced_at: None,
+    };
+    let first = Destination::Safe(Base::First);
+
+    let event = if play.starts_with("Strikeout") {
+        Event::Strikeout
+    } else if play.starts_with("Walk") || play.starts_with("Intentional Walk") {
+        Event::Walk
+    } else if play.starts_with("Hit By Pitch") {
+        Event::HitByPitch
+    } else if play.contains("Home Run") {
+        Event::HomeRun
+    } else if play.contains("Triple Play") {
+        Event::InPlay {
+            scoring: Scoring::Out,
+            batter: Destination::Out,
+            unnamed_outs: 2,
+            forced_at: None,
+        }
+    } else if play.contains("Double Play") {
+        Event::InPlay {
+            scoring: Scoring::Out,
+            batter: Destination::Out,
+            unnamed_outs: 1,
+            forced_at: None,
+        }
+    } else if play.starts_with("Single") {
+        in_play(Scoring::Single, first)
+    } else if play.starts_with("Double") || play.starts_with("Ground-rule Double") {
+        in_play(Scoring::Double, Destination::Safe(Base::Second))
+    } else if play.starts_with("Triple") {
+        in_play(Scoring::Triple, Destination::Safe(Base::Third))
+    } else if let Some(fielder) = reached_on_error(play) {
+        in_play(Scoring::Error(fielder), first)
+    } else if play.contains("Sacrifice Fly") || play.contains("Sacrifice Bunt") {
+        in_play(Scoring::Sacrifice, Destination::Out)
+    } else if let Some(at) = play.split("Forceout at ").nth(1) {
+        Event::InPlay {
+            scoring: Scoring::FieldersChoice,
+            batter: first,
+            unnamed_outs: 0,
+            forced_at: Some(parse_base(at)?),
+        }
+    } else if play.starts_with("Fielder's Choice") {
+        in_play(Scoring::FieldersChoice, first)
+    } else if OUTS.iter().any(|out| play.starts_with(out)) || play.starts_with("Foul ") {
+        in_play(Scoring::Out, Destination::Out)
+    } else {
+        return parse_running(play);
+    };
+    Some(event)
+}
+
+/// `"Reached on E6 (throw to 1B)"`, or catcher's interference, which the
+/// scorer charges to the catcher.
+fn reached_on_error(play: &str) -> Option<PlayerPosition> {
+    if play.starts_with("Reached on Interference") || play.starts_with("Catcher Interference") {
+        return Some(PlayerPosition::Catcher);
+    }
+    let digit = play.strip_prefix("Reached on E")?.chars().next()?;
+    fielder(digit.to_digit(10)?)
+}
+
+fn fielder(number: u32) -> Option<PlayerPosition> {
+    Some(match number {
+        1 => PlayerPosition::Pitcher,
+        2 => PlayerPosition::Catcher,
+        3 => PlayerPosition::FirstBase,
+        4 => PlayerPosition::SecondBase,
+        5 => PlayerPosition::ThirdBase,
+        6 => PlayerPosition::Shortstop,
+        7 => PlayerPosition::LeftField,
+        8 => PlayerPosition::CenterField,
+        9 => PlayerPosition::RightField,
+        _ => return None,
+    })
+}
+
+/// A row that does not end the plate appearance. Some, like `"Defensive
+/// Indifference"`, have no event of their own and leave it all to the clauses.
+fn parse_running(play: &str) -> Option<Event> {
+    let at = |prefix: &str| play.strip_prefix(prefix).and_then(parse_base);
+    let event = if let Some(base) = at("Stolen Base ") {
+        Some(RunnerEvent::StolenBase(base))
+    } else if let Some(base) = at("Caught Stealing ") {
+        Some(RunnerEvent::CaughtStealing(base))
+    } else if let Some(base) = at("Picked off ").or_else(|| at("Pickoff ")) {
+        Some(RunnerEvent::Pickoff(base))
+    } else if play.starts_with("Wild Pitch") {
+        Some(RunnerEvent::WildPitch)
+    } else if play.starts_with("Passed Ball") {
+        Some(RunnerEvent::PassedBall)
+    } else if play.starts_with("Balk") {
+        Some(RunnerEvent::Balk)
+    } else if ["Defensive Indifference", "Advance", "Runner"]
+        .iter()
+        .any(|prefix| play.starts_with(prefix))
+    {
+        None
+    } else {
+        return None;
+    };
+    Some(Event::Running(event))
+}
+
+/// `"2B"`, `"3B (C-SS)"`, `"Hm/CF-C"`: the base at the start of `text`.
+fn parse_base(text: &str) -> Option<Base> {
+    let token = text
+        .split(|c: char| c.is_whitespace() || c == '/' || c == '(' || c == ':')
+        .next()?;
+    match token {
+        "1B" => Some(Base::First),
+        "2B" => Some(Base::Second),
+        "3B" => Some(Base::Third),
+        "Hm" | "HM" | "Home" => Some(Base::Home),
+        _ => None,
+    }
+}
+
+/// `"Freeman Scores/unER"`, `"Betts to 3B/Adv on throw"`, `"Smith out at Hm/CF-C"`.
+fn parse_runner(clause: &str) -> Result<RunnerMove, TranslateError> {
+    let unrecognized = || TranslateError::UnrecognizedRunner(clause.to_string());
+    let movement = clause.split('/').next().unwrap_or_default().trim();
+
+    let (name, destination) = if let Some((name, _)) = movement.split_once(" out at ") {
+        (name, Destination::Out)
+    } else if let Some(name) = movement.strip_suffix(" Scores") {
+        (name, Destination::Safe(Base::Home))
+    } else if let Some((name, base)) = movement.split_once(" to ") {
+        (name, Destination::Safe(parse_base(base).ok_or_else(unrecognized)?))
+    } else {
+        return Err(unrecognized());
+    };
+    Ok(RunnerMove {
+        name: name.trim().to_string(),
+        destination,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(text: &str) -> Event {
+        Description::parse(text).expect("recognized").event
+    }
+
+    fn scoring(text: &str) -> (Scoring, Destination) {
+        match event(text) {
+            Event::InPlay { scoring, batter, .. } => (scoring, batter),
+            other => panic!("{text} is not in play: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_plate_appearances_that_end_without_a_ball_in_play() {
+        assert_eq!(event("Strikeout Swinging"), Event::Strikeout);
+        assert_eq!(event("Strikeout Looking"), Event::Strikeout);
+        assert_eq!(event("Walk"), Event::Walk);
+        assert_eq!(event("Intentional Walk"), Event::Walk);
+        assert_eq!(event("Hit By Pitch"), Event::HitByPitch);
+        assert_eq!(event("Home Run (Fly Ball to Deep LF Line)"), Event::HomeRun);
+        assert_eq!(event("Inside-the-park Home Run (Fly Ball to CF)"), Event::HomeRun);
+    }
+
+    #[test]
+    fn test_balls_in_play_are_scored_like_the_scorer_scored_them() {
+        let first = Destination::Safe(Base::First);
+        assert_eq!(scoring("Single to CF (Line Drive)"), (Scoring::Single, first));
+        assert_eq!(
+            scoring("Ground-rule Double (Fly Ball to Deep LF)"),
+            (Scoring::Double, Destination::Safe(Base::Second))
+        );
+        assert_eq!(scoring("Groundout: SS-1B"), (Scoring::Out, Destination::Out));
+        assert_eq!(scoring("Popfly: C (Foul Territory)"), (Scoring::Out, Destination::Out));
+        assert_eq!(
+            scoring("Flyball: LF/Sacrifice Fly (Deep LF)"),
+            (Scoring::Sacrifice, Destination::Out)
+        );
+        assert_eq!(scoring("Sacrifice Bunt: P-1B"), (Scoring::Sacrifice, Destination::Out));
+        assert_eq!(
+            scoring("Reached on E6 (throw to 1B) (Ground Ball to SS)"),
+            (Scoring::Error(PlayerPosition::Shortstop), first)
+        );
+        assert_eq!(scoring("Fielder's Choice 2B"), (Scoring::FieldersChoice, first));
+    }
+
+    #[test]
+    fn test_outs_without_a_runner_clause_are_implied() {
+        assert_eq!(
+            event("Ground Ball Double Play: SS-2B-1B"),
+            Event::InPlay {
+                scoring: Scoring::Out,
+                batter: Destination::Out,
+                unnamed_outs: 1,
+                forced_at: None,
+            }
+        );
+        assert_eq!(
+            event("Groundout: SS-2B/Forceout at 2B"),
+            Event::InPlay {
+                scoring: Scoring::FieldersChoice,
+                batter: Destination::Safe(Base::First),
+                unnamed_outs: 0,
+                forced_at: Some(Base::Second),
+            }
+        );
+    }
+
+    #[test]
+    fn test_runner_clauses_name_where_each_runner_went() {
+        let description = Description::parse(
+            "Single to RF (Ground Ball); Freeman Scores/unER; Betts to 3B/Adv on throw; Smith out at Hm/RF-C",
+        )
+        .expect("recognized");
+        assert_eq!(
+            description.runners,
+            vec![
+                RunnerMove {
+                    name: "Freeman".to_string(),
+                    destination: Destination::Safe(Base::Home),
+                },
+                RunnerMove {
+                    name: "Betts".to_string(),
+                    destination: Destination::Safe(Base::Third),
+                },
+                RunnerMove {
+                    name: "Smith".to_string(),
+                    destination: Destination::Out,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_running_plays_keep_the_batter_at_the_plate() {
+        assert_eq!(
+            event("Stolen Base 2B"),
+            Event::Running(Some(RunnerEvent::StolenBase(Base::Second)))
+        );
+        assert_eq!(
+            event("Caught Stealing 3B (C-3B)"),
+            Event::Running(Some(RunnerEvent::CaughtStealing(Base::Third)))
+        );
+        assert_eq!(
+            event("Picked off 1B (P-1B)"),
+            Event::Running(Some(RunnerEvent::Pickoff(Base::First)))
+        );
+        assert_eq!(event("Wild Pitch"), Event::Running(Some(RunnerEvent::WildPitch)));
+        assert_eq!(event("Defensive Indifference"), Event::Running(None));
+    }
+
+    #[test]
+    fn test_unrecognized_text_is_an_error_not_a_guess() {
+        assert!(matches!(
+            Description::parse("Something odd happened"),
+            Err(TranslateError::UnrecognizedPlay(_))
+        ));
+        assert!(matches!(
+            Description::parse("Single to LF; Freeman went somewhere"),
+            Err(TranslateError::UnrecognizedRunner(_))
+        ));
+    }
+}
@@ -0,0 +1,969 @@
+//! Replaying scraped play-by-play through the rules engine.
+//!
+//! Each row's free-text description is translated into the `PitchOutcome`s
+//! that `baseball_game_rules::Game` advances on, and the whole game is played
+//! again from the first pitch. Wherever the replay and the page disagree — the
+//! inning, outs or score before a row, a half inning's runs, the final score —
+//! the translator, the engine or the scraper is wrong, and the game is flagged.
+
+mod description;
+
+use std::fmt;
+
+use baseball_game_rules::{
+    Base, BaserunnerState, BattingPosition, Destination, Game, GameOutcome, HalfInning, InningHalf, PitchOutcome,
+    PlayResult, RuleSet, RunnerAdvance, RunnerEvent, Runs, Scoring,
+};
+use description::{Description, Event, RunnerMove};
+use thiserror::Error;
+
+use crate::parser::{BoxScore, ParsedLineScore, ParsedPlayByPlay};
+
+/// Why a row could not be turned into rules-engine events.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TranslateError {
+    #[error("no play description")]
+    MissingDescription,
+
+    #[error("unrecognized play: {0}")]
+    UnrecognizedPlay(String),
+
+    #[error("unrecognized runner clause: {0}")]
+    UnrecognizedRunner(String),
+
+    #[error("no runner on base for {0}")]
+    UnknownRunner(String),
+
+    #[error("{0} would have to go back a base")]
+    RunnerBackwards(String),
+}
+
+/// The columns of a play-by-play row the replay reads, whether it came from a
+/// parsed page or from the `play_by_play` table.
+#[derive(Debug, Clone, Default)]
+pub struct PlayRow {
+    pub event_num: i32,
+    pub inning: i32,
+    pub is_bottom: bool,
+    pub batter_name: String,
+    pub outs_before: Option<i32>,
+    pub runners_before: Option<String>,
+    pub score_batting_team: Option<i32>,
+    pub score_fielding_team: Option<i32>,
+    pub pitch_sequence: Option<String>,
+    pub play_description: Option<String>,
+}
+
+impl From<&ParsedPlayByPlay> for PlayRow {
+    fn from(event: &ParsedPlayByPlay) -> Self {
+        PlayRow {
+            event_num: event.event_num,
+            inning: event.inning,
+            is_bottom: event.is_bottom,
+            batter_name: event.batter_name.clone(),
+            outs_before: event.outs_before,
+            runners_before: event.runners_before.clone(),
+            score_batting_team: event.score_batting_team,
+            score_fielding_team: event.score_fielding_team,
+            pitch_sequence: event.pitch_sequence.clone(),
+            play_description: event.play_description.clone(),
+        }
+    }
+}
+
+/// One team's row of the scraped line score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScrapedLine {
+    pub innings: Vec<i32>,
+    pub total_runs: i32,
+}
+
+impl From<&ParsedLineScore> for ScrapedLine {
+    fn from(line: &ParsedLineScore) -> Self {
+        ScrapedLine {
+            innings: line.innings.clone(),
+            total_runs: line.total_runs,
+        }
+    }
+}
+
+/// Somewhere the replay and the scraped game part ways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Discrepancy {
+    /// The replay stops at a row it cannot translate.
+    Untranslatable {
+        event_num: i32,
+        reason: TranslateError,
+    },
+    /// The replayed game ended before the scraped one did.
+    GameOver {
+        event_num: i32,
+    },
+    /// The row went on after the replay's third out, and what came after was
+    /// left out.
+    PastThirdOut {
+        event_num: i32,
+    },
+    Inning {
+        event_num: i32,
+        scraped: (i32, bool),
+        replayed: (u8, bool),
+    },
+    Outs {
+        event_num: i32,
+        scraped: i32,
+        replayed: u8,
+    },
+    /// Batting team's runs, then the fielding team's.
+    Score {
+        event_num: i32,
+        scraped: (i32, i32),
+        replayed: (i32, i32),
+    },
+    InningRuns {
+        is_home: bool,
+        inning: usize,
+        scraped: i32,
+        replayed: i32,
+    },
+    TotalRuns {
+        is_home: bool,
+        scraped: i32,
+        replayed: i32,
+    },
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |is_home: bool| if is_home { "home" } else { "away" };
+        let half = |is_bottom: bool| if is_bottom { 'b' } else { 't' };
+        match self {
+            Discrepancy::Untranslatable { event_num, reason } => write!(f, "event {event_num}: {reason}"),
+            Discrepancy::GameOver { event_num } => write!(f, "event {event_num}: the replayed game was already over"),
+            Discrepancy::PastThirdOut { event_num } => {
+                write!(f, "event {event_num}: the play went on past the replayed third out")
+            }
+            Discrepancy::Inning {
+                event_num,
+                scraped,
+                replayed,
+            } => write!(
+                f,
+                "event {event_num}: scraped {}{}, replayed {}{}",
+                half(scraped.1),
+                scraped.0,
+                half(replayed.1),
+                replayed.0
+            ),
+            Discrepancy::Outs {
+                event_num,
+                scraped,
+                replayed,
+            } => write!(f, "event {event_num}: scraped {scraped} out(s), replayed {replayed}"),
+            Discrepancy::Score {
+                event_num,
+                scraped,
+                replayed,
+            } => write!(
+                f,
+                "event {event_num}: scraped score {}-{}, replayed {}-{}",
+                scraped.0, scraped.1, replayed.0, replayed.1
+            ),
+            Discrepancy::InningRuns {
+                is_home,
+                inning,
+                scraped,
+                replayed,
+            } => write!(
+                f,
+                "{} inning {inning}: scraped {scraped} run(s), replayed {replayed}",
+                side(*is_home)
+            ),
+            Discrepancy::TotalRuns {
+                is_home,
+                scraped,
+                replayed,
+            } => write!(
+                f,
+                "{} total: scraped {scraped} run(s), replayed {replayed}",
+                side(*is_home)
+            ),
+        }
+    }
+}
+
+/// What replaying one game found. Only the first row whose situation
+/// disagrees is reported, since every row after it usually follows suit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    pub rows_replayed: usize,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl ReplayReport {
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// The rules a scraped game was played under. Seven-inning doubleheaders come
+/// from the game id (2020 and 2021 only); the extra-inning runner from whether
+/// the first extra half inning opened with a runner on second, which also
+/// gets the postseason right.
+pub fn rules_for(bbref_game_id: &str, rows: &[PlayRow]) -> RuleSet {
+    let year = bbref_game_id.get(3..7).and_then(|year| year.parse::<i32>().ok());
+    let doubleheader = bbref_game_id.get(11..12).is_some_and(|game| game != "0");
+    let rules = if doubleheader && matches!(year, Some(2020 | 2021)) {
+        RuleSet::seven_inning_doubleheader()
+    } else {
+        RuleSet::major_league()
+    };
+
+    let regulation = i32::from(rules.regulation_innings());
+    let runner = rows
+        .iter()
+        .find(|row| row.inning > regulation)
+        .is_some_and(|row| row.runners_before.as_deref() == Some("-2-"));
+    rules.with_extra_inning_runner(runner)
+}
+
+/// Replays a parsed box score's play-by-play against its own line score.
+pub fn replay_box_score(box_score: &BoxScore) -> ReplayReport {
+    let rows: Vec<PlayRow> = box_score.play_by_play.iter().map(PlayRow::from).collect();
+    let rules = rules_for(&box_score.game_info.bbref_game_id, &rows);
+    replay(
+        &rows,
+        rules,
+        &ScrapedLine::from(&box_score.away_line_score),
+        &ScrapedLine::from(&box_score.home_line_score),
+    )
+}
+
+/// Plays `rows` through a fresh game under `rules` and compares the result
+/// with the scraped line score.
+pub fn replay(rows: &[PlayRow], rules: RuleSet, away: &ScrapedLine, home: &ScrapedLine) -> ReplayReport {
+    let mut report = ReplayReport::default();
+    let mut names = Names::default();
+    let mut outcome = GameOutcome::InProgress(Game::new(rules));
+    let mut in_step = true;
+
+    for row in rows {
+        let Some(game) = outcome.game_ref() else {
+            report.discrepancies.push(Discrepancy::GameOver {
+                event_num: row.event_num,
+            });
+            return report;
+        };
+        if in_step && let Some(discrepancy) = compare_situation(row, game) {
+            report.discrepancies.push(discrepancy);
+            in_step = false;
+        }
+
+        match translate(row, game.current_half_inning(), &mut names) {
+            Ok(pitches) => {
+                if pitches.past_third_out {
+                    report.discrepancies.push(Discrepancy::PastThirdOut {
+                        event_num: row.event_num,
+                    });
+                }
+                outcome = pitches.thrown.into_iter().fold(outcome, GameOutcome::advance);
+            }
+            Err(reason) => {
+                report.discrepancies.push(Discrepancy::Untranslatable {
+                    event_num: row.event_num,
+                    reason,
+                });
+                return report;
+            }
+        }
+        report.rows_replayed += 1;
+    }
+
+    let (away_innings, home_innings) = replayed_innings(&outcome);
+    compare_line(false, away, &away_innings, &mut report);
+    compare_line(true, home, &home_innings, &mut report);
+    report
+}
+
+fn compare_situation(row: &PlayRow, game: &Game) -> Option<Discrepancy> {
+    let half = game.current_half_inning();
+    let inning = game.current_inning().as_number();
+    let is_bottom = half.half() == InningHalf::Bottom;
+    if row.inning != i32::from(inning) || row.is_bottom != is_bottom {
+        return Some(Discrepancy::Inning {
+            event_num: row.event_num,
+            scraped: (row.inning, row.is_bottom),
+            replayed: (inning, is_bottom),
+        });
+    }
+
+    let outs = half.outs().as_number();
+    if let Some(scraped) = row.outs_before
+        && scraped != i32::from(outs)
+    {
+        return Some(Discrepancy::Outs {
+            event_num: row.event_num,
+            scraped,
+            replayed: outs,
+        });
+    }
+
+    let (batting, fielding) = if is_bottom {
+        (game.score().home(), game.score().away())
+    } else {
+        (game.score().away(), game.score().home())
+    };
+    let replayed = (i32::from(batting) + i32::from(half.runs_scored()), i32::from(fielding));
+    if let (Some(batting), Some(fielding)) = (row.score_batting_team, row.score_fielding_team)
+        && (batting, fielding) != replayed
+    {
+        return Some(Discrepancy::Score {
+            event_num: row.event_num,
+            scraped: (batting, fielding),
+            replayed,
+        });
+    }
+    None
+}
+
+/// Runs by inning for each side, counting a half inning still in progress
+/// when the rows ran out (a game called early, or cut short by a bad row).
+fn replayed_innings(outcome: &GameOutcome) -> (Vec<Runs>, Vec<Runs>) {
+    match outcome {
+        GameOutcome::Complete(summary) => (
+            summary.line_score().away_innings().to_vec(),
+            summary.line_score().home_innings().to_vec(),
+        ),
+        GameOutcome::InProgress(game) => {
+            let mut away = game.line_score().away_innings().to_vec();
+            let mut home = game.line_score().home_innings().to_vec();
+            let half = game.current_half_inning();
+            let innings = match half.half() {
+                InningHalf::Top => &mut away,
+                InningHalf::Bottom => &mut home,
+            };
+            let index = usize::from(game.current_inning().as_number()) - 1;
+            if innings.len() <= index {
+                innings.resize(index + 1, 0);
+            }
+            innings[index] += half.runs_scored();
+            (away, home)
+        }
+    }
+}
+
+fn compare_line(is_home: bool, scraped: &ScrapedLine, replayed: &[Runs], report: &mut ReplayReport) {
+    for inning in 0..scraped.innings.len().max(replayed.len()) {
+        let scraped = scraped.innings.get(inning).copied().unwrap_or(0);
+        let replayed = replayed.get(inning).copied().map_or(0, i32::from);
+        if scraped != replayed {
+            report.discrepancies.push(Discrepancy::InningRuns {
+                is_home,
+                inning: inning + 1,
+                scraped,
+                replayed,
+            });
+        }
+    }
+
+    let total: i32 = replayed.iter().copied().map(i32::from).sum();
+    if total != scraped.total_runs {
+        report.discrepancies.push(Discrepancy::TotalRuns {
+            is_home,
+            scraped: scraped.total_runs,
+            replayed: total,
+        });
+    }
+}
+
+const BASES: [Base; 3] = [Base::First, Base::Second, Base::Third];
+
+fn base_number(base: Base) -> u8 {
+    match base {
+        Base::First => 1,
+        Base::Second => 2,
+        Base::Third => 3,
+        Base::Home => 4,
+    }
+}
+
+fn runner_on(runners: BaserunnerState, base: Base) -> Option<BattingPosition> {
+    match base {
+        Base::First => runners.first(),
+        Base::Second => runners.second(),
+        Base::Third => runners.third(),
+        Base::Home => None,
+    }
+}
+
+fn base_of(runners: BaserunnerState, runner: BattingPosition) -> Option<Base> {
+    BASES.into_iter().find(|&base| runner_on(runners, base) == Some(runner))
+}
+
+/// Who each batting-order slot is, by the name the page gave the last batter
+/// there (or the pinch runner who replaced them), so runner clauses, which use
+/// surnames, can be matched to the runners the engine tracks by slot.
+#[derive(Debug, Default)]
+struct Names([[Option<String>; 9]; 2]);
+
+impl Names {
+    fn slot(&mut self, is_bottom: bool, position: BattingPosition) -> &mut Option<String> {
+        &mut self.0[usize::from(is_bottom)][usize::from(position.num() - 1)]
+    }
+
+    fn is(&mut self, is_bottom: bool, position: BattingPosition, surname: &str) -> bool {
+        self.slot(is_bottom, position)
+            .as_deref()
+            .is_some_and(|name| format!(" {name} ").contains(&format!(" {surname} ")))
+    }
+
+    /// Pairs each runner clause with the batter or a runner on base. A surname
+    /// that matches nobody belongs to a pinch runner, who is given the first
+    /// runner left over, lead runner first, and takes over that slot's name.
+    fn resolve(
+        &mut self,
+        is_bottom: bool,
+        before: &HalfInning,
+        clauses: &[RunnerMove],
+    ) -> Result<Moves, TranslateError> {
+        let runners = before.baserunners();
+        let mut moves = Moves::default();
+        let mut unmatched = Vec::new();
+        let mut claimed = [false; 3];
+
+        for clause in clauses {
+            let on_base = BASES.into_iter().enumerate().find(|&(index, base)| {
+                !claimed[index]
+                    && runner_on(runners, base).is_some_and(|runner| self.is(is_bottom, runner, &clause.name))
+            });
+            if let Some((index, _)) = on_base {
+                claimed[index] = true;
+                moves.runners[index] = Some(clause.destination);
+            } else if clause.name == "Batter" || self.is(is_bottom, before.current_batter(), &clause.name) {
+                moves.batter = Some(clause.destination);
+            } else {
+                unmatched.push(clause);
+            }
+        }
+
+        for clause in unmatched {
+            let leftover = BASES
+                .into_iter()
+                .enumerate()
+                .rev()
+                .find(|&(index, base)| !claimed[index] && runner_on(runners, base).is_some());
+            let Some((index, base)) = leftover else {
+                return Err(TranslateError::UnknownRunner(clause.name.clone()));
+            };
+            claimed[index] = true;
+            moves.runners[index] = Some(clause.destination);
+            if let Some(runner) = runner_on(runners, base) {
+                *self.slot(is_bottom, runner) = Some(clause.name.clone());
+            }
+        }
+        Ok(moves)
+    }
+}
+
+/// Where the clauses sent the batter and the runners on first, second and
+/// third. `None` for anyone the description did not name.
+#[derive(Debug, Default, Clone, Copy)]
+struct Moves {
+    batter: Option<Destination>,
+    runners: [Option<Destination>; 3],
+}
+
+impl Moves {
+    fn runner(self, base: Base) -> Option<Destination> {
+        BASES
+            .iter()
+            .position(|&b| b == base)
+            .and_then(|index| self.runners[index])
+    }
+
+    /// Every named runner and the batter, by the slot the engine knows them by.
+    fn named(self, before: &HalfInning) -> Vec<(BattingPosition, Destination)> {
+        let runners = before.baserunners();
+        let mut named: Vec<_> = BASES
+            .into_iter()
+            .filter_map(|base| Some((runner_on(runners, base)?, self.runner(base)?)))
+            .collect();
+        if let Some(destination) = self.batter {
+            named.push((before.current_batter(), destination));
+        }
+        named
+    }
+}
+
+/// The pitches for one row, played as they are chosen against a copy of the
+/// half inning, so the count and the runners are always known. Nothing is
+/// added once the copy reaches its third out; a row that still calls for more
+/// sets `past_third_out` instead.
+struct Pitches {
+    half: Option<HalfInning>,
+    thrown: Vec<PitchOutcome>,
+    past_third_out: bool,
+}
+
+impl Pitches {
+    fn new(half: HalfInning) -> Self {
+        Pitches {
+            half: Some(half),
+            thrown: Vec::new(),
+            past_third_out: false,
+        }
+    }
+
+    fn push(&mut self, pitch: PitchOutcome) {
+        if let Some(half) = self.half {
+            self.thrown.push(pitch);
+            self.half = half.advance(pitch).half_inning();
+        } else {
+            self.past_third_out = true;
+        }
+    }
+
+    /// The balls, strikes and fouls of a Retrosheet-style pitch sequence,
+    /// leaving out any that would end the plate appearance: the row's event
+    /// decides how it ends. Pickoff throws and other markers are skipped.
+    fn count(&mut self, sequence: Option<&str>) {
+        for code in sequence.unwrap_or_default().chars() {
+            let Some(half) = self.half else { return };
+            let count = half.current_plate_appearance().count();
+            let pitch = match code {
+                'B' | 'I' | 'P' | 'V' if count.balls().as_number() < 3 => PitchOutcome::Ball,
+                'C' | 'S' | 'K' | 'M' | 'Q' | 'T' if count.strikes().as_number() < 2 => PitchOutcome::Strike,
+                'F' | 'L' | 'O' | 'R' => PitchOutcome::Foul,
+                _ => continue,
+            };
+            self.push(pitch);
+        }
+    }
+
+    /// Ball four or strike three, however many pitches that takes from here.
+    fn finish_with(&mut self, pitch: PitchOutcome) {
+        let Some(half) = self.half else { return };
+        let count = half.current_plate_appearance().count();
+        let needed = match pitch {
+            PitchOutcome::Ball => 4 - count.balls().as_number(),
+            _ => 3 - count.strikes().as_number(),
+        };
+        for _ in 0..needed {
+            self.push(pitch);
+        }
+    }
+
+    /// Moves each named runner from wherever the engine has them now to where
+    /// the description says they finished, one base at a time and lead runner
+    /// first: a steal for each base taken, a pickoff for an out.
+    fn move_runners(&mut self, named: &[(BattingPosition, Destination)]) -> Result<(), TranslateError> {
+        let Some(half) = self.half else {
+            self.past_third_out |= !named.is_empty();
+            return Ok(());
+        };
+        let runners = half.baserunners();
+        let mut on_base: Vec<_> = named
+            .iter()
+            .filter_map(|&(runner, destination)| Some((base_of(runners, runner)?, destination)))
+            .collect();
+        on_base.sort_by_key(|&(base, _)| std::cmp::Reverse(base_number(base)));
+
+        for (mut base, destination) in on_base {
+            match destination {
+                Destination::Out => self.push(PitchOutcome::Baserunning(RunnerEvent::Pickoff(base))),
+                Destination::Safe(to) if base_number(to) < base_number(base) => {
+                    return Err(TranslateError::RunnerBackwards(format!("the runner on {base:?}")));
+                }
+                Destination::Safe(to) => {
+                    while base != to {
+                        base = base.next();
+                        self.push(PitchOutcome::Baserunning(RunnerEvent::StolenBase(base)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The pitches that replay one row against the half inning as it stands.
+fn translate(row: &PlayRow, before: &HalfInning, names: &mut Names) -> Result<Pitches, TranslateError> {
+    let text = row
+        .play_description
+        .as_deref()
+        .ok_or(TranslateError::MissingDescription)?;
+    let description = Description::parse(text)?;
+    *names.slot(row.is_bottom, before.current_batter()) = Some(row.batter_name.replace('\u{a0}', " "));
+    let moves = names.resolve(row.is_bottom, before, &description.runners)?;
+
+    let mut pitches = Pitches::new(*before);
+    let sequence = row.pitch_sequence.as_deref();
+    match description.event {
+        // A dropped third strike the batter beats out is still a strikeout,
+        // spelled out runner by runner so the batter can reach.
+        Event::Strikeout if matches!(moves.batter, Some(Destination::Safe(_))) => {
+            pitches.count(sequence);
+            let advance = in_play_advance(before.baserunners(), moves, Destination::Safe(Base::First), 0, None);
+            pitches.push(PitchOutcome::InPlay(PlayResult::Advanced(Scoring::Strikeout, advance)));
+        }
+        Event::Strikeout | Event::Walk | Event::HitByPitch => {
+            pitches.count(sequence);
+            match description.event {
+                Event::Strikeout => pitches.finish_with(PitchOutcome::Strike),
+                Event::Walk => pitches.finish_with(PitchOutcome::Ball),
+                _ => pitches.push(PitchOutcome::HitByPitch),
+            }
+            pitches.move_runners(&moves.named(before))?;
+        }
+        Event::HomeRun => {
+            pitches.count(sequence);
+            pitches.push(PitchOutcome::HomeRun);
+        }
+        Event::InPlay {
+            scoring,
+            batter,
+            unnamed_outs,
+            forced_at,
+        } => {
+            pitches.count(sequence);
+            let batter = moves.batter.unwrap_or(batter);
+            let advance = in_play_advance(before.baserunners(), moves, batter, unnamed_outs, forced_at);
+            pitches.push(PitchOutcome::InPlay(PlayResult::Advanced(scoring, advance)));
+        }
+        // The pitches of a plate appearance interrupted by running are all
+        // repeated on the row that ends it, so none are thrown here.
+        Event::Running(event) => return running(before, event, moves),
+    }
+    Ok(pitches)
+}
+
+/// Every runner spelled out for a ball in play: where the clauses sent them,
+/// out if the play implies it (a double play's second out, a force), or
+/// holding, since the page names everyone who moved.
+fn in_play_advance(
+    runners: BaserunnerState,
+    moves: Moves,
+    batter: Destination,
+    unnamed_outs: u8,
+    forced_at: Option<Base>,
+) -> RunnerAdvance {
+    let named_outs = moves.runners.iter().filter(|&&m| m == Some(Destination::Out)).count();
+    let mut unnamed_outs = usize::from(unnamed_outs).saturating_sub(named_outs);
+    let mut advance = RunnerAdvance::new().with_batter(batter);
+    for base in BASES {
+        if runner_on(runners, base).is_none() {
+            continue;
+        }
+        let destination = match moves.runner(base) {
+            Some(destination) => destination,
+            None if forced_at == Some(base.next()) => Destination::Out,
+            None if unnamed_outs > 0 => {
+                unnamed_outs -= 1;
+                Destination::Out
+            }
+            None => Destination::Safe(base),
+        };
+        advance = advance.with_runner(base, destination);
+    }
+    advance
+}
+
+/// A row where only runners move. The named event is used when the engine's
+/// version of it leaves the half inning exactly where the clauses do; a wild
+/// pitch that moved only some of the runners is replayed runner by runner.
+fn running(before: &HalfInning, event: Option<RunnerEvent>, mut moves: Moves) -> Result<Pitches, TranslateError> {
+    let implied = match event {
+        Some(RunnerEvent::StolenBase(to)) => Some((to, Destination::Safe(to))),
+        Some(RunnerEvent::CaughtStealing(to)) => Some((to, Destination::Out)),
+        _ => None,
+    };
+    if let Some((to, destination)) = implied
+        && let Some(from) = BASES.into_iter().find(|&base| base.next() == to)
+    {
+        let index = usize::from(base_number(from) - 1);
+        moves.runners[index] = moves.runners[index].or(Some(destination));
+    }
+    if let Some(RunnerEvent::Pickoff(base)) = event
+        && let Some(index) = BASES.iter().position(|&b| b == base)
+    {
+        moves.runners[index] = moves.runners[index].or(Some(Destination::Out));
+    }
+
+    let mut by_runner = Pitches::new(*before);
+    by_runner.move_runners(&moves.named(before))?;
+
+    if let Some(event) = event {
+        let named = PitchOutcome::Baserunning(event);
+        if before.advance(named).half_inning() == by_runner.half {
+            return Ok(Pitches {
+                thrown: vec![named],
+                ..by_runner
+            });
+        }
+    }
+    Ok(by_runner)
+}
+
+#[cfg(test)]
+mod tests {
+    use baseball_game_rules::InningHalf;
+
+    use super::*;
+
+    fn row(event_num: i32, is_bottom: bool, batter: &str, outs: i32, score: (i32, i32), play: &str) -> PlayRow {
+        PlayRow {
+            event_num,
+            inning: 1,
+            is_bottom,
+            batter_name: batter.to_string(),
+            outs_before: Some(outs),
+            score_batting_team: Some(score.0),
+            score_fielding_team: Some(score.1),
+            play_description: Some(play.to_string()),
+            ..PlayRow::default()
+        }
+    }
+
+    /// One inning: the visitors score on a sacrifice fly, the home side goes
+    /// down in order.
+    fn one_inning() -> Vec<PlayRow> {
+        vec![
+            row(1, false, "Freddie Freeman", 0, (0, 0), "Single to CF (Line Drive)"),
+            row(
+                2,
+                false,
+                "Mookie Betts",
+                0,
+                (0, 0),
+                "Double to LF (Line Drive); Freeman to 3B",
+            ),
+            row(
+                3,
+                false,
+                "Will Smith",
+                0,
+                (0, 0),
+                "Flyball: CF/Sacrifice Fly (Deep CF); Freeman Scores",
+            ),
+            row(4, false, "Max Muncy", 1, (1, 0), "Strikeout Swinging"),
+            row(5, false, "Teoscar Hernandez", 2, (1, 0), "Groundout: SS-1B"),
+            row(6, true, "Ian Happ", 0, (0, 1), "Popfly: SS"),
+            row(7, true, "Seiya Suzuki", 1, (0, 1), "Lineout: 2B"),
+            row(8, true, "Dansby Swanson", 2, (0, 1), "Flyball: RF"),
+        ]
+    }
+
+    fn line(innings: &[i32]) -> ScrapedLine {
+        ScrapedLine {
+            innings: innings.to_vec(),
+            total_runs: innings.iter().sum(),
+        }
+    }
+
+    fn one_inning_rules() -> RuleSet {
+        RuleSet::default().with_regulation_innings(1)
+    }
+
+    #[test]
+    fn test_a_game_that_agrees_with_its_line_score_is_clean() {
+        let report = replay(&one_inning(), one_inning_rules(), &line(&[1]), &line(&[0]));
+        assert_eq!(report.rows_replayed, 8);
+        assert!(report.is_clean(), "{:?}", report.discrepancies);
+    }
+
+    #[test]
+    fn test_a_line_score_that_disagrees_is_flagged() {
+        let report = replay(&one_inning(), one_inning_rules(), &line(&[1]), &line(&[2]));
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                Discrepancy::InningRuns {
+                    is_home: true,
+                    inning: 1,
+                    scraped: 2,
+                    replayed: 0,
+                },
+                Discrepancy::TotalRuns {
+                    is_home: true,
+                    scraped: 2,
+                    replayed: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outs_that_disagree_are_flagged_at_the_first_row() {
+        let mut rows = one_inning();
+        rows[4].outs_before = Some(1);
+        rows[5].outs_before = Some(1);
+        let report = replay(&rows, one_inning_rules(), &line(&[1]), &line(&[0]));
+        assert_eq!(
+            report.discrepancies,
+            vec![Discrepancy::Outs {
+                event_num: 5,
+                scraped: 1,
+                replayed: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_an_untranslatable_row_stops_the_replay() {
+        let mut rows = one_inning();
+        rows[3].play_description = Some("Something nobody has seen before".to_string());
+        let report = replay(&rows, one_inning_rules(), &line(&[1]), &line(&[0]));
+        assert_eq!(report.rows_replayed, 3);
+        assert!(matches!(
+            report.discrepancies.as_slice(),
+            [Discrepancy::Untranslatable { event_num: 4, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_a_strikeout_replays_its_pitch_sequence() {
+        let mut names = Names::default();
+        let strikeout = PlayRow {
+            pitch_sequence: Some("BCFFBS".to_string()),
+            ..row(1, false, "Max Muncy", 0, (0, 0), "Strikeout Swinging")
+        };
+        let pitches = translate(
+            &strikeout,
+            &HalfInning::new(InningHalf::Top, BattingPosition::First),
+            &mut names,
+        )
+        .expect("translates");
+        assert_eq!(
+            pitches.thrown,
+            vec![
+                PitchOutcome::Ball,
+                PitchOutcome::Strike,
+                PitchOutcome::Foul,
+                PitchOutcome::Foul,
+                PitchOutcome::Ball,
+                PitchOutcome::Strike,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_a_dropped_third_strike_the_batter_beats_out_is_still_a_strikeout() {
+        let mut names = Names::default();
+        let dropped = row(1, false, "Max Muncy", 0, (0, 0), "Strikeout Swinging; Muncy to 1B/WP");
+        let pitches = translate(
+            &dropped,
+            &HalfInning::new(InningHalf::Top, BattingPosition::First),
+            &mut names,
+        )
+        .expect("translates");
+        let Some(PitchOutcome::InPlay(play)) = pitches.thrown.last() else {
+            panic!("ends with the batter running: {:?}", pitches.thrown);
+        };
+        assert_eq!(play.scoring(), Scoring::Strikeout);
+        assert!(!play.is_out());
+    }
+
+    #[test]
+    fn test_a_row_that_goes_on_past_the_third_out_is_flagged() {
+        let rows = vec![
+            row(1, false, "Freddie Freeman", 0, (0, 0), "Single to CF (Line Drive)"),
+            row(2, false, "Mookie Betts", 0, (0, 0), "Strikeout Looking"),
+            row(3, false, "Will Smith", 1, (0, 0), "Strikeout Looking"),
+            row(4, false, "Max Muncy", 2, (0, 0), "Strikeout Looking; Freeman to 2B"),
+        ];
+        let report = replay(&rows, one_inning_rules(), &line(&[0]), &line(&[0]));
+        assert_eq!(report.discrepancies, vec![Discrepancy::PastThirdOut { event_num: 4 }]);
+    }
+
+    #[test]
+    fn test_a_wild_pitch_that_moves_only_some_runners_goes_runner_by_runner() {
+        let game = [
+            row(1, false, "Freddie Freeman", 0, (0, 0), "Triple to RF"),
+            row(2, false, "Mookie Betts", 0, (0, 0), "Walk"),
+        ]
+        .iter()
+        .try_fold(
+            (GameOutcome::InProgress(Game::new(one_inning_rules())), Names::default()),
+            |(outcome, mut names), row| {
+                let half = *outcome.game_ref()?.current_half_inning();
+                let pitches = translate(row, &half, &mut names).ok()?;
+                Some((pitches.thrown.into_iter().fold(outcome, GameOutcome::advance), names))
+            },
+        );
+        let (outcome, mut names) = game.expect("runners on first and third");
+        let half = *outcome.game_ref().expect("nobody out").current_half_inning();
+
+        let partial = row(3, false, "Will Smith", 0, (0, 0), "Wild Pitch; Betts to 2B");
+        assert_eq!(
+            translate(&partial, &half, &mut names).map(|pitches| pitches.thrown),
+            Ok(vec![PitchOutcome::Baserunning(RunnerEvent::StolenBase(Base::Second))])
+        );
+        let everyone = row(
+            3,
+            false,
+            "Will Smith",
+            0,
+            (0, 0),
+            "Wild Pitch; Freeman Scores; Betts to 2B",
+        );
+        assert_eq!(
+            translate(&everyone, &half, &mut names).map(|pitches| pitches.thrown),
+            Ok(vec![PitchOutcome::Baserunning(RunnerEvent::WildPitch)])
+        );
+    }
+
+    #[test]
+    fn test_a_pinch_runner_takes_over_the_slot_they_ran_for() {
+        let rows = vec![
+            row(1, false, "Max Muncy", 0, (0, 0), "Single to LF (Ground Ball)"),
+            row(
+                2,
+                false,
+                "Will Smith",
+                0,
+                (0, 0),
+                "Single to RF (Line Drive); Taylor to 3B",
+            ),
+            row(
+                3,
+                false,
+                "Mookie Betts",
+                0,
+                (0, 0),
+                "Flyball: LF/Sacrifice Fly; Taylor Scores",
+            ),
+            row(
+                4,
+                false,
+                "Freddie Freeman",
+                1,
+                (1, 0),
+                "Ground Ball Double Play: SS-2B-1B",
+            ),
+            row(5, true, "Ian Happ", 0, (0, 1), "Strikeout Looking"),
+            row(6, true, "Seiya Suzuki", 1, (0, 1), "Strikeout Looking"),
+            row(7, true, "Dansby Swanson", 2, (0, 1), "Strikeout Looking"),
+        ];
+        let report = replay(&rows, one_inning_rules(), &line(&[1]), &line(&[0]));
+        assert!(report.is_clean(), "{:?}", report.discrepancies);
+    }
+
+    #[test]
+    fn test_rules_come_from_the_game_id_and_the_first_extra_inning() {
+        assert_eq!(rules_for("NYA202008150", &[]).regulation_innings(), 9);
+        assert_eq!(rules_for("NYA202008151", &[]).regulation_innings(), 7);
+        assert_eq!(rules_for("NYA201908151", &[]).regulation_innings(), 9);
+
+        let tenth = PlayRow {
+            inning: 10,
+            runners_before: Some("-2-".to_string()),
+            ..PlayRow::default()
+        };
+        assert!(rules_for("NYA202304010", std::slice::from_ref(&tenth)).extra_inning_runner());
+        let bare = PlayRow {
+            runners_before: None,
+            ..tenth
+        };
+        assert!(!rules_for("NYA201904010", &[bare]).extra_inning_runner());
+    }
+}
@@ -0,0 +1,145 @@
+use std::io::{Read, Write};
+
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use super::{
+    ScrapeError,
+    fetch::{Fetcher, Page, path_of},
+};
+use crate::db::PageArchiveDb;
+
+/// Keeps every page fetched, keyed by URL path. [`PageArchiveDb`] is the
+/// `page_archive` table.
+pub trait PageArchive {
+    fn store(&self, path: &str, page: &Page) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl PageArchive for PageArchiveDb<'_> {
+    async fn store(&self, path: &str, page: &Page) -> Result<(), sqlx::Error> {
+        PageArchiveDb::store(self, path, page).await
+    }
+}
+
+/// Wraps a fetcher so every page it fetches is written to an archive.
+///
+/// Rate-limited responses are not pages and aren't kept; every other status
+/// is, so the archive also records which games bbref had no page for. A page
+/// that can't be archived is still returned.
+pub struct ArchivingFetcher<F, A> {
+    fetcher: F,
+    archive: A,
+}
+
+impl<F, A> ArchivingFetcher<F, A> {
+    pub fn new(fetcher: F, archive: A) -> Self {
+        Self { fetcher, archive }
+    }
+}
+
+impl<F: Fetcher + Sync, A: PageArchive + Sync> Fetcher for ArchivingFetcher<F, A> {
+    async fn get(&self, url: &str) -> Result<Page, ScrapeError> {
+        let page = self.fetcher.get(url).await?;
+
+        if !page.is_rate_limited()
+            && let Err(e) = self.archive.store(path_of(url), &page).await
+        {
+            warn!("Failed to archive {url}: {e}");
+        }
+
+        Ok(page)
+    }
+}
+
+/// SHA-256 of a page body, in lowercase hex
+pub fn content_hash(body: &str) -> String {
+    format!("{:x}", Sha256::digest(body.as_bytes()))
+}
+
+/// Gzip a page body for the archive
+pub fn compress(body: &str) -> Result<Vec<u8>, std::io::Error> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes())?;
+    encoder.finish()
+}
+
+/// Unzip a page body from the archive
+pub fn decompress(bytes: &[u8]) -> Result<String, std::io::Error> {
+    let mut body = String::new();
+    GzDecoder::new(bytes).read_to_string(&mut body)?;
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::scraper::{Fault, FixtureFetcher};
+
+    /// Archives into memory instead of the database
+    #[derive(Default)]
+    struct MemoryArchive {
+        pages: Mutex<Vec<(String, u16, String)>>,
+    }
+
+    impl PageArchive for MemoryArchive {
+        fn store(&self, path: &str, page: &Page) -> impl Future<Output = Result<(), sqlx::Error>> + Send {
+            let mut pages = self.pages.lock().unwrap();
+            pages.push((path.to_string(), page.status, content_hash(&page.body)));
+            std::future::ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_compressed_pages_round_trip() {
+        let html = "<html><body>".to_string() + &"<tr><td>0</td></tr>".repeat(500) + "</body></html>";
+
+        let compressed = compress(&html).unwrap();
+
+        assert!(compressed.len() < html.len() / 10);
+        assert_eq!(decompress(&compressed).unwrap(), html);
+    }
+
+    #[test]
+    fn test_content_hash_is_sha256_hex() {
+        assert_eq!(
+            content_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_ne!(content_hash("a"), content_hash("b"));
+    }
+
+    #[tokio::test]
+    async fn test_every_fetched_page_is_archived_but_rate_limits_are_not() {
+        let fixture = FixtureFetcher::new()
+            .with_page("/boxes/CHN/CHN202504100.shtml", "box score")
+            .with_faults("/boxes/CHN/CHN202504100.shtml", [Fault::Status(429)]);
+        let fetcher = ArchivingFetcher::new(fixture, MemoryArchive::default());
+
+        for url in [
+            "http://x/boxes/CHN/CHN202504100.shtml",
+            "http://x/boxes/CHN/CHN202504100.shtml",
+            "http://x/boxes/MIL/MIL202504110.shtml",
+        ] {
+            fetcher.get(url).await.unwrap();
+        }
+
+        assert_eq!(
+            *fetcher.archive.pages.lock().unwrap(),
+            [
+                (
+                    "/boxes/CHN/CHN202504100.shtml".to_string(),
+                    200,
+                    content_hash("box score")
+                ),
+                (
+                    "/boxes/MIL/MIL202504110.shtml".to_string(),
+                    404,
+                    content_hash("Not Found")
+                ),
+            ]
+        );
+    }
+}
@@ -1,25 +1,22 @@
-use std::{path::Path, time::Duration};
+use std::path::Path;
 
-use reqwest::{Client, StatusCode};
+use futures_util::{StreamExt, stream};
 use thiserror::Error;
-use tokio::time::sleep;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
-use super::schedule::{BoxScoreUrl, schedule_url_for_year};
+use super::{
+    fetch::{Fetcher, HttpFetcher},
+    pacer::Pacer,
+    schedule::{BoxScoreUrl, schedule_path_for_year},
+};
 use crate::{
-    db::{BoxScoreInserter, FailedScrapesDb, InsertError},
+    db::{BoxScoreInserter, FailedScrapesDb, InsertError, JobState, ScrapeJobsDb},
     parser::BoxScore,
 };
 
-const BASE_URL: &str = "https://www.baseball-reference.com";
-const USER_AGENT: &str = "Mozilla/5.0 (compatible; BaseballScraper/1.0; educational project)";
+/// The live site, which scrapers fetch from unless given another base URL
+pub const BASE_URL: &str = "https://www.baseball-reference.com";
 
-/// Base delay between requests (~2 requests/second)
-const BASE_DELAY: Duration = Duration::from_secs(3);
-/// Maximum delay after repeated backoffs
-const MAX_DELAY: Duration = Duration::from_mins(5);
-/// Multiplier applied to delay on rate-limit or server errors
-const BACKOFF_MULTIPLIER: f64 = 4.0;
 /// Maximum retries per request before giving up
 const MAX_RETRIES: u32 = 10;
 
@@ -31,6 +28,12 @@ pub enum ScrapeError {
     #[error("Rate limited (HTTP {0})")]
     RateLimited(u16),
 
+    #[error("Timed out fetching {0}")]
+    Timeout(String),
+
+    #[error("{last} after {retries} retries")]
+    GaveUp { last: Box<ScrapeError>, retries: u32 },
+
     #[error("Parse error: {0}")]
     Parse(#[from] crate::parser::ParseError),
 
@@ -52,30 +55,93 @@ pub enum ScrapeResult {
     Failed { game_id: String, error: String },
 }
 
+/// Where scraped box scores are imported. [`BoxScoreInserter`] writes them to
+/// the database.
+pub trait GameStore {
+    /// Check if a game has already been imported
+    fn game_exists(&self, bbref_game_id: &str) -> impl Future<Output = Result<bool, InsertError>> + Send;
+
+    /// Import a parsed box score, returning its id in the store
+    fn insert(&self, box_score: &BoxScore) -> impl Future<Output = Result<i32, InsertError>> + Send;
+}
+
+impl GameStore for BoxScoreInserter<'_> {
+    async fn game_exists(&self, bbref_game_id: &str) -> Result<bool, InsertError> {
+        BoxScoreInserter::game_exists(self, bbref_game_id).await
+    }
+
+    async fn insert(&self, box_score: &BoxScore) -> Result<i32, InsertError> {
+        BoxScoreInserter::insert(self, box_score).await
+    }
+}
+
+/// Records each game's progress through the pipeline. [`ScrapeJobsDb`] is the
+/// persistent `scrape_jobs` queue.
+pub trait JobQueue {
+    fn set_state(
+        &self,
+        bbref_game_id: &str,
+        state: JobState,
+        error: Option<&str>,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl JobQueue for ScrapeJobsDb<'_> {
+    async fn set_state(&self, bbref_game_id: &str, state: JobState, error: Option<&str>) -> Result<(), sqlx::Error> {
+        ScrapeJobsDb::set_state(self, bbref_game_id, state, error).await
+    }
+}
+
+/// The queue for scrapes that aren't queued: there is nothing to record
+struct Untracked;
+
+impl JobQueue for Untracked {
+    fn set_state(
+        &self,
+        _bbref_game_id: &str,
+        _state: JobState,
+        _error: Option<&str>,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send {
+        std::future::ready(Ok(()))
+    }
+}
+
 /// Scraper for Baseball Reference box scores
-pub struct Scraper {
-    client: Client,
+pub struct Scraper<F = HttpFetcher> {
+    fetcher: F,
+    base_url: String,
     output_dir: Option<std::path::PathBuf>,
+    pacer: Pacer,
 }
 
 impl Scraper {
-    /// Create a new scraper
+    /// Create a new scraper that fetches from the live site over HTTP
     ///
     /// # Panics
     /// Panics if the HTTP client cannot be built.
     pub fn new() -> Self {
-        let client = Client::builder()
-            .user_agent(USER_AGENT)
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("http client should be valid");
+        Self::with_fetcher(HttpFetcher::new())
+    }
+}
 
+impl<F: Fetcher> Scraper<F> {
+    /// Create a scraper that gets its pages from `fetcher`
+    pub fn with_fetcher(fetcher: F) -> Self {
         Self {
-            client,
+            fetcher,
+            base_url: BASE_URL.to_string(),
             output_dir: None,
+            pacer: Pacer::new(),
         }
     }
 
+    /// Fetch from a different site root, e.g. a local mirror
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Set directory to save downloaded HTML files
     #[must_use]
     pub fn with_output_dir(mut self, dir: impl AsRef<Path>) -> Self {
@@ -83,45 +149,47 @@ impl Scraper {
         self
     }
 
-    /// Fetch a schedule page for a given year
+    /// Fetch a schedule page for a given year. Like box scores, schedules wait
+    /// their turn with the scraper's shared pacing and back off when rate
+    /// limited, so a long run of seasons doesn't hit bbref back to back.
     pub async fn fetch_schedule(&self, year: i32) -> Result<String, ScrapeError> {
-        let url = schedule_url_for_year(year);
-        info!("Fetching schedule: {}", url);
-
-        let response = self.client.get(&url).send().await?;
-        let html = response.text().await?;
-
-        // Save to file if output directory is set
-        if let Some(ref dir) = self.output_dir {
-            let filename = format!("{year}-schedule.shtml");
-            let path = dir.join(&filename);
-            std::fs::write(&path, &html)?;
-            info!("Saved schedule to: {}", path.display());
-        }
-
-        Ok(html)
+        self.fetch_paced(&schedule_path_for_year(year), &format!("{year}-schedule.shtml"))
+            .await
     }
 
     /// Fetch a box score page, returning the HTML on success or an error with
     /// rate-limit awareness. Returns `ScrapeError::RateLimited` for 429 and 5xx
-    /// responses so callers can back off.
+    /// responses and `ScrapeError::Timeout` for requests that time out, so
+    /// callers can back off.
     pub async fn fetch_boxscore(&self, url: &BoxScoreUrl) -> Result<String, ScrapeError> {
-        let full_url = format!("{}{}", BASE_URL, url.path);
+        self.fetch_page(&url.path, &format!("{}.shtml", url.game_id)).await
+    }
+
+    /// Fetch a box score, retrying with backoff on rate-limit errors and timeouts.
+    /// Every attempt waits its turn with the scraper's shared pacing.
+    pub async fn fetch_with_backoff(&self, url: &BoxScoreUrl) -> Result<String, String> {
+        self.fetch_paced(&url.path, &format!("{}.shtml", url.game_id))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Fetch the page at `path`, saving it as `filename` if an output directory
+    /// is set. Rate-limited responses are `ScrapeError::RateLimited`.
+    async fn fetch_page(&self, path: &str, filename: &str) -> Result<String, ScrapeError> {
+        let full_url = format!("{}{}", self.base_url, path);
         info!("Fetching: {}", full_url);
 
-        let response = self.client.get(&full_url).send().await?;
-        let status = response.status();
+        let page = self.fetcher.get(&full_url).await?;
 
-        if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
-            return Err(ScrapeError::RateLimited(status.as_u16()));
+        if page.is_rate_limited() {
+            return Err(ScrapeError::RateLimited(page.status));
         }
 
-        let html = response.text().await?;
+        let html = page.body;
 
         // Save to file if output directory is set
         if let Some(ref dir) = self.output_dir {
-            let filename = format!("{}.shtml", url.game_id);
-            let path = dir.join(&filename);
+            let path = dir.join(filename);
             std::fs::write(&path, &html)?;
             info!("Saved to: {}", path.display());
         }
@@ -129,16 +197,47 @@ impl Scraper {
         Ok(html)
     }
 
+    /// [`Self::fetch_page`], waiting a turn before every attempt and retrying
+    /// with backoff on rate-limit errors and timeouts
+    async fn fetch_paced(&self, path: &str, filename: &str) -> Result<String, ScrapeError> {
+        let mut attempt = 0;
+
+        loop {
+            self.pacer.wait_turn().await;
+
+            match self.fetch_page(path, filename).await {
+                Ok(html) => {
+                    // Success — ease back toward base delay
+                    self.pacer.ease();
+                    return Ok(html);
+                }
+                Err(e @ (ScrapeError::RateLimited(_) | ScrapeError::Timeout(_))) => {
+                    attempt += 1;
+                    let delay = self.pacer.back_off();
+                    if attempt > MAX_RETRIES {
+                        return Err(ScrapeError::GaveUp {
+                            last: Box::new(e),
+                            retries: MAX_RETRIES,
+                        });
+                    }
+                    warn!("{e}, retry {attempt}/{MAX_RETRIES} after {delay:?}");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     /// Scrape and import a single box score, retrying with backoff on rate-limit errors.
-    /// Returns the result and whether a rate-limit was hit (so the caller can adjust pacing).
+    /// Records the game as fetched and then parsed along the way; the caller
+    /// records how it ended.
     async fn scrape_and_import_with_backoff(
         &self,
         url: &BoxScoreUrl,
-        inserter: &BoxScoreInserter<'_>,
-        current_delay: &mut Duration,
+        store: &impl GameStore,
+        jobs: &impl JobQueue,
     ) -> ScrapeResult {
         // Check if game already exists before fetching
-        match inserter.game_exists(&url.game_id).await {
+        match store.game_exists(&url.game_id).await {
             Ok(true) => {
                 return ScrapeResult::AlreadyExists {
                     game_id: url.game_id.clone(),
@@ -150,35 +249,16 @@ impl Scraper {
             }
         }
 
-        let mut attempt = 0;
-
-        let html = loop {
-            match self.fetch_boxscore(url).await {
-                Ok(h) => {
-                    // Success — ease back toward base delay
-                    *current_delay = (*current_delay / 2).max(BASE_DELAY);
-                    break h;
-                }
-                Err(ScrapeError::RateLimited(status)) => {
-                    attempt += 1;
-                    *current_delay = current_delay.mul_f64(BACKOFF_MULTIPLIER).min(MAX_DELAY);
-                    if attempt > MAX_RETRIES {
-                        return ScrapeResult::Failed {
-                            game_id: url.game_id.clone(),
-                            error: format!("Rate limited (HTTP {status}) after {MAX_RETRIES} retries"),
-                        };
-                    }
-                    warn!("Rate limited (HTTP {status}), retry {attempt}/{MAX_RETRIES} after {current_delay:?}");
-                    sleep(*current_delay).await;
-                }
-                Err(e) => {
-                    return ScrapeResult::Failed {
-                        game_id: url.game_id.clone(),
-                        error: e.to_string(),
-                    };
-                }
+        let html = match self.fetch_with_backoff(url).await {
+            Ok(html) => html,
+            Err(error) => {
+                return ScrapeResult::Failed {
+                    game_id: url.game_id.clone(),
+                    error,
+                };
             }
         };
+        record_state(jobs, &url.game_id, JobState::Fetched, None).await;
 
         // Parse the box score
         let box_score = match BoxScore::from_html(&html, &url.game_id) {
@@ -190,9 +270,19 @@ impl Scraper {
                 };
             }
         };
+        if let Some(drift) = box_score.warnings.iter().find(|w| w.kind.is_layout_drift()) {
+            warn!(
+                "{}: page layout differs from what the parser expects ({} warnings), first: {drift}",
+                url.game_id,
+                box_score.warnings.len(),
+            );
+        } else if !box_score.warnings.is_empty() {
+            debug!("{}: {} parse warnings", url.game_id, box_score.warnings.len());
+        }
+        record_state(jobs, &url.game_id, JobState::Parsed, None).await;
 
         // Import to database
-        match inserter.insert(&box_score).await {
+        match store.insert(&box_score).await {
             Ok(db_id) => ScrapeResult::Imported {
                 game_id: url.game_id.clone(),
                 db_id,
@@ -206,8 +296,8 @@ impl Scraper {
     }
 
     /// Scrape multiple box scores with rate limiting
-    pub async fn scrape_all(&self, urls: &[BoxScoreUrl], inserter: &BoxScoreInserter<'_>) -> Vec<ScrapeResult> {
-        self.scrape_all_with_tracking(urls, inserter, None).await
+    pub async fn scrape_all(&self, urls: &[BoxScoreUrl], store: &impl GameStore) -> Vec<ScrapeResult> {
+        self.scrape_all_with_tracking(urls, store, None).await
     }
 
     /// Scrape multiple box scores with adaptive rate limiting and optional failure tracking.
@@ -217,59 +307,90 @@ impl Scraper {
     pub async fn scrape_all_with_tracking(
         &self,
         urls: &[BoxScoreUrl],
-        inserter: &BoxScoreInserter<'_>,
+        store: &impl GameStore,
         failed_db: Option<&FailedScrapesDb<'_>>,
     ) -> Vec<ScrapeResult> {
-        let mut results = Vec::with_capacity(urls.len());
-        let total = urls.len();
-        let mut delay = BASE_DELAY;
+        self.scrape_queue(urls, store, failed_db, &Untracked, 1).await
+    }
 
-        for (i, url) in urls.iter().enumerate() {
-            info!("[{}/{}] Processing: {} (delay: {delay:?})", i + 1, total, url.game_id);
+    /// Scrape queued games, up to `concurrency` at once, recording each game's
+    /// progress in `jobs`.
+    ///
+    /// However many games are in flight, their requests share the scraper's
+    /// adaptive rate limit: concurrency overlaps the waiting on bbref and the
+    /// database, it does not fetch any faster than one game alone would.
+    pub async fn scrape_queue(
+        &self,
+        urls: &[BoxScoreUrl],
+        store: &impl GameStore,
+        failed_db: Option<&FailedScrapesDb<'_>>,
+        jobs: &impl JobQueue,
+        concurrency: usize,
+    ) -> Vec<ScrapeResult> {
+        let total = urls.len();
 
-            let result = self.scrape_and_import_with_backoff(url, inserter, &mut delay).await;
+        stream::iter(urls.iter().enumerate())
+            .map(|(i, url)| async move {
+                info!(
+                    "[{}/{}] Processing: {} (delay: {:?})",
+                    i + 1,
+                    total,
+                    url.game_id,
+                    self.pacer.delay()
+                );
+
+                let result = self.scrape_and_import_with_backoff(url, store, jobs).await;
+                record_result(&result, failed_db, jobs).await;
+                result
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+}
 
-            match &result {
-                ScrapeResult::Imported { game_id, db_id } => {
-                    info!("Imported {} with DB ID {}", game_id, db_id);
-                    // Remove from failed scrapes if it was a retry
-                    if let Some(db) = failed_db
-                        && let Err(e) = db.delete_failure(game_id).await
-                    {
-                        warn!("Failed to remove {} from failed_scrapes: {}", game_id, e);
-                    }
-                }
-                ScrapeResult::AlreadyExists { game_id } => {
-                    info!("Skipped {} (already exists)", game_id);
-                    // Also remove from failed scrapes since it exists
-                    if let Some(db) = failed_db
-                        && let Err(e) = db.delete_failure(game_id).await
-                    {
-                        warn!("Failed to remove {} from failed_scrapes: {}", game_id, e);
-                    }
-                }
-                ScrapeResult::Failed { game_id, error } => {
-                    warn!("Failed {}: {}", game_id, error);
-                    // Record the failure
-                    if let Some(db) = failed_db
-                        && let Err(e) = db.record_failure(game_id, error).await
-                    {
-                        warn!("Failed to record failure for {}: {}", game_id, e);
-                    }
-                }
+/// Log how a game ended and record it in the queue and, if tracking, in
+/// `failed_scrapes`.
+async fn record_result(result: &ScrapeResult, failed_db: Option<&FailedScrapesDb<'_>>, jobs: &impl JobQueue) {
+    match result {
+        ScrapeResult::Imported { game_id, db_id } => {
+            info!("Imported {} with DB ID {}", game_id, db_id);
+            record_state(jobs, game_id, JobState::Imported, None).await;
+            // Remove from failed scrapes if it was a retry
+            if let Some(db) = failed_db
+                && let Err(e) = db.delete_failure(game_id).await
+            {
+                warn!("Failed to remove {} from failed_scrapes: {}", game_id, e);
             }
-
-            // Only delay after actual HTTP requests (not skipped duplicates)
-            let needs_delay = !matches!(&result, ScrapeResult::AlreadyExists { .. });
-
-            results.push(result);
-
-            if needs_delay && i < total - 1 {
-                sleep(delay).await;
+        }
+        ScrapeResult::AlreadyExists { game_id } => {
+            info!("Skipped {} (already exists)", game_id);
+            record_state(jobs, game_id, JobState::Imported, None).await;
+            // Also remove from failed scrapes since it exists
+            if let Some(db) = failed_db
+                && let Err(e) = db.delete_failure(game_id).await
+            {
+                warn!("Failed to remove {} from failed_scrapes: {}", game_id, e);
+            }
+        }
+        ScrapeResult::Failed { game_id, error } => {
+            warn!("Failed {}: {}", game_id, error);
+            record_state(jobs, game_id, JobState::Failed, Some(error)).await;
+            // Record the failure
+            if let Some(db) = failed_db
+                && let Err(e) = db.record_failure(game_id, error).await
+            {
+                warn!("Failed to record failure for {}: {}", game_id, e);
             }
         }
+    }
+}
 
-        results
+/// Move a game along in the queue. A game whose state can't be saved is
+/// picked up again on the next run, so this only warns.
+async fn record_state(jobs: &impl JobQueue, game_id: &str, state: JobState, error: Option<&str>) {
+    if let Err(e) = jobs.set_state(game_id, state, error).await {
+        warn!("Failed to mark {game_id} {state} in scrape_jobs: {e}");
     }
 }
 
@@ -278,3 +399,288 @@ impl Default for Scraper {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use tokio::time::Instant;
+
+    use super::*;
+    use crate::scraper::{
+        Fault, FixtureFetcher, extract_boxscore_urls_from_html,
+        pacer::{BASE_DELAY, MAX_DELAY},
+    };
+
+    const CUBS_GAME: &str = "/boxes/CHN/CHN202504100.shtml";
+    const BREWERS_GAME: &str = "/boxes/MIL/MIL202504110.shtml";
+    const SCHEDULE: &str = "/leagues/majors/2025-schedule.shtml";
+
+    /// Just enough of a bbref box score page for the parser to accept
+    fn box_score_page(away: &str, home: &str) -> String {
+        let team = |code: &str, score: i32| {
+            format!(
+                r#"<div><strong><a href="/teams/{code}/2025.shtml">{code}</a></strong><div class="score">{score}</div></div>"#
+            )
+        };
+        let line = |code: &str, runs: i32| {
+            format!(
+                r#"<tr><td></td><td><a href="/teams/{code}/2025.shtml">{code}</a></td><td>{runs}</td><td>{runs}</td><td>5</td><td>0</td></tr>"#
+            )
+        };
+        let table = |code: &str, kind: &str| {
+            format!(
+                r#"<table class="stats_table" id="{code}{kind}"><tbody><tr><th data-append-csv="{code}01">{code} Player 2B</th><td data-stat="AB">4</td></tr></tbody></table>"#
+            )
+        };
+
+        format!(
+            r#"<html><body><h1>{away} vs {home} Box Score: April 10, 2025</h1>
+            <div class="scorebox">{}{}</div>
+            <table class="linescore"><tbody>{}{}</tbody></table>
+            <!-- {}{}{}{} --></body></html>"#,
+            team(away, 2),
+            team(home, 3),
+            line(away, 2),
+            line(home, 3),
+            table(away, "batting"),
+            table(home, "batting"),
+            table(away, "pitching"),
+            table(home, "pitching"),
+        )
+    }
+
+    fn schedule_page() -> String {
+        format!(r#"<html><body><a href="{CUBS_GAME}">Boxscore</a><a href="{BREWERS_GAME}">Boxscore</a></body></html>"#)
+    }
+
+    fn cubs_game() -> BoxScoreUrl {
+        BoxScoreUrl::for_game("CHN202504100").expect("valid game id")
+    }
+
+    /// Imports into memory instead of the database
+    #[derive(Default)]
+    struct MemoryStore {
+        games: Mutex<Vec<String>>,
+    }
+
+    impl GameStore for MemoryStore {
+        fn game_exists(&self, bbref_game_id: &str) -> impl Future<Output = Result<bool, InsertError>> + Send {
+            let games = self.games.lock().unwrap();
+            std::future::ready(Ok(games.iter().any(|id| id == bbref_game_id)))
+        }
+
+        fn insert(&self, box_score: &BoxScore) -> impl Future<Output = Result<i32, InsertError>> + Send {
+            let mut games = self.games.lock().unwrap();
+            games.push(box_score.game_info.bbref_game_id.clone());
+            std::future::ready(Ok(i32::try_from(games.len()).unwrap()))
+        }
+    }
+
+    /// Records every state change instead of writing to `scrape_jobs`
+    #[derive(Default)]
+    struct MemoryQueue {
+        states: Mutex<Vec<(String, JobState)>>,
+    }
+
+    impl MemoryQueue {
+        fn states_of(&self, game_id: &str) -> Vec<JobState> {
+            let states = self.states.lock().unwrap();
+            states
+                .iter()
+                .filter(|(id, _)| id == game_id)
+                .map(|(_, state)| *state)
+                .collect()
+        }
+    }
+
+    impl JobQueue for MemoryQueue {
+        fn set_state(
+            &self,
+            bbref_game_id: &str,
+            state: JobState,
+            _error: Option<&str>,
+        ) -> impl Future<Output = Result<(), sqlx::Error>> + Send {
+            self.states.lock().unwrap().push((bbref_game_id.to_string(), state));
+            std::future::ready(Ok(()))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_backoff_grows_on_rate_limits_and_eases_after_success() {
+        let fixture = FixtureFetcher::new()
+            .with_page(CUBS_GAME, box_score_page("MIL", "CHN"))
+            .with_faults(CUBS_GAME, [Fault::Status(429), Fault::Status(503)]);
+        let scraper = Scraper::with_fetcher(fixture);
+
+        let start = Instant::now();
+        let html = scraper.fetch_with_backoff(&cubs_game()).await;
+
+        assert!(html.is_ok(), "{html:?}");
+        // 3s backs off to 12s, then 48s (a minute waited in all); the success halves it again
+        assert_eq!(start.elapsed(), Duration::from_mins(1));
+        assert_eq!(scraper.pacer.delay(), Duration::from_secs(24));
+        assert_eq!(scraper.fetcher.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeouts_back_off_like_rate_limits() {
+        let fixture = FixtureFetcher::new()
+            .with_page(CUBS_GAME, box_score_page("MIL", "CHN"))
+            .with_faults(CUBS_GAME, [Fault::Timeout]);
+        let scraper = Scraper::with_fetcher(fixture);
+
+        let start = Instant::now();
+        let html = scraper.fetch_with_backoff(&cubs_game()).await;
+
+        assert!(html.is_ok(), "{html:?}");
+        assert_eq!(start.elapsed(), Duration::from_secs(12));
+        assert_eq!(scraper.pacer.delay(), Duration::from_secs(6));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gives_up_after_max_retries_at_max_delay() {
+        let fixture = FixtureFetcher::new()
+            .with_page(CUBS_GAME, box_score_page("MIL", "CHN"))
+            .with_faults(CUBS_GAME, [Fault::Status(500); MAX_RETRIES as usize + 1]);
+        let scraper = Scraper::with_fetcher(fixture);
+
+        assert_eq!(scraper.pacer.delay(), BASE_DELAY);
+        let error = scraper
+            .fetch_with_backoff(&cubs_game())
+            .await
+            .expect_err("every attempt fails");
+
+        assert_eq!(error, format!("Rate limited (HTTP 500) after {MAX_RETRIES} retries"));
+        assert_eq!(scraper.pacer.delay(), MAX_DELAY);
+        assert_eq!(scraper.fetcher.requests().len(), MAX_RETRIES as usize + 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_schedules_wait_their_turn_and_back_off() {
+        let fixture = FixtureFetcher::new()
+            .with_page(SCHEDULE, schedule_page())
+            .with_page("/leagues/majors/2024-schedule.shtml", schedule_page())
+            .with_faults(SCHEDULE, [Fault::Status(429)]);
+        let scraper = Scraper::with_fetcher(fixture);
+
+        let start = Instant::now();
+        scraper.fetch_schedule(2024).await.expect("schedule is served");
+        let schedule = scraper.fetch_schedule(2025).await.expect("schedule is served");
+
+        assert_eq!(schedule, schedule_page());
+        // 3s to the rate-limited try, then 12s backed off before the retry
+        assert_eq!(start.elapsed(), Duration::from_secs(3 + 12));
+        assert_eq!(scraper.fetcher.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_missing_page_fails_without_retrying() {
+        let scraper = Scraper::with_fetcher(FixtureFetcher::new());
+        let store = MemoryStore::default();
+
+        let results = scraper.scrape_all(&[cubs_game()], &store).await;
+
+        assert!(
+            matches!(&results[..], [ScrapeResult::Failed { error, .. }] if error.starts_with("Parse error")),
+            "{results:?}"
+        );
+        assert_eq!(scraper.fetcher.requests().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scrape_parse_and_import_offline() {
+        let fixture = FixtureFetcher::new()
+            .with_page(SCHEDULE, schedule_page())
+            .with_page(CUBS_GAME, box_score_page("MIL", "CHN"))
+            .with_page(BREWERS_GAME, box_score_page("CHN", "MIL"))
+            .with_faults(BREWERS_GAME, [Fault::Timeout]);
+        let scraper = Scraper::with_fetcher(fixture).with_base_url("http://127.0.0.1:8080/");
+        let store = MemoryStore::default();
+
+        let schedule = scraper.fetch_schedule(2025).await.expect("schedule is served");
+        let urls = extract_boxscore_urls_from_html(&schedule);
+        let results = scraper.scrape_all(&urls, &store).await;
+
+        assert!(
+            results.iter().all(|r| matches!(r, ScrapeResult::Imported { .. })),
+            "{results:?}"
+        );
+        assert_eq!(*store.games.lock().unwrap(), ["CHN202504100", "MIL202504110"]);
+        assert_eq!(
+            scraper.fetcher.requests(),
+            [
+                format!("http://127.0.0.1:8080{SCHEDULE}"),
+                format!("http://127.0.0.1:8080{CUBS_GAME}"),
+                format!("http://127.0.0.1:8080{BREWERS_GAME}"),
+                format!("http://127.0.0.1:8080{BREWERS_GAME}"),
+            ]
+        );
+
+        // A second pass finds both games imported and fetches nothing
+        let results = scraper.scrape_all(&urls, &store).await;
+        assert!(
+            results.iter().all(|r| matches!(r, ScrapeResult::AlreadyExists { .. })),
+            "{results:?}"
+        );
+        assert_eq!(scraper.fetcher.requests().len(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_scrapes_share_the_rate_limit() {
+        let urls: Vec<BoxScoreUrl> = (0..4)
+            .map(|day| BoxScoreUrl::for_game(&format!("CHN2025041{day}0")).expect("valid game id"))
+            .collect();
+        let fixture = || {
+            urls.iter()
+                .fold(FixtureFetcher::new(), |fixture, url| {
+                    fixture.with_page(url.path.clone(), box_score_page("MIL", "CHN"))
+                })
+                .with_latency(Duration::from_secs(5))
+        };
+
+        // Requests still start 3s apart, but each one's 5s wait overlaps the next
+        let scraper = Scraper::with_fetcher(fixture());
+        let start = Instant::now();
+        let results = scraper
+            .scrape_queue(&urls, &MemoryStore::default(), None, &Untracked, 4)
+            .await;
+        assert!(
+            results.iter().all(|r| matches!(r, ScrapeResult::Imported { .. })),
+            "{results:?}"
+        );
+        assert_eq!(start.elapsed(), Duration::from_secs(9 + 5));
+
+        // One at a time, every game waits out the whole 5s before the next starts
+        let scraper = Scraper::with_fetcher(fixture());
+        let start = Instant::now();
+        scraper
+            .scrape_queue(&urls, &MemoryStore::default(), None, &Untracked, 1)
+            .await;
+        assert_eq!(start.elapsed(), Duration::from_secs(4 * 5));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_job_states_follow_each_game_through_the_pipeline() {
+        let fixture = FixtureFetcher::new().with_page(CUBS_GAME, box_score_page("MIL", "CHN"));
+        let scraper = Scraper::with_fetcher(fixture);
+        let store = MemoryStore::default();
+        store.games.lock().unwrap().push("NYA202504120".to_string());
+        let queue = MemoryQueue::default();
+
+        let urls = [
+            cubs_game(),
+            BoxScoreUrl::for_game("MIL202504110").expect("valid game id"),
+            BoxScoreUrl::for_game("NYA202504120").expect("valid game id"),
+        ];
+        scraper.scrape_queue(&urls, &store, None, &queue, 2).await;
+
+        assert_eq!(
+            queue.states_of("CHN202504100"),
+            [JobState::Fetched, JobState::Parsed, JobState::Imported]
+        );
+        // The missing page is fetched as a 404 and fails to parse
+        assert_eq!(queue.states_of("MIL202504110"), [JobState::Fetched, JobState::Failed]);
+        assert_eq!(queue.states_of("NYA202504120"), [JobState::Imported]);
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use reqwest::Client;
+
+use super::ScrapeError;
+
+const USER_AGENT: &str = "Mozilla/5.0 (compatible; BaseballScraper/1.0; educational project)";
+/// How long a single request may take before it counts as a timeout
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A fetched page: the HTTP status and the body, whatever the status was
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Page {
+    /// True for the responses that mean "slow down": 429 and any 5xx
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == 429 || (500..600).contains(&self.status)
+    }
+}
+
+/// Fetches pages by absolute URL.
+///
+/// Only transport failures are errors; an HTTP error status comes back as a
+/// [`Page`] so the scraper can decide whether to back off.
+pub trait Fetcher {
+    fn get(&self, url: &str) -> impl Future<Output = Result<Page, ScrapeError>> + Send;
+}
+
+/// Fetches pages over HTTP
+pub struct HttpFetcher {
+    client: Client,
+}
+
+impl HttpFetcher {
+    /// Create a fetcher with the scraper's user agent and request timeout
+    ///
+    /// # Panics
+    /// Panics if the HTTP client cannot be built.
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("http client should be valid");
+
+        Self { client }
+    }
+}
+
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    async fn get(&self, url: &str) -> Result<Page, ScrapeError> {
+        let timed_out = |e: reqwest::Error| {
+            if e.is_timeout() {
+                ScrapeError::Timeout(url.to_string())
+            } else {
+                ScrapeError::Http(e)
+            }
+        };
+
+        let response = self.client.get(url).send().await.map_err(timed_out)?;
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(timed_out)?;
+
+        Ok(Page { status, body })
+    }
+}
+
+/// The path part of an absolute URL (`https://host/boxes/...` -> `/boxes/...`)
+pub(super) fn path_of(url: &str) -> &str {
+    match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |slash| &rest[slash..]),
+        None => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_of() {
+        assert_eq!(
+            path_of("https://www.baseball-reference.com/boxes/CHN/CHN202503180.shtml"),
+            "/boxes/CHN/CHN202503180.shtml"
+        );
+        assert_eq!(path_of("http://127.0.0.1:8080"), "/");
+        assert_eq!(
+            path_of("/leagues/majors/2025-schedule.shtml"),
+            "/leagues/majors/2025-schedule.shtml"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use super::{
+    ScrapeError,
+    fetch::{Fetcher, Page, path_of},
+    schedule::{BoxScoreUrl, schedule_path_for_year},
+};
+
+/// A failure the fixture fetcher returns instead of a page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Respond with this HTTP status and an empty body
+    Status(u16),
+    /// Fail the way a request that timed out does
+    Timeout,
+}
+
+/// Serves saved pages from memory so the scrape → parse → import pipeline can
+/// run offline.
+///
+/// Pages are keyed by URL path, so the fetcher works with any base URL. Faults
+/// queued for a path are returned, in order, before its page is; a path with no
+/// page is a 404. Every URL requested is recorded.
+#[derive(Debug, Default)]
+pub struct FixtureFetcher {
+    pages: HashMap<String, String>,
+    faults: Mutex<HashMap<String, VecDeque<Fault>>>,
+    requests: Mutex<Vec<String>>,
+    latency: Duration,
+}
+
+impl FixtureFetcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every page in a directory written by `Scraper::with_output_dir`:
+    /// `{game_id}.shtml` box scores and `{year}-schedule.shtml` schedules.
+    /// Other files are ignored.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let mut fixture = Self::new();
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(stem) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".shtml"))
+            else {
+                continue;
+            };
+
+            let url_path = if let Some(year) = stem.strip_suffix("-schedule") {
+                match year.parse() {
+                    Ok(year) => schedule_path_for_year(year),
+                    Err(_) => continue,
+                }
+            } else if let Some(url) = BoxScoreUrl::for_game(stem) {
+                url.path
+            } else {
+                continue;
+            };
+
+            fixture.pages.insert(url_path, std::fs::read_to_string(&path)?);
+        }
+
+        Ok(fixture)
+    }
+
+    /// Serve `html` at `path` (e.g. "/boxes/CHN/CHN202503180.shtml")
+    #[must_use]
+    pub fn with_page(mut self, path: impl Into<String>, html: impl Into<String>) -> Self {
+        self.pages.insert(path.into(), html.into());
+        self
+    }
+
+    /// Queue faults to return for `path` before its page is served
+    #[must_use]
+    pub fn with_faults(self, path: impl Into<String>, faults: impl IntoIterator<Item = Fault>) -> Self {
+        self.faults
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(path.into())
+            .or_default()
+            .extend(faults);
+        self
+    }
+
+    /// Take this long to answer every request, like a slow server would
+    #[must_use]
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Every URL requested so far, in order
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn respond(&self, url: &str) -> Result<Page, ScrapeError> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(url.to_string());
+
+        let path = path_of(url);
+        let fault = self
+            .faults
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(path)
+            .and_then(VecDeque::pop_front);
+
+        match fault {
+            Some(Fault::Status(status)) => Ok(Page {
+                status,
+                body: String::new(),
+            }),
+            Some(Fault::Timeout) => Err(ScrapeError::Timeout(url.to_string())),
+            None => Ok(self.pages.get(path).map_or_else(
+                || Page {
+                    status: 404,
+                    body: "Not Found".to_string(),
+                },
+                |body| Page {
+                    status: 200,
+                    body: body.clone(),
+                },
+            )),
+        }
+    }
+}
+
+impl Fetcher for FixtureFetcher {
+    fn get(&self, url: &str) -> impl Future<Output = Result<Page, ScrapeError>> + Send {
+        let latency = self.latency;
+        let response = self.respond(url);
+        async move {
+            if !latency.is_zero() {
+                tokio::time::sleep(latency).await;
+            }
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faults_come_before_the_page() {
+        let fixture = FixtureFetcher::new()
+            .with_page("/a.shtml", "<html></html>")
+            .with_faults("/a.shtml", [Fault::Status(429), Fault::Timeout]);
+
+        assert_eq!(fixture.respond("http://x/a.shtml").map(|p| p.status).ok(), Some(429));
+        assert!(matches!(
+            fixture.respond("http://x/a.shtml"),
+            Err(ScrapeError::Timeout(_))
+        ));
+        assert_eq!(fixture.respond("http://x/a.shtml").map(|p| p.status).ok(), Some(200));
+        assert_eq!(
+            fixture.respond("http://x/missing.shtml").map(|p| p.status).ok(),
+            Some(404)
+        );
+        assert_eq!(fixture.requests().len(), 4);
+    }
+
+    #[test]
+    fn test_from_dir_maps_saved_files_to_their_urls() {
+        let dir = std::env::temp_dir().join(format!("baseballref-fixture-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("CHN202503180.shtml"), "box score").unwrap();
+        std::fs::write(dir.join("2025-schedule.shtml"), "schedule").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let fixture = FixtureFetcher::from_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(fixture.pages.len(), 2);
+        assert_eq!(fixture.pages["/boxes/CHN/CHN202503180.shtml"], "box score");
+        assert_eq!(fixture.pages["/leagues/majors/2025-schedule.shtml"], "schedule");
+    }
+}
@@ -1,5 +1,14 @@
+mod archive;
 mod client;
+mod fetch;
+mod fixture;
+mod pacer;
 mod schedule;
 
-pub use client::{ScrapeError, ScrapeResult, Scraper};
-pub use schedule::{BoxScoreUrl, extract_boxscore_urls, extract_boxscore_urls_from_html, schedule_url_for_year};
+pub use archive::{ArchivingFetcher, PageArchive, compress, content_hash, decompress};
+pub use client::{BASE_URL, GameStore, JobQueue, ScrapeError, ScrapeResult, Scraper};
+pub use fetch::{Fetcher, HttpFetcher, Page};
+pub use fixture::{Fault, FixtureFetcher};
+pub use schedule::{
+    BoxScoreUrl, extract_boxscore_urls, extract_boxscore_urls_from_html, schedule_path_for_year, schedule_url_for_year,
+};
@@ -0,0 +1,78 @@
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use tokio::time::{Instant, sleep_until};
+
+/// Base delay between requests (~2 requests/second)
+pub(super) const BASE_DELAY: Duration = Duration::from_secs(3);
+/// Maximum delay after repeated backoffs
+pub(super) const MAX_DELAY: Duration = Duration::from_mins(5);
+/// Multiplier applied to delay on rate-limit or server errors
+const BACKOFF_MULTIPLIER: f64 = 4.0;
+
+/// Adaptive rate limit shared by every request a scraper makes.
+///
+/// Requests start at least `delay` apart however many games are in flight.
+/// A rate limit or timeout stretches the delay and holds back every request
+/// for that long; successes ease it back toward [`BASE_DELAY`].
+pub(super) struct Pacer {
+    pace: Mutex<Pace>,
+}
+
+struct Pace {
+    delay: Duration,
+    next: Instant,
+}
+
+impl Pacer {
+    pub(super) fn new() -> Self {
+        Self {
+            pace: Mutex::new(Pace {
+                delay: BASE_DELAY,
+                next: Instant::now(),
+            }),
+        }
+    }
+
+    /// The current delay between requests
+    pub(super) fn delay(&self) -> Duration {
+        self.lock().delay
+    }
+
+    /// Wait until the next request may start, and claim its slot
+    pub(super) async fn wait_turn(&self) {
+        loop {
+            // Re-check after every wake: a backoff may have moved the slot
+            let next = {
+                let mut pace = self.lock();
+                let now = Instant::now();
+                if pace.next <= now {
+                    pace.next = now + pace.delay;
+                    return;
+                }
+                pace.next
+            };
+            sleep_until(next).await;
+        }
+    }
+
+    /// Slow down after a rate limit or timeout, returning the new delay
+    pub(super) fn back_off(&self) -> Duration {
+        let mut pace = self.lock();
+        pace.delay = pace.delay.mul_f64(BACKOFF_MULTIPLIER).min(MAX_DELAY);
+        pace.next = Instant::now() + pace.delay;
+        pace.delay
+    }
+
+    /// Ease back toward the base delay after a success
+    pub(super) fn ease(&self) {
+        let mut pace = self.lock();
+        pace.delay = (pace.delay / 2).max(BASE_DELAY);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Pace> {
+        self.pace.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
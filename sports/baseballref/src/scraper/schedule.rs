@@ -2,6 +2,8 @@ use std::path::Path;
 
 use scraper::{Html, Selector};
 
+use super::client::BASE_URL;
+
 /// A box score URL extracted from the schedule
 #[derive(Debug, Clone)]
 pub struct BoxScoreUrl {
@@ -11,6 +13,28 @@ pub struct BoxScoreUrl {
     pub path: String,
 }
 
+impl BoxScoreUrl {
+    /// Build the URL for a game id. The first three characters of a bbref game
+    /// id are the home team code, which is also the directory the page is in.
+    /// Returns None if the id is too short to contain one.
+    pub fn for_game(game_id: &str) -> Option<Self> {
+        if game_id.len() < 11 {
+            return None;
+        }
+        let team_code = game_id.get(..3)?;
+        Some(Self {
+            game_id: game_id.to_string(),
+            path: format!("/boxes/{team_code}/{game_id}.shtml"),
+        })
+    }
+
+    /// The box score at a URL path (e.g. "/boxes/CHN/CHN202503180.shtml"), or
+    /// None if the path isn't a box score's
+    pub fn from_path(path: &str) -> Option<Self> {
+        parse_boxscore_href(path)
+    }
+}
+
 /// Extract all box score URLs from a schedule HTML file
 pub fn extract_boxscore_urls(path: impl AsRef<Path>) -> Result<Vec<BoxScoreUrl>, std::io::Error> {
     let html = std::fs::read_to_string(path)?;
@@ -21,7 +45,13 @@ pub fn extract_boxscore_urls(path: impl AsRef<Path>) -> Result<Vec<BoxScoreUrl>,
 /// Format: <https://www.baseball-reference.com/leagues/majors/{year}-schedule.shtml>
 #[must_use]
 pub fn schedule_url_for_year(year: i32) -> String {
-    format!("https://www.baseball-reference.com/leagues/majors/{year}-schedule.shtml")
+    format!("{BASE_URL}{}", schedule_path_for_year(year))
+}
+
+/// Path of the schedule page for a given year, relative to the site root
+#[must_use]
+pub fn schedule_path_for_year(year: i32) -> String {
+    format!("/leagues/majors/{year}-schedule.shtml")
 }
 
 /// Extract all box score URLs from schedule HTML content
@@ -132,6 +162,21 @@ mod tests {
         assert!(parse_boxscore_href("/teams/CHC/2025.shtml").is_none());
     }
 
+    #[test]
+    fn test_box_score_url_for_game() {
+        let url = BoxScoreUrl::for_game("WS2196504230").expect("valid game id");
+        assert_eq!(url.path, "/boxes/WS2/WS2196504230.shtml");
+        assert_eq!(
+            schedule_url_for_year(2025),
+            "https://www.baseball-reference.com/leagues/majors/2025-schedule.shtml"
+        );
+        assert!(BoxScoreUrl::for_game("CHN").is_none());
+
+        let url = BoxScoreUrl::from_path(&url.path).expect("a box score path");
+        assert_eq!(url.game_id, "WS2196504230");
+        assert!(BoxScoreUrl::from_path(&schedule_path_for_year(2025)).is_none());
+    }
+
     #[test]
     fn test_extract_from_sample_html() {
         let html = r#"